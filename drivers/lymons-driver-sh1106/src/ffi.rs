@@ -9,9 +9,10 @@ use std::ffi::c_char;
 use std::mem::ManuallyDrop;
 
 /// Plugin ABI version
-pub const LYMONS_PLUGIN_ABI_VERSION_MAJOR: u32 = 1;
+pub const LYMONS_PLUGIN_ABI_VERSION_MAJOR: u32 = 2;
 pub const LYMONS_PLUGIN_ABI_VERSION_MINOR: u32 = 0;
 pub const LYMONS_PLUGIN_ABI_VERSION_PATCH: u32 = 0;
+pub const LYMONS_PLUGIN_ABI_MAGIC: u32 = 0x4C59_4D53;
 
 /// Maximum length for error messages
 pub const LYMONS_ERROR_MESSAGE_SIZE: usize = 256;
@@ -152,6 +153,8 @@ pub struct LyMonsDisplayCapabilities {
 /// Plugin vtable - function pointers for all driver operations
 #[repr(C)]
 pub struct LyMonsPluginVTable {
+    pub abi_magic: u32,
+    pub abi_major: u32,
     pub abi_version: extern "C" fn(*mut u32, *mut u32, *mut u32),
     pub plugin_info: extern "C" fn(*mut c_char, *mut c_char, *mut c_char),
     pub create: extern "C" fn(
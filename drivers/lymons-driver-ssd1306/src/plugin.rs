@@ -572,6 +572,8 @@ extern "C" fn set_rotation(
 
 /// Static vtable
 static VTABLE: LyMonsPluginVTable = LyMonsPluginVTable {
+    abi_magic: LYMONS_PLUGIN_ABI_MAGIC,
+    abi_major: LYMONS_PLUGIN_ABI_VERSION_MAJOR,
     abi_version,
     plugin_info,
     create,
@@ -255,6 +255,8 @@ extern "C" fn set_rotation(handle: *mut LyMonsDriverHandle, degrees: u16, error:
 }
 
 static VTABLE: LyMonsPluginVTable = LyMonsPluginVTable {
+    abi_magic: LYMONS_PLUGIN_ABI_MAGIC,
+    abi_major: LYMONS_PLUGIN_ABI_VERSION_MAJOR,
     abi_version, plugin_info, create, destroy, capabilities, init,
     set_brightness, flush, clear, write_buffer, set_invert, set_rotation,
 };
@@ -0,0 +1,135 @@
+/*
+ *  assets.rs
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  Asset directory lookup - every SVG/layout path is built from here
+ *
+ *  The assets directory is searched for once, in order:
+ *    $LYMONS_ASSET_PATH     the assets directory itself
+ *    ./assets               relative to the working directory
+ *    /usr/share/lymons/assets
+ *  If none exists a single warning is logged and ./assets is used anyway,
+ *  so a binary started from the wrong directory says why the logo, weather
+ *  icons and meters are missing instead of quietly drawing nothing.
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *  Public License.
+ *
+ */
+
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use log::{info, warn};
+
+/// Points at the assets directory, overriding the search
+pub const ENV_VAR: &str = "LYMONS_ASSET_PATH";
+
+/// Assets relative to the working directory, also the fallback when nothing is found
+pub const LOCAL_DIR: &str = "./assets";
+
+/// Where packaged installs put the assets
+pub const SYSTEM_DIR: &str = "/usr/share/lymons/assets";
+
+static ROOT: OnceLock<String> = OnceLock::new();
+
+/// First candidate that is a directory: the env override, then `local`, then `system`
+pub fn resolve_root(env: Option<&Path>, local: &Path, system: &Path) -> Option<PathBuf> {
+    env.into_iter()
+        .chain([local, system])
+        .find(|dir| dir.is_dir())
+        .map(Path::to_path_buf)
+}
+
+/// The assets directory, searched for on first use
+pub fn root() -> &'static str {
+    ROOT.get_or_init(|| {
+        let env = std::env::var_os(ENV_VAR).map(PathBuf::from);
+        if let Some(dir) = env.as_deref().filter(|d| !d.is_dir()) {
+            warn!("assets: {ENV_VAR}={} is not a directory, ignored", dir.display());
+        }
+        match resolve_root(env.as_deref(), Path::new(LOCAL_DIR), Path::new(SYSTEM_DIR)) {
+            Some(dir) => {
+                let dir = dir.to_string_lossy().trim_end_matches('/').to_string();
+                if dir != LOCAL_DIR {
+                    info!("assets: using {dir}");
+                }
+                dir
+            }
+            None => {
+                warn!(
+                    "assets: no assets directory found (tried ${ENV_VAR}, {LOCAL_DIR}, {SYSTEM_DIR}) - \
+                     logo, weather icons and meters will be missing; run from the install \
+                     directory or set {ENV_VAR}"
+                );
+                LOCAL_DIR.to_string()
+            }
+        }
+    })
+}
+
+/// Path of `relative` inside the assets directory, e.g. `path("mono/")` or
+/// `path("alert.svg")`; a trailing `/` is kept for folder prefixes.
+pub fn path(relative: &str) -> String {
+    format!("{}/{}", root(), relative.trim_start_matches('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!("lymons-assets-{}-{name}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn env_then_local_then_system() {
+        let tmp = TempDir::new("order");
+        let (env, local, system) = (tmp.0.join("env"), tmp.0.join("local"), tmp.0.join("system"));
+        let resolve = || resolve_root(Some(&env), &local, &system);
+
+        assert_eq!(resolve(), None);
+        std::fs::create_dir(&system).unwrap();
+        assert_eq!(resolve(), Some(system.clone()));
+        std::fs::create_dir(&local).unwrap();
+        assert_eq!(resolve(), Some(local.clone()));
+        std::fs::create_dir(&env).unwrap();
+        assert_eq!(resolve(), Some(env.clone()));
+
+        // a missing override falls through to the search
+        assert_eq!(resolve_root(Some(&tmp.0.join("nope")), &local, &system), Some(local));
+        assert_eq!(resolve_root(None, &tmp.0.join("nope"), &system), Some(system));
+    }
+
+    #[test]
+    fn paths_join_under_the_root() {
+        let alert = path("alert.svg");
+        assert!(alert.ends_with("/alert.svg") && !alert.contains("//"));
+        assert!(path("mono/").ends_with("/mono/"));
+    }
+}
@@ -12,6 +12,9 @@
  *    {font}_space.svg             — blank (for blinking colon)
  *    {font}_minus.svg             — minus sign
  *
+ *  7seg is also built in, so a missing or incomplete font zip always
+ *  falls back to readable digits rather than failing the display.
+ *
  *  All SVGs have a 25×44 viewBox.  Characters are rendered at load time
  *  to a 1bpp packed pixel mask at the display-appropriate size:
  *    display height ≤ 70px  →  25 × 44  (1:1)
@@ -38,7 +41,7 @@
 #![allow(dead_code)]
 
 use log::{info, warn};
-use std::io::Read;
+use std::io::{Read, Seek};
 
 // Character slot indices (13 total)
 const IDX_0: usize = 0;
@@ -56,6 +59,12 @@ const SVG_HEIGHT: u32 = 44;
 const SIZE_NORMAL: (u32, u32) = (25, 44);   // height ≤ 70
 const SIZE_LARGE:  (u32, u32) = (60, 105);  // height > 70 (ST7789)
 
+/// Font used when the requested one cannot be loaded
+pub const FALLBACK_FONT: &str = "7seg";
+
+/// `data/7seg.zip`, compiled in so the fallback never depends on the install
+const BUILTIN_FALLBACK: &[u8] = include_bytes!("../data/7seg.zip");
+
 // Clock digit layout
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ClockDigitLayout {
@@ -77,6 +86,8 @@ pub enum ClockDigitLayout {
 ///
 /// Owns all pixel data — no lifetime parameter.
 pub struct ClockFontData {
+    /// Font actually loaded - `FALLBACK_FONT` when the requested one was unusable
+    pub name:         String,
     pub digit_width:  u32,
     pub digit_height: u32,
     pub digit_layout: ClockDigitLayout,
@@ -86,6 +97,17 @@ pub struct ClockFontData {
 }
 
 impl ClockFontData {
+    /// Font with transparent `width` x `height` glyphs
+    pub fn blank(width: u32, height: u32) -> Self {
+        Self {
+            name: String::new(),
+            digit_width: width,
+            digit_height: height,
+            digit_layout: ClockDigitLayout::StandardTime,
+            chars: std::array::from_fn(|_| vec![0u8; (width * height * 4) as usize]),
+        }
+    }
+
     /// Return the RGBA data for `c` as a flat slice (`w * h * 4` bytes).
     pub fn get_char_rgba(&self, c: char) -> Option<&[u8]> {
         let data = match c {
@@ -150,35 +172,49 @@ fn load_from_zip(font_name: &str, width: u32, height: u32) -> Option<[Vec<u8>; C
     let file = std::fs::File::open(&path).map_err(|e| {
         warn!("Cannot open font zip {}: {}", path, e);
     }).ok()?;
+    load_from_archive(file, font_name, &path, width, height)
+}
 
-    let mut archive = zip::ZipArchive::new(file).map_err(|e| {
+/// Render all 13 characters of `font_name` from a font zip; `None` if any is missing.
+fn load_from_archive<R: Read + Seek>(
+    reader: R,
+    font_name: &str,
+    path: &str,
+    width: u32,
+    height: u32,
+) -> Option<[Vec<u8>; CHAR_COUNT]> {
+    let mut archive = zip::ZipArchive::new(reader).map_err(|e| {
         warn!("Cannot read zip {}: {}", path, e);
     }).ok()?;
 
     // Build the array; using a loop + collect into array
     let mut chars: [Vec<u8>; CHAR_COUNT] = Default::default();
-    for idx in 0..CHAR_COUNT {
+    for (idx, slot) in chars.iter_mut().enumerate() {
         let entry_name = format!("{}_{}.svg", font_name, char_name(idx));
-        match archive.by_name(&entry_name) {
-            Ok(mut entry) => {
-                let mut svg_bytes = Vec::new();
-                if entry.read_to_end(&mut svg_bytes).is_ok() {
-                    chars[idx] = render_svg_to_rgba(&svg_bytes, width, height);
-                } else {
-                    warn!("Failed to read {} from zip", entry_name);
-                    chars[idx] = vec![0u8; (width * height * 4) as usize];
-                }
-            }
-            Err(_) => {
-                warn!("Entry {} not found in {}", entry_name, path);
-                chars[idx] = vec![0u8; (width * height) as usize];
-            }
+        let Ok(mut entry) = archive.by_name(&entry_name) else {
+            warn!("Entry {} not found in {}", entry_name, path);
+            return None;
+        };
+        let mut svg_bytes = Vec::new();
+        if entry.read_to_end(&mut svg_bytes).is_err() {
+            warn!("Failed to read {} from {}", entry_name, path);
+            return None;
         }
+        *slot = render_svg_to_rgba(&svg_bytes, width, height);
     }
 
     Some(chars)
 }
 
+/// The compiled-in 7seg font; correctly sized blank glyphs should even that fail
+fn load_builtin(width: u32, height: u32) -> [Vec<u8>; CHAR_COUNT] {
+    load_from_archive(std::io::Cursor::new(BUILTIN_FALLBACK), FALLBACK_FONT, "built-in 7seg", width, height)
+        .unwrap_or_else(|| {
+            warn!("Built-in 7seg font unusable, clock digits will be blank");
+            std::array::from_fn(|_| vec![0u8; (width * height * 4) as usize])
+        })
+}
+
 // ── Public API ────────────────────────────────────────────────────────────────
 
 /// Load a clock font by name for the given display height.
@@ -187,7 +223,7 @@ fn load_from_zip(font_name: &str, width: u32, height: u32) -> Option<[Vec<u8>; C
 ///   7seg, dejavu, dotty, gawker, grandes, ledreal,
 ///   marvel, moomy, noto, poppins, roboto
 ///
-/// Falls back to `7seg` if the requested font cannot be loaded.
+/// Falls back to the built-in `7seg` if the requested font cannot be loaded.
 ///
 /// Added logic for additional screen sizes (ST7789)
 /// 
@@ -195,7 +231,12 @@ pub fn set_clock_font(font_name: &str, display_width: u32, display_height: u32)
     let mut layout = ClockDigitLayout::StandardTime;
     let (mut width, mut height) = if display_height > 70 { SIZE_LARGE } else { SIZE_NORMAL };
 
-    if 5*width > display_width {
+    if display_height > display_width {
+        // portrait - stack HH over MM at the normal digit proportions
+        layout = ClockDigitLayout::SquareTime;
+        width  = display_width / 3;
+        height = (width * SIZE_NORMAL.1 / SIZE_NORMAL.0).min(display_height / 3);
+    } else if 5*width > display_width {
         warn!("Display width {} too small for font width {}, scaling down", display_width, width);
         let scale = display_width as f32 / (6.0 * width as f32); // 6 as we need buffer at start end
         width  = (width  as f32 * scale) as u32;
@@ -209,20 +250,41 @@ pub fn set_clock_font(font_name: &str, display_width: u32, display_height: u32)
     }
     info!("Load SVG clock font: {} @ {}×{}", font_name, width, height);
 
-    // Try requested font, then fall back to 7seg
-    let chars = load_from_zip(font_name, width, height)
-        .or_else(|| {
-            if font_name != "7seg" {
-                warn!("Font '{}' not found, falling back to 7seg", font_name);
-                load_from_zip("7seg", width, height)
-            } else {
-                None
-            }
-        })
-        .unwrap_or_else(|| {
-            warn!("7seg zip also missing — using blank glyphs");
-            Default::default()
-        });
+    // Try requested font, then fall back to the built-in 7seg
+    let (name, chars) = match load_from_zip(font_name, width, height) {
+        Some(chars) => (font_name.to_string(), chars),
+        None => {
+            warn!("Clock font '{}' unusable, falling back to built-in {}", font_name, FALLBACK_FONT);
+            (FALLBACK_FONT.to_string(), load_builtin(width, height))
+        }
+    };
+
+    ClockFontData { name, digit_width: width, digit_height: height, digit_layout: layout, chars }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    ClockFontData { digit_width: width, digit_height: height, digit_layout: layout, chars }
+    fn lit(font: &ClockFontData, c: char) -> usize {
+        font.get_char_rgba(c).unwrap().chunks(4).filter(|px| px[3] >= 128).count()
+    }
+
+    #[test]
+    fn unknown_font_falls_back_to_builtin_7seg() {
+        let font = set_clock_font("no-such-font", 128, 64);
+        assert_eq!(font.name, FALLBACK_FONT);
+        let size = (font.digit_width * font.digit_height * 4) as usize;
+        for c in "0123456789: -".chars() {
+            assert_eq!(font.get_char_rgba(c).unwrap().len(), size, "{c:?}");
+        }
+        assert!(lit(&font, '8') > lit(&font, '1'), "fallback digits are drawn");
+    }
+
+    #[test]
+    fn builtin_matches_the_shipped_7seg_zip() {
+        let builtin = load_builtin(25, 44);
+        let shipped = load_from_zip(FALLBACK_FONT, 25, 44).expect("data/7seg.zip");
+        assert!(builtin == shipped);
+    }
 }
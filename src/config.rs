@@ -26,11 +26,15 @@ pub enum ConfigError {
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
 pub struct WeatherConfig {
-    pub api:       Option<String>,  // Tomorrow.io API key
-    pub units:     Option<String>,  // "metric" | "imperial"
-    pub translate: Option<String>,  // language/translation code
-    pub latitude:  Option<f64>,
-    pub longitude: Option<f64>,
+    pub api:          Option<String>,  // provider API key
+    pub provider:     Option<String>,  // "tomorrow" (default) | "owm"
+    pub units:        Option<String>,  // "metric" | "imperial"
+    pub translate:    Option<String>,  // language/translation code
+    pub latitude:     Option<f64>,
+    pub longitude:    Option<f64>,
+    pub refresh_mins: Option<u64>,     // provider poll interval - defaults to 35, floored at the provider minimum
+    pub forecast_days: Option<usize>,  // forecast page day limit - defaults to all the layout fits
+    pub temperature:  Option<crate::display::components::weather::TemperatureShown>,  // "actual" | "feels_like" | "both" - defaults to both
 }
 
 impl WeatherConfig {
@@ -39,6 +43,21 @@ impl WeatherConfig {
         self.api.as_deref().map(|k| !k.is_empty()).unwrap_or(false)
     }
 
+    /// Resolve the configured provider, defaulting to tomorrow.io.
+    pub fn provider_kind(&self) -> crate::weather::ProviderKind {
+        crate::weather::ProviderKind::parse(self.provider.as_deref().unwrap_or(""))
+            .unwrap_or_default()
+    }
+
+    /// Provider poll interval, never below what the provider's free tier allows.
+    pub fn refresh_interval(&self) -> std::time::Duration {
+        let min = self.provider_kind().min_refresh();
+        self.refresh_mins
+            .map(|m| std::time::Duration::from_secs(m.saturating_mul(60)))
+            .unwrap_or(crate::weather::DEFAULT_REFRESH)
+            .max(min)
+    }
+
     /// Normalise units to the string Tomorrow.io expects.
     pub fn normalised_units(&self) -> String {
         match self.units.as_deref().unwrap_or("metric").to_lowercase().as_str() {
@@ -48,6 +67,191 @@ impl WeatherConfig {
     }
 }
 
+/// VU meter calibration - all optional, unset fields keep the standard scale.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
+pub struct VuConfig {
+    pub reference_offset_db: Option<f64>,  // added to the input level, + for quiet sources
+    pub floor_db:            Option<f64>,  // dB at the left stop - defaults to -23
+    pub ceil_db:             Option<f64>,  // dB at the right stop - defaults to +4.8
+    pub peak_hold_ms:        Option<u64>,  // peak meter hold marker stays put this long - defaults to 500
+    pub peak_decay:          Option<f32>,  // then falls at this many LEDs per second - defaults to 8
+    pub combi_peak:          Option<crate::visualization::CombiPeak>,  // combination peak column: centre | left | right
+    pub combi_peak_width:    Option<f32>,  // its share of the panel width, 0.04-0.5 - unset keeps the asset's
+}
+
+impl VuConfig {
+    /// Resolve against the standard VU scale.
+    pub fn calibration(&self) -> crate::vuphysics_new::VuCalibration {
+        let std = crate::vuphysics_new::VuCalibration::default();
+        crate::vuphysics_new::VuCalibration {
+            reference_offset_db: self.reference_offset_db.unwrap_or(std.reference_offset_db),
+            floor_db: self.floor_db.unwrap_or(std.floor_db),
+            ceil_db: self.ceil_db.unwrap_or(std.ceil_db),
+        }
+    }
+
+    /// Combination meter arrangement, unset fields keep the asset's.
+    pub fn combi_layout(&self) -> crate::visualization::CombiLayout {
+        crate::visualization::CombiLayout {
+            peak: self.combi_peak.unwrap_or_default(),
+            peak_width: self.combi_peak_width,
+        }
+    }
+
+    /// Peak meter hold marker timing, unset fields keep the histogram cap feel.
+    pub fn peak_hold(&self) -> crate::vision::PeakHold {
+        let std = crate::vision::PeakHold::default();
+        crate::vision::PeakHold {
+            hold: self.peak_hold_ms.map_or(std.hold, std::time::Duration::from_millis),
+            decay_rate: self.peak_decay.unwrap_or(std.decay_rate),
+        }
+    }
+}
+
+/// Spectrum band AGC - leave the section out for raw levels.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
+pub struct SpectrumAgcConfig {
+    pub attack_ms:  Option<u64>,  // running maximum rises this fast - defaults to 50
+    pub release_ms: Option<u64>,  // and falls back this slowly - defaults to 3000
+}
+
+impl SpectrumAgcConfig {
+    pub fn settings(&self) -> crate::spectrum::AgcSettings {
+        let std = crate::spectrum::AgcSettings::default();
+        crate::spectrum::AgcSettings {
+            attack: self.attack_ms.map_or(std.attack, std::time::Duration::from_millis),
+            release: self.release_ms.map_or(std.release, std::time::Duration::from_millis),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
+pub struct BurnInConfig {
+    pub enabled:       Option<bool>,  // defaults to true when the block is present
+    pub max_shift_px:  Option<u8>,    // jitter box half-size, 1-4 - defaults to 1
+    pub interval_secs: Option<u64>,   // seconds between 1px moves - defaults to 60
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
+pub struct SlideshowConfig {
+    pub dir:           Option<String>,  // folder of PNG/JPEG/SVG images - idle stays on the clock without it
+    pub interval_secs: Option<u64>,     // seconds per image - defaults to 30
+}
+
+impl SlideshowConfig {
+    pub fn interval(&self) -> std::time::Duration {
+        self.interval_secs
+            .map(std::time::Duration::from_secs)
+            .unwrap_or(crate::display::components::slideshow::DEFAULT_INTERVAL)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
+pub struct QrCodeConfig {
+    pub template:      Option<String>,  // URL with {artist} {title} {album} - defaults to "artist – title" text
+    pub interval_mins: Option<u64>,     // show the code this often while playing - unset, on demand only
+}
+
+impl QrCodeConfig {
+    pub fn interval(&self) -> Option<std::time::Duration> {
+        self.interval_mins.filter(|&m| m > 0).map(|m| std::time::Duration::from_secs(m * 60))
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
+pub struct SleepTimerConfig {
+    pub minutes: Option<u64>,   // start counting down this long at launch - unset, only on demand
+    pub pause:   Option<bool>,  // pause the player when it runs out - defaults to false
+}
+
+impl SleepTimerConfig {
+    /// Countdown started at launch, if any
+    pub fn at_launch(&self) -> Option<std::time::Duration> {
+        self.minutes.filter(|&m| m > 0).map(|m| std::time::Duration::from_secs(m * 60))
+    }
+
+    /// Length of an on demand timer, the launch one or half an hour
+    pub fn length(&self) -> std::time::Duration {
+        self.at_launch().unwrap_or(crate::display::components::sleep_timer::DEFAULT_SLEEP)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
+pub struct HistoryConfig {
+    pub length: Option<usize>,   // tracks kept - defaults to 8
+    pub file:   Option<String>,  // JSON file so the list survives restarts - memory only without it
+}
+
+impl HistoryConfig {
+    pub fn length(&self) -> usize {
+        self.length.unwrap_or(crate::display::components::history::DEFAULT_LENGTH)
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
+pub struct MqttConfig {
+    pub broker:       Option<String>,  // host or host:port (default port 1883) - publishing is off without it
+    pub topic_prefix: Option<String>,  // defaults to "lymons"
+    pub client_id:    Option<String>,  // defaults to lymons-<pid>
+    pub username:     Option<String>,
+    pub password:     Option<String>,
+}
+
+impl MqttConfig {
+    /// Broker as host:port, or None when unset.
+    pub fn broker_addr(&self) -> Option<String> {
+        let broker = self.broker.as_deref().map(str::trim).filter(|b| !b.is_empty())?;
+        if broker.rsplit_once(':').is_some_and(|(_, port)| port.parse::<u16>().is_ok()) {
+            Some(broker.to_string())
+        } else {
+            Some(format!("{broker}:1883"))
+        }
+    }
+
+    pub fn topic_prefix(&self) -> String {
+        self.topic_prefix.as_deref()
+            .map(|p| p.trim_end_matches('/'))
+            .filter(|p| !p.is_empty())
+            .unwrap_or("lymons")
+            .to_string()
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
+pub struct HttpStatusConfig {
+    pub port: Option<u16>,     // status server is off without it
+    pub bind: Option<String>,  // listen address - defaults to 0.0.0.0
+}
+
+impl HttpStatusConfig {
+    /// Listen address as host:port, or None when no port is set.
+    pub fn listen_addr(&self) -> Option<String> {
+        let port = self.port?;
+        let bind = self.bind.as_deref().map(str::trim).filter(|b| !b.is_empty()).unwrap_or("0.0.0.0");
+        Some(format!("{bind}:{port}"))
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
+pub struct MetricsConfig {
+    pub fields:     Option<Vec<String>>,  // cpu, cpu_temp, mem, disk, net, fps, uptime - in display order
+    pub temp_units: Option<String>,       // "C" | "F" - defaults to C
+    pub interface:  Option<String>,       // network interface for `net` - defaults to the busiest non-loopback
+}
+
+impl MetricsConfig {
+    /// Resolve against the default metrics line.
+    pub fn format(&self) -> crate::metrics::MetricsFormat {
+        let fahrenheit = self.temp_units.as_deref()
+            .map(|u| u.eq_ignore_ascii_case("f"))
+            .unwrap_or(false);
+        match &self.fields {
+            Some(fields) => crate::metrics::MetricsFormat::from_names(fields, fahrenheit),
+            None => crate::metrics::MetricsFormat { fahrenheit, ..Default::default() },
+        }
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
 pub struct DisplayConfig {
     pub width:      Option<u32>,
@@ -58,6 +262,20 @@ pub struct DisplayConfig {
     pub driver:     Option<DriverKind>,
     pub bus:        Option<BusConfig>,
     pub emulated:   Option<bool>,
+    pub progress_bar: Option<crate::display::layout_manager::ProgressPosition>,  // top | bottom - scroller progress bar + info line
+    pub track_lines: Option<Vec<crate::display::components::scrollers::TrackTag>>,  // scroller rows top down, e.g. [composer, album, title]
+    pub frame_cap:  Option<u32>,    // max frames per second, sleep out the rest of each frame - unset or 0 is uncapped
+    pub bus_retries: Option<u32>,   // retries for a frame transfer hitting a transient bus error - defaults to 3
+    pub watchdog_failures: Option<u32>,      // failed frames in a row before the driver is re-initialised - defaults to 5, 0 disables
+    pub watchdog_timeout_secs: Option<u64>,  // or frames failing for this long - defaults to 10
+}
+
+/// Another panel on the same Pi, fed from the main panel's LMS and weather state
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
+pub struct SecondDisplayConfig {
+    #[serde(flatten)]
+    pub display: DisplayConfig,
+    pub mode:    Option<crate::display::DisplayMode>,  // hold one mode, e.g. clock - unset follows the main panel
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -90,29 +308,118 @@ pub enum DriverKind {
     St7796s,
 }
 
+impl DriverKind {
+    /// Canonical lowercase name, as given to `-d` and used for plugin and asset folders
+    pub fn name(&self) -> &'static str {
+        match self {
+            DriverKind::Ssd1306     => "ssd1306",
+            DriverKind::Ssd1309     => "ssd1309",
+            DriverKind::Ssd1322     => "ssd1322",
+            DriverKind::Sh1106      => "sh1106",
+            DriverKind::Sh1122      => "sh1122",
+            DriverKind::SharpMemory => "sharpmemory",
+            DriverKind::St7789      => "st7789",
+            DriverKind::St7796s     => "st7796s",
+        }
+    }
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
 pub struct Config {
     pub log_level:      Option<String>,  // "info" | "debug"
-    pub player:         Option<String>,  // LMS player name to monitor
+    pub player:         Option<String>,  // LMS player name to monitor, or mac:aa:bb:.. for its MAC
     pub text_font:      Option<String>,  // TTF font name (zip in ./data/)
     pub text_font_size: Option<f32>,     // TTF font size in points - defaults to 9.0
-    pub scroll_mode:    Option<String>,  // "cylon" | "loop" | "loopleft"
+    pub scroll_mode:    Option<String>,  // "cylon" | "cylon_eased" | "loop" | "loopleft"
+    pub loop_pause_ms:  Option<u64>,     // hold at start after each loop pass - defaults to 0
+    pub loop_gap:       Option<crate::textable::LoopGap>, // pixels (24) or line width fraction (0.25) before the repeat
+    pub info_line:      Option<Vec<crate::display::components::info_line::InfoToken>>, // left, centre, right - defaults to [elapsed, mode, end]
+    pub cylon_trail:    Option<usize>,   // ticks of fading afterglow behind cylon text on grayscale panels, 0-6 - defaults to 0
+    pub sanitize_text:  Option<bool>,    // swap emoji and glyphs the bitmap fonts lack for stand-ins - defaults to true
+    pub scroll_poll_ms: Option<u64>,     // main loop sleep per frame while scrolling - defaults to 50
+    pub clock_poll_ms:  Option<u64>,     // on the clock - defaults to 100
+    pub viz_poll_ms:    Option<u64>,     // with the visualizer up - defaults to 36
     pub show_remaining: Option<bool>,
     pub clock_font:     Option<String>,
+    pub date_format:    Option<String>,  // chrono strftime for the clock date line - defaults to "%a %b %d"
+    pub show_seconds:   Option<bool>,    // HH:MM:SS clock where the panel is wide enough
     pub easter_egg:     Option<String>,
+    pub egg_rotate_mins: Option<u64>,    // cycle through the eggs this often - unset keeps one egg
     pub visualizer:     Option<String>,
+    pub viz_source:     Option<String>,  // "shm" (default) | "fifo:/path" - raw stereo s16le PCM at 44.1k
     pub hist_scheme:    Option<String>,  // "classic" | "ocean" | "fire" | "neon"
+    pub spectrum_agc:   Option<SpectrumAgcConfig>,  // normalize histogram bands, unset keeps raw levels
+    pub viz_downmix:    Option<crate::visualizer::DownmixMode>,  // "average" | "rms_sum" | "max" stereo to mono - unset: rms_sum meters, max histograms
+    pub svg_dither:     Option<String>,  // Gray4 SVG dithering: "none" | "bayer" | "floyd" - defaults to none
     pub show_metrics:   Option<bool>,
+    pub metrics:        Option<MetricsConfig>,
     pub show_splash:    Option<bool>,
+    pub splash_ms:      Option<u64>,     // splash hold in milliseconds - defaults to 2000
+    pub splash_wait:    Option<bool>,    // false draws the splash and carries on initialising - defaults to true
+    pub no_lms:         Option<bool>,    // desk clock: no LMS server, only clock/weather/slideshow - player not needed
+    pub mac_interface:  Option<String>,  // network interface whose MAC identifies the player to LMS - defaults to the first MAC reported
+    pub locale:         Option<String>,  // display language - "en" | "de" built in, others translate weather online - defaults to the weather language
     pub i2c_bus:        Option<String>,
     /// Standalone lat/lon — fallback for astral when weather is not configured.
     pub latitude:       Option<f64>,
     pub longitude:      Option<f64>,
     pub display:        Option<DisplayConfig>,
+    pub second_display: Option<SecondDisplayConfig>,  // a second panel, needs its own bus
     pub weather:        Option<WeatherConfig>,
+    pub vu:             Option<VuConfig>,
+    pub burn_in:        Option<BurnInConfig>,
+    pub slideshow:      Option<SlideshowConfig>,
+    pub history:        Option<HistoryConfig>,  // recently played idle screen, large panels only
+    pub big_title:      Option<bool>,    // now playing as the title alone in the largest font that fits
+    pub qr_code:        Option<QrCodeConfig>,  // now playing QR code overlay, timed or on demand
+    pub sleep_timer:    Option<SleepTimerConfig>,  // countdown, optionally pausing the player at zero
+    pub idle_behavior:  Option<crate::display::IdleBehavior>,  // "clock" | "blank" | "dim_clock" while stopped - defaults to clock
+    pub inactivity_timeout_mins: Option<u32>,  // power the panel off after this long stopped, 0/unset never
+    pub theme:          Option<crate::display::theme::ColorTheme>,  // field name -> colour, greyscale/colour panels only
+    pub mqtt:           Option<MqttConfig>,
+    pub http_status:    Option<HttpStatusConfig>,
+    /// CLI only: run the panel self-test then exit.
+    #[serde(skip)]
+    pub self_test:      Option<bool>,
+    /// CLI only: record the emulator panel to a GIF then exit.
+    #[serde(skip)]
+    pub record_gif:     Option<GifCapture>,
+}
+
+/// `--record-gif SECONDS PATH`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GifCapture {
+    pub seconds: u64,
+    pub path:    PathBuf,
+}
+
+impl GifCapture {
+    pub const MAX_SECONDS: u64 = 600;
+
+    fn parse(args: &[String]) -> Result<Self, ConfigError> {
+        let [seconds, path] = args else {
+            return Err(ConfigError::Validation("--record-gif takes SECONDS and PATH".to_string()));
+        };
+        match seconds.parse::<u64>() {
+            Ok(s) if (1..=Self::MAX_SECONDS).contains(&s) => Ok(Self { seconds: s, path: PathBuf::from(path) }),
+            _ => Err(ConfigError::Validation(format!(
+                "--record-gif: '{}' is not a length in seconds (1-{})", seconds, Self::MAX_SECONDS
+            ))),
+        }
+    }
 }
 
 impl Config {
+    /// How long `splash` blocks: the configured hold, or nothing with `splash_wait: false`.
+    pub fn splash_hold(&self) -> std::time::Duration {
+        if self.splash_wait == Some(false) {
+            return std::time::Duration::ZERO;
+        }
+        self.splash_ms
+            .map(std::time::Duration::from_millis)
+            .unwrap_or(crate::display::manager::DEFAULT_SPLASH_HOLD)
+    }
+
     /// Resolve lat/lon: weather config first, standalone fallback second.
     /// Returns `(None, None)` when neither is set — callers should GeoIP.
     pub fn effective_lat_lng(&self) -> (Option<f64>, Option<f64>) {
@@ -134,8 +441,25 @@ impl Config {
             if wc.latitude.is_none()  { wc.latitude  = lat; }
             if wc.longitude.is_none() { wc.longitude = lon; }
         }
+        if wc.translate.is_none() {
+            wc.translate = self.locale.clone();
+        }
         Some(wc)
     }
+
+    /// Info line slots, the stock elapsed | mode | end unless configured
+    pub fn info_line_slots(&self) -> crate::display::components::info_line::InfoLineSlots {
+        self.info_line.as_deref()
+            .and_then(crate::display::components::info_line::InfoLineSlots::from_tokens)
+            .unwrap_or_default()
+    }
+
+    /// Display language: `locale`, else the weather language
+    pub fn language(&self) -> Option<&str> {
+        self.locale.as_deref()
+            .or_else(|| self.weather.as_ref().and_then(|w| w.translate.as_deref()))
+            .filter(|lang| !lang.is_empty())
+    }
 }
 
 #[derive(Debug, Parser, Clone)]
@@ -155,15 +479,15 @@ pub struct Cli {
     #[arg(short = 'v', long, alias = "verbose", action = ArgAction::SetTrue)]
     pub debug: bool,
 
-    /// LMS player name to monitor (required unless set in config file)
+    /// LMS player name, or mac:<MAC> to pick it by MAC, to monitor (required unless set in config file or --no-lms)
     #[arg(short = 'N', long)]
     pub name: Option<String>,
 
-    /// Weather: API key,units,lang,latitude,longitude (comma-separated)
+    /// Weather: API key,units,lang,latitude,longitude (comma-separated); add provider=owm for OpenWeatherMap, refresh=<mins> for the poll interval
     #[arg(short = 'W', long = "weather", value_name = "WEATHER")]
     pub weather: Option<String>,
 
-    /// Tomorrow.io API key (overrides --weather key field)
+    /// Weather provider API key (overrides --weather key field)
     #[arg(long = "weather-api")]
     pub weather_api: Option<String>,
 
@@ -183,10 +507,26 @@ pub struct Cli {
     #[arg(long)]
     pub lon: Option<f64>,
 
-    /// Text scroll mode
-    #[arg(short = 'z', long, value_parser = ["loop", "loopleft", "cylon"])]
+    /// Text scroll mode (cylon_eased slows into each edge)
+    #[arg(short = 'z', long, value_parser = ["loop", "loopleft", "cylon", "cylon_eased"])]
     pub scroll: Option<String>,
 
+    /// Pause in milliseconds at the start position after each loop/loopleft pass
+    #[arg(long = "loop-pause", value_name = "MS")]
+    pub loop_pause: Option<u64>,
+
+    /// Main loop sleep in milliseconds per frame while scrolling (10-1000, default 50)
+    #[arg(long = "scroll-poll", value_name = "MS")]
+    pub scroll_poll: Option<u64>,
+
+    /// Main loop sleep in milliseconds per frame on the clock (10-1000, default 100)
+    #[arg(long = "clock-poll", value_name = "MS")]
+    pub clock_poll: Option<u64>,
+
+    /// Main loop sleep in milliseconds per frame with the visualizer up (10-1000, default 36)
+    #[arg(long = "viz-poll", value_name = "MS")]
+    pub viz_poll: Option<u64>,
+
     /// Show remaining time instead of total duration
     #[arg(short = 'r', long, action = ArgAction::SetTrue)]
     pub remain: bool,
@@ -209,16 +549,44 @@ pub struct Cli {
           value_parser = ["bass","blackfly","cassette","ibmpc","moog","pipboy","radio40","radio50","reel2reel","scope","technics","tubeamp","tvtime","vcr","none"])]
     pub eggs: Option<String>,
 
+    /// Rotate through the easter eggs every N minutes
+    #[arg(long = "egg-rotate", value_name = "MINS")]
+    pub egg_rotate: Option<u64>,
+
     /// Skip splash screen
     #[arg(long, action = ArgAction::SetTrue)]
     pub no_splash: bool,
 
+    /// Hold the splash screen this many milliseconds (default 2000)
+    #[arg(long = "splash-ms", value_name = "MS")]
+    pub splash_ms: Option<u64>,
+
+    /// Draw the splash screen but carry on initialising instead of holding it
+    #[arg(long, action = ArgAction::SetTrue)]
+    pub no_splash_wait: bool,
+
+    /// Run without an LMS server: clock, weather and slideshow only, no player needed
+    #[arg(long, action = ArgAction::SetTrue)]
+    pub no_lms: bool,
+
     /// Show device metrics overlay
     #[arg(short = 'k', long, action = ArgAction::SetTrue)]
     pub metrics: bool,
 
-    /// I2C bus device path
-    #[arg(long, default_value = "/dev/i2c-1")]
+    /// Network interface whose MAC identifies this player to LMS (e.g. end0) [default: first MAC reported]
+    #[arg(long = "mac-interface", value_name = "IFACE")]
+    pub mac_interface: Option<String>,
+
+    /// Display language for labels and weather conditions, built in: en, de [default: the weather language]
+    #[arg(long, value_name = "LANG")]
+    pub locale: Option<String>,
+
+    /// Start a sleep timer of this many minutes; sleep_timer.pause pauses the player when it runs out
+    #[arg(long, value_name = "MINUTES")]
+    pub sleep: Option<u64>,
+
+    /// I2C bus device path [default: /dev/i2c-1]
+    #[arg(long)]
     pub i2c_bus: Option<String>,
 
     /// [Internal] emulation mode
@@ -230,9 +598,13 @@ pub struct Cli {
           value_parser = ["ssd1306","ssd1309","ssd1322","sh1106","sh1122","sharpmemory","st7789","st7796s"])]
     pub driver: Option<String>,
 
+    /// Rotate the panel clockwise: 0, 90, 180 or 270 degrees
+    #[arg(long = "rotate", value_name = "DEG")]
+    pub rotate: Option<u16>,
+
     /// Visualizer type
     #[arg(short = 'a', long = "viz",
-          value_parser = ["combination","hist_aio","hist_mono","hist_stereo","peak_mono","peak_stereo","vu_aio","vu_mono","vu_stereo","waveform_spectrum","no_viz"])]
+          value_parser = ["combination","hist_aio","hist_mono","hist_mirror","hist_stereo","peak_mono","peak_stereo","vu_aio","vu_mono","vu_stereo","waveform_spectrum","oscilloscope","no_viz"])]
     pub viz: Option<String>,
 
     /// Histogram colour scheme
@@ -242,15 +614,86 @@ pub struct Cli {
     /// Print fully merged config and exit
     #[arg(long, action = ArgAction::SetTrue)]
     pub dump_config: bool,
+
+    /// Run the panel self-test pattern (pixels, contrast, resolution) and exit
+    #[arg(long, action = ArgAction::SetTrue)]
+    pub selftest: bool,
+
+    /// List built-in and plugin display drivers with their load status, then exit
+    #[arg(long, action = ArgAction::SetTrue)]
+    pub list_drivers: bool,
+
+    /// Print the field rectangles of one page for the configured display, then exit
+    #[arg(long, value_name = "PAGE",
+          value_parser = clap::builder::PossibleValuesParser::new(crate::display::layout_dump::PAGES))]
+    pub dump_layout: Option<String>,
+
+    /// Record the emulator panel for SECONDS to an animated GIF at PATH, then exit
+    #[arg(long = "record-gif", num_args = 2, value_names = ["SECONDS", "PATH"])]
+    pub record_gif: Option<Vec<String>>,
 }
 
+/// I2C bus used when neither the config file nor the CLI names one.
+pub const DEFAULT_I2C_BUS: &str = "/dev/i2c-1";
+
 /// Parse CLI, read YAML config file, merge (CLI wins), validate.
 /// Returns the fully resolved `Config`.
 pub fn load() -> Result<Config, ConfigError> {
     let cli = Cli::parse();
 
+    // needs no player or config file, so answer before validation
+    if cli.list_drivers {
+        print!("{}", crate::display::DisplayDriverFactory::driver_report());
+        std::process::exit(0);
+    }
+
+    // a layout check needs no player either
+    if let Some(page) = &cli.dump_layout {
+        let cfg = merged(&cli)?;
+        let display = crate::display::DisplayDriverFactory::with_defaults(
+            cfg.display.unwrap_or_default(),
+            cfg.i2c_bus.as_deref().unwrap_or(DEFAULT_I2C_BUS),
+        );
+        match crate::display::layout_dump::dump(&display, cfg.theme, page) {
+            Ok(report) => {
+                print!("{report}");
+                std::process::exit(0);
+            }
+            Err(e) => return Err(ConfigError::Validation(e)),
+        }
+    }
+
+    let cfg = resolve(&cli)?;
+
+    if cli.dump_config {
+        let s = serde_yaml::to_string(&cfg)?;
+        println!("{s}");
+        std::process::exit(0);
+    }
+
+    Ok(cfg)
+}
+
+/// Build the effective config for already parsed CLI args.
+///
+/// Precedence is CLI > config file > built-in default: a flag only wins
+/// when it was actually given, so CLI args carry no defaults of their own.
+pub fn resolve(cli: &Cli) -> Result<Config, ConfigError> {
+    let cfg = merged(cli)?;
+
+    // 4. Validate
+    validate(&cfg)?;
+
+    Ok(cfg)
+}
+
+/// Defaults, config file and CLI merged, not yet validated
+fn merged(cli: &Cli) -> Result<Config, ConfigError> {
     // 1. Defaults
-    let mut cfg = Config::default();
+    let mut cfg = Config {
+        i2c_bus: Some(DEFAULT_I2C_BUS.to_string()),
+        ..Config::default()
+    };
 
     // 2. YAML file
     let config_path = cli.config.clone()
@@ -269,16 +712,8 @@ pub fn load() -> Result<Config, ConfigError> {
     }
 
     // 3. CLI overrides (highest precedence)
-    apply_cli_overrides(&mut cfg, &cli);
-
-    // 4. Validate
-    validate(&cfg)?;
-
-    if cli.dump_config {
-        let s = serde_yaml::to_string(&cfg)?;
-        println!("{s}");
-        std::process::exit(0);
-    }
+    apply_cli_overrides(&mut cfg, cli);
+    cfg.record_gif = cli.record_gif.as_deref().map(GifCapture::parse).transpose()?;
 
     Ok(cfg)
 }
@@ -300,6 +735,12 @@ fn find_config_file() -> Option<PathBuf> {
 }
 
 fn read_yaml(path: &Path) -> Result<Config, ConfigError> {
+    // the config format is YAML only - say so rather than fail on a TOML parse
+    if path.extension().is_some_and(|e| e.eq_ignore_ascii_case("toml")) {
+        return Err(ConfigError::Validation(format!(
+            "{}: TOML config is not supported, use YAML (see lymons.yaml)", path.display()
+        )));
+    }
     let s = fs::read_to_string(path)?;
     Ok(serde_yaml::from_str(&s)?)
 }
@@ -313,13 +754,31 @@ fn merge(dst: &mut Config, src: Config) {
     take!(text_font);
     take!(text_font_size);
     take!(scroll_mode);
+    take!(loop_pause_ms);
+    take!(loop_gap);
+    take!(cylon_trail);
+    take!(info_line);
+    take!(sanitize_text);
+    take!(scroll_poll_ms);
+    take!(clock_poll_ms);
+    take!(viz_poll_ms);
     take!(show_remaining);
     take!(clock_font);
+    take!(date_format);
+    take!(show_seconds);
     take!(easter_egg);
+    take!(egg_rotate_mins);
     take!(visualizer);
     take!(hist_scheme);
+    take!(viz_source);
+    take!(spectrum_agc);
+    take!(viz_downmix);
+    take!(svg_dither);
     take!(show_metrics);
-    take!(show_splash);
+    take!(metrics);
+    take!(show_splash); take!(splash_ms); take!(splash_wait); take!(no_lms);
+    take!(mac_interface);
+    take!(locale);
     take!(i2c_bus);     // need to retire this and fold any code under display.bus.bus
     take!(latitude);
     take!(longitude);
@@ -334,6 +793,19 @@ fn merge(dst: &mut Config, src: Config) {
         (Some(d), Some(s)) => merge_weather(d, s),
         _ => {}
     }
+    take!(second_display);
+    take!(vu);
+    take!(burn_in);
+    take!(slideshow);
+    take!(history);
+    take!(big_title);
+    take!(qr_code);
+    take!(sleep_timer);
+    take!(idle_behavior);
+    take!(inactivity_timeout_mins);
+    take!(theme);
+    take!(mqtt);
+    take!(http_status);
 }
 
 fn merge_display(dst: &mut DisplayConfig, src: DisplayConfig) {
@@ -342,20 +814,26 @@ fn merge_display(dst: &mut DisplayConfig, src: DisplayConfig) {
     }
     take!(width); take!(height); take!(rotate_deg); take!(invert);
     take!(brightness); take!(driver); take!(bus); take!(emulated);
+    take!(progress_bar); take!(track_lines); take!(frame_cap); take!(bus_retries);
+    take!(watchdog_failures); take!(watchdog_timeout_secs);
 }
 
 fn merge_weather(dst: &mut WeatherConfig, src: WeatherConfig) {
     macro_rules! take {
         ($field:ident) => { if src.$field.is_some() { dst.$field = src.$field; } };
     }
-    take!(api); take!(units); take!(translate); take!(latitude); take!(longitude);
+    take!(api); take!(provider); take!(units); take!(translate); take!(latitude); take!(longitude);
+    take!(refresh_mins); take!(forecast_days); take!(temperature);
 }
 
 fn apply_cli_overrides(cfg: &mut Config, cli: &Cli) {
     if cli.debug        { cfg.log_level = Some("debug".to_string()); }
     if cli.remain       { cfg.show_remaining = Some(true); }
     if cli.no_splash    { cfg.show_splash = Some(false); }
+    if cli.no_splash_wait { cfg.splash_wait = Some(false); }
+    if cli.no_lms       { cfg.no_lms = Some(true); }
     if cli.metrics      { cfg.show_metrics = Some(true); }
+    if cli.selftest     { cfg.self_test = Some(true); }
     if cli.emulated {
         cfg.display.get_or_insert_with(DisplayConfig::default).emulated = Some(true);
     }
@@ -367,19 +845,41 @@ fn apply_cli_overrides(cfg: &mut Config, cli: &Cli) {
     take_opt!(cli.text_font      => cfg.text_font);
     take_opt!(cli.text_font_size => cfg.text_font_size);
     take_opt!(cli.scroll         => cfg.scroll_mode);
+    take_opt!(cli.loop_pause     => cfg.loop_pause_ms);
+    take_opt!(cli.splash_ms      => cfg.splash_ms);
+    take_opt!(cli.scroll_poll    => cfg.scroll_poll_ms);
+    take_opt!(cli.clock_poll     => cfg.clock_poll_ms);
+    take_opt!(cli.viz_poll       => cfg.viz_poll_ms);
     take_opt!(cli.clock_font     => cfg.clock_font);
     take_opt!(cli.eggs           => cfg.easter_egg);
+    take_opt!(cli.egg_rotate     => cfg.egg_rotate_mins);
     take_opt!(cli.viz            => cfg.visualizer);
     take_opt!(cli.hist_scheme    => cfg.hist_scheme);
     take_opt!(cli.i2c_bus        => cfg.i2c_bus);
+    take_opt!(cli.mac_interface  => cfg.mac_interface);
+    take_opt!(cli.locale         => cfg.locale);
+    if let Some(mins) = cli.sleep {
+        cfg.sleep_timer.get_or_insert_with(SleepTimerConfig::default).minutes = Some(mins);
+    }
     take_opt!(cli.lat            => cfg.latitude);
     take_opt!(cli.lon            => cfg.longitude);
 
     // Weather overrides — comma-separated -W/--weather first, then discrete flags win
     if let Some(w_str) = &cli.weather {
         // Format: key,units,lang,latitude,longitude  (any trailing fields may be omitted)
-        let parts: Vec<&str> = w_str.splitn(5, ',').collect();
+        // provider=<name> and refresh=<mins> tokens may appear anywhere and are removed before positional parsing
         let w = cfg.weather.get_or_insert_with(WeatherConfig::default);
+        let mut parts: Vec<&str> = Vec::new();
+        for tok in w_str.split(',') {
+            let tok_trim = tok.trim();
+            if let Some(name) = tok_trim.strip_prefix("provider=") {
+                w.provider = Some(name.to_string());
+            } else if let Some(mins) = tok_trim.strip_prefix("refresh=") {
+                if let Ok(mins) = mins.parse::<u64>() { w.refresh_mins = Some(mins); }
+            } else {
+                parts.push(tok);
+            }
+        }
         if parts.len() >= 1 && !parts[0].is_empty() { w.api       = Some(parts[0].to_string()); }
         if parts.len() >= 2 && !parts[1].is_empty() { w.units     = Some(parts[1].to_string()); }
         if parts.len() >= 3 && !parts[2].is_empty() { w.translate = Some(parts[2].to_string()); }
@@ -418,10 +918,15 @@ fn apply_cli_overrides(cfg: &mut Config, cli: &Cli) {
         };
         cfg.display.get_or_insert_with(DisplayConfig::default).driver = Some(driver);
     }
+    if let Some(deg) = cli.rotate {
+        cfg.display.get_or_insert_with(DisplayConfig::default).rotate_deg = Some(deg);
+    }
 }
 
 fn validate(cfg: &Config) -> Result<(), ConfigError> {
-    if cfg.player.as_ref().map(|p| p.is_empty()).unwrap_or(true) {
+    // neither the self-test nor the desk clock talks to LMS, so a player isn't needed
+    let needs_player = cfg.self_test != Some(true) && cfg.no_lms != Some(true);
+    if needs_player && cfg.player.as_ref().map(|p| p.is_empty()).unwrap_or(true) {
         return Err(ConfigError::Validation(
             "Player name is required: set 'player' in config file or use -N / --name".into()
         ));
@@ -439,7 +944,315 @@ fn validate(cfg: &Config) -> Result<(), ConfigError> {
                 ));
             }
         }
+        if display.bus_retries.is_some_and(|r| r > crate::display::BusRetry::MAX_RETRIES) {
+            return Err(ConfigError::Validation(format!(
+                "display bus_retries must be 0-{}", crate::display::BusRetry::MAX_RETRIES
+            )));
+        }
+        if let Some(lines) = display.track_lines.as_ref() {
+            if lines.is_empty() {
+                return Err(ConfigError::Validation("display track_lines needs at least one tag".into()));
+            }
+            if let Some(tag) = lines.iter().enumerate().find_map(|(i, t)| lines[..i].contains(t).then_some(t)) {
+                return Err(ConfigError::Validation(
+                    format!("display track_lines lists {} twice", tag.field_name())
+                ));
+            }
+        }
+    }
+    if cfg.info_line.as_ref().is_some_and(|tokens| tokens.len() != 3) {
+        return Err(ConfigError::Validation(
+            "info_line needs three tokens: left, centre and right".into()
+        ));
+    }
+    if cfg.cylon_trail.is_some_and(|t| t > crate::display::components::scrollers::MAX_CYLON_TRAIL) {
+        return Err(ConfigError::Validation(format!(
+            "cylon_trail must be 0-{} ticks", crate::display::components::scrollers::MAX_CYLON_TRAIL
+        )));
+    }
+    if cfg.second_display.as_ref().is_some_and(|s| s.display.bus.is_none()) {
+        return Err(ConfigError::Validation(
+            "second_display needs its own bus, e.g. i2c at address 0x3D".into()
+        ));
+    }
+    if cfg.second_display.as_ref().is_some_and(|s| s.display.bus_retries.is_some_and(|r| r > crate::display::BusRetry::MAX_RETRIES)) {
+        return Err(ConfigError::Validation(format!(
+            "second_display bus_retries must be 0-{}", crate::display::BusRetry::MAX_RETRIES
+        )));
+    }
+    if let Some(vu) = cfg.vu.as_ref() {
+        let cal = vu.calibration();
+        if cal.floor_db >= cal.ceil_db {
+            return Err(ConfigError::Validation(
+                "vu floor_db must be below ceil_db".into()
+            ));
+        }
+        if cal.reference_offset_db.abs() > 24.0 {
+            return Err(ConfigError::Validation(
+                "vu reference_offset_db must be within ±24 dB".into()
+            ));
+        }
+        if let Some(width) = vu.combi_peak_width
+            && !crate::visualization::CombiLayout::PEAK_WIDTH_RANGE.contains(&width)
+        {
+            return Err(ConfigError::Validation(
+                "vu combi_peak_width must be between 0.04 and 0.5".into()
+            ));
+        }
+    }
+    if let Some(p) = cfg.weather.as_ref().and_then(|w| w.provider.as_deref())
+        && crate::weather::ProviderKind::parse(p).is_none()
+    {
+        return Err(ConfigError::Validation(format!(
+            "unknown weather provider '{}': expected tomorrow or owm", p
+        )));
     }
     Ok(())
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+player: study
+scroll_mode: loop
+clock_font: roboto
+easter_egg: cassette
+visualizer: vu_stereo
+i2c_bus: /dev/i2c-3
+display:
+  driver: ssd1322
+  width: 256
+  height: 64
+  bus:
+    type: spi
+    bus: /dev/spidev0.0
+    dc_pin: 24
+weather:
+  api: abc123
+  units: imperial
+"#;
+
+    fn write_sample(name: &str, body: &str) -> PathBuf {
+        let path = std::env::temp_dir()
+            .join(format!("lymons-config-test-{}-{}", std::process::id(), name));
+        fs::write(&path, body).unwrap();
+        path
+    }
+
+    fn resolve_args(path: &Path, extra: &[&str]) -> Result<Config, ConfigError> {
+        let mut args = vec!["LyMonS", "-c", path.to_str().unwrap()];
+        args.extend_from_slice(extra);
+        resolve(&Cli::try_parse_from(args).unwrap())
+    }
+
+    #[test]
+    fn sample_file_populates_config() {
+        let path = write_sample("full.yaml", SAMPLE);
+        let cfg = resolve_args(&path, &[]).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(cfg.player.as_deref(), Some("study"));
+        assert_eq!(cfg.scroll_mode.as_deref(), Some("loop"));
+        assert_eq!(cfg.easter_egg.as_deref(), Some("cassette"));
+        assert_eq!(cfg.visualizer.as_deref(), Some("vu_stereo"));
+        assert_eq!(cfg.i2c_bus.as_deref(), Some("/dev/i2c-3"));
+        let display = cfg.display.unwrap();
+        assert!(matches!(display.driver, Some(DriverKind::Ssd1322)));
+        assert!(matches!(display.bus, Some(BusConfig::Spi { dc_pin: 24, .. })));
+        assert_eq!(cfg.weather.unwrap().normalised_units(), "imperial");
+    }
+
+    #[test]
+    fn partial_cli_overrides_only_given_flags() {
+        let path = write_sample("partial.yaml", SAMPLE);
+        let cfg = resolve_args(&path, &["-z", "cylon", "-a", "hist_mono", "--rotate", "180", "--mac-interface", "end0"]).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(cfg.scroll_mode.as_deref(), Some("cylon"));
+        assert_eq!(cfg.visualizer.as_deref(), Some("hist_mono"));
+        assert_eq!(cfg.display.as_ref().unwrap().rotate_deg, Some(180));
+        assert_eq!(cfg.mac_interface.as_deref(), Some("end0"));
+        // untouched by the CLI - the file still wins over built-in defaults
+        assert_eq!(cfg.clock_font.as_deref(), Some("roboto"));
+        assert_eq!(cfg.i2c_bus.as_deref(), Some("/dev/i2c-3"));
+        assert_eq!(cfg.display.unwrap().width, Some(256));
+    }
+
+    #[test]
+    fn built_in_default_applies_when_file_and_cli_are_silent() {
+        let path = write_sample("minimal.yaml", "player: study\n");
+        let cfg = resolve_args(&path, &[]).unwrap();
+        let _ = fs::remove_file(&path);
+        assert_eq!(cfg.i2c_bus.as_deref(), Some(DEFAULT_I2C_BUS));
+    }
+
+    #[test]
+    fn vu_section_overrides_only_given_fields() {
+        let path = write_sample("vu.yaml", "player: study\nvu:\n  reference_offset_db: 6\n");
+        let cfg = resolve_args(&path, &[]).unwrap();
+        let _ = fs::remove_file(&path);
+        let cal = cfg.vu.unwrap().calibration();
+        assert_eq!(cal.reference_offset_db, 6.0);
+        assert_eq!(cal.floor_db, crate::vuphysics_new::VU_FLOOR_DB);
+        assert_eq!(cal.ceil_db, crate::vuphysics_new::VU_CEIL_DB);
+    }
+
+    #[test]
+    fn combi_layout_reads_and_bounds_the_peak_column() {
+        use crate::visualization::{CombiLayout, CombiPeak};
+
+        let path = write_sample("combi.yaml", "player: study\nvu:\n  combi_peak: right\n  combi_peak_width: 0.06\n");
+        let cfg = resolve_args(&path, &[]).unwrap();
+        let _ = fs::remove_file(&path);
+        assert_eq!(
+            cfg.vu.unwrap().combi_layout(),
+            CombiLayout { peak: CombiPeak::Right, peak_width: Some(0.06) }
+        );
+
+        let path = write_sample("combi-wide.yaml", "player: study\nvu:\n  combi_peak_width: 0.8\n");
+        let err = resolve_args(&path, &[]);
+        let _ = fs::remove_file(&path);
+        assert!(matches!(err, Err(ConfigError::Validation(_))));
+    }
+
+    #[test]
+    fn info_line_takes_three_tokens() {
+        use crate::display::components::info_line::{InfoLineSlots, InfoToken};
+
+        let path = write_sample("info-line.yaml", "player: study\ninfo_line: [playlist_pos, bitrate, remaining]\n");
+        let cfg = resolve_args(&path, &[]).unwrap();
+        let _ = fs::remove_file(&path);
+        assert_eq!(
+            cfg.info_line_slots(),
+            InfoLineSlots { left: InfoToken::PlaylistPos, centre: InfoToken::Bitrate, right: InfoToken::Remaining }
+        );
+
+        let path = write_sample("info-line-short.yaml", "player: study\ninfo_line: [elapsed, mode]\n");
+        let err = resolve_args(&path, &[]);
+        let _ = fs::remove_file(&path);
+        assert!(matches!(err, Err(ConfigError::Validation(_))));
+    }
+
+    #[test]
+    fn weather_refresh_floors_at_provider_minimum() {
+        let path = write_sample("refresh.yaml", "player: study\nweather:\n  api: abc\n  refresh_mins: 2\n");
+        let cfg = resolve_args(&path, &["-W", "abc,metric,,,,provider=owm,refresh=60"]).unwrap();
+        let from_file = resolve_args(&path, &[]).unwrap();
+        let _ = fs::remove_file(&path);
+
+        let w = cfg.weather.unwrap();
+        assert_eq!(w.refresh_mins, Some(60));
+        assert_eq!(w.refresh_interval(), std::time::Duration::from_secs(60 * 60));
+        // 2 minutes would burn through the tomorrow.io free tier
+        let w = from_file.weather.unwrap();
+        assert_eq!(w.refresh_interval(), crate::weather::ProviderKind::TomorrowIo.min_refresh());
+        assert_eq!(WeatherConfig::default().refresh_interval(), crate::weather::DEFAULT_REFRESH);
+    }
+
+    #[test]
+    fn mqtt_broker_gets_default_port() {
+        let path = write_sample("mqtt.yaml", "player: study\nmqtt:\n  broker: nas.local\n");
+        let cfg = resolve_args(&path, &[]).unwrap();
+        let _ = fs::remove_file(&path);
+        let mqtt = cfg.mqtt.unwrap();
+        assert_eq!(mqtt.broker_addr().as_deref(), Some("nas.local:1883"));
+        assert_eq!(mqtt.topic_prefix(), "lymons");
+    }
+
+    #[test]
+    fn toml_config_is_rejected_with_hint() {
+        let path = write_sample("config.toml", "player = \"study\"\n");
+        let err = resolve_args(&path, &[]).unwrap_err();
+        let _ = fs::remove_file(&path);
+        assert!(err.to_string().contains("use YAML"));
+    }
+
+    #[test]
+    fn splash_hold_follows_config_and_cli() {
+        use std::time::Duration;
+        let path = write_sample("splash.yaml", "player: study\nsplash_ms: 750\n");
+        let from_file = resolve_args(&path, &[]).unwrap();
+        let from_cli = resolve_args(&path, &["--splash-ms", "300"]).unwrap();
+        let no_wait = resolve_args(&path, &["--no-splash-wait"]).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(from_file.splash_hold(), Duration::from_millis(750));
+        assert_eq!(from_cli.splash_hold(), Duration::from_millis(300));
+        assert_eq!(no_wait.splash_hold(), Duration::ZERO);
+        assert_eq!(Config::default().splash_hold(), crate::display::manager::DEFAULT_SPLASH_HOLD);
+    }
+
+    #[test]
+    fn second_display_takes_a_bus_and_optional_mode() {
+        let path = write_sample("second.yaml", "player: study\nsecond_display:\n  driver: ssd1306\n  mode: clock\n  bus:\n    type: i2c\n    bus: /dev/i2c-1\n    address: 0x3D\n");
+        let cfg = resolve_args(&path, &[]).unwrap();
+        let _ = fs::remove_file(&path);
+        let second = cfg.second_display.unwrap();
+        assert_eq!(second.mode, Some(crate::display::DisplayMode::Clock));
+        assert!(matches!(second.display.driver, Some(DriverKind::Ssd1306)));
+        assert!(matches!(second.display.bus, Some(BusConfig::I2c { address: 61, .. })));
+
+        let path = write_sample("second-nobus.yaml", "player: study\nsecond_display:\n  driver: ssd1306\n");
+        let err = resolve_args(&path, &[]);
+        let _ = fs::remove_file(&path);
+        assert!(matches!(err, Err(ConfigError::Validation(_))));
+    }
+
+    #[test]
+    fn bus_retries_are_capped() {
+        for (name, yaml, ok) in [
+            ("retries-ten.yaml", "display:\n  bus_retries: 10\n", true),
+            ("retries-many.yaml", "display:\n  bus_retries: 30\n", false),
+            ("retries-second.yaml", "second_display:\n  bus_retries: 11\n  bus:\n    type: i2c\n    bus: /dev/i2c-1\n    address: 0x3D\n", false),
+        ] {
+            let path = write_sample(name, &format!("player: study\n{yaml}"));
+            let cfg = resolve_args(&path, &[]);
+            let _ = fs::remove_file(&path);
+            assert_eq!(cfg.is_ok(), ok, "{yaml}");
+        }
+    }
+
+    #[test]
+    fn track_lines_parse_and_reject_repeats() {
+        use crate::display::components::scrollers::TrackTag;
+
+        let path = write_sample("lines.yaml", "player: study\ndisplay:\n  track_lines: [composer, album, title]\n");
+        let cfg = resolve_args(&path, &[]).unwrap();
+        let _ = fs::remove_file(&path);
+        assert_eq!(cfg.display.unwrap().track_lines, Some(vec![TrackTag::Composer, TrackTag::Album, TrackTag::Title]));
+
+        for (name, lines) in [("lines-twice.yaml", "[title, title]"), ("lines-empty.yaml", "[]")] {
+            let path = write_sample(name, &format!("player: study\ndisplay:\n  track_lines: {lines}\n"));
+            let err = resolve_args(&path, &[]);
+            let _ = fs::remove_file(&path);
+            assert!(matches!(err, Err(ConfigError::Validation(_))), "{lines}");
+        }
+    }
+
+    #[test]
+    fn no_lms_makes_the_player_optional() {
+        let path = write_sample("no-lms.yaml", "clock_font: roboto\n");
+        let without = resolve_args(&path, &[]);
+        let with = resolve_args(&path, &["--no-lms"]);
+        let named = resolve_args(&path, &["--no-lms", "-N", "study"]);
+        let _ = fs::remove_file(&path);
+
+        assert!(without.unwrap_err().to_string().contains("Player name is required"));
+        let cfg = with.unwrap();
+        assert_eq!((cfg.no_lms, cfg.player), (Some(true), None));
+        assert_eq!(named.unwrap().player.as_deref(), Some("study"));
+    }
+
+    #[test]
+    fn record_gif_needs_a_sensible_length() {
+        let path = write_sample("gif.yaml", "player: study\n");
+        let cfg = resolve_args(&path, &["--record-gif", "8", "eggs.gif"]);
+        let zero = resolve_args(&path, &["--record-gif", "0", "eggs.gif"]);
+        let _ = fs::remove_file(&path);
+        assert_eq!(cfg.unwrap().record_gif, Some(GifCapture { seconds: 8, path: PathBuf::from("eggs.gif") }));
+        assert!(zero.is_err());
+    }
+}
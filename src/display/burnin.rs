@@ -0,0 +1,121 @@
+/*
+ *  display/burnin.rs
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  OLED burn-in protection - slow pixel shift of static pages
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *  Public License.
+ *
+ */
+
+use std::time::{Duration, Instant};
+use embedded_graphics::prelude::Point;
+
+/// Walks page content around a small box, one pixel per interval, so static
+/// clock and weather pixels never sit on the same spot for long.
+#[derive(Debug, Clone)]
+pub struct BurnInShift {
+    enabled: bool,
+    max_shift: i32,
+    interval: Duration,
+    started: Option<Instant>,
+}
+
+impl Default for BurnInShift {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_shift: 1,
+            interval: Duration::from_secs(60),
+            started: None,
+        }
+    }
+}
+
+impl BurnInShift {
+    /// Configure the shift; `max_shift_px` is clamped to 1-4 px.
+    pub fn configure(&mut self, enabled: bool, max_shift_px: u8, interval: Duration) {
+        self.enabled = enabled;
+        self.max_shift = max_shift_px.clamp(1, 4) as i32;
+        self.interval = interval.max(Duration::from_secs(1));
+        self.started = None;
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Offset for `now`. The first call anchors the schedule at the centre.
+    pub fn offset_at(&mut self, now: Instant) -> Point {
+        if !self.enabled {
+            return Point::zero();
+        }
+        let started = *self.started.get_or_insert(now);
+        let step = now.duration_since(started).as_millis() / self.interval.as_millis().max(1);
+        self.path_point(step as usize)
+    }
+
+    /// Offset for the current time
+    pub fn offset(&mut self) -> Point {
+        self.offset_at(Instant::now())
+    }
+
+    /// Serpentine walk over the (2n+1)^2 box, back and forth so each step
+    /// moves a single pixel; step 0 is the centre (the middle cell of an
+    /// odd-sided snake).
+    fn path_point(&self, step: usize) -> Point {
+        let side = (2 * self.max_shift + 1) as usize;
+        let cells = side * side;
+        let period = 2 * (cells - 1);
+        let k = (cells / 2 + step) % period;
+        let idx = if k < cells { k } else { period - k };
+        let row = idx / side;
+        let col = if row.is_multiple_of(2) { idx % side } else { side - 1 - idx % side };
+        Point::new(col as i32 - self.max_shift, row as i32 - self.max_shift)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn offset_moves_each_interval_within_bounds() {
+        let mut shift = BurnInShift::default();
+        shift.configure(true, 2, Duration::from_secs(60));
+        let t0 = Instant::now();
+
+        assert_eq!(shift.offset_at(t0), Point::zero());
+        assert_eq!(shift.offset_at(t0 + Duration::from_secs(59)), Point::zero());
+
+        let mut prev = Point::zero();
+        for minute in 1..=60u64 {
+            let p = shift.offset_at(t0 + Duration::from_secs(60 * minute));
+            assert!(p.x.abs() <= 2 && p.y.abs() <= 2, "{p:?} outside the jitter box");
+            // a single pixel move per interval, never a jump across the box
+            assert_eq!((p.x - prev.x).abs() + (p.y - prev.y).abs(), 1, "minute {minute}: {prev:?} -> {p:?}");
+            prev = p;
+        }
+    }
+
+    #[test]
+    fn disabled_shift_stays_put() {
+        let mut shift = BurnInShift::default();
+        let t0 = Instant::now();
+        assert_eq!(shift.offset_at(t0 + Duration::from_secs(600)), Point::zero());
+        assert!(!shift.enabled());
+    }
+}
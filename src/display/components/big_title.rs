@@ -0,0 +1,149 @@
+/*
+ *  display/components/big_title.rs
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  Big title - the track title alone, as large as the panel allows
+ *
+ *  The title is set in the largest bitmap font that fits it on one line;
+ *  a title too long even for the smallest font scrolls in that font.
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *  Public License.
+ *
+ */
+
+use embedded_graphics::mono_font::iso_8859_13::{
+    FONT_10X20, FONT_5X8, FONT_6X10, FONT_7X14, FONT_8X13, FONT_9X15, FONT_9X18_BOLD,
+};
+use embedded_graphics::mono_font::MonoFont;
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::Rectangle;
+use embedded_text::alignment::{HorizontalAlignment, VerticalAlignment};
+
+use crate::display::field::Field;
+use crate::display::components::scrollers::Marquee;
+use crate::textable::ScrollMode;
+
+/// Fonts tried for the title, largest first
+pub const BIG_TITLE_FONTS: [&MonoFont<'static>; 7] =
+    [&FONT_10X20, &FONT_9X18_BOLD, &FONT_9X15, &FONT_8X13, &FONT_7X14, &FONT_6X10, &FONT_5X8];
+
+/// Largest of `fonts` (largest first) that sets `text` on one line within
+/// `size`; None when even the last one overflows
+pub fn largest_fitting_font(
+    text: &str,
+    size: Size,
+    fonts: &[&'static MonoFont<'static>],
+) -> Option<&'static MonoFont<'static>> {
+    let chars = text.chars().count() as u32;
+    fonts.iter().copied().find(|font| {
+        let pitch = font.character_size.width + font.character_spacing;
+        let width = (chars * pitch).saturating_sub(font.character_spacing);
+        width <= size.width && font.character_size.height <= size.height
+    })
+}
+
+/// The track title in big text
+pub struct BigTitle {
+    title: String,
+    marquee: Marquee,
+}
+
+impl BigTitle {
+    pub fn new(scroll_mode: ScrollMode) -> Self {
+        Self { title: String::new(), marquee: Marquee::new(scroll_mode) }
+    }
+
+    pub fn set_title(&mut self, title: &str) {
+        if self.title != title {
+            self.title = title.to_string();
+            self.marquee.set_text(title);
+        }
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// The title row inside `field`: one line in the largest font that fits,
+    /// centred vertically. `true` when even the smallest font overflows and
+    /// the row scrolls instead.
+    pub fn row(&self, field: &Field) -> (Field, bool) {
+        let fitted = largest_fitting_font(&self.title, field.bounds.size, &BIG_TITLE_FONTS);
+        let font = fitted.unwrap_or(BIG_TITLE_FONTS[BIG_TITLE_FONTS.len() - 1]);
+        let height = font.character_size.height.min(field.height());
+        let top = field.bounds.top_left.y + (field.height() - height) as i32 / 2;
+
+        let mut row = field.clone();
+        row.font = Some(font);
+        row.bounds = Rectangle::new(Point::new(field.bounds.top_left.x, top), Size::new(field.width(), height));
+        row.horizontal_alignment = HorizontalAlignment::Center;
+        row.vertical_alignment = VerticalAlignment::Middle;
+        (row, fitted.is_none())
+    }
+
+    /// Advance the scroll one tick for an overflowing `row`
+    pub fn update(&mut self, row: &Field) {
+        self.marquee.update(row);
+    }
+
+    /// Draw the scrolling title, clipped to `row`
+    pub fn render_scrolling<D, C>(&self, row: &Field, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+        C: PixelColor,
+        crate::display::color::Color: crate::display::color_proxy::ConvertColor<C>,
+    {
+        self.marquee.render_field(row, target)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn font_shrinks_with_title_length_then_scrolls() {
+        let fits = |text: &str, size| largest_fitting_font(text, size, &BIG_TITLE_FONTS).map(|f| f.character_size);
+        // 256 wide panel: 25 characters of 10x20
+        let panel = Size::new(252, 56);
+        assert_eq!(fits("So What", panel), Some(FONT_10X20.character_size));
+        assert_eq!(fits(&"x".repeat(25), panel), Some(FONT_10X20.character_size));
+        // 26 drop to 9x18 (9px pitch, 28 fit)
+        assert_eq!(fits(&"x".repeat(26), panel), Some(FONT_9X18_BOLD.character_size));
+        // 5x8 is 50 characters, one more scrolls
+        assert_eq!(fits(&"x".repeat(50), panel), Some(FONT_5X8.character_size));
+        assert_eq!(fits(&"x".repeat(51), panel), None);
+
+        // a short panel rules out the tall fonts whatever the length
+        assert_eq!(fits("So What", Size::new(124, 14)), Some(FONT_8X13.character_size));
+    }
+
+    #[test]
+    fn row_is_centred_in_the_chosen_font() {
+        let field = Field::new_text("big_title", Rectangle::new(Point::new(2, 0), Size::new(252, 60)), &FONT_5X8);
+        let mut big = BigTitle::new(ScrollMode::ScrollLeft);
+        big.set_title("Freddie Freeloader");
+        let (row, scrolls) = big.row(&field);
+        assert!(!scrolls);
+        assert_eq!(row.font.unwrap().character_size, FONT_10X20.character_size);
+        assert_eq!(row.bounds, Rectangle::new(Point::new(2, 20), Size::new(252, 20)));
+
+        big.set_title(&"Bitches Brew ".repeat(5));
+        let (row, scrolls) = big.row(&field);
+        assert!(scrolls);
+        assert_eq!(row.font.unwrap().character_size, FONT_5X8.character_size);
+    }
+}
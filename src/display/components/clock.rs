@@ -27,8 +27,49 @@ use embedded_graphics::prelude::*;
 use embedded_graphics::pixelcolor::{BinaryColor, Gray4, Rgb565};
 use crate::display::layout::LayoutConfig;
 use crate::clock_font_svg::{ClockDigitLayout, ClockFontData};
+use crate::display::time_source::{system_time, SharedTimeSource};
 use std::time::Instant;
 
+use chrono::format::{Item, StrftimeItems};
+use log::{info, warn};
+
+/// Date line under the clock unless configured otherwise, e.g. `Tue Mar 04`
+pub const DEFAULT_DATE_FORMAT: &str = "%a %b %d";
+
+/// Check a chrono strftime string, e.g. `%d %b` or `%Y-%m-%d`
+pub fn validate_date_format(format: &str) -> Result<(), String> {
+    if format.trim().is_empty() {
+        return Err("empty date format".to_string());
+    }
+    if StrftimeItems::new(format).any(|item| matches!(item, Item::Error)) {
+        return Err(format!("invalid date format '{format}'"));
+    }
+    Ok(())
+}
+
+/// Gap after every clock character, colons included
+const CLOCK_DIGIT_GAP_HORIZONTAL: i32 = 1;
+
+/// Clock characters in HH:MM:SS
+pub const SECONDS_CHARS: usize = 8;
+
+/// Width of `chars` clock characters and the gaps between them
+pub fn clock_visual_width(digit_width: i32, chars: usize) -> i32 {
+    let chars = chars as i32;
+    digit_width * chars + CLOCK_DIGIT_GAP_HORIZONTAL * (chars - 1).max(0)
+}
+
+/// Left edge of each of `chars` clock characters centred on a `panel_width` panel.
+///
+/// A clock wider than the panel starts at the left edge.
+pub fn clock_x_positions(digit_width: i32, panel_width: u32, chars: usize) -> Vec<i32> {
+    let total_clock_visual_width = clock_visual_width(digit_width, chars).min(panel_width as i32);
+    let clock_x_start = (panel_width as i32 - total_clock_visual_width) / 2;
+    (0..chars as i32)
+        .map(|i| clock_x_start + i * (digit_width + CLOCK_DIGIT_GAP_HORIZONTAL))
+        .collect()
+}
+
 /// Clock display state
 #[derive(Debug, Clone)]
 pub struct ClockState {
@@ -66,6 +107,12 @@ pub struct ClockDisplay {
     clock_font: ClockFontData,
     layout: LayoutConfig,
     metrics: bool,
+    /// chrono strftime string for the date line
+    date_format: String,
+    /// HH:MM:SS instead of HH:MM plus the seconds progress bar, when it fits
+    show_seconds: bool,
+    /// Time the digits show
+    time: SharedTimeSource,
 }
 
 impl ClockDisplay {
@@ -76,9 +123,36 @@ impl ClockDisplay {
             clock_font,
             layout,
             metrics,
+            date_format: DEFAULT_DATE_FORMAT.to_string(),
+            show_seconds: false,
+            time: system_time(),
         }
     }
 
+    /// Read the time from `time` rather than the system clock
+    pub fn set_time_source(&mut self, time: SharedTimeSource) {
+        self.time = time;
+    }
+
+    /// Use `format` for the date line; an invalid format keeps the default with a warning
+    pub fn set_date_format(&mut self, format: &str) {
+        self.date_format = match validate_date_format(format) {
+            Ok(()) => format.to_string(),
+            Err(e) => {
+                warn!("clock: {e}, using '{DEFAULT_DATE_FORMAT}'");
+                DEFAULT_DATE_FORMAT.to_string()
+            }
+        };
+    }
+
+    /// Date line text for `now`
+    pub fn format_date<Tz: chrono::TimeZone>(&self, now: &chrono::DateTime<Tz>) -> String
+    where
+        Tz::Offset: std::fmt::Display,
+    {
+        now.format(&self.date_format).to_string()
+    }
+
     /// Update the clock with current time
     pub fn update(&mut self, current_time_secs: f32) {
         // TODO: Update clock state based on current time
@@ -87,18 +161,31 @@ impl ClockDisplay {
 
     /// Toggle colon for blinking effect
     pub fn toggle_colon(&mut self) {
-        let now = Instant::now();
-        if now.duration_since(self.state.last_colon_toggle_time).as_millis() >= 500 {
+        let now = self.time.instant();
+        if now.saturating_duration_since(self.state.last_colon_toggle_time).as_millis() >= 500 {
             self.state.colon_on = !self.state.colon_on;
             self.state.last_colon_toggle_time = now;
         }
     }
 
+    /// Show HH:MM:SS when the panel is wide enough for eight clock characters
+    pub fn set_show_seconds(&mut self, show: bool) {
+        self.show_seconds = show;
+        if show && !self.seconds_shown() {
+            info!("clock: no room for seconds on a {} px panel, keeping the progress bar", self.layout.width);
+        }
+    }
+
+    /// True when the seconds digits replace the seconds progress bar
+    pub fn seconds_shown(&self) -> bool {
+        self.show_seconds
+            && ClockDigitLayout::SquareTime != self.clock_font.digit_layout
+            && clock_visual_width(self.clock_font.digit_width as i32, SECONDS_CHARS) <= self.layout.width as i32
+    }
+
     // DRY - core clock digits layout
-    fn render_core(&self, y_start: i32, progress: Point) -> ([char; 5], [i32; 5],i32) 
+    fn render_core(&self, y_start: i32, progress: Point) -> (Vec<char>, Vec<i32>, i32)
     { 
-        use chrono::Local;
-
         let digit_width = self.clock_font.digit_width as i32;
         let digit_height = self.clock_font.digit_height as i32;
 
@@ -108,41 +195,26 @@ impl ClockDisplay {
             y_start
         };
 
-        let current_time = Local::now();
+        let current_time = self.time.now();
         let w = self.layout.width;
-        let hours_str = format!("{:02}", current_time.format("%H"));
-        let minutes_str = format!("{:02}", current_time.format("%M"));
         let current_second: u32 = current_time.format("%S").to_string().parse().unwrap_or(0);
-        let colon_on = current_second % 2 == 0;
-
-        let time_chars: [char; 5] = [
-            hours_str.chars().nth(0).unwrap_or('0'),
-            hours_str.chars().nth(1).unwrap_or('0'),
-            if colon_on { ':' } else { ' ' },
-            minutes_str.chars().nth(0).unwrap_or('0'),
-            minutes_str.chars().nth(1).unwrap_or('0'),
-        ];
-
-        const CLOCK_DIGIT_GAP_HORIZONTAL: i32 = 1;
-        const CLOCK_COLON_MINUTE_GAP: i32 = 1;
-        let mut total_clock_visual_width: i32 = (digit_width * 5) +
-                                             CLOCK_DIGIT_GAP_HORIZONTAL * 2 + // H-H and H-Colon gaps
-                                             CLOCK_COLON_MINUTE_GAP +          // Colon-M1 gap
-                                             CLOCK_DIGIT_GAP_HORIZONTAL;       // M1-M2 gap
-
-        if total_clock_visual_width > w as i32 { total_clock_visual_width = w as i32; }
-
-        let clock_x_start: i32 = (w as i32 - total_clock_visual_width) / 2;
-        let mut x_positions: [i32; 5] = [
-            clock_x_start,
-            clock_x_start + digit_width + CLOCK_DIGIT_GAP_HORIZONTAL,
-            clock_x_start + (digit_width * 2) + (CLOCK_DIGIT_GAP_HORIZONTAL * 2),
-            clock_x_start + (digit_width * 3) + (CLOCK_DIGIT_GAP_HORIZONTAL * 2) + CLOCK_COLON_MINUTE_GAP,
-            clock_x_start + (digit_width * 4) + (CLOCK_DIGIT_GAP_HORIZONTAL * 2) + CLOCK_COLON_MINUTE_GAP,
-        ];
-        let square_adj = digit_width as i32 / 2;
+        let colon = if current_second.is_multiple_of(2) { ':' } else { ' ' };
+
+        let seconds = self.seconds_shown();
+        let time_str = if seconds {
+            current_time.format("%H:%M:%S").to_string()
+        } else {
+            current_time.format("%H:%M").to_string()
+        };
+        let time_chars: Vec<char> = time_str.chars()
+            .map(|c| if c == ':' { colon } else { c })
+            .collect();
+
+        let mut x_positions = clock_x_positions(digit_width, w, time_chars.len());
         if ClockDigitLayout::SquareTime==self.clock_font.digit_layout {
-            x_positions = [
+            let clock_x_start = x_positions[0];
+            let square_adj = digit_width / 2;
+            x_positions = vec![
                 clock_x_start + square_adj,
                 clock_x_start + square_adj + digit_width + CLOCK_DIGIT_GAP_HORIZONTAL,
                 -1,
@@ -167,7 +239,7 @@ impl ClockDisplay {
         use embedded_graphics::primitives::{Rectangle as EgRectangle, PrimitiveStyleBuilder};
         let (time_chars, x_positions, mut y_adj) = self.render_core(y_start, progress);
         // if the display is square draw HH over MM else HH:MM
-        for i in 0..5 {
+        for i in 0..time_chars.len() {
             // square mode we've set colon off screen
             // use this fact to position minutes
             if x_positions[i] <= 0 {
@@ -194,7 +266,7 @@ impl ClockDisplay {
         use embedded_graphics::primitives::{Rectangle as EgRectangle, PrimitiveStyleBuilder};
         let (time_chars, x_positions, mut y_adj) = self.render_core(y_start, progress);
         // if the display is square draw HH over MM else HH:MM
-        for i in 0..5 {
+        for i in 0..time_chars.len() {
             // square mode we've set colon off screen
             // use this fact to position minutes
             if x_positions[i] <= 0 {
@@ -221,7 +293,7 @@ impl ClockDisplay {
         use embedded_graphics::primitives::{Rectangle as EgRectangle, PrimitiveStyleBuilder};
         let (time_chars, x_positions, mut y_adj) = self.render_core(y_start, progress);
         // if the display is square draw HH over MM else HH:MM
-        for i in 0..5 {
+        for i in 0..time_chars.len() {
             // square mode we've set colon off screen
             // use this fact to position minutes
             if x_positions[i] <= 0 {
@@ -324,3 +396,67 @@ impl ClockDisplay {
         self.state.last_date_drawn = date;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn clock() -> ClockDisplay {
+        let layout = crate::display::layout::layout_for_resolution(128, 64, crate::display::traits::ColorDepth::Monochrome);
+        let font = ClockFontData::blank(25, 44);
+        ClockDisplay::new(layout, font, false)
+    }
+
+    #[test]
+    fn date_line_follows_the_configured_format() {
+        let date = chrono::Utc.with_ymd_and_hms(2025, 3, 4, 12, 0, 0).unwrap();
+        let mut clock = clock();
+        assert_eq!(clock.format_date(&date), "Tue Mar 04");
+        clock.set_date_format("%d %b");
+        assert_eq!(clock.format_date(&date), "04 Mar");
+        clock.set_date_format("%Y-%m-%d");
+        assert_eq!(clock.format_date(&date), "2025-03-04");
+    }
+
+    #[test]
+    fn invalid_date_format_keeps_the_default() {
+        assert!(validate_date_format("%d %b").is_ok());
+        assert!(validate_date_format("%Q").is_err());
+        assert!(validate_date_format("").is_err());
+
+        let date = chrono::Utc.with_ymd_and_hms(2025, 3, 4, 12, 0, 0).unwrap();
+        let mut clock = clock();
+        clock.set_date_format("%Y-%m-%d");
+        clock.set_date_format("%Q %d");
+        assert_eq!(clock.format_date(&date), "Tue Mar 04");
+    }
+
+    #[test]
+    fn seconds_layout_centres_eight_characters() {
+        // HH:MM keeps the original spacing: 5 x 25 + 4 gaps on 128 px
+        assert_eq!(clock_visual_width(25, 5), 129);
+        assert_eq!(clock_x_positions(25, 256, 5), [63, 89, 115, 141, 167]);
+
+        // HH:MM:SS is 8 x 25 + 7 gaps = 207 px, centred on 256 px
+        assert_eq!(clock_visual_width(25, SECONDS_CHARS), 207);
+        let x = clock_x_positions(25, 256, SECONDS_CHARS);
+        assert_eq!(x, [24, 50, 76, 102, 128, 154, 180, 206]);
+        assert_eq!(x[7] + 25 + x[0], 256 - 1);
+    }
+
+    #[test]
+    fn seconds_fall_back_to_the_progress_bar_on_small_panels() {
+        let mut small = clock();
+        small.set_show_seconds(true);
+        assert!(!small.seconds_shown());
+
+        let layout = crate::display::layout::layout_for_resolution(256, 64, crate::display::traits::ColorDepth::Gray4);
+        let mut wide = ClockDisplay::new(layout, ClockFontData::blank(25, 44), false);
+        assert!(!wide.seconds_shown());
+        wide.set_show_seconds(true);
+        assert!(wide.seconds_shown());
+        let (chars, x, _) = wide.render_core(0, Point::new(0, 64));
+        assert_eq!((chars.len(), x.len()), (SECONDS_CHARS, SECONDS_CHARS));
+    }
+}
@@ -0,0 +1,170 @@
+/*
+ *  display/components/history.rs
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  Recently played - ring buffer of the last few tracks for the idle screen
+ *
+ *  The main loop records artist/title whenever the track changes; repeats of
+ *  the newest entry are ignored so tag-only updates (volume, progress) do not
+ *  flood the list. With a file configured the list is written as JSON after
+ *  every new entry and read back at start-up.
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *  Public License.
+ *
+ */
+
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+
+/// Tracks kept unless configured otherwise
+pub const DEFAULT_LENGTH: usize = 8;
+
+/// One played track
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Played {
+    pub artist: String,
+    pub title:  String,
+}
+
+impl Played {
+    /// Single list row, `artist - title` or just the title when there is no artist
+    pub fn line(&self) -> String {
+        if self.artist.is_empty() {
+            self.title.clone()
+        } else {
+            format!("{} - {}", self.artist, self.title)
+        }
+    }
+}
+
+/// Last `capacity` played tracks, oldest first
+pub struct PlayHistory {
+    entries:  VecDeque<Played>,
+    capacity: usize,
+    file:     Option<PathBuf>,
+}
+
+impl PlayHistory {
+    /// In-memory history holding at most `capacity` tracks (at least one)
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self { entries: VecDeque::with_capacity(capacity), capacity, file: None }
+    }
+
+    /// History persisted to `path`, seeded from it when the file exists
+    pub fn with_file(capacity: usize, path: &Path) -> Self {
+        let mut history = Self::new(capacity);
+        match std::fs::read_to_string(path) {
+            Ok(json) => match serde_json::from_str::<Vec<Played>>(&json) {
+                Ok(saved) => {
+                    let skip = saved.len().saturating_sub(history.capacity);
+                    history.entries.extend(saved.into_iter().skip(skip));
+                    info!("history: {} tracks restored from {}", history.entries.len(), path.display());
+                }
+                Err(e) => warn!("history: ignoring {}: {e}", path.display()),
+            },
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => warn!("history: cannot read {}: {e}", path.display()),
+        }
+        history.file = Some(path.to_path_buf());
+        history
+    }
+
+    /// Record a track; false when it is blank or already the newest entry
+    pub fn push(&mut self, artist: &str, title: &str) -> bool {
+        let played = Played { artist: artist.trim().to_string(), title: title.trim().to_string() };
+        if played.title.is_empty() || self.entries.back() == Some(&played) {
+            return false;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(played);
+        self.save();
+        true
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Newest first, as the list is drawn
+    pub fn recent(&self) -> impl Iterator<Item = &Played> {
+        self.entries.iter().rev()
+    }
+
+    fn save(&self) {
+        let Some(path) = &self.file else { return };
+        let result = serde_json::to_string(&self.entries)
+            .map_err(std::io::Error::other)
+            .and_then(|json| std::fs::write(path, json));
+        if let Err(e) = result {
+            warn!("history: cannot write {}: {e}", path.display());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn titles(history: &PlayHistory) -> Vec<String> {
+        history.recent().map(|p| p.title.clone()).collect()
+    }
+
+    #[test]
+    fn keeps_only_the_last_n_in_order() {
+        let mut history = PlayHistory::new(3);
+        for n in 1..=5 {
+            assert!(history.push("Artist", &format!("Track {n}")));
+        }
+        assert_eq!(history.len(), 3);
+        assert_eq!(titles(&history), ["Track 5", "Track 4", "Track 3"]);
+    }
+
+    #[test]
+    fn repeats_and_blank_titles_are_skipped() {
+        let mut history = PlayHistory::new(3);
+        assert!(history.push("Miles Davis", "So What"));
+        assert!(!history.push("Miles Davis", "So What "));
+        assert!(!history.push("Miles Davis", ""));
+        assert_eq!(history.len(), 1);
+        assert_eq!(history.recent().next().unwrap().line(), "Miles Davis - So What");
+    }
+
+    #[test]
+    fn persisted_history_survives_a_restart() {
+        let path = std::env::temp_dir().join(format!("lymons-history-test-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        {
+            let mut history = PlayHistory::with_file(4, &path);
+            history.push("A", "One");
+            history.push("B", "Two");
+            history.push("C", "Three");
+        }
+        // a shorter list on restart keeps the newest tracks
+        let history = PlayHistory::with_file(2, &path);
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(titles(&history), ["Three", "Two"]);
+    }
+}
@@ -0,0 +1,181 @@
+/*
+ *  display/components/info_line.rs
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  Info line under the track - three slots, elapsed | mode | remaining
+ *  unless configured otherwise
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *  Public License.
+ *
+ */
+
+use std::fmt::Write;
+
+use arrayvec::ArrayString;
+
+use crate::display::components::status_bar::dsd_label;
+
+/// One slot's text, on the stack so the info line allocates nothing per frame
+pub type InfoText = ArrayString<32>;
+
+/// What an info line slot shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InfoToken {
+    /// Track time played, `m:ss`
+    Elapsed,
+    /// Track time left, `-m:ss`
+    Remaining,
+    /// Track length, `m:ss`
+    Total,
+    /// Remaining or total, following `show_remaining` and the time toggle
+    End,
+    /// Playing, Paused or Stopped
+    Mode,
+    /// Sample size and rate, e.g. `24/96` or `DSD64`
+    Bitrate,
+    /// Place in the playlist, e.g. `3/12`
+    PlaylistPos,
+}
+
+/// The left, centre and right slots
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InfoLineSlots {
+    pub left: InfoToken,
+    pub centre: InfoToken,
+    pub right: InfoToken,
+}
+
+impl Default for InfoLineSlots {
+    fn default() -> Self {
+        Self { left: InfoToken::Elapsed, centre: InfoToken::Mode, right: InfoToken::End }
+    }
+}
+
+/// Track state the slots are filled from
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InfoLineData<'a> {
+    pub elapsed: f32,
+    pub duration: f32,
+    pub remaining: f32,
+    pub show_remaining: bool,
+    pub mode: &'a str,
+    pub bitrate: &'a str,
+    /// Track number counted from 1 and playlist length
+    pub playlist_pos: Option<(u32, u32)>,
+}
+
+impl InfoLineSlots {
+    /// Slots from exactly three tokens, left to right
+    pub fn from_tokens(tokens: &[InfoToken]) -> Option<Self> {
+        match *tokens {
+            [left, centre, right] => Some(Self { left, centre, right }),
+            _ => None,
+        }
+    }
+
+    /// Fill `out` with the left, centre and right text, reusing its buffers
+    pub fn fill(&self, data: &InfoLineData, out: &mut [InfoText; 3]) {
+        for (text, token) in out.iter_mut().zip([self.left, self.centre, self.right]) {
+            text.clear();
+            // text too long for the slot is cut short rather than failing the frame
+            let _ = write_token(text, token, data);
+        }
+    }
+}
+
+fn write_mm_ss(out: &mut impl Write, secs: f32) -> std::fmt::Result {
+    let secs = secs.max(0.0) as u32;
+    write!(out, "{}:{:02}", secs / 60, secs % 60)
+}
+
+/// Write one slot's text; nothing when the player hasn't reported it
+pub fn write_token(out: &mut impl Write, token: InfoToken, data: &InfoLineData) -> std::fmt::Result {
+    match token {
+        InfoToken::Elapsed => write_mm_ss(out, data.elapsed),
+        InfoToken::Remaining => {
+            out.write_char('-')?;
+            write_mm_ss(out, data.remaining)
+        }
+        InfoToken::Total => write_mm_ss(out, data.duration),
+        InfoToken::End if data.show_remaining => write_token(out, InfoToken::Remaining, data),
+        InfoToken::End => write_token(out, InfoToken::Total, data),
+        InfoToken::Mode => out.write_str(data.mode),
+        InfoToken::Bitrate => out.write_str(data.bitrate),
+        InfoToken::PlaylistPos => match data.playlist_pos {
+            Some((n, of)) => write!(out, "{n}/{of}"),
+            None => Ok(()),
+        },
+    }
+}
+
+/// Short resolution label, e.g. `16/44.1`, `24/96` or `DSD128`; empty for
+/// streams without one
+pub fn bitrate_text(samplesize: &str, samplerate: &str) -> String {
+    if let Some(dsd) = dsd_label(samplesize, samplerate) {
+        return dsd;
+    }
+    match (samplesize.trim().parse::<u32>(), samplerate.trim().parse::<u32>()) {
+        (Ok(size), Ok(rate)) if size > 0 && rate > 0 => {
+            let khz = rate as f32 / 1000.0;
+            if rate % 1000 == 0 { format!("{size}/{}", rate / 1000) } else { format!("{size}/{khz:.1}") }
+        }
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(slots: InfoLineSlots, data: &InfoLineData) -> [String; 3] {
+        let mut out = [InfoText::new(); 3];
+        slots.fill(data, &mut out);
+        out.map(|t| t.to_string())
+    }
+
+    #[test]
+    fn chosen_tokens_fill_the_three_slots() {
+        let data = InfoLineData {
+            elapsed: 75.0,
+            duration: 200.0,
+            remaining: 125.0,
+            show_remaining: false,
+            mode: "Playing",
+            bitrate: &bitrate_text("24", "96000"),
+            playlist_pos: Some((3, 12)),
+        };
+        assert_eq!(texts(InfoLineSlots::default(), &data), ["1:15", "Playing", "3:20"]);
+        assert_eq!(
+            texts(InfoLineSlots::default(), &InfoLineData { show_remaining: true, ..data }),
+            ["1:15", "Playing", "-2:05"]
+        );
+
+        let slots = InfoLineSlots::from_tokens(&[InfoToken::PlaylistPos, InfoToken::Bitrate, InfoToken::Remaining]).unwrap();
+        assert_eq!(texts(slots, &data), ["3/12", "24/96", "-2:05"]);
+
+        // a filled buffer is cleared before reuse
+        let mut out = [InfoText::new(); 3];
+        slots.fill(&data, &mut out);
+        InfoLineSlots::default().fill(&InfoLineData { playlist_pos: None, ..data }, &mut out);
+        assert_eq!(out.map(|t| t.to_string()), ["1:15", "Playing", "3:20"]);
+        assert!(InfoLineSlots::from_tokens(&[InfoToken::Mode]).is_none());
+
+        assert_eq!(bitrate_text("16", "44100"), "16/44.1");
+        assert_eq!(bitrate_text("1", "5644800"), "DSD128");
+        assert_eq!(bitrate_text("0", "0"), "");
+    }
+}
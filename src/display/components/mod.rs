@@ -26,6 +26,12 @@ pub mod scrollers;
 pub mod clock;
 pub mod weather;
 pub mod visualizer;
+pub mod slideshow;
+pub mod history;
+pub mod big_title;
+pub mod qr_code;
+pub mod info_line;
+pub mod sleep_timer;
 
 // Re-exports
 pub use status_bar::StatusBar;
@@ -33,3 +39,7 @@ pub use scrollers::ScrollingText;
 pub use clock::ClockDisplay;
 pub use weather::WeatherDisplay;
 pub use visualizer::VisualizerComponent;
+pub use slideshow::Slideshow;
+pub use history::PlayHistory;
+pub use big_title::BigTitle;
+pub use qr_code::NowPlayingQr;
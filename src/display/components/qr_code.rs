@@ -0,0 +1,214 @@
+/*
+ *  display/components/qr_code.rs
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  Now playing QR code - artist and title, or a configured URL, to share
+ *
+ *  The code is drawn dark on light as scanners expect, each module scaled
+ *  to the largest whole pixel count the panel allows; the quiet zone is
+ *  narrowed on short panels rather than drop the code.
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *  Public License.
+ *
+ */
+
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+use log::warn;
+use qrcodegen::{QrCode, QrCodeEcc};
+
+use crate::display::color::Color;
+use crate::display::color_proxy::ConvertColor;
+
+/// Light modules the QR spec asks for around the code
+pub const QUIET_ZONE: u32 = 4;
+/// Narrowest margin kept when the panel is too short for the full one
+pub const MIN_QUIET_ZONE: u32 = 1;
+
+/// Text encoded for a track: `template` with `{artist}`, `{title}` and
+/// `{album}` filled in URL-escaped, or "artist – title" without one
+pub fn qr_text(template: Option<&str>, artist: &str, title: &str, album: &str) -> String {
+    match template {
+        Some(t) => t
+            .replace("{artist}", &url_escape(artist))
+            .replace("{title}", &url_escape(title))
+            .replace("{album}", &url_escape(album)),
+        None => match (artist.is_empty(), title.is_empty()) {
+            (false, false) => format!("{artist} – {title}"),
+            (true, _) => title.to_string(),
+            (false, true) => artist.to_string(),
+        },
+    }
+}
+
+/// Percent-encode all but the RFC 3986 unreserved characters
+fn url_escape(s: &str) -> String {
+    s.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => (b as char).to_string(),
+            _ => format!("%{b:02X}"),
+        })
+        .collect()
+}
+
+/// How a code sits in its area
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QrFit {
+    /// Pixels a module side
+    pub module_px: u32,
+    /// Quiet zone, in modules
+    pub quiet: u32,
+}
+
+impl QrFit {
+    /// Side of the code and its quiet zone in pixels
+    pub fn side(&self, modules: u32) -> u32 {
+        (modules + 2 * self.quiet) * self.module_px
+    }
+}
+
+/// Largest whole-pixel module for a `modules` wide code in `area`, keeping
+/// the full quiet zone where it fits; None when even 1px modules overflow
+pub fn fit(modules: u32, area: Size) -> Option<QrFit> {
+    let side = area.width.min(area.height);
+    (MIN_QUIET_ZONE..=QUIET_ZONE).rev().find_map(|quiet| {
+        let module_px = side / (modules + 2 * quiet);
+        (module_px > 0).then_some(QrFit { module_px, quiet })
+    })
+}
+
+/// The QR code for what's playing
+#[derive(Default)]
+pub struct NowPlayingQr {
+    text: String,
+    code: Option<QrCode>,
+}
+
+impl NowPlayingQr {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Encode `text`, kept as is when unchanged; too long for a QR code leaves none
+    pub fn set_text(&mut self, text: &str) {
+        if self.text == text {
+            return;
+        }
+        self.text = text.to_string();
+        self.code = if text.is_empty() {
+            None
+        } else {
+            QrCode::encode_text(text, QrCodeEcc::Low)
+                .map_err(|_| warn!("Now playing text too long for a QR code ({} bytes)", text.len()))
+                .ok()
+        };
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn code(&self) -> Option<&QrCode> {
+        self.code.as_ref()
+    }
+
+    /// Draw the code and its quiet zone centred in `area`; false when there
+    /// is no code or it cannot fit
+    pub fn render<D>(&self, target: &mut D, area: Rectangle) -> Result<bool, D::Error>
+    where
+        D: DrawTarget,
+        Color: ConvertColor<D::Color>,
+    {
+        let Some(code) = &self.code else { return Ok(false) };
+        let modules = code.size() as u32;
+        let Some(fit) = fit(modules, area.size) else { return Ok(false) };
+
+        let side = fit.side(modules);
+        let origin = area.top_left
+            + Point::new((area.size.width - side) as i32 / 2, (area.size.height - side) as i32 / 2);
+        let light: D::Color = Color::White.to_color();
+        let dark: D::Color = Color::Black.to_color();
+        Rectangle::new(origin, Size::new(side, side))
+            .into_styled(PrimitiveStyle::with_fill(light))
+            .draw(target)?;
+
+        let px = fit.module_px as i32;
+        let start = origin + Point::new(fit.quiet as i32 * px, fit.quiet as i32 * px);
+        let dark_modules = (0..code.size())
+            .flat_map(|y| (0..code.size()).map(move |x| (x, y)))
+            .filter(|&(x, y)| code.get_module(x, y))
+            .map(|(x, y)| Rectangle::new(start + Point::new(x * px, y * px), Size::new(fit.module_px, fit.module_px)));
+        for module in dark_modules {
+            target.fill_solid(&module, dark)?;
+        }
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vframebuf::VarFrameBuf;
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    #[test]
+    fn known_text_gives_a_version_2_code() {
+        let mut qr = NowPlayingQr::new();
+        // 23 bytes with the en dash - past version 1-L's 17, within 2-L's 32
+        qr.set_text(&qr_text(None, "Miles Davis", "So What", "Kind of Blue"));
+        assert_eq!(qr.text(), "Miles Davis – So What");
+        let code = qr.code().unwrap();
+        assert_eq!(code.version().value(), 2);
+        assert_eq!(code.size(), 25);
+
+        qr.set_text(&"x".repeat(3000));
+        assert!(qr.code().is_none());
+    }
+
+    #[test]
+    fn modules_scale_with_the_panel_and_the_quiet_zone_gives_way() {
+        // 64px high panels: 25 + 8 quiet modules at 1px
+        assert_eq!(fit(25, Size::new(128, 64)), Some(QrFit { module_px: 1, quiet: 4 }));
+        assert_eq!(fit(25, Size::new(256, 64)), Some(QrFit { module_px: 1, quiet: 4 }));
+        assert_eq!(fit(25, Size::new(320, 240)), Some(QrFit { module_px: 7, quiet: 4 }));
+        // 33 modules keep a 1 module quiet zone at 36px, and don't fit at 34px
+        assert_eq!(fit(33, Size::new(128, 36)), Some(QrFit { module_px: 1, quiet: 1 }));
+        assert_eq!(fit(33, Size::new(128, 34)), None);
+    }
+
+    #[test]
+    fn code_is_dark_on_a_light_quiet_zone() {
+        let mut qr = NowPlayingQr::new();
+        qr.set_text("https://lyrion.org");
+        let code = qr.code().unwrap();
+        let mut fb = VarFrameBuf::new(128, 64, BinaryColor::Off);
+        assert!(qr.render(&mut fb, Rectangle::new(Point::zero(), Size::new(128, 64))).unwrap());
+
+        let modules = code.size();
+        let side = modules + 8;
+        let (left, top) = ((128 - side) / 2, (64 - side) / 2);
+        let at = |x: i32, y: i32| fb.as_slice()[(y * 128 + x) as usize];
+        // the quiet zone corner is lit, the finder pattern's corner is not
+        assert_eq!(at(left, top), BinaryColor::On);
+        assert_eq!(at(left + 4, top + 4), BinaryColor::Off);
+        for y in 0..modules {
+            for x in 0..modules {
+                let lit = at(left + 4 + x, top + 4 + y) == BinaryColor::On;
+                assert_eq!(lit, !code.get_module(x, y), "module {x},{y}");
+            }
+        }
+    }
+}
@@ -23,13 +23,141 @@
 
 #![allow(dead_code)] // scroller component helpers; some methods reserved
 
+use std::collections::VecDeque;
 use std::sync::Arc;
+use std::time::Duration;
 use embedded_graphics::prelude::*;
 use embedded_graphics::pixelcolor::BinaryColor;
+use embedded_graphics::mono_font::MonoFont;
 use crate::display::layout::LayoutConfig;
 use crate::display::field::Field;
 use crate::display::ttf_font::{BlendCoverage, TtfFont};
-use crate::textable::{TextScroller, ScrollMode};
+use crate::textable::{
+    eased_cylon_offset, eased_cylon_phase_step, sanitize_for_font, text_direction, visual_order, LoopGap, TextDirection, TextScroller, ScrollMode,
+};
+
+/// Track tag a scroller line can show, named as its layout field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrackTag {
+    AlbumArtist,
+    Album,
+    Title,
+    Artist,
+    Composer,
+    Conductor,
+}
+
+impl TrackTag {
+    pub const ALL: [TrackTag; 6] = [
+        TrackTag::AlbumArtist, TrackTag::Album, TrackTag::Title,
+        TrackTag::Artist, TrackTag::Composer, TrackTag::Conductor,
+    ];
+
+    /// Lines shown when none are configured, top to bottom
+    pub const DEFAULT_LINES: [TrackTag; 4] = [
+        TrackTag::AlbumArtist, TrackTag::Album, TrackTag::Title, TrackTag::Artist,
+    ];
+
+    /// Layout field the line renders into
+    pub fn field_name(self) -> &'static str {
+        match self {
+            TrackTag::AlbumArtist => "album_artist",
+            TrackTag::Album       => "album",
+            TrackTag::Title       => "title",
+            TrackTag::Artist      => "artist",
+            TrackTag::Composer    => "composer",
+            TrackTag::Conductor   => "conductor",
+        }
+    }
+
+    /// The tag drawn into layout field `name`
+    pub fn from_field_name(name: &str) -> Option<Self> {
+        TrackTag::ALL.into_iter().find(|tag| tag.field_name() == name)
+    }
+}
+
+/// Tags of the playing track, as handed to the scrollers
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TrackInfo {
+    pub album_artist: String,
+    pub album: String,
+    pub title: String,
+    pub artist: String,
+    pub composer: String,
+    pub conductor: String,
+    pub year: String,
+}
+
+impl TrackInfo {
+    pub fn get(&self, tag: TrackTag) -> &str {
+        match tag {
+            TrackTag::AlbumArtist => &self.album_artist,
+            TrackTag::Album       => &self.album,
+            TrackTag::Title       => &self.title,
+            TrackTag::Artist      => &self.artist,
+            TrackTag::Composer    => &self.composer,
+            TrackTag::Conductor   => &self.conductor,
+        }
+    }
+
+    /// Every tag fitted to the glyphs of a bitmap `font`
+    pub fn sanitized_for(&self, font: &MonoFont) -> TrackInfo {
+        let fit = |s: &str| sanitize_for_font(s, font).into_owned();
+        TrackInfo {
+            album_artist: fit(&self.album_artist),
+            album: fit(&self.album),
+            title: fit(&self.title),
+            artist: fit(&self.artist),
+            composer: fit(&self.composer),
+            conductor: fit(&self.conductor),
+            year: fit(&self.year),
+        }
+    }
+}
+
+/// Most ticks of cylon afterglow; more would share gray levels on a 4-bit panel
+pub const MAX_CYLON_TRAIL: usize = 6;
+/// Brightness of the newest trail copy relative to the text
+const CYLON_TRAIL_PEAK: f32 = 0.6;
+
+/// Brightness, 0.0-1.0 of the text colour, of the trail copy `age` ticks old
+/// in a trail of `frames`; fades evenly to nothing past the oldest
+pub fn cylon_trail_level(age: usize, frames: usize) -> f32 {
+    if age >= frames {
+        return 0.0;
+    }
+    CYLON_TRAIL_PEAK * (frames - age) as f32 / frames as f32
+}
+
+/// Draws through to `target` with every colour dimmed to `level`
+struct Dimmed<'a, D> {
+    target: &'a mut D,
+    level: f32,
+}
+
+impl<D: DrawTarget> Dimensions for Dimmed<'_, D> {
+    fn bounding_box(&self) -> embedded_graphics::primitives::Rectangle {
+        self.target.bounding_box()
+    }
+}
+
+impl<D, C> DrawTarget for Dimmed<'_, D>
+where
+    D: DrawTarget<Color = C>,
+    C: BlendCoverage,
+{
+    type Color = C;
+    type Error = D::Error;
+
+    fn draw_iter<I>(&mut self, pixels: I) -> Result<(), Self::Error>
+    where
+        I: IntoIterator<Item = Pixel<C>>,
+    {
+        let level = self.level;
+        self.target.draw_iter(pixels.into_iter().map(|Pixel(p, c)| Pixel(p, C::dim(c, level))))
+    }
+}
 
 /// Simple scroll state for one line of text
 struct ScrollState {
@@ -39,33 +167,101 @@ struct ScrollState {
     offset: i32,
     direction: i32, // -1 for left, 1 for right
     pause_counter: u32,
+    loop_pause_ticks: u32, // extra hold at the start position after each loop pass
+    loop_gap: Option<LoopGap>, // space before the repeat in loop mode, None keeps the font default
+    rtl: bool, // text is right-to-left: stored in visual order, offsets mirrored
+    text_width: i32, // last measured width, needed to mirror RTL offsets
+    phase: f32, // eased cylon position through one bounce, 0..1
+    trail: VecDeque<i32>, // cylon offsets of the last few ticks, newest first
+    trail_len: usize, // ticks of afterglow kept, 0 for none
     log_counter: u32, // For debug logging
 }
 
 impl ScrollState {
-    fn new() -> Self {
+    fn new(scroll_width: u32) -> Self {
         Self {
             text: String::new(),
             offset: 0,
             char_width: 6,
-            scroll_width: 0,
+            scroll_width,
             direction: -1,
             pause_counter: 0,
+            loop_pause_ticks: 0,
+            loop_gap: None,
+            rtl: false,
+            text_width: 0,
+            phase: 0.0,
+            trail: VecDeque::new(),
+            trail_len: 0,
             log_counter: 0,
         }
     }
 
     fn set_text(&mut self, text: String) {
+        let rtl = text_direction(&text) == TextDirection::RightToLeft;
+        let text = if rtl { visual_order(&text) } else { text };
         // Only reset scroll state if text actually changed
         if self.text != text {
             self.text = text;
+            self.rtl = rtl;
             self.offset = 0;
+            self.phase = 0.0;
+            self.trail.clear();
             self.pause_counter = 30; // Pause for 30 frames before scrolling
             self.log_counter = 0; // Reset log counter for new text
         }
     }
 
+    /// Take the field width and its font advance; a field without a font
+    /// measures with FONT_5X8, the font `render_field` falls back to
+    fn set_field_metrics(&mut self, field: &Field) {
+        let font = field.font.unwrap_or(&embedded_graphics::mono_font::iso_8859_13::FONT_5X8);
+        self.scroll_width = field.width();
+        self.char_width = font.character_size.width as usize + font.character_spacing as usize;
+    }
+
+    /// Whether the last measured text is wider than the field
+    fn overflows(&self) -> bool {
+        !self.text.is_empty() && self.text_width > self.scroll_width as i32
+    }
+
+    /// Back to the start position with the initial hold, keeping the text
+    fn restart(&mut self) {
+        self.offset = 0;
+        self.phase = 0.0;
+        self.direction = -1;
+        self.pause_counter = 30;
+        self.trail.clear();
+    }
+
     fn update(&mut self, scroll_mode: ScrollMode, ttf: Option<&TtfFont>) {
+        let before = self.offset;
+        self.advance(scroll_mode, ttf);
+        self.record_trail(before, scroll_mode);
+    }
+
+    /// Keep the offset this tick started from while a cylon scroll is moving;
+    /// held ticks repeat it so the trail settles under the text at each turn
+    fn record_trail(&mut self, before: i32, scroll_mode: ScrollMode) {
+        let cylon = matches!(scroll_mode, ScrollMode::ScrollCylon | ScrollMode::ScrollCylonEased);
+        if self.trail_len == 0 || !cylon || !self.overflows() {
+            self.trail.clear();
+            return;
+        }
+        self.trail.push_front(before);
+        self.trail.truncate(self.trail_len);
+    }
+
+    /// Draw positions of the trail, newest first, skipping any under the text
+    fn trail_offsets(&self) -> impl DoubleEndedIterator<Item = (usize, i32)> + '_ {
+        self.trail
+            .iter()
+            .enumerate()
+            .filter(|&(_, &offset)| offset != self.offset)
+            .map(|(age, &offset)| (age, self.mirrored(offset)))
+    }
+
+    fn advance(&mut self, scroll_mode: ScrollMode, ttf: Option<&TtfFont>) {
 
         if self.text.is_empty() {
             return;
@@ -73,12 +269,13 @@ impl ScrollState {
 
         let text_width = match ttf {
             Some(f) => f.measure_text(&self.text),
-            None    => (self.text.len() * self.char_width) as i32,
+            None    => (self.text.chars().count() * self.char_width) as i32,
         };
+        self.text_width = text_width;
 
-        // If text fits on screen, no scrolling needed
+        // If text fits on screen, no scrolling needed - RTL is right aligned (mirrored zero)
         if text_width <= self.scroll_width as i32{
-            let cx: i32 = (self.scroll_width as i32 - text_width) / 2;
+            let cx: i32 = if self.rtl { 0 } else { (self.scroll_width as i32 - text_width) / 2 };
             self.offset = cx;
             return;
         }
@@ -93,9 +290,9 @@ impl ScrollState {
             ScrollMode::ScrollLeft => {
                 // Continuous left scroll with loop
                 self.offset -= 1;
-                if self.offset < -(text_width + 12) {
+                if self.offset < -(text_width + self.loop_gap_px(12)) {
                     self.offset = 0;
-                    self.pause_counter = 30;
+                    self.pause_counter = 30 + self.loop_pause_ticks;
                 }
             }
             ScrollMode::ScrollCylon => {
@@ -109,6 +306,16 @@ impl ScrollState {
                     self.pause_counter = 30;
                 }
             }
+            ScrollMode::ScrollCylonEased => {
+                // glide between the edges, holding briefly at each turn
+                let travel = (text_width - self.scroll_width as i32) as f32;
+                let before = self.phase;
+                self.phase = (self.phase + eased_cylon_phase_step(travel, 1.0)).fract();
+                self.offset = eased_cylon_offset(self.phase, travel).round() as i32;
+                if (before < 0.5 && self.phase >= 0.5) || self.phase < before {
+                    self.pause_counter = 30;
+                }
+            }
             ScrollMode::Static => {
                 // center the text
                 let cx: i32 = if text_width < self.scroll_width as i32 {(self.scroll_width as i32 - text_width) / 2} else { 0 };
@@ -117,8 +324,28 @@ impl ScrollState {
         }
     }
 
+    /// Draw position relative to the field. Scrolling runs in LTR terms;
+    /// RTL text is mirrored so it starts right aligned and moves rightwards.
     fn get_offset(&self) -> i32 {
-        self.offset
+        self.mirrored(self.offset)
+    }
+
+    fn mirrored(&self, offset: i32) -> i32 {
+        if self.rtl {
+            self.scroll_width as i32 - self.text_width - offset
+        } else {
+            offset
+        }
+    }
+
+    /// Loop gap in pixels, `natural` unless one is configured
+    fn loop_gap_px(&self, natural: i32) -> i32 {
+        self.loop_gap.map_or(natural, |gap| gap.resolve(self.scroll_width) as i32)
+    }
+
+    /// Position of the trailing copy drawn in loop mode.
+    fn loop_copy_x(&self, x: i32, text_width: i32, gap: i32) -> i32 {
+        if self.rtl { x - text_width - gap } else { x + text_width + gap }
     }
 }
 
@@ -135,12 +362,17 @@ pub struct ScrollingText {
     album_scroll: ScrollState,
     title_scroll: ScrollState,
     artist_scroll: ScrollState,
+    composer_scroll: ScrollState,
+    conductor_scroll: ScrollState,
     combination_scroll: ScrollState,
     year_scroll: ScrollState,
+    /// Track lines shown, in order; the others are left empty
+    lines: Vec<TrackTag>,
     // attribute drivers
     scroll_mode: ScrollMode,
     layout: LayoutConfig,
-    display_width: u32,
+    /// Line width until a field's own width is known: the layout's content area
+    region_width: u32,
     /// Optional TTF renderer; when set, replaces MonoFont bitmap rendering.
     ttf_font: Option<Arc<TtfFont>>,
 }
@@ -148,7 +380,7 @@ pub struct ScrollingText {
 impl ScrollingText {
     /// Create a new scrolling text component
     pub fn new(layout: LayoutConfig, scroll_mode: ScrollMode) -> Self {
-        let display_width = layout.width;
+        let region_width = layout.content_width();
         Self {
             album_artist_scroller: None,
             album_scroller: None,
@@ -156,25 +388,131 @@ impl ScrollingText {
             artist_scroller: None,
             combination_scroller: None,
             year_scroller: None,
-            album_artist_scroll: ScrollState::new(),
-            album_scroll: ScrollState::new(),
-            title_scroll: ScrollState::new(),
-            artist_scroll: ScrollState::new(),
-            combination_scroll: ScrollState::new(),
-            year_scroll: ScrollState::new(),
+            album_artist_scroll: ScrollState::new(region_width),
+            album_scroll: ScrollState::new(region_width),
+            title_scroll: ScrollState::new(region_width),
+            artist_scroll: ScrollState::new(region_width),
+            composer_scroll: ScrollState::new(region_width),
+            conductor_scroll: ScrollState::new(region_width),
+            combination_scroll: ScrollState::new(region_width),
+            year_scroll: ScrollState::new(region_width),
+            lines: TrackTag::DEFAULT_LINES.to_vec(),
             scroll_mode,
             layout,
-            display_width,
+            region_width,
             ttf_font: None,
         }
     }
 
+    /// Scroll region width used before field metrics are applied
+    pub fn region_width(&self) -> u32 {
+        self.region_width
+    }
+
     /// Attach a TTF font.  Replaces MonoFont rendering in `render_field` and
     /// enables accurate pixel-width measurement for CJK and variable-width text.
     pub fn set_ttf_font(&mut self, font: Arc<TtfFont>) {
         self.ttf_font = Some(font);
     }
 
+    /// Hold loop scrolls at the start position for `pause` after each full pass.
+    /// `tick` is the interval between `update` calls; zero pause keeps the default behaviour.
+    pub fn set_loop_pause(&mut self, pause: Duration, tick: Duration) {
+        let ticks = if tick.is_zero() { 0 } else { (pause.as_millis() / tick.as_millis().max(1)) as u32 };
+        for state in [
+            &mut self.album_artist_scroll,
+            &mut self.album_scroll,
+            &mut self.title_scroll,
+            &mut self.artist_scroll,
+            &mut self.composer_scroll,
+            &mut self.conductor_scroll,
+            &mut self.combination_scroll,
+            &mut self.year_scroll,
+        ] {
+            state.loop_pause_ticks = ticks;
+        }
+    }
+
+    /// Space between looping text and its repeat; `None` keeps the font's three character gap
+    pub fn set_loop_gap(&mut self, gap: Option<LoopGap>) {
+        for state in [
+            &mut self.album_artist_scroll,
+            &mut self.album_scroll,
+            &mut self.title_scroll,
+            &mut self.artist_scroll,
+            &mut self.composer_scroll,
+            &mut self.conductor_scroll,
+            &mut self.combination_scroll,
+            &mut self.year_scroll,
+        ] {
+            state.loop_gap = gap;
+        }
+    }
+
+    /// Leave a fading copy of cylon text at its last `frames` positions, at
+    /// most `MAX_CYLON_TRAIL`; 0 turns the afterglow off. Meant for grayscale
+    /// panels, mono panels have no levels to fade through
+    pub fn set_cylon_trail(&mut self, frames: usize) {
+        let frames = frames.min(MAX_CYLON_TRAIL);
+        for state in [
+            &mut self.album_artist_scroll,
+            &mut self.album_scroll,
+            &mut self.title_scroll,
+            &mut self.artist_scroll,
+            &mut self.composer_scroll,
+            &mut self.conductor_scroll,
+            &mut self.combination_scroll,
+            &mut self.year_scroll,
+        ] {
+            state.trail_len = frames;
+            state.trail.truncate(frames);
+        }
+    }
+
+    /// Pick which track lines scroll, in display order; an empty set keeps the default four
+    pub fn set_lines(&mut self, lines: &[TrackTag]) {
+        self.lines = if lines.is_empty() { TrackTag::DEFAULT_LINES.to_vec() } else { lines.to_vec() };
+    }
+
+    /// Track lines shown, in display order
+    pub fn lines(&self) -> &[TrackTag] {
+        &self.lines
+    }
+
+    /// Whether the line for `tag` is shown and has text to scroll
+    pub fn is_active(&self, tag: TrackTag) -> bool {
+        self.lines.contains(&tag) && self.state(tag.field_name()).is_some_and(|s| !s.text.is_empty())
+    }
+
+    /// Scroll state behind layout field `name`
+    fn state(&self, name: &str) -> Option<&ScrollState> {
+        match name {
+            "album_artist" => Some(&self.album_artist_scroll),
+            "artist"       => Some(&self.artist_scroll),
+            "album"        => Some(&self.album_scroll),
+            "title"        => Some(&self.title_scroll),
+            "composer"     => Some(&self.composer_scroll),
+            "conductor"    => Some(&self.conductor_scroll),
+            "combination"  => Some(&self.combination_scroll),
+            "year"         => Some(&self.year_scroll),
+            _              => None,
+        }
+    }
+
+    fn state_mut(&mut self, name: &str) -> Option<&mut ScrollState> {
+        match name {
+            "album_artist" => Some(&mut self.album_artist_scroll),
+            "artist"       => Some(&mut self.artist_scroll),
+            "album"        => Some(&mut self.album_scroll),
+            "title"        => Some(&mut self.title_scroll),
+            "composer"     => Some(&mut self.composer_scroll),
+            "conductor"    => Some(&mut self.conductor_scroll),
+            "combination"  => Some(&mut self.combination_scroll),
+            "year"         => Some(&mut self.year_scroll),
+            _              => None,
+        }
+    }
+
     /// Update album artist text
     pub fn set_album_artist(&mut self, album_artist: String) {
         self.album_artist_scroll.set_text(album_artist);
@@ -212,23 +550,18 @@ impl ScrollingText {
         self.set_title(title);
     }
 
-    /// Update artist, title, and album
-    pub fn set_full_track_info(
-        &mut self, 
-        album_artist: String,
-        album: String, 
-        title: String, 
-        artist: String, 
-        year: String, 
-    ) 
-    {
-        self.set_album_artist(album_artist.clone());
-        self.set_album(album.clone());
-        self.set_title(title.clone());
-        self.set_artist(artist.clone());
-        self.set_year(year.clone());
+    /// Update every track line; lines not shown are left empty so they never scroll
+    pub fn set_full_track_info(&mut self, info: &TrackInfo) {
+        for tag in TrackTag::ALL {
+            let text = if self.lines.contains(&tag) { info.get(tag).to_string() } else { String::new() };
+            if let Some(state) = self.state_mut(tag.field_name()) {
+                state.set_text(text);
+            }
+        }
+        self.set_year(info.year.clone());
 
         // Set combination text (artist - title or partial)
+        let (artist, album, title) = (&info.artist, &info.album, &info.title);
         let scroll_text = match (artist.is_empty(), album.is_empty(), title.is_empty()) {
             (false, false, false) => format!("{} - {} - {}", artist.clone(), album.clone(), title.clone()),
             (false, false, true)  => format!("{} - {}", artist.clone(), album.clone()),
@@ -248,6 +581,8 @@ impl ScrollingText {
         self.album_scroll.update(self.scroll_mode, ttf);
         self.title_scroll.update(self.scroll_mode, ttf);
         self.artist_scroll.update(self.scroll_mode, ttf);
+        self.composer_scroll.update(self.scroll_mode, ttf);
+        self.conductor_scroll.update(self.scroll_mode, ttf);
         self.combination_scroll.update(self.scroll_mode, ttf);
         self.year_scroll.update(self.scroll_mode, ttf);
     }
@@ -261,15 +596,12 @@ impl ScrollingText {
     /// Update combination scroll position using actual field width
     pub fn update_combination_with_field(&mut self, field: &Field) {
         let ttf = self.ttf_font.as_deref();
-        self.combination_scroll.scroll_width = field.width();
-        if let Some(f) = field.font {
-            self.combination_scroll.char_width =
-                f.character_size.width as usize + f.character_spacing as usize;
-        }
+        self.combination_scroll.set_field_metrics(field);
         self.combination_scroll.update(self.scroll_mode, ttf);
     }
 
-    /// Update scroll position using field widths
+    /// Update scroll position using each field's width and font, so a line
+    /// laid out in a bigger font scrolls as soon as it no longer fits
     pub fn update_with_fields(
         &mut self,
         album_artist_field: &Field,
@@ -279,42 +611,23 @@ impl ScrollingText {
         year_field:         &Field,
     ) {
         let ttf = self.ttf_font.as_deref();
-
-        self.album_artist_scroll.scroll_width = album_artist_field.width();
-        if let Some(f) = album_artist_field.font {
-            self.album_artist_scroll.char_width =
-                f.character_size.width as usize + f.character_spacing as usize;
-        }
-        self.album_artist_scroll.update(self.scroll_mode, ttf);
-
-        self.album_scroll.scroll_width = album_field.width();
-        if let Some(f) = album_field.font {
-            self.album_scroll.char_width =
-                f.character_size.width as usize + f.character_spacing as usize;
-        }
-        self.album_scroll.update(self.scroll_mode, ttf);
-
-        self.title_scroll.scroll_width = title_field.width();
-        if let Some(f) = title_field.font {
-            self.title_scroll.char_width =
-                f.character_size.width as usize + f.character_spacing as usize;
-        }
-        self.title_scroll.update(self.scroll_mode, ttf);
-
-        self.artist_scroll.scroll_width = artist_field.width();
-        if let Some(f) = artist_field.font {
-            self.artist_scroll.char_width =
-                f.character_size.width as usize + f.character_spacing as usize;
+        for (state, field) in [
+            (&mut self.album_artist_scroll, album_artist_field),
+            (&mut self.album_scroll,        album_field),
+            (&mut self.title_scroll,        title_field),
+            (&mut self.artist_scroll,       artist_field),
+            (&mut self.year_scroll,         year_field),
+        ] {
+            state.set_field_metrics(field);
+            state.update(self.scroll_mode, ttf);
         }
+    }
 
-        self.year_scroll.update(self.scroll_mode, ttf);
-        self.year_scroll.scroll_width = year_field.width();
-        if let Some(f) = year_field.font {
-            self.year_scroll.char_width =
-                f.character_size.width as usize + f.character_spacing as usize;
+    /// Update scroll position for every track line and the year laid out on `page`
+    pub fn update_with_page(&mut self, page: &crate::display::PageLayout) {
+        for field in page.fields().iter().filter(|f| f.name != "combination") {
+            self.update_field_scroll(field);
         }
-        self.year_scroll.update(self.scroll_mode, ttf);
-
     }
 
     /// Update scroll width + advance one tick for a single named field.
@@ -322,21 +635,15 @@ impl ScrollingText {
     /// Call this before `render_field()` for each scrollable egg overlay field so
     /// the scroller knows the field width (for centering / overflow detection).
     pub fn update_field_scroll(&mut self, field: &Field) {
-        let ttf = self.ttf_font.as_deref();
-        let state = match field.name.as_str() {
-            "album_artist" => &mut self.album_artist_scroll,
-            "artist"       => &mut self.artist_scroll,
-            "album"        => &mut self.album_scroll,
-            "title"        => &mut self.title_scroll,
-            "combination"  => &mut self.combination_scroll,
-            "year"         => &mut self.year_scroll,
-            _              => return,
-        };
-        state.scroll_width = field.width();
-        if let Some(f) = field.font {
-            state.char_width = f.character_size.width as usize + f.character_spacing as usize;
-        }
-        state.update(self.scroll_mode, ttf);
+        let (scroll_mode, ttf) = (self.scroll_mode, self.ttf_font.clone());
+        let Some(state) = self.state_mut(&field.name) else { return };
+        state.set_field_metrics(field);
+        state.update(scroll_mode, ttf.as_deref());
+    }
+
+    /// True when the named line is wider than its field at the last update
+    pub fn field_overflows(&self, name: &str) -> bool {
+        self.state(name).is_some_and(ScrollState::overflows)
     }
 
     /// Render the scrolling text
@@ -362,8 +669,8 @@ impl ScrollingText {
 
             // For continuous loop mode, draw the text again after a gap
             if self.scroll_mode == ScrollMode::ScrollLeft {
-                let text_width = (self.album_artist_scroll.text.len() * char_width) as i32 - last_spacing;
-                let loop_x = x + text_width + word_gap; // 3 char gap
+                let text_width = (self.album_artist_scroll.text.chars().count() * char_width) as i32 - last_spacing;
+                let loop_x = self.album_artist_scroll.loop_copy_x(x, text_width, self.album_artist_scroll.loop_gap_px(word_gap)); // 3 char gap
                 Text::new(&self.album_artist_scroll.text, Point::new(loop_x, text_y), text_style).draw(target)?;
             }
         }
@@ -374,8 +681,8 @@ impl ScrollingText {
             Text::new(&self.album_scroll.text, Point::new(x, text_y), text_style).draw(target)?;
 
             if self.scroll_mode == ScrollMode::ScrollLeft {
-                let text_width = (self.album_scroll.text.len() * char_width) as i32 - last_spacing;
-                let loop_x = x + text_width + word_gap;
+                let text_width = (self.album_scroll.text.chars().count() * char_width) as i32 - last_spacing;
+                let loop_x = self.album_scroll.loop_copy_x(x, text_width, self.album_scroll.loop_gap_px(word_gap));
                 Text::new(&self.album_scroll.text, Point::new(loop_x, text_y), text_style).draw(target)?;
             }
         }
@@ -386,8 +693,8 @@ impl ScrollingText {
             Text::new(&self.title_scroll.text, Point::new(x, text_y), text_style).draw(target)?;
 
             if self.scroll_mode == ScrollMode::ScrollLeft {
-                let text_width = (self.title_scroll.text.len() * char_width) as i32 - last_spacing;
-                let loop_x = x + text_width + word_gap;
+                let text_width = (self.title_scroll.text.chars().count() * char_width) as i32 - last_spacing;
+                let loop_x = self.title_scroll.loop_copy_x(x, text_width, self.title_scroll.loop_gap_px(word_gap));
                 Text::new(&self.title_scroll.text, Point::new(loop_x, text_y), text_style).draw(target)?;
             }
         }
@@ -399,8 +706,8 @@ impl ScrollingText {
 
             // For continuous loop mode, draw the text again after a gap
             if self.scroll_mode == ScrollMode::ScrollLeft {
-                let text_width = (self.artist_scroll.text.len() * char_width) as i32 - last_spacing;
-                let loop_x = x + text_width + word_gap; // 12px gap
+                let text_width = (self.artist_scroll.text.chars().count() * char_width) as i32 - last_spacing;
+                let loop_x = self.artist_scroll.loop_copy_x(x, text_width, self.artist_scroll.loop_gap_px(word_gap)); // 12px gap
                 Text::new(&self.artist_scroll.text, Point::new(loop_x, text_y), text_style).draw(target)?;
             }
         }
@@ -421,15 +728,7 @@ impl ScrollingText {
     {
         use crate::display::color_proxy::ConvertColor;
 
-        let scroll_state = match field.name.as_str() {
-            "album_artist" => &self.album_artist_scroll,
-            "artist"       => &self.artist_scroll,
-            "album"        => &self.album_scroll,
-            "title"        => &self.title_scroll,
-            "combination"  => &self.combination_scroll,
-            "year"         => &self.year_scroll,
-            _              => return Ok(()),
-        };
+        let Some(scroll_state) = self.state(&field.name) else { return Ok(()) };
 
         if scroll_state.text.is_empty() {
             return Ok(());
@@ -447,14 +746,23 @@ impl ScrollingText {
             let baseline_y= field_pos.y + (field_h - line_h) / 2 + ascent;
             let x= field_pos.x + scroll_state.get_offset();
 
+            for (age, offset) in scroll_state.trail_offsets().rev() {
+                let mut dimmed = Dimmed { target: &mut clipped, level: cylon_trail_level(age, scroll_state.trail_len) };
+                ttf.render_text(&scroll_state.text, field_pos.x + offset, baseline_y, fg, &mut dimmed)?;
+            }
             ttf.render_text(&scroll_state.text, x, baseline_y, fg, &mut clipped)?;
 
             if field.scrollable && self.scroll_mode == ScrollMode::ScrollLeft {
                 let text_px = ttf.measure_text(&scroll_state.text);
-                let gap     = (ttf.pixel_size() * 2.0).round() as i32;
-                ttf.render_loop_copy(
-                    &scroll_state.text, x, baseline_y, fg, text_px, gap, &mut clipped,
-                )?;
+                let gap     = scroll_state.loop_gap_px((ttf.pixel_size() * 2.0).round() as i32);
+                if scroll_state.rtl {
+                    let loop_x = scroll_state.loop_copy_x(x, text_px, gap);
+                    ttf.render_text(&scroll_state.text, loop_x, baseline_y, fg, &mut clipped)?;
+                } else {
+                    ttf.render_loop_copy(
+                        &scroll_state.text, x, baseline_y, fg, text_px, gap, &mut clipped,
+                    )?;
+                }
             }
         } else {
             // MonoFont path — identical to the previous implementation.
@@ -470,13 +778,18 @@ impl ScrollingText {
             let x= field_pos.x + scroll_state.get_offset();
             let last_spacing = font.character_spacing as i32;
 
-
+            // afterglow oldest first, so newer copies and then the text land on top
+            for (age, offset) in scroll_state.trail_offsets().rev() {
+                let mut dimmed = Dimmed { target: &mut clipped, level: cylon_trail_level(age, scroll_state.trail_len) };
+                Text::new(&scroll_state.text, Point::new(field_pos.x + offset, baseline_y), text_style)
+                    .draw(&mut dimmed)?;
+            }
             Text::new(&scroll_state.text, Point::new(x, baseline_y), text_style)
                 .draw(&mut clipped)?;
 
             if field.scrollable && self.scroll_mode == ScrollMode::ScrollLeft {
-                let text_width = (scroll_state.text.len() * char_width) as i32 - last_spacing;
-                let loop_x     = x + text_width + word_gap as i32;
+                let text_width = (scroll_state.text.chars().count() * char_width) as i32 - last_spacing;
+                let loop_x     = scroll_state.loop_copy_x(x, text_width, scroll_state.loop_gap_px(word_gap as i32));
                 Text::new(&scroll_state.text, Point::new(loop_x, baseline_y), text_style)
                     .draw(&mut clipped)?;
             }
@@ -495,6 +808,23 @@ impl ScrollingText {
         self.year_scroller = None;
     }
 
+    /// Restart every line from its start position, e.g. when a new egg
+    /// overlay takes over mid-scroll
+    pub fn restart(&mut self) {
+        for state in [
+            &mut self.album_artist_scroll,
+            &mut self.album_scroll,
+            &mut self.title_scroll,
+            &mut self.artist_scroll,
+            &mut self.composer_scroll,
+            &mut self.conductor_scroll,
+            &mut self.combination_scroll,
+            &mut self.year_scroll,
+        ] {
+            state.restart();
+        }
+    }
+
     /// Get scroll mode
     pub fn scroll_mode(&self) -> ScrollMode {
         self.scroll_mode
@@ -504,5 +834,320 @@ impl ScrollingText {
     pub fn set_scroll_mode(&mut self, mode: ScrollMode) {
         self.scroll_mode = mode;
     }
-    
+
+}
+
+/// Single-line marquee for a fixed-font field outside the track scroller,
+/// e.g. the weather conditions text. Only scrolls once the text is wider
+/// than the field; callers keep their static rendering otherwise.
+pub struct Marquee {
+    state: ScrollState,
+    scroll_mode: ScrollMode,
+}
+
+impl Marquee {
+    /// Create a new marquee
+    pub fn new(scroll_mode: ScrollMode) -> Self {
+        Self {
+            state: ScrollState::new(0),
+            scroll_mode,
+        }
+    }
+
+    /// Update marquee text; position is only reset when the text changes
+    pub fn set_text(&mut self, text: &str) {
+        if self.state.text != text {
+            self.state.set_text(text.to_string());
+        }
+    }
+
+    /// Take the field width and font, then advance one tick
+    pub fn update(&mut self, field: &Field) {
+        self.state.set_field_metrics(field);
+        self.state.update(self.scroll_mode, None);
+    }
+
+    /// True when the measured text overflows the field and the mode moves it
+    pub fn is_scrolling(&self) -> bool {
+        self.scroll_mode != ScrollMode::Static && self.state.overflows()
+    }
+
+    /// Draw the text at the current offset, clipped to the field
+    pub fn render_field<D, C>(&self, field: &Field, target: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = C>,
+        C: PixelColor,
+        crate::display::color::Color: crate::display::color_proxy::ConvertColor<C>,
+    {
+        use crate::display::color_proxy::ConvertColor;
+        use embedded_graphics::mono_font::MonoTextStyle;
+        use embedded_graphics::text::Text;
+
+        if self.state.text.is_empty() {
+            return Ok(());
+        }
+
+        let font = field.font.unwrap_or(&embedded_graphics::mono_font::iso_8859_13::FONT_5X8);
+        let text_style = MonoTextStyle::new(font, field.fg_color.to_color());
+        let char_width = font.character_size.width as i32 + font.character_spacing as i32;
+        let field_pos = field.position();
+        let baseline_y = field_pos.y + font.baseline as i32;
+        let x = field_pos.x + self.state.get_offset();
+        let mut clipped = target.clipped(&field.bounds);
+
+        Text::new(&self.state.text, Point::new(x, baseline_y), text_style).draw(&mut clipped)?;
+
+        if self.scroll_mode == ScrollMode::ScrollLeft {
+            let text_width = self.state.text.chars().count() as i32 * char_width - font.character_spacing as i32;
+            let loop_x = self.state.loop_copy_x(x, text_width, 3 * char_width);
+            Text::new(&self.state.text, Point::new(loop_x, baseline_y), text_style).draw(&mut clipped)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn looping_state(loop_pause_ticks: u32) -> ScrollState {
+        let mut state = ScrollState::new(0);
+        state.scroll_width = 20;
+        state.loop_pause_ticks = loop_pause_ticks;
+        state.set_text("0123456789".to_string()); // 60px at 6px/char
+        state
+    }
+
+    /// Tick until the loop wraps back to the start position.
+    fn run_to_wrap(state: &mut ScrollState) {
+        let mut ticks = 0;
+        loop {
+            state.update(ScrollMode::ScrollLeft, None);
+            ticks += 1;
+            if state.offset == 0 && state.pause_counter > 0 && ticks > 30 {
+                break;
+            }
+            assert!(ticks < 1000, "scroll never wrapped");
+        }
+    }
+
+    #[test]
+    fn loop_pause_holds_offset_after_full_pass() {
+        let mut state = looping_state(20);
+        run_to_wrap(&mut state);
+        for _ in 0..(30 + 20) {
+            state.update(ScrollMode::ScrollLeft, None);
+            assert_eq!(state.get_offset(), 0);
+        }
+        state.update(ScrollMode::ScrollLeft, None);
+        assert_eq!(state.get_offset(), -1);
+    }
+
+    /// Ticks from leaving the start position until the loop wraps back to it
+    fn loop_cycle(gap: Option<LoopGap>) -> u32 {
+        let mut state = looping_state(0);
+        state.loop_gap = gap;
+        state.pause_counter = 0;
+        let mut ticks = 0;
+        loop {
+            state.update(ScrollMode::ScrollLeft, None);
+            ticks += 1;
+            if state.offset == 0 {
+                return ticks;
+            }
+            assert!(ticks < 1000, "scroll never wrapped");
+        }
+    }
+
+    #[test]
+    fn larger_loop_gap_lengthens_the_cycle() {
+        // 60px of text: the default 12px gap wraps after 60 + 12 + 1 ticks
+        let default = loop_cycle(None);
+        assert_eq!(default, 73);
+        assert_eq!(loop_cycle(Some(LoopGap::Pixels(12))), default);
+        assert_eq!(loop_cycle(Some(LoopGap::Pixels(48))), default + 36);
+        // half of the 20px line is a 10px gap
+        assert_eq!(loop_cycle(Some(LoopGap::Fraction(0.5))), default - 2);
+    }
+
+    #[test]
+    fn loop_gap_reads_pixels_or_a_fraction() {
+        assert_eq!(serde_yaml::from_str::<LoopGap>("24").unwrap(), LoopGap::Pixels(24));
+        assert_eq!(serde_yaml::from_str::<LoopGap>("0.25").unwrap(), LoopGap::Fraction(0.25));
+        assert_eq!(LoopGap::Fraction(0.25).resolve(256), 64);
+    }
+
+    #[test]
+    fn zero_loop_pause_keeps_default_hold() {
+        let mut state = looping_state(0);
+        run_to_wrap(&mut state);
+        for _ in 0..30 {
+            state.update(ScrollMode::ScrollLeft, None);
+        }
+        state.update(ScrollMode::ScrollLeft, None);
+        assert_eq!(state.get_offset(), -1);
+    }
+
+    #[test]
+    fn rtl_text_flips_direction_and_right_aligns() {
+        let mut state = ScrollState::new(0);
+        state.scroll_width = 100;
+        state.set_text("שלום".to_string());
+        assert!(state.rtl);
+        state.update(ScrollMode::ScrollLeft, None);
+        // fits: flush against the right edge
+        assert_eq!(state.get_offset(), 100 - state.text_width);
+        assert!(state.get_offset() > 0);
+
+        state.set_text("Hello".to_string());
+        assert!(!state.rtl);
+    }
+
+    #[test]
+    fn rtl_text_scrolls_rightwards() {
+        let mut state = looping_state(0);
+        state.set_text("אבגדהוזחטי".to_string());
+        state.pause_counter = 0;
+        state.update(ScrollMode::ScrollLeft, None);
+        let first = state.get_offset();
+        state.update(ScrollMode::ScrollLeft, None);
+        assert_eq!(state.get_offset(), first + 1);
+        assert_eq!(state.loop_copy_x(0, 60, 12), -72);
+    }
+
+    #[test]
+    fn set_loop_pause_converts_to_ticks() {
+        let layout = crate::display::layout::layout_for_resolution(128, 64, crate::display::ColorDepth::Monochrome);
+        let mut text = ScrollingText::new(layout, ScrollMode::ScrollLeft);
+        text.set_loop_pause(Duration::from_secs(2), Duration::from_millis(50));
+        assert_eq!(text.title_scroll.loop_pause_ticks, 40);
+    }
+
+    #[test]
+    fn cylon_trail_dims_with_age() {
+        use crate::vframebuf::VarFrameBuf;
+        use embedded_graphics::mono_font::iso_8859_13::FONT_5X8;
+        use embedded_graphics::pixelcolor::Gray4;
+        use embedded_graphics::primitives::Rectangle;
+
+        let levels: Vec<f32> = (0..4).map(|age| cylon_trail_level(age, 3)).collect();
+        assert!(levels.windows(2).all(|w| w[0] > w[1]), "{levels:?}");
+        assert_eq!(levels[3], 0.0);
+
+        let layout = crate::display::layout::layout_for_resolution(256, 64, crate::display::ColorDepth::Gray4);
+        let mut text = ScrollingText::new(layout, ScrollMode::ScrollCylon);
+        text.set_cylon_trail(3);
+        // one pixel bars five apart leave room for three trail columns each
+        text.set_title("|".repeat(20));
+        let field = Field::new_text("title", Rectangle::new(Point::zero(), Size::new(40, 8)), &FONT_5X8);
+        for _ in 0..34 {
+            text.update_field_scroll(&field);
+        }
+        assert_eq!(text.title_scroll.trail.len(), 3);
+
+        let mut fb = VarFrameBuf::new(40, 8, Gray4::BLACK);
+        text.render_field(&field, &mut fb).unwrap();
+        let row: Vec<u8> = (0..40).map(|x| fb.as_slice()[3 * 40 + x].luma()).collect();
+        let bar = row.iter().position(|&l| l == 15).unwrap();
+        // the text moves left, so its afterglow fades out to the right
+        assert_eq!(row[bar..bar + 5], [15, 9, 6, 3, 0], "{row:?}");
+
+        // nothing trails once the afterglow is off
+        text.set_cylon_trail(0);
+        text.update_field_scroll(&field);
+        assert!(text.title_scroll.trail.is_empty());
+    }
+
+    #[test]
+    fn marquee_scrolls_only_past_field_width() {
+        use embedded_graphics::mono_font::iso_8859_13::FONT_7X14;
+        use embedded_graphics::primitives::Rectangle;
+
+        // 124px at 7px/char: 17 characters fit
+        let field = Field::new_text("conditions", Rectangle::new(Point::new(2, 48), Size::new(124, 14)), &FONT_7X14);
+        let mut marquee = Marquee::new(ScrollMode::ScrollLeft);
+
+        marquee.set_text("Light Rain");
+        marquee.update(&field);
+        assert!(!marquee.is_scrolling());
+
+        marquee.set_text("Partly cloudy sky");
+        marquee.update(&field);
+        assert!(!marquee.is_scrolling());
+
+        marquee.set_text("Thunderstorm with heavy rain");
+        marquee.update(&field);
+        assert!(marquee.is_scrolling());
+
+        let mut fixed = Marquee::new(ScrollMode::Static);
+        fixed.set_text("Thunderstorm with heavy rain");
+        fixed.update(&field);
+        assert!(!fixed.is_scrolling());
+    }
+
+    #[test]
+    fn scroll_decision_uses_each_fields_font() {
+        use embedded_graphics::mono_font::iso_8859_13::{FONT_5X8, FONT_7X14};
+        use embedded_graphics::primitives::Rectangle;
+
+        let row = |name: &str, y: i32, font| Field::new_text(name, Rectangle::new(Point::new(2, y), Size::new(124, 14)), font);
+        let (album_artist, album, artist, year) =
+            (row("album_artist", 0, &FONT_5X8), row("album", 14, &FONT_5X8), row("artist", 42, &FONT_5X8), row("year", 56, &FONT_5X8));
+        let layout = crate::display::layout::layout_for_resolution(128, 64, crate::display::ColorDepth::Monochrome);
+        let mut text = ScrollingText::new(layout, ScrollMode::ScrollLeft);
+
+        // 19 characters fit 124px in 5x8 but need 133px in 7x14 - only the big title scrolls
+        let line = "Kind of Blue (1959)";
+        text.set_album(line.to_string());
+        text.set_title(line.to_string());
+        text.update_with_fields(&album_artist, &album, &row("title", 28, &FONT_7X14), &artist, &year);
+        assert!(!text.field_overflows("album"));
+        assert!(text.field_overflows("title"));
+
+        // the same title in the small font fits again
+        text.update_with_fields(&album_artist, &album, &row("title", 28, &FONT_5X8), &artist, &year);
+        assert!(!text.field_overflows("title"));
+    }
+
+    #[test]
+    fn two_line_config_activates_two_scrollers() {
+        let layout = crate::display::layout::layout_for_resolution(128, 64, crate::display::ColorDepth::Monochrome);
+        let mut text = ScrollingText::new(layout, ScrollMode::ScrollLeft);
+        let track = TrackInfo {
+            album_artist: "Berliner Philharmoniker".into(),
+            album: "Symphony No. 5".into(),
+            title: "I. Allegro con brio".into(),
+            artist: "Herbert von Karajan".into(),
+            composer: "Ludwig van Beethoven".into(),
+            conductor: "Herbert von Karajan".into(),
+            year: "1963".into(),
+        };
+
+        text.set_full_track_info(&track);
+        let active = |text: &ScrollingText| TrackTag::ALL.into_iter().filter(|&t| text.is_active(t)).collect::<Vec<_>>();
+        assert_eq!(active(&text), TrackTag::DEFAULT_LINES);
+
+        text.set_lines(&[TrackTag::Composer, TrackTag::Title]);
+        text.set_full_track_info(&track);
+        assert_eq!(active(&text), [TrackTag::Title, TrackTag::Composer]);
+        assert!(text.album_scroll.text.is_empty() && text.artist_scroll.text.is_empty());
+        assert_eq!(text.composer_scroll.text, "Ludwig van Beethoven");
+        assert_eq!(text.year_scroll.text, "1963");
+    }
+
+    #[test]
+    fn scroll_region_follows_the_panel_width() {
+        let wide = crate::display::layout::layout_for_resolution(256, 64, crate::display::ColorDepth::Gray4);
+        let content = wide.content_width();
+        let mut text = ScrollingText::new(wide, ScrollMode::ScrollLeft);
+        assert_eq!(text.region_width(), content);
+        assert!(content > 128, "{content}");
+
+        // 30 characters at 6px wrap a 128px region but fit the 256px panel
+        text.set_title("Bitches Brew (Legacy Edition)".to_string());
+        text.update();
+        assert!(!text.field_overflows("title"));
+        assert_eq!(text.title_scroll.scroll_width, content);
+    }
 }
@@ -0,0 +1,154 @@
+/*
+ *  display/components/sleep_timer.rs
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  Sleep timer - a countdown tucked in the top right corner of every mode,
+ *  and a callback when it runs out, e.g. to pause the player
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *  Public License.
+ *
+ */
+
+use std::time::{Duration, Instant};
+
+use embedded_graphics::mono_font::{iso_8859_13::FONT_4X6, MonoTextStyle};
+use embedded_graphics::prelude::*;
+use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+use embedded_graphics::text::{Baseline, Text};
+
+use crate::display::color::Color;
+use crate::display::color_proxy::ConvertColor;
+
+/// Timer length when none is configured
+pub const DEFAULT_SLEEP: Duration = Duration::from_secs(30 * 60);
+
+/// Countdown to sleep, firing its expiry callback once per start
+pub struct SleepTimer {
+    deadline: Option<Instant>,
+    on_expiry: Option<Box<dyn FnMut() + Send + Sync>>,
+}
+
+impl Default for SleepTimer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SleepTimer {
+    pub fn new() -> Self {
+        Self { deadline: None, on_expiry: None }
+    }
+
+    /// Call `f` when a running timer reaches zero
+    pub fn set_on_expiry(&mut self, f: impl FnMut() + Send + Sync + 'static) {
+        self.on_expiry = Some(Box::new(f));
+    }
+
+    /// Count down `duration` from `now`, replacing any running timer
+    pub fn start(&mut self, duration: Duration, now: Instant) {
+        self.deadline = Some(now + duration);
+    }
+
+    pub fn cancel(&mut self) {
+        self.deadline = None;
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.deadline.is_some()
+    }
+
+    /// Time left at `now`; None when not running
+    pub fn remaining_at(&self, now: Instant) -> Option<Duration> {
+        self.deadline.map(|deadline| deadline.saturating_duration_since(now))
+    }
+
+    /// Stop and fire the callback once the deadline has passed; true on the tick it expires
+    pub fn tick(&mut self, now: Instant) -> bool {
+        if self.deadline.is_none_or(|deadline| now < deadline) {
+            return false;
+        }
+        self.deadline = None;
+        if let Some(f) = self.on_expiry.as_mut() {
+            f();
+        }
+        true
+    }
+
+    /// Countdown text, `z` and minutes and seconds left, rounded up
+    pub fn text_at(&self, now: Instant) -> Option<String> {
+        let left = self.remaining_at(now)?;
+        let secs = left.as_secs() + u64::from(left.subsec_nanos() > 0);
+        Some(format!("z{}:{:02}", secs / 60, secs % 60))
+    }
+
+    /// Draw the countdown in the top right corner of `area`; nothing when not running
+    pub fn render<D>(&self, target: &mut D, area: Rectangle, now: Instant) -> Result<(), D::Error>
+    where
+        D: DrawTarget,
+        Color: ConvertColor<D::Color>,
+    {
+        let Some(text) = self.text_at(now) else { return Ok(()) };
+        let glyph = FONT_4X6.character_size;
+        let size = Size::new(text.len() as u32 * glyph.width + 2, glyph.height + 2);
+        let top_left = Point::new(area.top_left.x + area.size.width as i32 - size.width as i32, area.top_left.y);
+        Rectangle::new(top_left, size)
+            .into_styled(PrimitiveStyle::with_fill(Color::Black.to_color()))
+            .draw(target)?;
+        Text::with_baseline(&text, top_left + Point::new(1, 1), MonoTextStyle::new(&FONT_4X6, Color::White.to_color()), Baseline::Top)
+            .draw(target)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::time_source::{MockTimeSource, TimeSource};
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn counts_down_with_the_time_source_and_fires_once() {
+        let clock = MockTimeSource::new(chrono::Local::now());
+        let fired = Arc::new(AtomicU32::new(0));
+        let mut timer = SleepTimer::new();
+        let counter = fired.clone();
+        timer.set_on_expiry(move || { counter.fetch_add(1, Ordering::SeqCst); });
+
+        timer.start(Duration::from_secs(90), clock.instant());
+        assert_eq!(timer.text_at(clock.instant()).as_deref(), Some("z1:30"));
+        clock.advance(Duration::from_millis(30_500));
+        assert_eq!(timer.remaining_at(clock.instant()), Some(Duration::from_millis(59_500)));
+        assert_eq!(timer.text_at(clock.instant()).as_deref(), Some("z1:00"));
+        assert!(!timer.tick(clock.instant()));
+
+        clock.advance(Duration::from_secs(60));
+        assert!(timer.tick(clock.instant()));
+        assert!(!timer.tick(clock.instant()));
+        clock.advance(Duration::from_secs(60));
+        assert!(!timer.tick(clock.instant()));
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+        assert!(!timer.is_running());
+        assert_eq!(timer.text_at(clock.instant()), None);
+
+        // cancelled timers never fire
+        timer.start(Duration::from_secs(1), clock.instant());
+        timer.cancel();
+        clock.advance(Duration::from_secs(2));
+        assert!(!timer.tick(clock.instant()));
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+    }
+}
@@ -0,0 +1,340 @@
+/*
+ *  display/components/slideshow.rs
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  Idle slideshow - cycles a folder of user images
+ *
+ *  PNG, JPEG and SVG files are picked up in filename order. Each slide is
+ *  scaled to fit the panel (letterboxed, centred) and dithered to the
+ *  framebuffer colour depth when it comes up; only the current slide is
+ *  held in memory.
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *  Public License.
+ *
+ */
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use embedded_graphics::prelude::*;
+use log::{info, warn};
+use thiserror::Error;
+use tiny_skia::{FilterQuality, IntSize, Pixmap, PixmapPaint, Transform};
+
+use crate::svgimage::SvgImageRenderer;
+use crate::visualization::SvgColorDepth;
+
+/// Time each slide stays up unless configured otherwise
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// File extensions picked up from the slideshow folder, matched case-insensitively
+const EXTENSIONS: [&str; 4] = ["png", "jpg", "jpeg", "svg"];
+
+#[derive(Debug, Error)]
+pub enum SlideError {
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Image error: {0}")]
+    Image(#[from] image::ImageError),
+    #[error("PNG error: {0}")]
+    Png(String),
+    #[error("SVG error: {0}")]
+    Svg(String),
+    #[error("Unsupported image size")]
+    Size,
+}
+
+/// A slide scaled to the panel, packed once for the framebuffer depth
+struct Slide {
+    pixmap: Pixmap,
+    packed: Option<Vec<u8>>,
+}
+
+/// Folder slideshow for the idle screen
+pub struct Slideshow {
+    images: Vec<PathBuf>,
+    index: usize,
+    interval: Duration,
+    shown_at: Option<Instant>,
+    width: u32,
+    height: u32,
+    slide: Option<Slide>,
+}
+
+impl Slideshow {
+    /// Empty slideshow for a `width` x `height` panel
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            images: Vec::new(),
+            index: 0,
+            interval: DEFAULT_INTERVAL,
+            shown_at: None,
+            width,
+            height,
+            slide: None,
+        }
+    }
+
+    /// Scan `dir` for images, replacing any previous list; returns how many
+    /// were found. A missing or empty folder leaves the slideshow empty.
+    pub fn load_dir(&mut self, dir: &Path, interval: Duration) -> usize {
+        self.images = scan_dir(dir);
+        self.index = 0;
+        self.interval = interval.max(Duration::from_secs(1));
+        self.shown_at = None;
+        self.slide = None;
+        if self.images.is_empty() {
+            warn!("slideshow: no images in {}, idle screen stays on the clock", dir.display());
+        } else {
+            info!("slideshow: {} images from {}", self.images.len(), dir.display());
+        }
+        self.images.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.images.is_empty()
+    }
+
+    /// Image currently up, None when the slideshow is empty
+    pub fn current(&self) -> Option<&Path> {
+        self.images.get(self.index).map(PathBuf::as_path)
+    }
+
+    /// Move to the next image once the interval has elapsed at `now`. The
+    /// first call anchors the schedule; returns true when the slide changed.
+    pub fn advance_at(&mut self, now: Instant) -> bool {
+        if self.images.is_empty() {
+            return false;
+        }
+        let shown_at = *self.shown_at.get_or_insert(now);
+        if now.duration_since(shown_at) < self.interval {
+            return false;
+        }
+        self.index = (self.index + 1) % self.images.len();
+        self.shown_at = Some(now);
+        self.slide = None;
+        true
+    }
+
+    /// Draw the current slide, loading it on first use. Images that fail to
+    /// decode are dropped from the rotation; returns false once none are left.
+    pub fn draw<D>(&mut self, display: &mut D) -> Result<bool, D::Error>
+    where
+        D: DrawTarget,
+        D::Color: SvgColorDepth,
+    {
+        while self.slide.is_none() {
+            let Some(path) = self.images.get(self.index) else {
+                return Ok(false);
+            };
+            match load_slide(path, self.width, self.height) {
+                Ok(pixmap) => self.slide = Some(Slide { pixmap, packed: None }),
+                Err(e) => {
+                    warn!("slideshow: skipping {}: {}", path.display(), e);
+                    self.images.remove(self.index);
+                    if self.index >= self.images.len() {
+                        self.index = 0;
+                    }
+                }
+            }
+        }
+
+        let Some(slide) = self.slide.as_mut() else {
+            return Ok(false);
+        };
+        // each depth packs to a different size, so a size match means this depth's buffer
+        let size = D::Color::required_buffer_size(self.width, self.height);
+        if slide.packed.as_ref().is_none_or(|p| p.len() != size) {
+            slide.packed = Some(D::Color::pack_pixmap(&slide.pixmap));
+        }
+        let packed = slide.packed.as_deref().unwrap_or_default();
+        D::Color::draw_buffer_to_display(packed, self.width, Point::zero(), display)?;
+        Ok(true)
+    }
+}
+
+/// Supported images in `dir`, sorted by filename
+fn scan_dir(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut images: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_file())
+        .filter(|p| {
+            p.extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| EXTENSIONS.contains(&ext.to_lowercase().as_str()))
+        })
+        .collect();
+    images.sort();
+    images
+}
+
+/// Decode `path` and fit it to a `width` x `height` pixmap
+fn load_slide(path: &Path, width: u32, height: u32) -> Result<Pixmap, SlideError> {
+    let data = std::fs::read(path)?;
+    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("").to_lowercase();
+
+    if ext == "svg" {
+        // rasterize straight at the fitted size so vector art stays crisp
+        let svg = String::from_utf8_lossy(&data);
+        let mut renderer = SvgImageRenderer::new(&svg, width, height)
+            .map_err(|e| SlideError::Svg(e.to_string()))?;
+        let (sw, sh) = renderer.svg_size();
+        let (fw, fh, _) = fit(sw, sh, width, height);
+        renderer.set_target_size(fw.round().max(1.0) as u32, fh.round().max(1.0) as u32);
+        let source = renderer.render_to_pixmap().map_err(|e| SlideError::Svg(e.to_string()))?;
+        return letterbox(&source, width, height);
+    }
+
+    let source = if ext == "png" {
+        Pixmap::decode_png(&data).map_err(|e| SlideError::Png(e.to_string()))?
+    } else {
+        let rgba = image::load_from_memory_with_format(&data, image::ImageFormat::Jpeg)?.to_rgba8();
+        let size = IntSize::from_wh(rgba.width(), rgba.height()).ok_or(SlideError::Size)?;
+        // JPEG is opaque, so straight RGBA is already premultiplied
+        Pixmap::from_vec(rgba.into_raw(), size).ok_or(SlideError::Size)?
+    };
+    letterbox(&source, width, height)
+}
+
+/// Scaled size and scale factor that fit `w` x `h` inside the panel
+fn fit(w: f32, h: f32, width: u32, height: u32) -> (f32, f32, f32) {
+    let scale = (width as f32 / w).min(height as f32 / h);
+    (w * scale, h * scale, scale)
+}
+
+/// Scale `source` to fit the panel, centred on black
+fn letterbox(source: &Pixmap, width: u32, height: u32) -> Result<Pixmap, SlideError> {
+    let mut panel = Pixmap::new(width, height).ok_or(SlideError::Size)?;
+    let (fw, fh, scale) = fit(source.width() as f32, source.height() as f32, width, height);
+    let transform = Transform::from_scale(scale, scale)
+        .post_translate(((width as f32 - fw) / 2.0).floor(), ((height as f32 - fh) / 2.0).floor());
+    let paint = PixmapPaint { quality: FilterQuality::Bicubic, ..PixmapPaint::default() };
+    panel.draw_pixmap(0, 0, source.as_ref(), &paint, transform, None);
+    Ok(panel)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::pixelcolor::{BinaryColor, Gray4};
+    use crate::vframebuf::VarFrameBuf;
+
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!("lymons-{name}-{}", std::process::id()));
+            let _ = std::fs::remove_dir_all(&dir);
+            std::fs::create_dir_all(&dir).unwrap();
+            Self(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn write_png(path: &Path, w: u32, h: u32, grey: u8) {
+        let mut pixmap = Pixmap::new(w, h).unwrap();
+        pixmap.fill(tiny_skia::Color::from_rgba8(grey, grey, grey, 255));
+        pixmap.save_png(path).unwrap();
+    }
+
+    fn write_jpeg(path: &Path, w: u32, h: u32, grey: u8) {
+        let img = image::RgbImage::from_pixel(w, h, image::Rgb([grey, grey, grey]));
+        img.save_with_format(path, image::ImageFormat::Jpeg).unwrap();
+    }
+
+    fn names(show: &Slideshow) -> Option<String> {
+        show.current().and_then(|p| p.file_name()).map(|n| n.to_string_lossy().into_owned())
+    }
+
+    #[test]
+    fn slides_advance_in_filename_order_on_the_interval() {
+        let dir = TempDir::new("slideshow-order");
+        write_png(&dir.0.join("b.png"), 8, 8, 255);
+        write_jpeg(&dir.0.join("a.jpg"), 8, 8, 255);
+        std::fs::write(dir.0.join("c.svg"), r#"<svg xmlns="http://www.w3.org/2000/svg" width="4" height="2"><rect width="4" height="2" fill="white"/></svg>"#).unwrap();
+        write_png(&dir.0.join("D.PNG"), 8, 8, 255);
+        std::fs::write(dir.0.join("notes.txt"), "not an image").unwrap();
+
+        let mut show = Slideshow::new(128, 64);
+        assert_eq!(show.load_dir(&dir.0, Duration::from_secs(10)), 4);
+
+        let t0 = Instant::now();
+        assert!(!show.advance_at(t0));
+        assert_eq!(names(&show).as_deref(), Some("D.PNG"));
+        assert!(!show.advance_at(t0 + Duration::from_secs(9)));
+
+        let mut seen = Vec::new();
+        for step in 1..=4u64 {
+            assert!(show.advance_at(t0 + Duration::from_secs(10 * step)));
+            seen.push(names(&show).unwrap());
+        }
+        // sorted byte-wise, so upper case sorts first, and it wraps
+        assert_eq!(seen, ["a.jpg", "b.png", "c.svg", "D.PNG"]);
+    }
+
+    #[test]
+    fn every_format_draws_at_mono_and_gray4() {
+        let dir = TempDir::new("slideshow-draw");
+        write_png(&dir.0.join("1.png"), 16, 16, 255);
+        write_jpeg(&dir.0.join("2.jpg"), 16, 16, 255);
+        std::fs::write(dir.0.join("3.svg"), r#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><rect width="16" height="16" fill="white"/></svg>"#).unwrap();
+
+        let mut show = Slideshow::new(128, 64);
+        show.load_dir(&dir.0, Duration::from_secs(1));
+        let t0 = Instant::now();
+        show.advance_at(t0);
+        for step in 1..=3u64 {
+            let mut mono = VarFrameBuf::new(128, 64, BinaryColor::Off);
+            assert!(show.draw(&mut mono).unwrap());
+            let lit = mono.as_slice().iter().filter(|&&p| p == BinaryColor::On).count();
+            // a square image letterboxes to 64x64 in the middle of the panel
+            assert!((64 * 64 - 256..=64 * 64 + 256).contains(&lit), "{:?}: {lit} pixels lit", show.current());
+            assert_eq!(mono.as_slice()[32 * 128 + 10], BinaryColor::Off, "left border stays dark");
+
+            let mut gray = VarFrameBuf::new(128, 64, Gray4::new(0));
+            assert!(show.draw(&mut gray).unwrap());
+            assert_eq!(gray.as_slice()[32 * 128 + 64], Gray4::new(15));
+
+            show.advance_at(t0 + Duration::from_secs(step));
+        }
+    }
+
+    #[test]
+    fn missing_or_broken_images_leave_nothing_to_show() {
+        let mut show = Slideshow::new(128, 64);
+        assert_eq!(show.load_dir(Path::new("/nonexistent/lymons/slides"), DEFAULT_INTERVAL), 0);
+        assert!(show.is_empty());
+        assert!(!show.advance_at(Instant::now()));
+
+        let dir = TempDir::new("slideshow-broken");
+        std::fs::write(dir.0.join("broken.png"), b"not a png").unwrap();
+        show.load_dir(&dir.0, DEFAULT_INTERVAL);
+        assert_eq!(show.current().and_then(|p| p.file_name()).unwrap(), "broken.png");
+
+        let mut mono = VarFrameBuf::new(128, 64, BinaryColor::Off);
+        assert!(!show.draw(&mut mono).unwrap());
+        assert!(show.is_empty());
+    }
+}
@@ -25,12 +25,16 @@
 
 use embedded_graphics::prelude::*;
 use embedded_graphics::pixelcolor::BinaryColor;
-use crate::display::layout::LayoutConfig;
+use crate::display::layout::{LayoutCategory, LayoutConfig};
 use crate::display::field::Field;
 use arrayvec::ArrayString;
 use core::fmt::Write;
+use std::time::{Duration, Instant};
 use crate::glyphs;
 
+/// How long the bitrate text stays inverted after the stream changes resolution
+pub const BITRATE_FLASH: Duration = Duration::from_secs(1);
+
 /// Repeat mode for playback
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RepeatMode {
@@ -54,6 +58,60 @@ pub enum AudioBitrate {
     Bitrate(ArrayString<16>), // e.g., "24/192" - max 16 chars on stack
 }
 
+/// Status bar glyph width, plus the gap between neighbouring glyphs
+const GLYPH_WIDTH: i32 = 8;
+const GLYPH_GAP: i32 = 2;
+
+/// Volume glyph and the widest volume text ("mute" after a 3px pad)
+const VOLUME_WIDTH: i32 = GLYPH_WIDTH + 3 + 4 * 5;
+
+/// One indicator on the status bar
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusElement {
+    /// Volume glyph and percentage, always at the left
+    Volume,
+    /// Sample size / rate text, centred
+    Bitrate,
+    /// Lossless/lossy glyph
+    Format,
+    Repeat,
+    Shuffle,
+    /// SD/HD/DSD fidelity glyph
+    Audio,
+    /// Compact mode's single glyph: shuffle, else repeat, else fidelity
+    PlayState,
+}
+
+/// Elements drawn on a `field_width` status bar and their x offsets in the field.
+///
+/// Compact mode keeps volume and one state glyph, adding the bitrate only when
+/// `bitrate_width` pixels fit between them.
+pub fn status_elements(field_width: i32, bitrate_width: i32, compact: bool) -> Vec<(StatusElement, i32)> {
+    let mut elements = vec![(StatusElement::Volume, 0)];
+    let bitrate_x = (field_width - bitrate_width) / 2;
+    let right = field_width - GLYPH_WIDTH;
+    if compact {
+        let fits = bitrate_x >= VOLUME_WIDTH + GLYPH_GAP
+            && bitrate_x + bitrate_width <= right - GLYPH_GAP;
+        if bitrate_width > 0 && fits {
+            elements.push((StatusElement::Bitrate, bitrate_x));
+        }
+        elements.push((StatusElement::PlayState, right));
+    } else {
+        if bitrate_width > 0 {
+            elements.push((StatusElement::Bitrate, bitrate_x));
+        }
+        let step = GLYPH_WIDTH + GLYPH_GAP;
+        elements.extend([
+            (StatusElement::Audio, right),
+            (StatusElement::Shuffle, right - step),
+            (StatusElement::Repeat, right - 2 * step),
+            (StatusElement::Format, right - 3 * step),
+        ]);
+    }
+    elements
+}
+
 /// Status bar state
 #[derive(Debug, Clone)]
 pub struct StatusBarState {
@@ -80,6 +138,9 @@ pub struct StatusBarState {
 
     /// Formatted bitrate text for display - stack allocated
     pub bitrate_text: ArrayString<16>,
+
+    /// Lossless/lossy codec family
+    pub format: glyphs::FormatGlyph,
 }
 
 impl Default for StatusBarState {
@@ -93,25 +154,62 @@ impl Default for StatusBarState {
             samplerate: ArrayString::new(),
             samplesize: ArrayString::new(),
             bitrate_text: ArrayString::new(),
+            format: glyphs::FormatGlyph::Unknown,
         }
     }
 }
 
+/// Label for a DSD stream, e.g. "DSD64" or "DSD256"; None for PCM.
+///
+/// LMS marks DSD with a 1-bit sample size (or "DSD" in either field) and
+/// reports the 1-bit rate in Hz, a multiple of 44.1k (or 48k for the rarer
+/// family). Rates it does not recognise still read plain "DSD".
+pub fn dsd_label(samplesize: &str, samplerate: &str) -> Option<String> {
+    let is_dsd = samplesize.trim() == "1"
+        || samplesize.to_uppercase().contains("DSD")
+        || samplerate.to_uppercase().contains("DSD");
+    if !is_dsd {
+        return None;
+    }
+    let multiple = samplerate.trim().parse::<u32>().ok().and_then(|rate| {
+        [44_100, 48_000].into_iter()
+            .find(|base| rate % base == 0)
+            .map(|base| rate / base)
+            .filter(|m| *m >= 64)
+    });
+    Some(match multiple {
+        Some(m) => format!("DSD{m}"),
+        None => "DSD".to_string(),
+    })
+}
+
 /// Status bar component
 pub struct StatusBar {
     state: StatusBarState,
     layout: LayoutConfig,
+    /// Bitrate text is drawn inverted until this deadline
+    bitrate_flash_until: Option<Instant>,
+    /// Volume and a single state glyph, bitrate only when it fits
+    compact: bool,
 }
 
 impl StatusBar {
     /// Create a new status bar component
     pub fn new(layout: LayoutConfig) -> Self {
+        let compact = layout.category == LayoutCategory::Small;
         Self {
             state: StatusBarState::default(),
             layout,
+            bitrate_flash_until: None,
+            compact,
         }
     }
 
+    /// Compact mode, on by default for small panels
+    pub fn set_compact(&mut self, compact: bool) {
+        self.compact = compact;
+    }
+
     /// Helper to draw a monochrome glyph (8x8) on any color target
     /// Converts BinaryColor pixels to target color type
     fn draw_glyph<D, C>(&self, target: &mut D, glyph_data: &[u8; 8], x: i32, y: i32, color: C) -> Result<(), D::Error>
@@ -233,7 +331,7 @@ impl StatusBar {
         use embedded_graphics::geometry::Point;
 
         let compact = match field.name.as_str() {
-            "status_bar"       => false,
+            "status_bar"       => self.compact,
             "status_bar_small" => true,
             _                  => return Ok(()),
         };
@@ -247,39 +345,13 @@ impl StatusBar {
         let glyph_y = field_pos.y;  // Top of field for glyphs
         let text_y = glyph_y + 7;   // Baseline for text (7 = glyph height - 1px)
 
-        // LEFT: Volume glyph + text
-        let mut current_x = field_pos.x;
-
-        // Draw volume glyph
-        let vol_glyph = if self.state.is_muted || self.state.volume_percent == 0 {
-            &glyphs::GLYPH_VOLUME_OFF
-        } else {
-            &glyphs::GLYPH_VOLUME_ON
-        };
-        self.draw_glyph(target, vol_glyph, current_x, glyph_y, text_color)?;
-        current_x += 8; // Move past glyph
-
-        // Draw volume text
-        let vol_text = if self.state.is_muted || self.state.volume_percent == 0 {
-            current_x += 3;
-            "mute".to_string()
-        } else {
-            format!("{:>3}%", self.state.volume_percent)
-        };
-        Text::new(&vol_text, Point::new(current_x, text_y), text_style).draw(target)?;
-
-        // Compute audio fidelity glyph (shared between compact and full paths)
+        // Compute audio fidelity glyph alongside the bitrate text
         let mut audio_glyph = &glyphs::GLYPH_AUDIO_SD;
         let bitrate_text = if !self.state.samplesize.is_empty() && !self.state.samplerate.is_empty() {
             // Check for DSD/DSF (1-bit formats)
-            if self.state.samplesize.as_str() == "1" || self.state.samplesize.as_str().starts_with("DSD") {
+            if let Some(label) = dsd_label(&self.state.samplesize, &self.state.samplerate) {
                 audio_glyph = &glyphs::GLYPH_AUDIO_DSD;
-                if let Ok(rate) = self.state.samplerate.parse::<u32>() {
-                    let dsd_multiple = rate / 44100;
-                    format!("DSD{}", dsd_multiple)
-                } else {
-                    "DSD".to_string()
-                }
+                label
             } else {
                 // Regular PCM: convert sample rate to kHz
                 if self.state.samplesize.parse::<u32>().unwrap_or(16) > 16 {
@@ -299,44 +371,62 @@ impl StatusBar {
         } else {
             String::new()
         };
+        let text_width = bitrate_text.chars().count() as i32 * 5; // FONT_5X8 advance
 
-        if compact {
-            // Compact: vol glyph + vol% on left, fidelity glyph right-justified only
-            let audio_glyph_x = field_pos.x + field_width - 8;
-            self.draw_glyph(target, audio_glyph, audio_glyph_x, glyph_y, text_color)?;
-        } else {
-            // Full: CENTER bitrate text + RIGHT repeat/shuffle/audio glyphs
-            if !bitrate_text.is_empty() {
-                let text_width = bitrate_text.len() * 5; // Approximate width for FONT_5X8
-                let center_x = field_pos.x + (field_width - text_width as i32) / 2;
-                Text::new(&bitrate_text, Point::new(center_x, text_y), text_style).draw(target)?;
-            }
+        let shuffle_glyph = match self.state.shuffle_mode {
+            ShuffleMode::ByTracks => &glyphs::GLYPH_SHUFFLE_TRACKS,
+            ShuffleMode::ByAlbums => &glyphs::GLYPH_SHUFFLE_ALBUMS,
+            ShuffleMode::Off => &glyphs::GLYPH_NONE,
+        };
+        let repeat_glyph = match self.state.repeat_mode {
+            RepeatMode::One => &glyphs::GLYPH_REPEAT_ONE,
+            RepeatMode::All => &glyphs::GLYPH_REPEAT_ALL,
+            RepeatMode::Off => &glyphs::GLYPH_NONE,
+        };
 
-            // RIGHT: Repeat + Shuffle glyphs (right-justified)
-            let glyph_width = 8;
-            let glyph_gap = 2;
-
-            // audio glyph (audio)
-            let audio_glyph_x = field_pos.x + field_width - glyph_width;
-            self.draw_glyph(target, audio_glyph, audio_glyph_x, glyph_y, text_color)?;
-
-            // Shuffle glyph (left of audio)
-            let shuffle_glyph = match self.state.shuffle_mode {
-                ShuffleMode::ByTracks => &glyphs::GLYPH_SHUFFLE_TRACKS,
-                ShuffleMode::ByAlbums => &glyphs::GLYPH_SHUFFLE_ALBUMS,
-                ShuffleMode::Off => &glyphs::GLYPH_NONE,
-            };
-            let shuffle_x = audio_glyph_x - glyph_width - glyph_gap;
-            self.draw_glyph(target, shuffle_glyph, shuffle_x, glyph_y, text_color)?;
-
-            // Repeat glyph (to the left of shuffle)
-            let repeat_glyph = match self.state.repeat_mode {
-                RepeatMode::One => &glyphs::GLYPH_REPEAT_ONE,
-                RepeatMode::All => &glyphs::GLYPH_REPEAT_ALL,
-                RepeatMode::Off => &glyphs::GLYPH_NONE,
-            };
-            let repeat_x = shuffle_x - glyph_width - glyph_gap;
-            self.draw_glyph(target, repeat_glyph, repeat_x, glyph_y, text_color)?;
+        for (element, x) in status_elements(field_width, text_width, compact) {
+            let x = field_pos.x + x;
+            match element {
+                StatusElement::Volume => {
+                    let muted = self.state.is_muted || self.state.volume_percent == 0;
+                    let vol_glyph = if muted { &glyphs::GLYPH_VOLUME_OFF } else { &glyphs::GLYPH_VOLUME_ON };
+                    self.draw_glyph(target, vol_glyph, x, glyph_y, text_color)?;
+                    let (vol_text, pad) = if muted {
+                        ("mute".to_string(), 3)
+                    } else {
+                        (format!("{:>3}%", self.state.volume_percent), 0)
+                    };
+                    Text::new(&vol_text, Point::new(x + GLYPH_WIDTH + pad, text_y), text_style).draw(target)?;
+                }
+                StatusElement::Bitrate => {
+                    if self.is_flashing_at(Instant::now()) {
+                        // resolution just changed: light text on a lit block
+                        use embedded_graphics::primitives::{PrimitiveStyle, Rectangle};
+                        let back: C = crate::display::color::Color::Black.to_color();
+                        Rectangle::new(Point::new(x - 1, glyph_y), Size::new(text_width as u32 + 2, 9))
+                            .into_styled(PrimitiveStyle::with_fill(text_color))
+                            .draw(target)?;
+                        Text::new(&bitrate_text, Point::new(x, text_y), MonoTextStyle::new(&FONT_5X8, back))
+                            .draw(target)?;
+                    } else {
+                        Text::new(&bitrate_text, Point::new(x, text_y), text_style).draw(target)?;
+                    }
+                }
+                StatusElement::Format => self.draw_glyph(target, self.state.format.glyph(), x, glyph_y, text_color)?,
+                StatusElement::Repeat => self.draw_glyph(target, repeat_glyph, x, glyph_y, text_color)?,
+                StatusElement::Shuffle => self.draw_glyph(target, shuffle_glyph, x, glyph_y, text_color)?,
+                StatusElement::Audio => self.draw_glyph(target, audio_glyph, x, glyph_y, text_color)?,
+                StatusElement::PlayState => {
+                    let glyph = if self.state.shuffle_mode != ShuffleMode::Off {
+                        shuffle_glyph
+                    } else if self.state.repeat_mode != RepeatMode::Off {
+                        repeat_glyph
+                    } else {
+                        audio_glyph
+                    };
+                    self.draw_glyph(target, glyph, x, glyph_y, text_color)?;
+                }
+            }
         }
 
         // Draw horizontal line 1 pixel below status bar
@@ -374,6 +464,19 @@ impl StatusBar {
 
     /// Update bitrate information (zero heap allocations!)
     pub fn set_bitrate(&mut self, samplerate: &str, samplesize: &str) {
+        self.set_bitrate_at(samplerate, samplesize, Instant::now());
+    }
+
+    /// Update bitrate information as of `now`; a switch between two known
+    /// resolutions flashes the bitrate text for `BITRATE_FLASH`
+    pub fn set_bitrate_at(&mut self, samplerate: &str, samplesize: &str, now: Instant) {
+        // LMS reports 0 (or nothing) for streams without a resolution
+        let known = |rate: &str| !rate.is_empty() && rate != "0";
+        let changed = self.state.samplerate.as_str() != samplerate || self.state.samplesize.as_str() != samplesize;
+        if changed && known(&self.state.samplerate) && known(samplerate) {
+            self.bitrate_flash_until = Some(now + BITRATE_FLASH);
+        }
+
         // Clear and populate stack-allocated strings
         self.state.samplerate.clear();
         self.state.samplesize.clear();
@@ -390,6 +493,16 @@ impl StatusBar {
         self.state.audio_bitrate = AudioBitrate::Bitrate(self.state.bitrate_text);
     }
 
+    /// Update the codec family from the LMS content type (e.g. "flc", "mp3")
+    pub fn set_format(&mut self, format: &str) {
+        self.state.format = glyphs::format_to_glyph(format);
+    }
+
+    /// Whether the bitrate text is drawn inverted at `now`
+    pub fn is_flashing_at(&self, now: Instant) -> bool {
+        self.bitrate_flash_until.is_some_and(|until| now < until)
+    }
+
     /// Format volume text to stack-allocated string (zero allocations!)
     pub fn format_volume(&self) -> ArrayString<8> {
         let mut buf = ArrayString::new();
@@ -421,3 +534,72 @@ pub fn transpose_shuffle_mode(mode: i32) -> ShuffleMode {
         _ => ShuffleMode::Off,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status_bar() -> StatusBar {
+        StatusBar::new(crate::display::layout::layout_for_resolution(128, 64, crate::display::ColorDepth::Monochrome))
+    }
+
+    #[test]
+    fn dsd_rates_are_labelled_by_multiple() {
+        assert_eq!(dsd_label("1", "2822400").as_deref(), Some("DSD64"));
+        assert_eq!(dsd_label("1", "5644800").as_deref(), Some("DSD128"));
+        assert_eq!(dsd_label("1", "11289600").as_deref(), Some("DSD256"));
+        assert_eq!(dsd_label("1", "22579200").as_deref(), Some("DSD512"));
+        assert_eq!(dsd_label("1", "12288000").as_deref(), Some("DSD256"));
+        assert_eq!(dsd_label("DSD", "").as_deref(), Some("DSD"));
+        assert_eq!(dsd_label("24", "192000"), None);
+        assert_eq!(dsd_label("16", "44100"), None);
+    }
+
+    #[test]
+    fn resolution_change_flashes_the_bitrate() {
+        let mut bar = status_bar();
+        let t0 = Instant::now();
+
+        // the first track after start-up sets the resolution quietly
+        bar.set_bitrate_at("44100", "16", t0);
+        assert!(!bar.is_flashing_at(t0));
+        bar.set_bitrate_at("44100", "16", t0);
+        assert!(!bar.is_flashing_at(t0), "same resolution, no flash");
+
+        let t1 = t0 + Duration::from_secs(5);
+        bar.set_bitrate_at("96000", "24", t1);
+        assert!(bar.is_flashing_at(t1));
+        assert!(bar.is_flashing_at(t1 + BITRATE_FLASH - Duration::from_millis(1)));
+        assert!(!bar.is_flashing_at(t1 + BITRATE_FLASH));
+
+        // dropping to an unknown rate (radio, stop) is not a resolution switch
+        let t2 = t1 + Duration::from_secs(5);
+        bar.set_bitrate_at("0", "0", t2);
+        assert!(!bar.is_flashing_at(t2));
+    }
+
+    #[test]
+    fn compact_mode_drops_the_bitrate_when_it_does_not_fit() {
+        let names = |elements: Vec<(StatusElement, i32)>| elements.into_iter().map(|(e, _)| e).collect::<Vec<_>>();
+        // "24/192k" in 5x8
+        let bitrate = 7 * 5;
+
+        let full = names(status_elements(72, bitrate, false));
+        assert!(full.contains(&StatusElement::Bitrate));
+        assert!(full.contains(&StatusElement::Repeat) && full.contains(&StatusElement::Shuffle));
+
+        let compact = names(status_elements(72, bitrate, true));
+        assert_eq!(compact, [StatusElement::Volume, StatusElement::PlayState]);
+
+        // a 128px bar has room to keep it
+        let wide = names(status_elements(124, bitrate, true));
+        assert_eq!(wide, [StatusElement::Volume, StatusElement::Bitrate, StatusElement::PlayState]);
+    }
+
+    #[test]
+    fn small_panels_default_to_compact() {
+        assert!(status_bar().compact);
+        let wide = StatusBar::new(crate::display::layout::layout_for_resolution(256, 64, crate::display::ColorDepth::Gray4));
+        assert!(!wide.compact);
+    }
+}
@@ -29,10 +29,11 @@ use embedded_graphics::mono_font::iso_8859_13::FONT_5X8;
 use embedded_text::alignment::{HorizontalAlignment, VerticalAlignment};
 use crate::display::color_proxy::{ColorProxy, GradientLut, HistColorScheme, Pal16};
 use crate::display::layout::LayoutConfig;
+use crate::display::time_source::{system_time, SharedTimeSource};
 use crate::visualizer::Visualizer;
 use crate::visualization::{Visualization, Visual, SvgColorDepth};
 use crate::vision::{POLL_ENABLED, PEAK_METER_LEVELS_MAX};
-use crate::vision::ensure_band_state;
+use crate::vision::{ensure_band_state, PeakHold};
 use std::time::{Duration, Instant};
 
 /// Visualizer component state
@@ -68,6 +69,19 @@ pub fn aio_meter_attributes(meter_area_start: i32, w: i32, h: i32) -> (i32, i32,
     (mx, my, meter_width, meter_height)
 }
 
+/// L/R difference that swings the balance marker to the end of its scale
+pub const BALANCE_RANGE_DB: f32 = 6.0;
+
+/// Balance marker x on a centre-zero scale spanning `left..left + width`:
+/// centred when the channels match, towards the louder one otherwise,
+/// clamped to the scale ends.
+pub fn balance_marker_x(l_db: f32, r_db: f32, left: i32, width: u32) -> i32 {
+    let half = (width.max(1) as i32 - 1) / 2;
+    let t = ((l_db - r_db) / BALANCE_RANGE_DB).clamp(-1.0, 1.0);
+    let t = if t.is_nan() { 0.0 } else { t }; // both channels silent
+    left + half - (t * half as f32).round() as i32
+}
+
 #[inline]
 fn compute_leds(db: f64, level_brackets: &[i16]) -> Vec<bool> {
     level_brackets.iter().map(|&t| db >= t as f64).collect()
@@ -85,6 +99,27 @@ pub fn aio_text_attributes(w: i32) -> (i32, i32, i32)
     (text_margin, text_usable_width, meter_area_start)
 }
 
+/// Smallest full-scale the oscilloscope will zoom to (about -24 dBFS), so
+/// near-silence and dither aren't blown up to fill the panel.
+pub const SCOPE_FLOOR: i32 = 2048;
+
+/// Oscilloscope trace: one point per panel column, centred vertically and
+/// scaled so the frame's peak reaches the panel edge (never beyond `SCOPE_FLOOR` zoom).
+pub fn scope_trace(waveform: &[i16], width: u32, height: u32) -> Vec<Point> {
+    if waveform.is_empty() || width == 0 || height == 0 {
+        return Vec::new();
+    }
+    let peak = waveform.iter().map(|&v| (v as i32).abs()).max().unwrap_or(0).max(SCOPE_FLOOR);
+    let mid = (height as i32 - 1) / 2;
+    let amp = (height as i32 - 1) - mid;
+    (0..width as usize)
+        .map(|x| {
+            let v = waveform[x * waveform.len() / width as usize] as i32;
+            Point::new(x as i32, (mid - v * amp / peak).clamp(0, height as i32 - 1))
+        })
+        .collect()
+}
+
 /// Visualizer component wrapper
 pub struct VisualizerComponent {
     visualizer: Option<Visualizer>,
@@ -97,6 +132,10 @@ pub struct VisualizerComponent {
     aio_viz_rect: Option<Rectangle>,
     /// Pre-computed gradient LUT for Rgb565 histogram fills. Built once at construction.
     hist_lut: GradientLut,
+    /// Clock for peak cap hold and decay
+    time: SharedTimeSource,
+    /// Combination meter arrangement, kept across visual reloads
+    combi: crate::visualization::CombiLayout,
 }
 
 impl VisualizerComponent {
@@ -124,9 +163,16 @@ impl VisualizerComponent {
             visualization_type,
             aio_viz_rect: None,
             hist_lut,
+            time: system_time(),
+            combi: crate::visualization::CombiLayout::default(),
         }
     }
 
+    /// Time cap hold and decay from `time` rather than the system clock
+    pub fn set_time_source(&mut self, time: SharedTimeSource) {
+        self.time = time;
+    }
+
     /// Initialize the visualizer with actual Visualizer instance
     pub fn set_visualizer(&mut self, visualizer: Visualizer) {
         self.visualizer = Some(visualizer);
@@ -134,6 +180,14 @@ impl VisualizerComponent {
 
     pub fn update_visual(&mut self) {
         self.viz = crate::visualization::get_visual(self.visualization_type, self.viz_state.wide, self.layout.clone());
+        self.viz.set_combi_layout(self.combi);
+    }
+
+    /// Place the combination mode's peak column and size it against the VU faces
+    pub fn set_combi_layout(&mut self, combi: crate::visualization::CombiLayout) {
+        self.combi = combi;
+        self.viz.set_combi_layout(combi);
+        self.viz_state.init = true;
     }
 
     /// Get mutable reference to visualizer
@@ -175,6 +229,19 @@ impl VisualizerComponent {
         };
     }
 
+    /// Apply a VU calibration (reference offset, floor/ceil) to all needles.
+    pub fn set_vu_calibration(&mut self, cal: &crate::vuphysics_new::VuCalibration) {
+        let s = &mut self.viz_state;
+        for meter in [&mut s.vu_m, &mut s.vu_l, &mut s.vu_r] {
+            meter.set_calibration(cal);
+        }
+    }
+
+    /// Hold the peak meter markers for `hold`, then let them fall at `decay_rate` LEDs per second
+    pub fn set_peak_hold(&mut self, hold: Duration, decay_rate: f32) {
+        self.viz_state.peak_hold = PeakHold { hold, decay_rate: decay_rate.max(0.1) };
+    }
+
     /// Set the visualizer panel bounds for AIO modes (resolved from YAML layout).
     pub fn set_aio_viz_rect(&mut self, rect: Rectangle) {
         self.aio_viz_rect = Some(rect);
@@ -192,6 +259,7 @@ impl VisualizerComponent {
     {
         let viz_mut = &mut self.viz;
         let s = &mut self.viz_state;
+        s.frame_at = self.time.instant();
         match self.visualization_type {
             Visualization::PeakMono => {
                 Self::draw_peak_mono(target, viz_mut, s.this.db_m, s.this.hold_m, s)
@@ -200,7 +268,10 @@ impl VisualizerComponent {
                 Self::draw_peak_stereo(target, viz_mut, s.this.db_l, s.this.db_r, s.this.hold_l, s.this.hold_r, s)
             }
             Visualization::HistMono => {
-                Self::draw_hist_mono::<D, P>(target, viz_mut, s.last_bands_m.clone(), s, &self.hist_lut)
+                Self::draw_hist_mono::<D, P>(target, viz_mut, s.last_bands_m.clone(), s, &self.hist_lut, false)
+            }
+            Visualization::HistMirror => {
+                Self::draw_hist_mono::<D, P>(target, viz_mut, s.last_bands_m.clone(), s, &self.hist_lut, true)
             }
             Visualization::HistStereo => {
                 Self::draw_hist_pair::<D, P>(target, viz_mut, s.last_bands_l.clone(), s.last_bands_r.clone(), s, &self.hist_lut)
@@ -209,7 +280,7 @@ impl VisualizerComponent {
                 Self::draw_vu_mono(target, viz_mut, s.this.db_m, s)
             }
             Visualization::VuStereo => {
-                Self::draw_vu_stereo(target, viz_mut, s.this.db_l, s.this.db_r, s)
+                Self::draw_vu_stereo::<D, P>(target, viz_mut, s.this.db_l, s.this.db_r, s)
             }
             Visualization::VuAio => {
                 let (db_m, db_l, db_r) = (s.this.db_m, s.this.db_l, s.this.db_r);
@@ -224,6 +295,9 @@ impl VisualizerComponent {
             Visualization::WaveformSpectrum => {
                 Self::draw_waveform_spectrum::<D, P>(target, s.last_waveform_l.clone(), s.last_waveform_r.clone(), Vec::new(), s, &self.layout)
             }
+            Visualization::Oscilloscope => {
+                Self::draw_oscilloscope::<D, P>(target, &s.last_waveform_m)
+            }
             Visualization::VuStereoWithCenterPeak => {
                 Self::draw_vu_combi(target, viz_mut, s.this.db_l, s.this.db_r, s.this.db_m, s.this.hold_m, s)
             }
@@ -279,30 +353,36 @@ impl VisualizerComponent {
             -30, -20, -15, -10, -7, -5, -3, -2, -1,
             0, 1, 2, 3, 5, 7, 10
         ];
-        let n_db = level_brackets.len();
+        let n_db = level_brackets.len() as u8;
 
-        ensure_band_state(state, n_db, n_db, 0, viz);
-        let mut changed = state.last.db_l != l_db || state.last.db_r != r_db;
-        changed |= state.last.hold_l != l_hold || state.last.hold_r != r_hold;
+        // one "band" per channel - the cap state tracks the hold LED
+        ensure_band_state(state, 1, 1, 0, viz);
+
+        viz.peak_l = compute_leds(l_db as f64, &level_brackets);
+        viz.peak_r = compute_leds(r_db as f64, &level_brackets);
+        state.draw_bands_l[0] = leds_lit(&viz.peak_l);
+        state.draw_bands_r[0] = leds_lit(&viz.peak_r);
+        let now = state.frame_at;
+        let mut cap_changed = Self::update_caps_with(&mut state.cap_l, &mut state.cap_hold_until_l, &mut state.cap_last_update_l, &state.draw_bands_l, now, state.peak_hold);
+        cap_changed |= Self::update_caps_with(&mut state.cap_r, &mut state.cap_hold_until_r, &mut state.cap_last_update_r, &state.draw_bands_r, now, state.peak_hold);
+        let held_l = state.cap_l[0].max(l_hold).min(n_db);
+        let held_r = state.cap_r[0].max(r_hold).min(n_db);
+
+        let mut changed = state.last.db_l != l_db || state.last.db_r != r_db || cap_changed;
+        changed |= state.last.hold_l != held_l || state.last.hold_r != held_r;
 
         state.last.db_l = l_db;
         state.last.db_r = r_db;
-        state.last.hold_l = l_hold;
-        state.last.hold_r = r_hold;
+        state.last.hold_l = held_l;
+        state.last.hold_r = held_r;
 
         if !changed && !state.init {
             return Ok(false);
         }
         state.init = false;
 
-        viz.peak_l = compute_leds(l_db as f64, &level_brackets);
-        viz.peak_r = compute_leds(r_db as f64, &level_brackets);
-        let current_peak_l = viz.peak_l.iter().rposition(|&on| on).unwrap_or(0);
-        let current_peak_r = viz.peak_r.iter().rposition(|&on| on).unwrap_or(0);
-        viz.hold_l.fill(false);
-        viz.hold_r.fill(false);
-        viz.hold_l[current_peak_l] = true;
-        viz.hold_r[current_peak_r] = true;
+        set_hold_led(&mut viz.hold_l, held_l);
+        set_hold_led(&mut viz.hold_r, held_r);
 
         viz.render_svg_and_draw(
             display,
@@ -332,23 +412,26 @@ impl VisualizerComponent {
             0, 1, 2, 3, 5, 7, 10
         ];
 
-        ensure_band_state(state, 0, 0, level_brackets.len(), viz);
+        // one "band" - the cap state tracks the hold LED
+        ensure_band_state(state, 0, 0, 1, viz);
+
+        viz.peak_m = compute_leds(m_db as f64, &level_brackets);
+        state.draw_bands_m[0] = leds_lit(&viz.peak_m);
+        let cap_changed = Self::update_caps_with(&mut state.cap_m, &mut state.cap_hold_until_m, &mut state.cap_last_update_m, &state.draw_bands_m, state.frame_at, state.peak_hold);
+        let held = state.cap_m[0].max(hold).min(level_brackets.len() as u8);
 
-        let mut changed = state.last.db_m != m_db;
-        changed |= state.last.hold_m != hold;
+        let mut changed = state.last.db_m != m_db || cap_changed;
+        changed |= state.last.hold_m != held;
 
         state.last.db_m = m_db;
-        state.last.hold_m = hold;
+        state.last.hold_m = held;
 
         if !changed && !state.init {
             return Ok(false);
         }
         state.init = false;
 
-        viz.peak_m = compute_leds(m_db as f64, &level_brackets);
-        let current_peak = viz.peak_m.iter().rposition(|&on| on).unwrap_or(0);
-        viz.hold_m.fill(false);
-        viz.hold_m[current_peak] = true;
+        set_hold_led(&mut viz.hold_m, held);
 
         viz.render_svg_and_draw(
             display,
@@ -365,8 +448,6 @@ impl VisualizerComponent {
     //
 
     const HIST_DECAY_PER_TICK: u8 = 1;
-    const CAP_HOLD: Duration = Duration::from_millis(500);
-    const CAP_DECAY_LPS: f32 = 8.0;
     const CAP_THICKNESS_PX: u32 = 1;
 
     fn update_body_decay(dst: &mut [u8], src: &[u8], elapsed: Duration) -> bool {
@@ -382,6 +463,11 @@ impl VisualizerComponent {
     }
 
     fn update_caps(caps: &mut [u8], hold_until: &mut [Instant], last_upd: &mut [Instant], bars: &[u8], now: Instant) -> bool {
+        Self::update_caps_with(caps, hold_until, last_upd, bars, now, PeakHold::HISTOGRAM)
+    }
+
+    /// Raise caps to their bars, hold them for `timing.hold`, then let them fall
+    fn update_caps_with(caps: &mut [u8], hold_until: &mut [Instant], last_upd: &mut [Instant], bars: &[u8], now: Instant, timing: PeakHold) -> bool {
         let mut changed = false;
         for i in 0..bars.len() {
             let bar = bars[i];
@@ -391,7 +477,7 @@ impl VisualizerComponent {
 
             if bar >= *cap {
                 if *cap != bar { *cap = bar; changed = true; }
-                *hu = now + Self::CAP_HOLD;
+                *hu = now + timing.hold;
                 *lu = now;
                 continue;
             }
@@ -400,7 +486,7 @@ impl VisualizerComponent {
 
             let dt = now.saturating_duration_since(*lu).as_secs_f32();
             if dt <= 0.0 { continue; }
-            let drop = (dt * Self::CAP_DECAY_LPS).floor() as u8;
+            let drop = (dt * timing.decay_rate).floor() as u8;
             if drop == 0 { continue; }
 
             let after = cap.saturating_sub(drop).max(bar);
@@ -444,7 +530,7 @@ impl VisualizerComponent {
         let h = panel_size.height as i32;
         let n = (bars.len() as i32 - 1).max(1);
         let mut stride = num_integer::div_floor(w, n).max(1);
-        if n*stride >= w {stride -= 1;} // should never happen!
+        if n*stride >= w && stride > 1 {stride -= 1;} // should never happen!
         let mut bar_w = (stride - 1).max(1);
         if n <= 4 && w > n { stride = w / n; bar_w = stride-1; }
 
@@ -457,6 +543,7 @@ impl VisualizerComponent {
         for (i, (&lvl, &cap)) in bars.iter().zip(caps.iter()).enumerate() {
             if i > n as usize - 1 { break; }
             let x = origin.x + (i as i32) * stride;
+            if x >= origin.x + w { break; } // more bands than pixels - clip rather than overdraw
             let level_u = (lvl as u32).min(max_level);
             let bar_h = ((level_u * h_u) / max_level) as i32;
             let cap_level_u = (cap as u32).min(max_level);
@@ -505,7 +592,7 @@ impl VisualizerComponent {
         state.last_bands_l.copy_from_slice(&bands_l);
         state.last_bands_r.copy_from_slice(&bands_r);
 
-        let now = Instant::now();
+        let now = state.frame_at;
         let elapsed = now.saturating_duration_since(state.last_tick);
         state.last_tick = now;
 
@@ -543,6 +630,7 @@ impl VisualizerComponent {
         bands: Vec<u8>,
         state: &mut crate::vision::LastVizState,
         lut: &GradientLut,
+        mirror: bool,
     ) -> Result<bool, D::Error>
     where
         D: DrawTarget<Color = P::Output> + OriginDimensions,
@@ -551,7 +639,7 @@ impl VisualizerComponent {
         ensure_band_state(state, 0, 0, bands.len(), viz);
         state.last_bands_m.copy_from_slice(&bands);
 
-        let now = Instant::now();
+        let now = state.frame_at;
         let elapsed = now.saturating_duration_since(state.last_tick);
         state.last_tick = now;
 
@@ -573,8 +661,16 @@ impl VisualizerComponent {
         let inner_h = h - my - title_base - 1;
         let title_pos = h - title_base; 
         let pane_w = inner_w;
+        let origin = Point::new(mx, my);
+        let pane = Size::new(pane_w as u32, inner_h as u32);
 
-        Self::draw_hist_panel::<D, P>(display, "Downmix", title_base as u32, title_pos, Point::new(mx, my), Size::new(pane_w as u32, inner_h as u32), &state.draw_bands_m, &state.cap_m, lut)?;
+        if mirror {
+            let bars = mirror_bands(&state.draw_bands_m);
+            let caps = mirror_bands(&state.cap_m);
+            Self::draw_hist_panel::<D, P>(display, "Downmix", title_base as u32, title_pos, origin, pane, &bars, &caps, lut)?;
+        } else {
+            Self::draw_hist_panel::<D, P>(display, "Downmix", title_base as u32, title_pos, origin, pane, &state.draw_bands_m, &state.cap_m, lut)?;
+        }
 
         Ok(true)
     }
@@ -644,6 +740,32 @@ impl VisualizerComponent {
         Ok(true)
     }
 
+    /// Draw the downmix oscilloscope trace as a line across the full panel width
+    fn draw_oscilloscope<D, P>(display: &mut D, waveform: &[i16]) -> Result<bool, D::Error>
+    where
+        D: DrawTarget<Color = P::Output> + OriginDimensions,
+        P: ColorProxy,
+    {
+        use embedded_graphics::primitives::Line;
+
+        let Size { width, height } = display.size();
+        let trace = scope_trace(waveform, width, height);
+        if trace.is_empty() {
+            // nothing yet, show the flat centre line
+            let y = (height as i32 - 1) / 2;
+            Line::new(Point::new(0, y), Point::new(width as i32 - 1, y))
+                .into_styled(PrimitiveStyle::with_stroke(P::on(), 1))
+                .draw(display)?;
+            return Ok(true);
+        }
+        for pair in trace.windows(2) {
+            Line::new(pair[0], pair[1])
+                .into_styled(PrimitiveStyle::with_stroke(P::on(), 1))
+                .draw(display)?;
+        }
+        Ok(true)
+    }
+
     /// Draw AIO VU visualization — SVG-based VU needle on the right half; left panel rendered by manager
     fn draw_aio_vu<D, P>(
         display: &mut D,
@@ -734,7 +856,7 @@ impl VisualizerComponent {
         let my = 6i32;
         let title_base = 10i32;
 
-        let now = Instant::now();
+        let now = state.frame_at;
         let elapsed = now.saturating_duration_since(state.last_tick);
         state.last_tick = now;
         state.init = false;
@@ -830,7 +952,10 @@ impl VisualizerComponent {
         Ok(true)
     }
 
-    fn draw_vu_stereo<D>(
+    /// L/R needles. Needles are filled paths in the SVG asset, so resvg
+    /// anti-aliases their edges and Gray4/Rgb565 panels keep the partial
+    /// coverage as intermediate levels; mono panels threshold it away.
+    fn draw_vu_stereo<D, P>(
         display: &mut D,
         viz: &mut Visual,
         l_db: f32,
@@ -838,7 +963,8 @@ impl VisualizerComponent {
         state: &mut crate::vision::LastVizState,
     ) -> Result<bool, D::Error>
     where
-        D: DrawTarget + OriginDimensions + 'static,
+        D: DrawTarget<Color = P::Output> + OriginDimensions + 'static,
+        P: ColorProxy,
         D::Color: SvgColorDepth,
     {
         let dummy: Vec<bool> = Vec::new();
@@ -866,16 +992,55 @@ impl VisualizerComponent {
             state.last.disp_r as f64, state.vu_r.is_overloaded(),
             dummy.clone(), dummy.clone(), dummy.clone(), dummy.clone(), dummy.clone(), dummy.clone(),
         )?;
+        Self::draw_balance::<D, P>(display, viz.rect(), l_db, r_db)?;
         Ok(true)
     }
 
+    /// Centre-zero balance scale along the bottom of the meter pair, middle
+    /// third of the width, with a 3px marker leaning towards the louder channel.
+    fn draw_balance<D, P>(display: &mut D, rect: Rectangle, l_db: f32, r_db: f32) -> Result<(), D::Error>
+    where
+        D: DrawTarget<Color = P::Output>,
+        P: ColorProxy,
+    {
+        use embedded_graphics::primitives::Line;
+
+        let width = rect.size.width / 3;
+        if width < 9 || rect.size.height < 3 {
+            return Ok(());
+        }
+        let left = rect.top_left.x + (rect.size.width - width) as i32 / 2;
+        let right = left + width as i32 - 1;
+        let top = rect.top_left.y + rect.size.height as i32 - 3;
+        let mid = top + 1;
+        let centre = balance_marker_x(0.0, 0.0, left, width);
+        let marker = balance_marker_x(l_db, r_db, left + 1, width - 2); // keep the 3px marker inside
+
+        Rectangle::new(Point::new(left, top), Size::new(width, 3))
+            .into_styled(PrimitiveStyle::with_fill(P::off()))
+            .draw(display)?;
+        for (a, b) in [
+            (Point::new(left, mid), Point::new(right, mid)),
+            (Point::new(centre, top), Point::new(centre, top + 2)),
+        ] {
+            Line::new(a, b).into_styled(PrimitiveStyle::with_stroke(P::on(), 1)).draw(display)?;
+        }
+        Rectangle::new(Point::new(marker - 1, top), Size::new(3, 3))
+            .into_styled(PrimitiveStyle::with_fill(P::on()))
+            .draw(display)
+    }
+
+    /// L/R needles with the central mono peak column. The column LEDs live in
+    /// the `vucombi.svg` asset (`PEAK_00..PEAK_18`), scaled with the panel; the
+    /// held LED reuses the histogram cap hold/decay so it lingers then falls.
+    /// A non-zero `peak_hold` from the producer (LED level) acts as a floor.
     fn draw_vu_combi<D>(
         display: &mut D,
         viz: &mut Visual,
         l_db: f32,
         r_db: f32,
         m_db: f32,
-        _peak_hold: u8,
+        peak_hold: u8,
         state: &mut crate::vision::LastVizState,
     ) -> Result<bool, D::Error>
     where
@@ -888,15 +1053,22 @@ impl VisualizerComponent {
             0, 2, 3, 5, 8
         ];
 
-        ensure_band_state(state, 0, 0, 0, viz);
+        // one "band" - the mono peak column, so the cap state tracks the hold LED
+        ensure_band_state(state, 0, 0, 1, viz);
 
         state.vu_l.update(l_db as f64);
-        state.vu_r.update(l_db as f64);
+        state.vu_r.update(r_db as f64);
         let disp_l = state.vu_l.angle_degrees() as f32;
         let disp_r = state.vu_r.angle_degrees() as f32;
 
+        viz.peak_m = compute_leds(m_db as f64, &level_brackets);
+        state.draw_bands_m[0] = leds_lit(&viz.peak_m);
+        let now = state.frame_at;
+        let cap_changed = Self::update_caps_with(&mut state.cap_m, &mut state.cap_hold_until_m, &mut state.cap_last_update_m, &state.draw_bands_m, now, state.peak_hold);
+        let held = state.cap_m[0].max(peak_hold).min(level_brackets.len() as u8);
+
         let mut changed = state.last.db_l != l_db || state.last.db_r != r_db;
-        changed |= state.last.db_m != m_db;
+        changed |= state.last.db_m != m_db || cap_changed;
         changed |= state.last.disp_l != disp_l || state.last.disp_r != disp_r;
 
         state.last.db_l = l_db;
@@ -904,15 +1076,12 @@ impl VisualizerComponent {
         state.last.db_m = m_db;
         state.last.disp_l = disp_l;
         state.last.disp_r = disp_r;
-        state.last.hold_m = _peak_hold;
+        state.last.hold_m = held;
 
         if !changed && !state.init { return Ok(false); }
         state.init = false;
 
-        viz.peak_m = compute_leds(m_db as f64, &level_brackets);
-        let current_peak = viz.peak_m.iter().rposition(|&on| on).unwrap_or(0);
-        viz.hold_m.fill(false);
-        viz.hold_m[current_peak] = true;
+        set_hold_led(&mut viz.hold_m, held);
 
         viz.render_svg_and_draw(
             display,
@@ -925,3 +1094,258 @@ impl VisualizerComponent {
     }
 
 }
+
+/// Peak meter level as LEDs lit, 0 when below the bottom bracket
+fn leds_lit(leds: &[bool]) -> u8 {
+    leds.iter().rposition(|&on| on).map_or(0, |i| i as u8 + 1)
+}
+
+/// Light only the hold LED for a `held` level, none at 0
+fn set_hold_led(hold: &mut [bool], held: u8) {
+    hold.fill(false);
+    if let Some(led) = (held as usize).checked_sub(1).and_then(|i| hold.get_mut(i)) {
+        *led = true;
+    }
+}
+
+/// Band drawn at bar `slot` of a mirrored histogram of `bands` bands.
+///
+/// The lowest band sits in the centre bar and each band appears once either
+/// side of it, so there are `2 * bands - 1` bars.
+pub fn mirror_band_index(slot: usize, bands: usize) -> usize {
+    slot.abs_diff(bands.saturating_sub(1))
+}
+
+/// Bands laid out centre-outward for `draw_hist_panel`.
+///
+/// The panel spreads `len - 1` bars across its width, so a spare trailing
+/// entry keeps the outermost right bar.
+fn mirror_bands(bands: &[u8]) -> Vec<u8> {
+    if bands.is_empty() {
+        return Vec::new();
+    }
+    let slots = 2 * bands.len() - 1;
+    (0..slots).map(|slot| bands[mirror_band_index(slot, bands.len())])
+        .chain(std::iter::once(0))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vframebuf::VarFrameBuf;
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    /// Frames, 50ms apart after a peak of 10 LEDs drops to silence, that keep the hold pinned
+    fn frames_held(hold: Duration) -> usize {
+        let timing = PeakHold { hold, decay_rate: 8.0 };
+        let start = Instant::now();
+        let (mut caps, mut hold_until, mut last) = (vec![0u8], vec![start], vec![start]);
+        VisualizerComponent::update_caps_with(&mut caps, &mut hold_until, &mut last, &[10], start, timing);
+        (1..=100)
+            .map(|frame| start + Duration::from_millis(50 * frame))
+            .take_while(|&now| {
+                VisualizerComponent::update_caps_with(&mut caps, &mut hold_until, &mut last, &[0], now, timing);
+                caps[0] == 10
+            })
+            .count()
+    }
+
+    #[test]
+    fn longer_peak_hold_pins_the_marker_for_more_frames() {
+        let short = frames_held(Duration::from_millis(200));
+        let long = frames_held(Duration::from_millis(1000));
+        assert!((3..8).contains(&short), "{short}");
+        assert!(long >= 19 && long > short, "{long}");
+    }
+
+    #[test]
+    fn balance_marker_leans_to_the_louder_channel() {
+        // scale over x 10..=50, centre 30
+        assert_eq!(balance_marker_x(-6.0, -6.0, 10, 41), 30);
+        assert_eq!(balance_marker_x(-3.0, -6.0, 10, 41), 20);
+        assert_eq!(balance_marker_x(-9.0, -6.0, 10, 41), 40);
+        // clamped to the scale ends, silence stays centred
+        assert_eq!(balance_marker_x(0.0, -40.0, 10, 41), 10);
+        assert_eq!(balance_marker_x(-40.0, 0.0, 10, 41), 50);
+        assert_eq!(balance_marker_x(f32::NEG_INFINITY, f32::NEG_INFINITY, 10, 41), 30);
+    }
+
+    #[test]
+    fn mirror_maps_bars_centre_outward() {
+        let map = |bands| (0..2 * bands - 1).map(|slot| mirror_band_index(slot, bands)).collect::<Vec<_>>();
+        assert_eq!(map(4), [3, 2, 1, 0, 1, 2, 3]);
+        assert_eq!(map(5), [4, 3, 2, 1, 0, 1, 2, 3, 4]);
+        assert_eq!(mirror_bands(&[10, 20, 30]), [30, 20, 10, 20, 30, 0]);
+        assert!(mirror_bands(&[]).is_empty());
+    }
+
+    #[test]
+    fn scope_silence_sits_on_centre_line() {
+        let trace = scope_trace(&[0; 64], 128, 64);
+        assert_eq!(trace.len(), 128);
+        assert!(trace.iter().all(|p| p.y == 31));
+    }
+
+    #[test]
+    fn scope_full_scale_reaches_panel_edges() {
+        let trace = scope_trace(&[i16::MAX, i16::MIN + 1], 2, 64);
+        assert_eq!(trace[0].y, 0);
+        assert_eq!(trace[1].y, 63);
+    }
+
+    #[test]
+    fn scope_auto_scales_quiet_signal_but_not_noise() {
+        // a -12 dBFS signal is stretched to the full height
+        let trace = scope_trace(&[8192, -8192], 2, 64);
+        assert_eq!((trace[0].y, trace[1].y), (0, 63));
+        // dither stays close to the centre thanks to the floor
+        let trace = scope_trace(&[16, -16], 2, 64);
+        assert!(trace.iter().all(|p| (p.y - 31).abs() <= 1));
+    }
+
+    fn combi_column(m_db: f32, comp: &mut VisualizerComponent) -> Vec<BinaryColor> {
+        let mut fb = VarFrameBuf::new(128, 64, BinaryColor::Off);
+        let s = comp.viz_state_mut();
+        (s.this.db_l, s.this.db_r, s.this.db_m) = (-20.0, -20.0, m_db);
+        comp.render::<_, crate::display::color_proxy::MonoProxy>(&mut fb).unwrap();
+        // centre column sits between the needles, x 58..70 on the 128px asset
+        (10..52).flat_map(|y| (58..70).map(move |x| (x, y)))
+            .map(|(x, y)| fb.as_slice()[y * 128 + x])
+            .collect()
+    }
+
+    #[test]
+    fn combi_centre_peak_follows_mono_level() {
+        use crate::display::layout::layout_for_resolution;
+        use crate::display::traits::ColorDepth;
+
+        let layout = layout_for_resolution(128, 64, ColorDepth::Monochrome);
+        let mut comp = VisualizerComponent::new(layout, Visualization::VuStereoWithCenterPeak, "classic");
+        comp.set_visualization_type(Visualization::VuStereoWithCenterPeak);
+
+        let quiet = combi_column(-60.0, &mut comp);
+        let loud = combi_column(6.0, &mut comp);
+        let lit = |col: &[BinaryColor]| col.iter().filter(|&&c| c == BinaryColor::On).count();
+        assert!(lit(&loud) > lit(&quiet), "centre column should light with level");
+        // hold keeps the top LED while the level falls back
+        assert_eq!(comp.viz_state().cap_m[0], 18);
+        combi_column(-60.0, &mut comp);
+        assert_eq!(comp.viz_state().last.hold_m, 18);
+    }
+
+    #[test]
+    fn combi_panels_tile_the_panel_without_overlap() {
+        use crate::visualization::{CombiLayout, CombiPeak};
+
+        for width in [128u32, 256] {
+            let area = Rectangle::new(Point::zero(), Size::new(width, 64));
+            for peak in [CombiPeak::Centre, CombiPeak::Left, CombiPeak::Right] {
+                for peak_width in [None, Some(0.0), Some(0.04), Some(0.2), Some(0.5), Some(2.0)] {
+                    let p = CombiLayout { peak, peak_width }.panels(area, 0.09);
+                    let rects = [p.vu_left, p.peak, p.vu_right];
+                    let case = format!("{width}px {peak:?} {peak_width:?}");
+                    for (i, a) in rects.iter().enumerate() {
+                        assert!(area.contains(a.top_left) && a.size.height == 64, "{case}");
+                        assert!(a.top_left.x as u32 + a.size.width <= width, "{case}");
+                        for b in &rects[i + 1..] {
+                            assert!(a.intersection(b).is_zero_sized(), "{case} {a:?} {b:?}");
+                        }
+                    }
+                    assert_eq!(rects.iter().map(|r| r.size.width).sum::<u32>(), width, "{case}");
+                    assert!(p.peak.size.width >= CombiLayout::MIN_PEAK_PX, "{case}");
+                    assert!(p.vu_left.top_left.x < p.vu_right.top_left.x, "{case}");
+                }
+            }
+        }
+        // the peak lands where asked
+        let area = Rectangle::new(Point::zero(), Size::new(256, 64));
+        let at = |peak| CombiLayout { peak, peak_width: Some(0.1) }.panels(area, 0.09).peak;
+        assert_eq!(at(CombiPeak::Left), Rectangle::new(Point::zero(), Size::new(26, 64)));
+        assert_eq!(at(CombiPeak::Centre).top_left.x, 115);
+        assert_eq!(at(CombiPeak::Right), Rectangle::new(Point::new(230, 0), Size::new(26, 64)));
+    }
+
+    #[test]
+    fn combi_peak_column_moves_to_the_side() {
+        use crate::display::layout::layout_for_resolution;
+        use crate::display::traits::ColorDepth;
+        use crate::visualization::{CombiLayout, CombiPeak};
+
+        let layout = layout_for_resolution(128, 64, ColorDepth::Monochrome);
+        let mut comp = VisualizerComponent::new(layout, Visualization::VuStereoWithCenterPeak, "classic");
+        comp.set_visualization_type(Visualization::VuStereoWithCenterPeak);
+        comp.set_combi_layout(CombiLayout { peak: CombiPeak::Right, peak_width: Some(0.1) });
+
+        let strip = |m_db: f32, comp: &mut VisualizerComponent| {
+            let mut fb = VarFrameBuf::new(128, 64, BinaryColor::Off);
+            let s = comp.viz_state_mut();
+            (s.this.db_l, s.this.db_r, s.this.db_m) = (-20.0, -20.0, m_db);
+            s.init = true;
+            comp.render::<_, crate::display::color_proxy::MonoProxy>(&mut fb).unwrap();
+            // the 13px column at the right edge, inside its outline
+            (10..52).flat_map(|y| (117..126).map(move |x| (x, y)))
+                .filter(|&(x, y)| fb.as_slice()[y * 128 + x] == BinaryColor::On)
+                .count()
+        };
+        let quiet = strip(-60.0, &mut comp);
+        let loud = strip(6.0, &mut comp);
+        assert!(loud > quiet, "right edge column should light with level ({quiet} -> {loud})");
+    }
+
+    fn render_lit(kind: Visualization, width: u32) -> usize {
+        use crate::display::layout::layout_for_resolution;
+        use crate::display::traits::ColorDepth;
+
+        let layout = layout_for_resolution(width, 64, ColorDepth::Monochrome);
+        let mut comp = VisualizerComponent::new(layout, kind, "classic");
+        comp.set_visualization_type(kind);
+        let s = comp.viz_state_mut();
+        (s.this.db_l, s.this.db_r, s.this.db_m) = (-6.0, -6.0, -6.0);
+        let mut fb = VarFrameBuf::new(width, 64, BinaryColor::Off);
+        assert!(comp.render::<_, crate::display::color_proxy::MonoProxy>(&mut fb).unwrap());
+        fb.as_slice().iter().filter(|&&c| c == BinaryColor::On).count()
+    }
+
+    #[test]
+    fn vu_mono_draws_meter() {
+        assert!(render_lit(Visualization::VuMono, 128) > 0);
+    }
+
+    #[test]
+    fn aio_vu_draws_meter_narrow_and_wide() {
+        assert!(render_lit(Visualization::VuAio, 128) > 0);
+        assert!(render_lit(Visualization::VuAio, 256) > 0);
+    }
+
+    #[test]
+    fn gray4_vu_needle_edges_are_anti_aliased() {
+        use crate::display::layout::layout_for_resolution;
+        use crate::display::traits::ColorDepth;
+        use embedded_graphics::pixelcolor::{Gray4, GrayColor};
+
+        let layout = layout_for_resolution(256, 64, ColorDepth::Gray4);
+        let mut comp = VisualizerComponent::new(layout, Visualization::VuStereo, "classic");
+        comp.set_visualization_type(Visualization::VuStereo);
+        comp.render::<_, crate::display::color_proxy::Gray4Proxy>(
+            &mut VarFrameBuf::new(256, 64, Gray4::BLACK)).unwrap();
+        // pin the needle angle directly - the ballistics run on wall-clock time
+        let mut frame = |angle: f64| {
+            let mut fb = VarFrameBuf::new(256, 64, Gray4::BLACK);
+            let none = Vec::new;
+            comp.viz.render_svg_and_draw(&mut fb, angle, false, angle, false,
+                none(), none(), none(), none(), none(), none()).unwrap();
+            fb.as_slice().iter().map(|c| c.luma()).collect::<Vec<u8>>()
+        };
+        let (rest, loud) = (frame(-40.0), frame(17.0));
+
+        // pixels the needle sweeps through, at either angle
+        let needle: Vec<u8> = rest.iter().zip(&loud)
+            .filter(|(a, b)| a != b)
+            .flat_map(|(&a, &b)| [a, b])
+            .collect();
+        assert!(!needle.is_empty(), "needle did not move");
+        let partial = needle.iter().filter(|&&l| l != 0 && l != 15).count();
+        assert!(partial > 0, "needle edges should carry partial-coverage grey levels");
+    }
+}
@@ -26,7 +26,11 @@
 use embedded_graphics::prelude::*;
 use embedded_graphics::pixelcolor::BinaryColor;
 use crate::display::layout::LayoutConfig;
-use crate::weather::WeatherData;
+use crate::display::components::scrollers::Marquee;
+use crate::textable::ScrollMode;
+use crate::astral::AstralData;
+use crate::weather::{WeatherAlert, WeatherData, WEATHER_CACHE_TTL};
+use chrono::{DateTime, Local};
 use std::time::Instant;
 
 /// Weather display mode
@@ -43,8 +47,15 @@ pub enum WeatherDisplayMode {
 pub struct WeatherDisplay {
     layout: LayoutConfig,
     last_weather_data: Vec<WeatherData>,
+    last_updated: Option<DateTime<Local>>,
     display_mode: WeatherDisplayMode,
     display_switch_timer: Option<Instant>,
+    /// Scrolls long condition descriptions that overflow their field
+    conditions_marquee: Marquee,
+    /// SVG raster buffer reused by every icon and glyph, frame to frame
+    svg_scratch: Vec<u8>,
+    /// Active severe weather alert, shown as a banner over the current conditions
+    alert: Option<WeatherAlert>,
 }
 
 impl WeatherDisplay {
@@ -53,8 +64,12 @@ impl WeatherDisplay {
         Self {
             layout,
             last_weather_data: Vec::new(),
+            last_updated: None,
             display_mode: WeatherDisplayMode::Current,
             display_switch_timer: None,
+            conditions_marquee: Marquee::new(ScrollMode::ScrollLeft),
+            svg_scratch: Vec::new(),
+            alert: None,
         }
     }
 
@@ -63,6 +78,29 @@ impl WeatherDisplay {
         self.last_weather_data = weather_data;
     }
 
+    /// Set or clear the active alert
+    pub fn set_alert(&mut self, alert: Option<WeatherAlert>) {
+        self.alert = alert;
+    }
+
+    /// Active alert, if any
+    pub fn alert(&self) -> Option<&WeatherAlert> {
+        self.alert.as_ref()
+    }
+
+    /// Record when the data was fetched (may be a cached snapshot)
+    pub fn set_last_updated(&mut self, last_updated: DateTime<Local>) {
+        self.last_updated = Some(last_updated);
+    }
+
+    /// True when the data is older than the cache TTL
+    pub fn is_stale(&self) -> bool {
+        self.last_updated
+            .and_then(|t| Local::now().signed_duration_since(t).to_std().ok())
+            .map(|age| age > WEATHER_CACHE_TTL)
+            .unwrap_or(false)
+    }
+
     /// Get current display mode
     pub fn display_mode(&self) -> WeatherDisplayMode {
         self.display_mode
@@ -125,4 +163,173 @@ impl WeatherDisplay {
     pub fn weather_data(&self) -> &[WeatherData] {
         &self.last_weather_data
     }
+
+    /// Conditions marquee, advanced once per weather render
+    pub fn conditions_marquee(&self) -> &Marquee {
+        &self.conditions_marquee
+    }
+
+    /// Mutable conditions marquee
+    pub fn conditions_marquee_mut(&mut self) -> &mut Marquee {
+        &mut self.conditions_marquee
+    }
+
+    /// SVG raster scratch buffer
+    pub fn svg_scratch_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.svg_scratch
+    }
+
+    /// Conditions marquee and SVG scratch together, for the current conditions render
+    pub fn render_parts(&mut self) -> (&Marquee, &mut Vec<u8>) {
+        (&self.conditions_marquee, &mut self.svg_scratch)
+    }
+}
+
+/// Which temperature the current conditions page shows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TemperatureShown {
+    /// Measured air temperature only
+    Actual,
+    /// Apparent ("feels like") temperature only
+    FeelsLike,
+    /// Actual with feels like in brackets
+    #[default]
+    Both,
+}
+
+/// Formatted readings for the current conditions page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrentWeatherText {
+    pub temperature: String,
+    pub humidity: String,
+    pub wind: String,
+    pub precipitation: String,
+    pub pressure: String,
+}
+
+impl CurrentWeatherText {
+    /// Temperature reads "actual°units", "apparent°units" or "actual(apparent)°units".
+    pub fn new(weather: &WeatherData, shown: TemperatureShown) -> Self {
+        let actual = weather.temperature_avg.round() as i32;
+        let apparent = weather.temperature_apparent_avg.round() as i32;
+        let reading = match shown {
+            TemperatureShown::Actual => actual.to_string(),
+            TemperatureShown::FeelsLike => apparent.to_string(),
+            TemperatureShown::Both => format!("{}({})", actual, apparent),
+        };
+        Self {
+            temperature: format!("{}°{}", reading, weather.temperature_units),
+            humidity: format!("{}%", weather.humidity_avg),
+            wind: format!("{} {} {}",
+                weather.wind_speed_avg.round() as i32,
+                weather.wind_speed_units,
+                weather.wind_direction),
+            precipitation: format!("{}%", weather.precipitation_probability_avg.round() as i32),
+            pressure: format!("{}{}", weather.pressure_sea_level_avg, weather.pressure_sea_level_units),
+        }
+    }
+}
+
+/// Formatted sun/moon event times for the wide weather panel
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AstralTimesText {
+    pub sunrise: String,
+    pub sunset: String,
+    pub moonrise: String,
+    pub moonset: String,
+}
+
+impl AstralTimesText {
+    /// Prefer the weather API times; fall back to astral calculations.
+    /// Weather APIs rarely populate the moon times, so astral is usually primary there.
+    pub fn new(weather: &WeatherData, astral: Option<&AstralData>) -> Self {
+        fn hhmm(t: Option<DateTime<Local>>) -> String {
+            t.map(|t| t.format("%H:%M").to_string())
+                .unwrap_or_else(|| "--:--".to_string())
+        }
+        Self {
+            sunrise: hhmm(weather.sunrise_time.or_else(|| astral.and_then(|a| a.sunrise))),
+            sunset: hhmm(weather.sunset_time.or_else(|| astral.and_then(|a| a.sunset))),
+            moonrise: hhmm(weather.moonrise_time.or_else(|| astral.and_then(|a| a.moonrise))),
+            moonset: hhmm(weather.moonset_time.or_else(|| astral.and_then(|a| a.moonset))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::weather::TempUnits;
+    use chrono::TimeZone;
+
+    #[test]
+    fn moonset_text_uses_moonset_time() {
+        let weather = WeatherData {
+            moonrise_time: Local.with_ymd_and_hms(2026, 3, 1, 7, 15, 0).single(),
+            moonset_time: Local.with_ymd_and_hms(2026, 3, 1, 19, 40, 0).single(),
+            ..Default::default()
+        };
+        let text = AstralTimesText::new(&weather, None);
+        assert_eq!(text.moonrise, "07:15");
+        assert_eq!(text.moonset, "19:40");
+        assert_ne!(text.moonrise, text.moonset);
+    }
+
+    #[test]
+    fn cached_celsius_renders_as_fahrenheit_after_toggle() {
+        let weather = WeatherData {
+            temperature_avg: 20.0,
+            temperature_apparent_avg: 18.0,
+            temperature_max: 25.0,
+            temperature_min: 10.0,
+            temperature_units: "C".to_string(),
+            wind_speed_avg: 16.0,
+            wind_speed_units: "km/h".to_string(),
+            wind_direction: "NW".to_string(),
+            ..Default::default()
+        };
+        let both = TemperatureShown::Both;
+        assert_eq!(CurrentWeatherText::new(&weather, both).temperature, "20(18)°C");
+
+        let units = TempUnits::Celsius.toggled();
+        let text = CurrentWeatherText::new(&weather.in_units(units), both);
+        assert_eq!(text.temperature, "68(64)°F");
+        assert_eq!(text.wind, "10 mph NW");
+
+        // and back again without drift
+        let back = weather.in_units(units).in_units(units.toggled());
+        assert_eq!(CurrentWeatherText::new(&back, both), CurrentWeatherText::new(&weather, both));
+        assert!((back.temperature_min - 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn missing_times_fall_back_to_astral_then_placeholder() {
+        let astral = AstralData {
+            date: chrono::NaiveDate::from_ymd_opt(2026, 3, 1).unwrap(),
+            sunrise: None,
+            sunset: None,
+            moonrise: None,
+            moonset: Local.with_ymd_and_hms(2026, 3, 1, 20, 5, 0).single(),
+        };
+        let text = AstralTimesText::new(&WeatherData::default(), Some(&astral));
+        assert_eq!(text.moonset, "20:05");
+        assert_eq!(text.moonrise, "--:--");
+    }
+
+    #[test]
+    fn temperature_shows_actual_feels_like_or_both() {
+        let weather = WeatherData {
+            temperature_avg: -2.4,
+            temperature_apparent_avg: -7.6,
+            temperature_units: "C".to_string(),
+            ..Default::default()
+        };
+        let temp = |shown| CurrentWeatherText::new(&weather, shown).temperature;
+        assert_eq!(temp(TemperatureShown::Actual), "-2°C");
+        assert_eq!(temp(TemperatureShown::FeelsLike), "-8°C");
+        assert_eq!(temp(TemperatureShown::Both), "-2(-8)°C");
+        assert_eq!(TemperatureShown::default(), TemperatureShown::Both);
+        assert_eq!(serde_yaml::from_str::<TemperatureShown>("feels_like").unwrap(), TemperatureShown::FeelsLike);
+    }
 }
@@ -106,6 +106,46 @@ pub struct EmulatorState {
 
     /// Request to cycle to next visualization
     pub cycle_visualization: bool,
+
+    /// Request to flip weather between Celsius and Fahrenheit
+    pub toggle_temp_units: bool,
+
+    /// Request to flip the track time between total and remaining
+    pub toggle_show_remaining: bool,
+
+    /// Request to show the now playing QR code
+    pub show_qr: bool,
+
+    /// Request to start the sleep timer, or cancel a running one
+    pub toggle_sleep_timer: bool,
+
+    /// Pending transport command for the monitored player
+    pub transport_request: Option<crate::sliminfo::TransportCommand>,
+
+    /// GIF capture in progress, fed and finished by each flush
+    pub gif_recording: Option<crate::display::gif_record::GifRecorder>,
+}
+
+#[cfg(feature = "emulator")]
+impl EmulatorState {
+    /// Panel contents as RGBA, unscaled and without brightness or invert.
+    /// Mono pixels are white rather than the on-screen tint.
+    pub fn panel_rgba(&self) -> Vec<u8> {
+        self.buffer.iter()
+            .flat_map(|c| match c {
+                EmulatorColor::Mono(BinaryColor::On) => [255, 255, 255, 255],
+                other => other.to_rgba(),
+            })
+            .collect()
+    }
+
+    /// Record the next `length` of frames to a GIF at `path`
+    pub fn start_gif_recording(&mut self, length: std::time::Duration, path: impl Into<std::path::PathBuf>) {
+        let rec = crate::display::gif_record::GifRecorder::new(
+            self.width, self.height, length, path, std::time::Instant::now());
+        log::info!("Recording {:?} of frames to {}", length, rec.path().display());
+        self.gif_recording = Some(rec);
+    }
 }
 
 /// Emulator display driver
@@ -188,6 +228,12 @@ impl EmulatorDriver {
             current_display_mode: crate::display::DisplayMode::Clock,
             cycle_easter_egg: false,
             cycle_visualization: false,
+            toggle_temp_units: false,
+            toggle_show_remaining: false,
+            show_qr: false,
+            toggle_sleep_timer: false,
+            transport_request: None,
+            gif_recording: None,
         }));
 
         Ok(Self {
@@ -233,6 +279,12 @@ impl EmulatorDriver {
             current_display_mode: crate::display::DisplayMode::Clock,
             cycle_easter_egg: false,
             cycle_visualization: false,
+            toggle_temp_units: false,
+            toggle_show_remaining: false,
+            show_qr: false,
+            toggle_sleep_timer: false,
+            transport_request: None,
+            gif_recording: None,
         }));
 
         Ok(Self {
@@ -278,6 +330,12 @@ impl EmulatorDriver {
             current_display_mode: crate::display::DisplayMode::Clock,
             cycle_easter_egg: false,
             cycle_visualization: false,
+            toggle_temp_units: false,
+            toggle_show_remaining: false,
+            show_qr: false,
+            toggle_sleep_timer: false,
+            transport_request: None,
+            gif_recording: None,
         }));
 
         Ok(Self { framebuffer, capabilities, state })
@@ -339,6 +397,18 @@ impl EmulatorDriver {
         }
 
         state.frame_count += 1;
+
+        let now = std::time::Instant::now();
+        let rgba = state.gif_recording.as_ref().map(|_| state.panel_rgba());
+        if let (Some(rec), Some(rgba)) = (state.gif_recording.as_mut(), rgba) {
+            rec.push_rgba(&rgba, now);
+            if rec.is_done(now) {
+                if let Err(e) = rec.save() {
+                    log::error!("GIF recording failed: {}", e);
+                }
+                state.gif_recording = None;
+            }
+        }
     }
 
     /// Check and consume requested display mode (for keyboard triggers)
@@ -0,0 +1,214 @@
+/*
+ *  display/drivers/i2c_bus.rs
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  I2C bus opening, with the configured clock speed
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *  Public License.
+ *
+ */
+
+use std::path::{Path, PathBuf};
+
+use linux_embedded_hal::I2cdev;
+use log::{info, warn};
+
+use crate::config::{BusConfig, DisplayConfig};
+use crate::display::error::DisplayError;
+
+/// Opens an I2C bus for a driver - a trait so tests can see what was asked for.
+pub trait I2cOpener {
+    type Bus;
+
+    /// Open `path`; `speed_hz` None leaves the bus at its default clock.
+    fn open(&self, path: &str, speed_hz: Option<u32>) -> Result<Self::Bus, DisplayError>;
+}
+
+/// Linux i2c-dev.
+///
+/// The adapter clock is fixed by the device tree when the bus driver loads
+/// (`dtparam=i2c_arm_baudrate=1000000` on a Pi) and i2c-dev cannot change it,
+/// so a configured speed is checked against the running clock and a mismatch
+/// logged with the setting that fixes it.
+pub struct LinuxI2c;
+
+impl I2cOpener for LinuxI2c {
+    type Bus = I2cdev;
+
+    fn open(&self, path: &str, speed_hz: Option<u32>) -> Result<I2cdev, DisplayError> {
+        let bus = I2cdev::new(path)
+            .map_err(|e| DisplayError::I2cError(format!("Failed to open {}: {}", path, e)))?;
+        if let Some(wanted) = speed_hz {
+            match adapter_clock_hz(path) {
+                Some(running) if running == wanted => info!("{} clock {} Hz", path, running),
+                Some(running) => warn!(
+                    "{} clock is {} Hz, {} Hz configured - set dtparam=i2c_arm_baudrate={} and reboot",
+                    path, running, wanted, wanted
+                ),
+                None => info!("{} clock unknown, {} Hz configured", path, wanted),
+            }
+        }
+        Ok(bus)
+    }
+}
+
+/// Configured I2C clock for `config`, None when unset or not an I2C bus.
+pub fn i2c_speed(config: &DisplayConfig) -> Option<u32> {
+    match &config.bus {
+        Some(BusConfig::I2c { speed_hz, .. }) => *speed_hz,
+        _ => None,
+    }
+}
+
+/// Open the bus a driver was configured with, at the configured clock.
+pub fn open_configured<O: I2cOpener>(opener: &O, path: &str, config: &DisplayConfig) -> Result<O::Bus, DisplayError> {
+    opener.open(path, i2c_speed(config))
+}
+
+/// Device tree clock node for an i2c-dev path ("/dev/i2c-1").
+fn clock_frequency_path(path: &str) -> Option<PathBuf> {
+    let adapter = Path::new(path).file_name()?.to_str()?;
+    adapter.starts_with("i2c-").then(|| {
+        PathBuf::from("/sys/class/i2c-adapter").join(adapter).join("of_node/clock-frequency")
+    })
+}
+
+/// Device tree cells are big-endian u32.
+fn parse_clock_frequency(cell: &[u8]) -> Option<u32> {
+    Some(u32::from_be_bytes(cell.get(..4)?.try_into().ok()?))
+}
+
+/// Running adapter clock, None off device tree systems.
+fn adapter_clock_hz(path: &str) -> Option<u32> {
+    parse_clock_frequency(&std::fs::read(clock_frequency_path(path)?).ok()?)
+}
+
+/// In-memory bus for driver tests: keeps every write, and while `wedged`
+/// NACKs frame data until the controller init sequence comes through.
+#[cfg(test)]
+#[derive(Clone, Default)]
+pub(crate) struct FakeBus {
+    pub writes: std::sync::Arc<std::sync::Mutex<Vec<Vec<u8>>>>,
+    pub wedged: std::sync::Arc<std::sync::atomic::AtomicBool>,
+}
+
+#[cfg(test)]
+impl FakeBus {
+    /// Command writes so far, control byte stripped
+    pub fn commands(&self) -> Vec<Vec<u8>> {
+        self.writes.lock().unwrap().iter()
+            .filter(|w| w.first() == Some(&0x00))
+            .map(|w| w[1..].to_vec())
+            .collect()
+    }
+
+    /// Frame data bytes written so far
+    pub fn data_len(&self) -> usize {
+        self.writes.lock().unwrap().iter()
+            .filter(|w| w.first() == Some(&0x40))
+            .map(|w| w.len() - 1)
+            .sum()
+    }
+
+    pub fn clear(&self) {
+        self.writes.lock().unwrap().clear();
+    }
+}
+
+#[cfg(test)]
+impl embedded_hal::i2c::ErrorType for FakeBus {
+    type Error = embedded_hal::i2c::ErrorKind;
+}
+
+#[cfg(test)]
+impl embedded_hal::i2c::I2c for FakeBus {
+    fn transaction(&mut self, _address: u8, operations: &mut [embedded_hal::i2c::Operation<'_>]) -> Result<(), Self::Error> {
+        use std::sync::atomic::Ordering;
+        for op in operations {
+            if let embedded_hal::i2c::Operation::Write(bytes) = op {
+                // only the init sequence switches the charge pump on
+                if bytes.starts_with(&[0x00, 0x8D]) {
+                    self.wedged.store(false, Ordering::SeqCst);
+                }
+                if bytes.first() == Some(&0x40) && self.wedged.load(Ordering::SeqCst) {
+                    return Err(embedded_hal::i2c::ErrorKind::NoAcknowledge(
+                        embedded_hal::i2c::NoAcknowledgeSource::Data,
+                    ));
+                }
+                self.writes.lock().unwrap().push(bytes.to_vec());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+impl I2cOpener for FakeBus {
+    type Bus = FakeBus;
+
+    fn open(&self, _path: &str, _speed_hz: Option<u32>) -> Result<FakeBus, DisplayError> {
+        Ok(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    /// Records each open instead of touching a bus.
+    #[derive(Default)]
+    struct RecordingOpener(RefCell<Vec<(String, Option<u32>)>>);
+
+    impl I2cOpener for RecordingOpener {
+        type Bus = ();
+
+        fn open(&self, path: &str, speed_hz: Option<u32>) -> Result<(), DisplayError> {
+            self.0.borrow_mut().push((path.to_string(), speed_hz));
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn configured_speed_reaches_the_open_call() {
+        let opener = RecordingOpener::default();
+        let mut config = DisplayConfig {
+            bus: Some(BusConfig::I2c { bus: "/dev/i2c-1".into(), address: 0x3C, speed_hz: Some(1_000_000) }),
+            ..Default::default()
+        };
+        open_configured(&opener, "/dev/i2c-1", &config).unwrap();
+
+        // unset keeps the bus default
+        config.bus = Some(BusConfig::I2c { bus: "/dev/i2c-1".into(), address: 0x3C, speed_hz: None });
+        open_configured(&opener, "/dev/i2c-1", &config).unwrap();
+
+        assert_eq!(*opener.0.borrow(), [
+            ("/dev/i2c-1".to_string(), Some(1_000_000)),
+            ("/dev/i2c-1".to_string(), None),
+        ]);
+    }
+
+    #[test]
+    fn device_tree_clock_is_read_big_endian() {
+        assert_eq!(
+            clock_frequency_path("/dev/i2c-1"),
+            Some(PathBuf::from("/sys/class/i2c-adapter/i2c-1/of_node/clock-frequency"))
+        );
+        assert_eq!(clock_frequency_path("/dev/spidev0.0"), None);
+        assert_eq!(parse_clock_frequency(&[0x00, 0x0f, 0x42, 0x40]), Some(1_000_000));
+        assert_eq!(parse_clock_frequency(&[0x00, 0x01]), None);
+    }
+}
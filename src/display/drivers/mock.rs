@@ -76,15 +76,27 @@ pub struct MockDriverState {
     /// Last invert state set
     pub last_invert: Option<bool>,
 
+    /// Every set_display_on() call, in order
+    pub display_on_calls: Vec<bool>,
+
     /// Whether the driver is initialized
     pub is_initialized: bool,
 
     /// Total bytes written via write_buffer
     pub bytes_written: usize,
 
+    /// Packed buffer from the most recent write_buffer call
+    pub last_frame: Vec<u8>,
+
     /// Simulate failures (for error testing)
     pub simulate_flush_failure: bool,
     pub simulate_init_failure: bool,
+
+    /// Fail this many flushes with a transient bus error, then recover
+    pub simulate_transient_flushes: usize,
+
+    /// Fail every flush until the next init(), like a wedged I2C bus
+    pub simulate_wedged_bus: bool,
 }
 
 impl MockDriver {
@@ -170,29 +182,30 @@ impl MockDriver {
             .count()
     }
 
-    /// Save framebuffer to PBM file (for visual debugging)
-    #[cfg(test)]
-    pub fn save_to_pbm(&self, path: &str) -> std::io::Result<()> {
-        use std::fs::File;
-        use std::io::Write;
-
-        let mut file = File::create(path)?;
-
-        // PBM header
-        writeln!(file, "P1")?;
-        writeln!(file, "{} {}", self.capabilities.width, self.capabilities.height)?;
-
-        // Pixel data
-        for (i, &pixel) in self.framebuffer.as_slice().iter().enumerate() {
-            write!(file, "{}", if pixel == BinaryColor::On { "1" } else { "0" })?;
-            if (i + 1) % self.capabilities.width as usize == 0 {
-                writeln!(file)?;
-            } else {
-                write!(file, " ")?;
-            }
+    /// Packed buffer the host sent with the last write_buffer call
+    pub fn last_frame(&self) -> Vec<u8> {
+        self.state.lock().unwrap().last_frame.clone()
+    }
+
+    /// Framebuffer as a plain (P1) PBM image, for golden files and visual diffs
+    pub fn to_pbm(&self) -> String {
+        let width = self.capabilities.width as usize;
+        let mut out = format!("P1\n{} {}\n", self.capabilities.width, self.capabilities.height);
+
+        for row in self.framebuffer.as_slice().chunks(width) {
+            let line: Vec<&str> = row.iter()
+                .map(|&pixel| if pixel == BinaryColor::On { "1" } else { "0" })
+                .collect();
+            out.push_str(&line.join(" "));
+            out.push('\n');
         }
 
-        Ok(())
+        out
+    }
+
+    /// Save framebuffer to PBM file (for visual debugging)
+    pub fn save_to_pbm(&self, path: &str) -> std::io::Result<()> {
+        std::fs::write(path, self.to_pbm())
     }
 }
 
@@ -218,6 +231,7 @@ impl DisplayDriver for MockDriver {
 
         state.init_count += 1;
         state.is_initialized = true;
+        state.simulate_wedged_bus = false;
         Ok(())
     }
 
@@ -233,6 +247,13 @@ impl DisplayDriver for MockDriver {
         if state.simulate_flush_failure {
             return Err(DisplayError::Other("Simulated flush failure".to_string()));
         }
+        if state.simulate_wedged_bus {
+            return Err(DisplayError::I2cError("Simulated wedged bus".to_string()));
+        }
+        if state.simulate_transient_flushes > 0 {
+            state.simulate_transient_flushes -= 1;
+            return Err(DisplayError::BusTransient("Simulated EREMOTEIO".to_string()));
+        }
 
         state.flush_count += 1;
         Ok(())
@@ -263,6 +284,7 @@ impl DisplayDriver for MockDriver {
         {
             let mut state = self.state.lock().unwrap();
             state.bytes_written += buffer.len();
+            state.last_frame = buffer.to_vec();
         }
 
         // Unpack buffer into framebuffer
@@ -290,6 +312,12 @@ impl DisplayDriver for MockDriver {
         Ok(())
     }
 
+    fn set_display_on(&mut self, on: bool) -> Result<(), DisplayError> {
+        let mut state = self.state.lock().unwrap();
+        state.display_on_calls.push(on);
+        Ok(())
+    }
+
     fn set_rotation(&mut self, degrees: u16) -> Result<(), DisplayError> {
         if degrees != 0 && degrees != 90 && degrees != 180 && degrees != 270 {
             return Err(DisplayError::InvalidRotation(degrees));
@@ -446,6 +474,16 @@ mod tests {
         assert_eq!(driver.state().lock().unwrap().bytes_written, 1024);
     }
 
+    #[test]
+    fn test_mock_driver_records_frame_and_pbm() {
+        let mut driver = MockDriver::new_with_size(8, 2).unwrap();
+
+        driver.write_buffer(&[0b0000_0101, 0x00]).unwrap();
+
+        assert_eq!(driver.last_frame(), vec![0b0000_0101, 0x00]);
+        assert_eq!(driver.to_pbm(), "P1\n8 2\n1 0 1 0 0 0 0 0\n0 0 0 0 0 0 0 0\n");
+    }
+
     #[test]
     fn test_mock_driver_buffer_size_mismatch() {
         let mut driver = MockDriver::new_with_size(128, 64).unwrap();
@@ -455,4 +493,19 @@ mod tests {
 
         assert!(driver.write_buffer(&buffer).is_err());
     }
+
+    #[test]
+    fn transfer_reports_transient_and_fatal_flush_failures() {
+        let mut driver = MockDriver::new_with_size(128, 64).unwrap();
+        let frame = vec![0xA5; 128 * 64 / 8];
+
+        // a one-off failure surfaces as transient, the next transfer goes through
+        driver.state().lock().unwrap().simulate_transient_flushes = 1;
+        assert!(driver.transfer(&frame).unwrap_err().is_transient());
+        driver.transfer(&frame).unwrap();
+        assert_eq!(driver.last_frame(), frame);
+
+        driver.state().lock().unwrap().simulate_flush_failure = true;
+        assert!(!driver.transfer(&frame).unwrap_err().is_transient());
+    }
 }
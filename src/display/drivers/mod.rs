@@ -43,8 +43,11 @@ pub mod st7789;
 #[cfg(feature = "driver-st7796s")]
 pub mod st7796s;
 
-// Mock driver for testing
-#[cfg(test)]
+// I2C bus opening shared by the I2C drivers
+pub mod i2c_bus;
+
+// Mock driver for testing - public with the headless feature for integration tests
+#[cfg(any(test, feature = "headless"))]
 pub mod mock;
 
 // Emulator driver for desktop testing
@@ -92,6 +92,7 @@ impl Sh1106Driver {
             invert: Some(false),
             rotate_deg: Some(0),
             emulated: Some(false),
+            ..Default::default()
         }
     }
 
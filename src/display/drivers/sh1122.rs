@@ -93,6 +93,7 @@ impl Sh1122Driver {
             invert:     Some(false),
             rotate_deg: Some(0),
             emulated:   Some(false),
+            ..Default::default()
         }
     }
 
@@ -23,9 +23,10 @@
 
 #![allow(dead_code)] // SSD1306 driver helpers; some methods reserved
 
+use embedded_hal::i2c::I2c;
 use linux_embedded_hal::I2cdev;
 use ssd1306::{
-    mode::BufferedGraphicsMode,
+    mode::{BufferedGraphicsMode, DisplayConfig as _},
     prelude::*,
     size::{DisplaySize128x64, DisplaySize128x32},
     I2CDisplayInterface,
@@ -39,6 +40,8 @@ use embedded_graphics::geometry::Size;
 
 use crate::config::{DisplayConfig, BusConfig};
 use crate::display::error::DisplayError;
+use crate::display::drivers::i2c_bus::{open_configured, I2cOpener, LinuxI2c};
+use crate::display::framebuffer::{dirty_pages, PAGE_ROWS};
 use crate::display::traits::{
     DisplayDriver, DrawableDisplay, DisplayCapabilities, ColorDepth,
     BusInterface, I2cInfo,
@@ -54,22 +57,40 @@ pub const ALT_I2C_ADDRESS: u8 = 0x3D;
 /// Maximum supported I2C clock speed
 pub const DEFAULT_I2C_SPEED_HZ: u32 = 400_000;
 
-/// SSD1306 display driver wrapper
-pub struct Ssd1306Driver {
+/// SSD1306 display driver wrapper, generic over the I2C bus for tests
+pub struct Ssd1306Driver<I = I2cdev> {
     /// The underlying ssd1306 driver
-    display: Ssd1306Variants,
+    display: Ssd1306Variants<I>,
 
     /// Framebuffer for drawing operations
     framebuffer: VarFrameBuf<BinaryColor>,
 
     /// Display capabilities
     capabilities: DisplayCapabilities,
+
+    /// Pixels the panel last received, empty until the first flush
+    shown: Vec<BinaryColor>,
+
+    /// Last brightness set, put back after a re-init
+    brightness: Option<u8>,
+
+    /// Inversion and power state, put back after a re-init
+    inverted: bool,
+    display_on: bool,
 }
 
 /// Enum to handle different SSD1306 display sizes
-enum Ssd1306Variants {
-    Size128x64(Ssd1306<I2CInterface<I2cdev>, DisplaySize128x64, BufferedGraphicsMode<DisplaySize128x64>>),
-    Size128x32(Ssd1306<I2CInterface<I2cdev>, DisplaySize128x32, BufferedGraphicsMode<DisplaySize128x32>>),
+enum Ssd1306Variants<I> {
+    Size128x64(Ssd1306<I2CInterface<I>, DisplaySize128x64, BufferedGraphicsMode<DisplaySize128x64>>),
+    Size128x32(Ssd1306<I2CInterface<I>, DisplaySize128x32, BufferedGraphicsMode<DisplaySize128x32>>),
+}
+
+fn i2c_interface_info() -> BusInterface {
+    BusInterface::I2c(I2cInfo {
+        default_address: DEFAULT_I2C_ADDRESS,
+        alt_address: Some(ALT_I2C_ADDRESS),
+        max_speed_hz: DEFAULT_I2C_SPEED_HZ,
+    })
 }
 
 impl Ssd1306Driver {
@@ -83,23 +104,16 @@ impl Ssd1306Driver {
             bus: Some(BusConfig::I2c {
                 bus: "/dev/i2c-1".to_string(),
                 address: DEFAULT_I2C_ADDRESS,
-                speed_hz: Some(DEFAULT_I2C_SPEED_HZ),
+                speed_hz: None, // bus default, see i2c_bus
             }),
             brightness: Some(200),
             invert: Some(false),
             rotate_deg: Some(0),
             emulated: Some(false),
+            ..Default::default()
         }
     }
 
-    fn i2c_interface_info() -> BusInterface {
-        BusInterface::I2c(I2cInfo {
-            default_address: DEFAULT_I2C_ADDRESS,
-            alt_address: Some(ALT_I2C_ADDRESS),
-            max_speed_hz: DEFAULT_I2C_SPEED_HZ,
-        })
-    }
-
     /// Create a new SSD1306 driver using I2C
     ///
     /// # Arguments
@@ -115,12 +129,23 @@ impl Ssd1306Driver {
         i2c_bus_path: &str,
         address: u8,
         config: &DisplayConfig,
+    ) -> Result<Self, DisplayError> {
+        Self::with_opener(&LinuxI2c, i2c_bus_path, address, config)
+    }
+}
+
+impl<I: I2c + Send + Sync + 'static> Ssd1306Driver<I> {
+    /// `new_i2c` on a bus from `opener`
+    pub(crate) fn with_opener<O: I2cOpener<Bus = I>>(
+        opener: &O,
+        i2c_bus_path: &str,
+        address: u8,
+        config: &DisplayConfig,
     ) -> Result<Self, DisplayError> {
         info!("Initializing SSD1306 on {} at address 0x{:02X}", i2c_bus_path, address);
 
-        // Open I2C device
-        let i2c = I2cdev::new(i2c_bus_path)
-            .map_err(|e| DisplayError::I2cError(format!("Failed to open {}: {}", i2c_bus_path, e)))?;
+        // Open I2C device, checking the clock against any configured speed_hz
+        let i2c = open_configured(opener, i2c_bus_path, config)?;
 
         // Determine display size from config or default to 128x64
         let width = config.width.unwrap_or(128);
@@ -133,14 +158,13 @@ impl Ssd1306Driver {
                 let display = Ssd1306::new(interface, DisplaySize128x64, DisplayRotation::Rotate0)
                     .into_buffered_graphics_mode();
 
-                // BufferedGraphicsMode is initialized automatically
-                // The ssd1306 crate handles initialization internally
+                // The controller is set up by init()
 
                 let caps = DisplayCapabilities {
                     width: 128,
                     height: 64,
                     color_depth: ColorDepth::Monochrome,
-                    interface: Self::i2c_interface_info(),
+                    interface: i2c_interface_info(),
                     supports_rotation: true,
                     max_fps: 30, // I2C is slower
                     supports_brightness: true,
@@ -155,14 +179,13 @@ impl Ssd1306Driver {
                 let display = Ssd1306::new(interface, DisplaySize128x32, DisplayRotation::Rotate0)
                     .into_buffered_graphics_mode();
 
-                // BufferedGraphicsMode is initialized automatically
-                // The ssd1306 crate handles initialization internally
+                // The controller is set up by init()
 
                 let caps = DisplayCapabilities {
                     width: 128,
                     height: 32,
                     color_depth: ColorDepth::Monochrome,
-                    interface: Self::i2c_interface_info(),
+                    interface: i2c_interface_info(),
                     supports_rotation: true,
                     max_fps: 30,
                     supports_brightness: true,
@@ -186,6 +209,10 @@ impl Ssd1306Driver {
             display,
             framebuffer,
             capabilities,
+            shown: Vec::new(),
+            brightness: None,
+            inverted: false,
+            display_on: true,
         };
 
         // Apply configuration options
@@ -197,74 +224,61 @@ impl Ssd1306Driver {
             driver.set_invert(invert)?;
         }
 
-        if let Some(rotation) = config.rotate_deg {
-            driver.set_rotation(rotation)?;
-        }
+        // rotate_deg is applied to the framebuffer by DisplayManager - setting
+        // it in hardware as well would rotate twice
 
         info!("SSD1306 initialized successfully ({}x{})", width, height);
 
         Ok(driver)
     }
 
-    /// Helper method to convert framebuffer to display format and flush
+    /// Send the framebuffer, touching only pixels that differ from what the
+    /// panel last received. The ssd1306 crate flushes the bounding box of the
+    /// pixels set since its last flush, so an unchanged frame costs no bus
+    /// traffic and a blinking colon just a few pages.
     fn flush_framebuffer(&mut self) -> Result<(), DisplayError> {
-        // Copy framebuffer contents to the ssd1306 display
+        let width = self.capabilities.width as usize;
+        let next = self.framebuffer.as_slice();
+        let dirty = dirty_pages(&self.shown, next, width);
         match &mut self.display {
             Ssd1306Variants::Size128x64(display) => {
-                // Clear the display buffer
-                display.clear(BinaryColor::Off)
-                    .map_err(|_| DisplayError::Other("Failed to clear display".to_string()))?;
-
-                // Draw framebuffer contents to display
-                for y in 0..self.capabilities.height {
-                    for x in 0..self.capabilities.width {
-                        let point = Point::new(x as i32, y as i32);
-                        let idx = (y * self.capabilities.width + x) as usize;
-
-                        if let Some(&color) = self.framebuffer.as_slice().get(idx) {
-                            if color == BinaryColor::On {
-                                Pixel(point, BinaryColor::On)
-                                    .draw(display)
-                                    .map_err(|_| DisplayError::DrawingError("Failed to draw pixel".to_string()))?;
-                            }
-                        }
-                    }
-                }
-
-                // Flush to hardware
+                set_changed_pixels(next, &self.shown, &dirty, width, |x, y, on| display.set_pixel(x, y, on));
                 display.flush()
                     .map_err(|e| DisplayError::Other(format!("Flush failed: {:?}", e)))?;
             }
             Ssd1306Variants::Size128x32(display) => {
-                // Similar logic for 128x32
-                display.clear(BinaryColor::Off)
-                    .map_err(|_| DisplayError::Other("Failed to clear display".to_string()))?;
-
-                for y in 0..self.capabilities.height {
-                    for x in 0..self.capabilities.width {
-                        let point = Point::new(x as i32, y as i32);
-                        let idx = (y * self.capabilities.width + x) as usize;
-
-                        if let Some(&color) = self.framebuffer.as_slice().get(idx) {
-                            if color == BinaryColor::On {
-                                Pixel(point, BinaryColor::On)
-                                    .draw(display)
-                                    .map_err(|_| DisplayError::DrawingError("Failed to draw pixel".to_string()))?;
-                            }
-                        }
-                    }
-                }
-
+                set_changed_pixels(next, &self.shown, &dirty, width, |x, y, on| display.set_pixel(x, y, on));
                 display.flush()
                     .map_err(|e| DisplayError::Other(format!("Flush failed: {:?}", e)))?;
             }
         }
-
+        // only once the panel has it - a failed flush is resent in full on retry
+        self.shown.clear();
+        self.shown.extend_from_slice(next);
         Ok(())
     }
 }
 
-impl DisplayDriver for Ssd1306Driver {
+/// Hand `set` each pixel of the dirty pages that differs from `shown`.
+fn set_changed_pixels(
+    next: &[BinaryColor],
+    shown: &[BinaryColor],
+    dirty: &[bool],
+    width: usize,
+    mut set: impl FnMut(u32, u32, bool),
+) {
+    let page_len = width * PAGE_ROWS;
+    for page in (0..dirty.len()).filter(|&p| dirty[p]) {
+        let start = page * page_len;
+        for (idx, pixel) in next.iter().enumerate().skip(start).take(page_len) {
+            if shown.get(idx) != Some(pixel) {
+                set((idx % width) as u32, (idx / width) as u32, pixel.is_on());
+            }
+        }
+    }
+}
+
+impl<I: I2c + Send + Sync + 'static> DisplayDriver for Ssd1306Driver<I> {
     fn as_any(&self) -> &dyn std::any::Any {
         self
     }
@@ -278,12 +292,30 @@ impl DisplayDriver for Ssd1306Driver {
     }
 
     fn init(&mut self) -> Result<(), DisplayError> {
-        // Display is already initialized in new() constructor
-        // The ssd1306 crate doesn't expose a re-init method in BufferedGraphicsMode
+        // Sends the full init sequence, so it also recovers a controller
+        // that lost its setup. That resets contrast, inversion and power,
+        // so put back what was set through this driver.
+        match &mut self.display {
+            Ssd1306Variants::Size128x64(display) => display.init(),
+            Ssd1306Variants::Size128x32(display) => display.init(),
+        }
+        .map_err(|e| DisplayError::Other(format!("Init failed: {:?}", e)))?;
+        // controller RAM is cleared too, the next flush sends every page
+        self.shown.clear();
+        if let Some(brightness) = self.brightness {
+            self.set_brightness(brightness)?;
+        }
+        if self.inverted {
+            self.set_invert(true)?;
+        }
+        if !self.display_on {
+            self.set_display_on(false)?;
+        }
         Ok(())
     }
 
     fn set_brightness(&mut self, value: u8) -> Result<(), DisplayError> {
+        self.brightness = Some(value);
         match &mut self.display {
             Ssd1306Variants::Size128x64(display) => {
                 // Use the prelude Brightness enum
@@ -351,19 +383,29 @@ impl DisplayDriver for Ssd1306Driver {
     }
 
     fn set_invert(&mut self, inverted: bool) -> Result<(), DisplayError> {
+        self.inverted = inverted;
         match &mut self.display {
             Ssd1306Variants::Size128x64(display) => {
-                display.set_display_on(!inverted)
+                display.set_invert(inverted)
                     .map_err(|e| DisplayError::Other(format!("Set invert failed: {:?}", e)))?;
             }
             Ssd1306Variants::Size128x32(display) => {
-                display.set_display_on(!inverted)
+                display.set_invert(inverted)
                     .map_err(|e| DisplayError::Other(format!("Set invert failed: {:?}", e)))?;
             }
         }
         Ok(())
     }
 
+    fn set_display_on(&mut self, on: bool) -> Result<(), DisplayError> {
+        self.display_on = on;
+        match &mut self.display {
+            Ssd1306Variants::Size128x64(display) => display.set_display_on(on),
+            Ssd1306Variants::Size128x32(display) => display.set_display_on(on),
+        }
+        .map_err(|e| DisplayError::Other(format!("Set display on failed: {:?}", e)))
+    }
+
     fn set_rotation(&mut self, degrees: u16) -> Result<(), DisplayError> {
         let rotation = match degrees {
             0 => DisplayRotation::Rotate0,
@@ -387,12 +429,12 @@ impl DisplayDriver for Ssd1306Driver {
     }
 }
 
-impl DrawableDisplay for Ssd1306Driver {
+impl<I: I2c + Send + Sync + 'static> DrawableDisplay for Ssd1306Driver<I> {
     type Color = BinaryColor;
 }
 
 // Provide direct DrawTarget access on the driver itself
-impl DrawTarget for Ssd1306Driver {
+impl<B> DrawTarget for Ssd1306Driver<B> {
     type Color = BinaryColor;
     type Error = core::convert::Infallible;
 
@@ -415,8 +457,78 @@ impl DrawTarget for Ssd1306Driver {
     }
 }
 
-impl OriginDimensions for Ssd1306Driver {
+impl<B> OriginDimensions for Ssd1306Driver<B> {
     fn size(&self) -> Size {
         Size::new(self.capabilities.width, self.capabilities.height)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_changed_pixels_are_resent() {
+        let shown = vec![BinaryColor::Off; 128 * 64];
+        let mut next = shown.clone();
+        next[20 * 128 + 5] = BinaryColor::On;
+        let dirty = dirty_pages(&shown, &next, 128);
+
+        let mut set = Vec::new();
+        set_changed_pixels(&next, &shown, &dirty, 128, |x, y, on| set.push((x, y, on)));
+        assert_eq!(set, [(5, 20, true)]);
+
+        // first flush sends everything
+        let mut count = 0;
+        set_changed_pixels(&next, &[], &dirty_pages(&[], &next, 128), 128, |_, _, _| count += 1);
+        assert_eq!(count, 128 * 64);
+    }
+
+    #[test]
+    fn init_resends_the_setup_and_restores_contrast_and_invert() {
+        use crate::display::drivers::i2c_bus::FakeBus;
+
+        let bus = FakeBus::default();
+        let config = DisplayConfig { brightness: Some(40), invert: Some(true), ..Ssd1306Driver::default_config() };
+        let mut driver = Ssd1306Driver::with_opener(&bus, "/dev/i2c-1", DEFAULT_I2C_ADDRESS, &config).unwrap();
+        bus.clear();
+        driver.init().unwrap();
+
+        // the crate's sequence resets contrast and inversion, ours go back on top
+        let commands = bus.commands();
+        let pump = commands.iter().position(|c| c[..] == [0x8D, 0x14]).expect("init sequence sent");
+        let contrast = commands.iter().rposition(|c| c[0] == 0x81).unwrap();
+        let invert = commands.iter().rposition(|c| c[..] == [0xA6] || c[..] == [0xA7]).unwrap();
+        assert!(contrast > pump && invert > pump);
+        assert_eq!(commands[contrast], [0x81, 0x00]);
+        assert_eq!(commands[invert], [0xA7]);
+        // and the panel is left on
+        let on = commands.iter().rposition(|c| c[..] == [0xAF]).unwrap();
+        assert!(commands.iter().rposition(|c| c[..] == [0xAE]).unwrap() < on);
+    }
+
+    #[test]
+    fn flush_after_init_resends_every_page() {
+        use crate::display::drivers::i2c_bus::FakeBus;
+
+        let bus = FakeBus::default();
+        let mut driver = Ssd1306Driver::with_opener(&bus, "/dev/i2c-1", DEFAULT_I2C_ADDRESS, &Ssd1306Driver::default_config()).unwrap();
+        // one lit pixel on every page
+        let mut frame = vec![0u8; 128 * 64 / 8];
+        for page in 0..8 {
+            frame[page * 16] = 1;
+        }
+        driver.write_buffer(&frame).unwrap();
+
+        driver.init().unwrap();
+        bus.clear();
+        driver.write_buffer(&frame).unwrap();
+        // the init wiped the panel, so the unchanged frame goes out in full
+        let lit: u32 = bus.writes.lock().unwrap().iter()
+            .filter(|w| w.first() == Some(&0x40))
+            .flat_map(|w| w[1..].iter().map(|b| b.count_ones()))
+            .sum();
+        assert_eq!(bus.data_len(), 128 * 64 / 8);
+        assert_eq!(lit, 8);
+    }
+}
@@ -101,6 +101,7 @@ impl Ssd1309Driver {
             invert: Some(false),
             rotate_deg: Some(0),
             emulated: Some(false),
+            ..Default::default()
         }
     }
 
@@ -121,6 +122,7 @@ impl Ssd1309Driver {
             invert: Some(false),
             rotate_deg: Some(0),
             emulated: Some(false),
+            ..Default::default()
         }
     }
 
@@ -92,6 +92,7 @@ impl Ssd1322Driver {
             invert: Some(false),
             rotate_deg: Some(0),
             emulated: Some(false),
+            ..Default::default()
         }
     }
 
@@ -240,3 +241,42 @@ impl OriginDimensions for Ssd1322Driver {
         Size::new(self.capabilities.width, self.capabilities.height)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::framebuffer::FrameBuffer;
+
+    #[test]
+    fn packed_frame_lands_on_the_same_pixels() {
+        let mut driver = Ssd1322Driver::new_spi("/dev/spidev0.0", 24, 25, &Ssd1322Driver::default_config()).unwrap();
+        let mut fb = FrameBuffer::new(driver.capabilities());
+        let pixels = fb.as_gray4_mut().as_mut_slice();
+        pixels[0] = Gray4::new(0xA);           // (0,0)
+        pixels[1] = Gray4::new(0x5);           // (1,0)
+        pixels[255] = Gray4::new(0xF);         // (255,0)
+        pixels[256 + 2] = Gray4::new(0x3);     // (2,1)
+        pixels[256 * 64 - 1] = Gray4::new(0x7); // (255,63)
+
+        let packed = fb.to_packed_bytes();
+        // 128 bytes a row, left pixel in the high nibble
+        assert_eq!(packed.len(), 256 * 64 / 2);
+        assert_eq!(packed[0], 0xA5);
+        assert_eq!(packed[127], 0x0F);
+        assert_eq!(packed[128 + 1], 0x30);
+        assert_eq!(packed[128 * 64 - 1], 0x07);
+        assert_eq!(packed.iter().filter(|&&b| b != 0).count(), 4);
+
+        driver.write_buffer(&packed).unwrap();
+        assert_eq!(driver.framebuffer.as_slice(), fb.as_gray4().unwrap().as_slice());
+    }
+
+    #[test]
+    fn short_buffer_is_rejected() {
+        let mut driver = Ssd1322Driver::new_spi("/dev/spidev0.0", 24, 25, &Ssd1322Driver::default_config()).unwrap();
+        assert!(matches!(
+            driver.write_buffer(&[0; 256 * 64 / 2 - 1]),
+            Err(DisplayError::BufferSizeMismatch { expected: 8192, actual: 8191 })
+        ));
+    }
+}
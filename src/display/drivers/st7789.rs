@@ -109,6 +109,7 @@ impl St7789Driver {
             invert: Some(false),
             rotate_deg: Some(0),
             emulated: Some(false),
+            ..Default::default()
         }
     }
 
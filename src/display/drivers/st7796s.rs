@@ -104,6 +104,7 @@ impl St7796sDriver {
             invert: Some(false),
             rotate_deg: Some(0),
             emulated: Some(false),
+            ..Default::default()
         }
     }
 
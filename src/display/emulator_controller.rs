@@ -17,6 +17,7 @@ use super::drivers::emulator::EmulatorDriver;
 use super::traits::DisplayDriver;
 use super::error::DisplayError;
 use super::DisplayMode;
+use super::field::{centred_x, mono_text_width};
 use crate::textable::{ScrollMode, TextScroller, transform_scroll_mode};
 use crate::eggs::{Eggs, set_easter_egg};
 use crate::clock_font_svg::{ClockFontData, set_clock_font};
@@ -155,7 +156,7 @@ impl EmulatorDisplayController {
         let sample_size_int: u32 = samplesize.parse().unwrap_or(0);
         let sample_rate_int: u32 = samplerate.parse().unwrap_or(0);
 
-        self.audio_bitrate = if sample_size_int == 1 && sample_rate_int > 0 {
+        self.audio_bitrate = if crate::display::components::status_bar::dsd_label(&samplesize, &samplerate).is_some() {
             AudioBitrate::DSD
         } else if sample_size_int >= 24 || sample_rate_int >= 96000 {
             AudioBitrate::HD
@@ -190,8 +191,9 @@ impl EmulatorDisplayController {
 
         let scroll_mode_enum = transform_scroll_mode(scroll_mode);
 
-        // Create scrollers for album, title, artist
-        // Y positions from constants.rs (assuming 128x64 display)
+        // Create scrollers for album, title, artist, each as wide as the panel
+        // Y positions from constants.rs (assuming 64px tall display)
+        let width = self.driver.size().width;
         let y_positions = [18, 27, 36, 45]; // album_artist, album, title, artist
 
         // Album scroller (Y=27)
@@ -199,7 +201,7 @@ impl EmulatorDisplayController {
             let scroller = TextScroller::new(
                 "album".to_string(),
                 Point::new(0, y_positions[1]),
-                128,
+                width,
                 album,
                 FONT_5X8,
                 scroll_mode_enum,
@@ -212,7 +214,7 @@ impl EmulatorDisplayController {
             let scroller = TextScroller::new(
                 "title".to_string(),
                 Point::new(0, y_positions[2]),
-                128,
+                width,
                 title,
                 FONT_5X8,
                 scroll_mode_enum,
@@ -225,7 +227,7 @@ impl EmulatorDisplayController {
             let scroller = TextScroller::new(
                 "artist".to_string(),
                 Point::new(0, y_positions[3]),
-                128,
+                width,
                 artist,
                 FONT_5X8,
                 scroll_mode_enum,
@@ -247,7 +249,7 @@ impl EmulatorDisplayController {
         self.track_duration_secs = duration;
         self.current_track_time_secs = elapsed;
         self.remaining_time_secs = remaining;
-        self.mode_text = mode;
+        self.mode_text = crate::translate::ModeLabels::default().text_for(&mode);
     }
 
     /// Set display mode
@@ -278,11 +280,11 @@ impl EmulatorDisplayController {
                 // TODO: Implement visualizer
                 self.render_scrolling().await?;
             },
-            DisplayMode::WeatherCurrent | DisplayMode::WeatherForecast => {
-                // TODO: Implement weather
+            DisplayMode::WeatherCurrent | DisplayMode::WeatherForecast | DisplayMode::Slideshow | DisplayMode::History => {
+                // TODO: Implement weather, slideshow and history
                 self.render_clock()?;
             },
-            DisplayMode::Scrolling => {
+            DisplayMode::Scrolling | DisplayMode::BigTitle => {
                 self.render_scrolling().await?;
             },
         }
@@ -337,7 +339,7 @@ impl EmulatorDisplayController {
 
         // Bitrate text (right side)
         if !self.bitrate_text.is_empty() {
-            let bitrate_x = 128 - (self.bitrate_text.len() as i32 * 5) - 10;
+            let bitrate_x = 128 - mono_text_width(&self.bitrate_text, &FONT_5X8) as i32 - 10;
             draw_text(&mut self.driver, &self.bitrate_text, bitrate_x, 0, &FONT_5X8)?;
         }
 
@@ -419,8 +421,7 @@ impl EmulatorDisplayController {
         draw_text(&mut self.driver, &current_time_str, 0, info_line_y, &FONT_5X8)?;
 
         // Mode text (center)
-        let mode_text_width = (self.mode_text.len() * 5) as i32;
-        let mode_x = (128 - mode_text_width) / 2;
+        let mode_x = centred_x(0, 128, &self.mode_text, &FONT_5X8);
         draw_text(&mut self.driver, &self.mode_text, mode_x, info_line_y, &FONT_5X8)?;
 
         // Remaining/total time (right)
@@ -429,7 +430,7 @@ impl EmulatorDisplayController {
         } else {
             format!(" {}", seconds_to_hms(self.track_duration_secs))
         };
-        let time_width = (time_str.len() * 5) as i32;
+        let time_width = mono_text_width(&time_str, &FONT_5X8) as i32;
         let time_x = 128 - time_width;
         draw_text(&mut self.driver, &time_str, time_x, info_line_y, &FONT_5X8)?;
 
@@ -34,11 +34,13 @@ use winit::{
 use winit_input_helper::WinitInputHelper;
 
 #[cfg(feature = "emulator")]
-use crate::display::drivers::emulator::{EmulatorState, EmulatorColor};
+use crate::display::drivers::emulator::EmulatorState;
 #[cfg(feature = "emulator")]
 use std::sync::{Arc, Mutex};
 #[cfg(feature = "emulator")]
-use std::time::Instant;
+use std::time::{Duration, Instant};
+#[cfg(feature = "emulator")]
+use crate::display::gif_record::{gif_capture_name, GIF_KEY_SECONDS};
 
 /// Emulator window configuration
 #[cfg(feature = "emulator")]
@@ -163,13 +165,22 @@ impl EmulatorWindow {
         println!("    ESC / Q   - Quit");
         println!("    W         - Lock to weather mode");
         println!("    C         - Lock to clock mode");
+        println!("    L         - Lock to slideshow mode");
+        println!("    Y         - Lock to recently played mode");
         println!("    A         - Return to automatic mode");
         println!("    E         - Cycle easter egg animations");
         println!("    V         - Cycle visualizations");
+        println!("    U         - Toggle weather units (C/F)");
+        println!("    T         - Toggle total / remaining track time");
+        println!("    K         - Show the now playing QR code");
+        println!("    Z         - Start / cancel the sleep timer");
+        println!("    SPACE     - Play / pause");
+        println!("    N / P     - Next / previous track");
         println!("    G         - Toggle pixel grid");
         println!("    F         - Toggle FPS counter");
         println!("    H         - Toggle help overlay");
-        println!("    S         - Save screenshot");
+        println!("    S         - Save screenshot (PNG in working dir)");
+        println!("    M         - Record {}s to an animated GIF (working dir)", GIF_KEY_SECONDS);
         println!("    B         - Cycle brightness");
         println!("    R         - Cycle rotation");
         println!("    I         - Toggle invert");
@@ -223,7 +234,26 @@ impl EmulatorWindow {
 
                 // Save screenshot
                 if input.key_pressed(VirtualKeyCode::S) {
-                    println!("Screenshot saved (TODO: implement)");
+                    let path = crate::display::framebuffer::frame_capture_name();
+                    match self.save_screenshot(&path) {
+                        Ok(()) => {
+                            println!("Screenshot saved to {}", path);
+                            log::info!("Emulator screenshot saved to {}", path);
+                        }
+                        Err(e) => println!("Screenshot failed: {}", e),
+                    }
+                }
+
+                // Record the next few seconds to a GIF
+                if input.key_pressed(VirtualKeyCode::M) {
+                    let mut state = self.state.lock().unwrap();
+                    if state.gif_recording.is_some() {
+                        println!("GIF recording already running");
+                    } else {
+                        let path = gif_capture_name();
+                        state.start_gif_recording(Duration::from_secs(GIF_KEY_SECONDS), &path);
+                        println!("Recording {}s to {}", GIF_KEY_SECONDS, path.display());
+                    }
                 }
 
                 // Cycle brightness
@@ -289,6 +319,22 @@ impl EmulatorWindow {
                     println!("Clock mode triggered (manual override active)");
                 }
 
+                // Trigger slideshow mode (manual override), falls back to the clock without images
+                if input.key_pressed(VirtualKeyCode::L) {
+                    let mut state = self.state.lock().unwrap();
+                    state.requested_mode = Some(crate::display::DisplayMode::Slideshow);
+                    state.manual_mode_override = true;
+                    println!("Slideshow mode triggered (manual override active)");
+                }
+
+                // Trigger recently played mode (manual override), falls back to the clock until a track plays
+                if input.key_pressed(VirtualKeyCode::Y) {
+                    let mut state = self.state.lock().unwrap();
+                    state.requested_mode = Some(crate::display::DisplayMode::History);
+                    state.manual_mode_override = true;
+                    println!("Recently played mode triggered (manual override active)");
+                }
+
                 // Return to automatic mode
                 if input.key_pressed(VirtualKeyCode::A) {
                     let mut state = self.state.lock().unwrap();
@@ -314,6 +360,41 @@ impl EmulatorWindow {
                     state.requested_mode = Some(crate::display::DisplayMode::Visualizer);
                     println!("Cycling to next visualization (manual mode locked)");
                 }
+
+                // Flip weather units, applied by the main loop
+                if input.key_pressed(VirtualKeyCode::U) {
+                    self.state.lock().unwrap().toggle_temp_units = true;
+                }
+
+                // Flip total/remaining track time, applied by the main loop
+                if input.key_pressed(VirtualKeyCode::T) {
+                    self.state.lock().unwrap().toggle_show_remaining = true;
+                }
+
+                // Now playing QR code, raised by the main loop
+                if input.key_pressed(VirtualKeyCode::K) {
+                    self.state.lock().unwrap().show_qr = true;
+                }
+
+                // Sleep timer on or off, applied by the main loop
+                if input.key_pressed(VirtualKeyCode::Z) {
+                    self.state.lock().unwrap().toggle_sleep_timer = true;
+                }
+
+                // Transport controls, sent to the monitored player by the main loop
+                let transport = if input.key_pressed(VirtualKeyCode::Space) {
+                    Some(crate::sliminfo::TransportCommand::PlayPause)
+                } else if input.key_pressed(VirtualKeyCode::N) {
+                    Some(crate::sliminfo::TransportCommand::Next)
+                } else if input.key_pressed(VirtualKeyCode::P) {
+                    Some(crate::sliminfo::TransportCommand::Prev)
+                } else {
+                    None
+                };
+                if let Some(cmd) = transport {
+                    self.state.lock().unwrap().transport_request = Some(cmd);
+                    println!("Transport: {:?}", cmd);
+                }
             }
 
             // Request redraw on every loop iteration (not just on input)
@@ -322,6 +403,18 @@ impl EmulatorWindow {
         });
     }
 
+    /// Write the panel contents (unscaled, no brightness or grid) as a PNG.
+    /// Mono pixels are saved black/white rather than the on-screen tint.
+    fn save_screenshot(&self, path: &str) -> Result<(), crate::display::DisplayError> {
+        let (width, height, rgba) = {
+            let state = self.state.lock().unwrap();
+            (state.width, state.height, state.panel_rgba())
+        };
+        let png = crate::display::framebuffer::encode_rgba_png(width, height, rgba)?;
+        std::fs::write(path, png)
+            .map_err(|e| crate::display::DisplayError::Other(format!("Failed to write {}: {}", path, e)))
+    }
+
     fn render(&self, frame: &mut [u8]) {
         let state = self.state.lock().unwrap();
 
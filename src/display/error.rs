@@ -38,6 +38,9 @@ pub enum DisplayError {
     /// SPI communication error
     SpiError(String),
 
+    /// Recoverable bus hiccup (EREMOTEIO, EAGAIN, ...); retrying the transfer may succeed
+    BusTransient(String),
+
     /// GPIO pin error
     GpioError(String),
 
@@ -72,6 +75,8 @@ impl fmt::Display for DisplayError {
                 write!(f, "I2C communication error: {}", msg),
             DisplayError::SpiError(msg) =>
                 write!(f, "SPI communication error: {}", msg),
+            DisplayError::BusTransient(msg) =>
+                write!(f, "Transient bus error: {}", msg),
             DisplayError::GpioError(msg) =>
                 write!(f, "GPIO error: {}", msg),
             DisplayError::InvalidConfiguration(msg) =>
@@ -92,6 +97,18 @@ impl fmt::Display for DisplayError {
     }
 }
 
+impl DisplayError {
+    /// True for bus hiccups worth retrying; everything else is treated as fatal
+    pub fn is_transient(&self) -> bool {
+        matches!(self, DisplayError::BusTransient(_))
+    }
+}
+
+/// Errno values the kernel I2C/SPI layers return for a one-off failed transfer
+pub fn is_transient_errno(errno: i32) -> bool {
+    matches!(errno, libc::EREMOTEIO | libc::EAGAIN | libc::ETIMEDOUT | libc::EBUSY)
+}
+
 impl Error for DisplayError {
     // display_interface::DisplayError doesn't implement std::error::Error
     // so we can't provide it as a source
@@ -114,7 +131,16 @@ impl From<display_interface::DisplayError> for DisplayError {
 // Conversion from Linux I2C errors
 impl From<linux_embedded_hal::I2CError> for DisplayError {
     fn from(err: linux_embedded_hal::I2CError) -> Self {
-        DisplayError::I2cError(format!("{:?}", err))
+        use linux_embedded_hal::i2cdev::linux::LinuxI2CError;
+        let errno = match err.inner() {
+            LinuxI2CError::Errno(errno) => Some(*errno),
+            LinuxI2CError::Io(e) => e.raw_os_error(),
+        };
+        if errno.is_some_and(is_transient_errno) {
+            DisplayError::BusTransient(format!("{:?}", err))
+        } else {
+            DisplayError::I2cError(format!("{:?}", err))
+        }
     }
 }
 
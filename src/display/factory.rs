@@ -25,7 +25,7 @@
 
 use crate::config::{DisplayConfig, DriverKind, BusConfig};
 use crate::display::error::DisplayFactoryError;
-use crate::display::traits::DisplayDriver;
+use crate::display::traits::{BusInterface, ColorDepth, DisplayCapabilities, DisplayDriver, I2cInfo, SpiInfo};
 use log::{info, debug};
 
 #[cfg(feature = "driver-ssd1306")]
@@ -55,6 +55,9 @@ use crate::display::plugin::{PluginLoader, PluginDriverAdapter};
 /// Type alias for boxed display driver trait objects
 pub type BoxedDriver = Box<dyn DisplayDriver>;
 
+/// Driver used when neither the config file nor `-d` names one
+pub const DEFAULT_DRIVER: DriverKind = DriverKind::Ssd1306;
+
 /// Factory for creating display drivers from configuration
 pub struct DisplayDriverFactory;
 
@@ -240,6 +243,62 @@ impl DisplayDriverFactory {
         }
     }
 
+    /// Drivers compiled into this binary
+    pub fn builtin_drivers() -> Vec<&'static str> {
+        let mut names = Vec::new();
+        if cfg!(feature = "driver-ssd1306") { names.push("ssd1306"); }
+        if cfg!(feature = "driver-ssd1309") { names.push("ssd1309"); }
+        if cfg!(feature = "driver-ssd1322") { names.push("ssd1322"); }
+        if cfg!(feature = "driver-sh1106") { names.push("sh1106"); }
+        if cfg!(feature = "driver-sh1122") { names.push("sh1122"); }
+        if cfg!(feature = "driver-st7789") { names.push("st7789"); }
+        if cfg!(feature = "driver-st7796s") { names.push("st7796s"); }
+        names
+    }
+
+    /// Plugin drivers found in the search paths, with their load status
+    #[cfg(feature = "plugin-system")]
+    pub fn list_available() -> Vec<crate::display::plugin::DiscoveredPlugin> {
+        PluginLoader::discover()
+    }
+
+    /// Human readable driver listing for `--list-drivers`
+    pub fn driver_report() -> String {
+        let mut out = format!("Built-in drivers: {}\n", Self::builtin_drivers().join(", "));
+
+        #[cfg(feature = "plugin-system")]
+        {
+            use crate::display::plugin::PluginStatus;
+
+            out.push_str("Plugin search paths:\n");
+            for path in PluginLoader::search_paths() {
+                let mark = if path.is_dir() { "" } else { " (missing)" };
+                out.push_str(&format!("  {}{}\n", path.display(), mark));
+            }
+
+            let plugins = Self::list_available();
+            if plugins.is_empty() {
+                out.push_str("No plugin drivers found\n");
+            }
+            for p in plugins {
+                let status = match p.status {
+                    PluginStatus::Loaded(m) => format!(
+                        "ok - {} v{} (ABI {}.{}.{})",
+                        m.name, m.version, m.abi_version.0, m.abi_version.1, m.abi_version.2
+                    ),
+                    PluginStatus::Failed(e) => format!("failed - {}", e),
+                    PluginStatus::Shadowed => "shadowed by an earlier path".to_string(),
+                };
+                out.push_str(&format!("  {:<12} {}  {}\n", p.driver_type, p.path.display(), status));
+            }
+        }
+
+        #[cfg(not(feature = "plugin-system"))]
+        out.push_str("Plugin system not compiled in\n");
+
+        out
+    }
+
     /// Try to load a plugin for the specified driver kind
     ///
     /// Returns Some(driver) if a plugin was successfully loaded,
@@ -249,17 +308,7 @@ impl DisplayDriverFactory {
         config: &DisplayConfig,
         driver_kind: &DriverKind
     ) -> Option<BoxedDriver> {
-        // Map DriverKind to plugin name
-        let plugin_name = match driver_kind {
-            DriverKind::Ssd1306 => "ssd1306",
-            DriverKind::Ssd1309 => "ssd1309",
-            DriverKind::Ssd1322 => "ssd1322",
-            DriverKind::Sh1106 => "sh1106",
-            DriverKind::Sh1122 => "sh1122",
-            DriverKind::SharpMemory => "sharpmemory",
-            DriverKind::St7789 => "st7789",
-            DriverKind::St7796s => "st7796s",
-        };
+        let plugin_name = driver_kind.name();
 
         debug!("Searching for plugin: {}", plugin_name);
 
@@ -378,6 +427,7 @@ impl DisplayDriverFactory {
                     invert: Some(false),
                     rotate_deg: Some(0),
                     emulated: Some(false),
+                    ..Default::default()
                 }
             }
             #[cfg(feature = "driver-st7789")]
@@ -395,6 +445,66 @@ impl DisplayDriverFactory {
         }
     }
 
+    /// Fill in what the config leaves out so a bare install still starts.
+    ///
+    /// No driver means the classic 128x64 SSD1306; no bus means the
+    /// driver's default wiring, with I2C on `i2c_bus` (`--i2c-bus`).
+    pub fn with_defaults(mut config: DisplayConfig, i2c_bus: &str) -> DisplayConfig {
+        let kind = config.driver.clone().unwrap_or(DEFAULT_DRIVER);
+        if config.bus.is_none() {
+            let defaults = Self::default_config_for(&kind);
+            config.bus = defaults.bus.map(|bus| match bus {
+                BusConfig::I2c { address, speed_hz, .. } =>
+                    BusConfig::I2c { bus: i2c_bus.to_string(), address, speed_hz },
+                other => other,
+            });
+            config.width = config.width.or(defaults.width);
+            config.height = config.height.or(defaults.height);
+        }
+        config.driver = Some(kind);
+        config
+    }
+
+    /// Capabilities a driver would report for this config, without opening the bus.
+    ///
+    /// Used by `--dump-layout` to resolve pages for hardware that is not attached;
+    /// the size comes from the config when given, else the driver's usual panel.
+    pub fn nominal_capabilities(config: &DisplayConfig) -> DisplayCapabilities {
+        let kind = config.driver.clone().unwrap_or(DEFAULT_DRIVER);
+        let (width, height, color_depth) = match kind {
+            DriverKind::Ssd1306 | DriverKind::Ssd1309 => (128, 64, ColorDepth::Monochrome),
+            DriverKind::Sh1106 => (132, 64, ColorDepth::Monochrome),
+            DriverKind::Ssd1322 | DriverKind::Sh1122 => (256, 64, ColorDepth::Gray4),
+            DriverKind::SharpMemory => (400, 240, ColorDepth::Monochrome),
+            DriverKind::St7789 => (320, 170, ColorDepth::Rgb565),
+            DriverKind::St7796s => (480, 320, ColorDepth::Rgb565),
+        };
+        let interface = match &config.bus {
+            Some(BusConfig::Spi { speed_hz, .. }) => BusInterface::Spi(SpiInfo {
+                max_speed_hz: speed_hz.unwrap_or(10_000_000),
+                dc_pin_desc:  "",
+                rst_pin_desc: "",
+                rst_required: false,
+            }),
+            _ => BusInterface::I2c(I2cInfo {
+                default_address: 0x3C,
+                alt_address:     None,
+                max_speed_hz:    400_000,
+            }),
+        };
+        DisplayCapabilities {
+            width: config.width.unwrap_or(width),
+            height: config.height.unwrap_or(height),
+            color_depth,
+            interface,
+            supports_rotation: false,
+            max_fps: 60,
+            supports_brightness: true,
+            supports_invert: false,
+            driver_name: kind.name().to_string(),
+        }
+    }
+
     /// Validate a configuration without creating a driver
     ///
     /// This is useful for checking configuration at startup before attempting
@@ -424,6 +534,15 @@ impl DisplayDriverFactory {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_driver_report_lists_builtins() {
+        let report = DisplayDriverFactory::driver_report();
+        assert!(report.starts_with("Built-in drivers:"));
+        for name in DisplayDriverFactory::builtin_drivers() {
+            assert!(report.contains(name));
+        }
+    }
+
     #[test]
     fn test_validate_config_no_driver() {
         let config = DisplayConfig {
@@ -439,6 +558,31 @@ mod tests {
         assert!(DisplayDriverFactory::validate_config(&config).is_err());
     }
 
+    #[test]
+    fn missing_driver_and_bus_default_to_ssd1306() {
+        let config = DisplayDriverFactory::with_defaults(DisplayConfig::default(), "/dev/i2c-3");
+        assert!(matches!(config.driver, Some(DriverKind::Ssd1306)));
+        assert!(matches!(config.bus, Some(BusConfig::I2c { ref bus, .. }) if bus == "/dev/i2c-3"));
+        assert!(DisplayDriverFactory::validate_config(&config).is_ok());
+
+        // an explicit driver and bus are left alone
+        let spi = BusConfig::Spi {
+            bus: "/dev/spidev0.0".to_string(),
+            speed_hz: None,
+            dc_pin: 25,
+            rst_pin: None,
+            cs_pin: None,
+        };
+        let config = DisplayDriverFactory::with_defaults(DisplayConfig {
+            driver: Some(DriverKind::Ssd1322),
+            bus: Some(spi),
+            ..Default::default()
+        }, "/dev/i2c-1");
+        assert!(matches!(config.driver, Some(DriverKind::Ssd1322)));
+        assert!(matches!(config.bus, Some(BusConfig::Spi { dc_pin: 25, .. })));
+        assert_eq!(config.width, None);
+    }
+
     #[test]
     fn test_validate_config_no_bus() {
         let config = DisplayConfig {
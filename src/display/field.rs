@@ -28,7 +28,12 @@ use embedded_graphics::pixelcolor::BinaryColor;
 use embedded_graphics::primitives::Rectangle;
 use embedded_graphics::mono_font::MonoFont;
 use embedded_text::{alignment::{HorizontalAlignment, VerticalAlignment}};
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::time::Duration;
+use chrono::{DateTime, Local};
 use super::color::Color;
+use super::progress::ProgressStyle;
 
 /// Field type determines rendering behavior
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -41,6 +46,48 @@ pub enum FieldType {
     Custom,
 }
 
+/// What a text field does with content wider than its bounds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Overflow {
+    /// Cut off at the field edge
+    #[default]
+    Clip,
+    /// Trim to fit and end with `ELLIPSIS`
+    Ellipsis,
+    /// Scroll horizontally, same as `scrollable`
+    Scroll,
+}
+
+/// Truncation marker. The ISO-8859 mono fonts have no U+2026 glyph, so
+/// three full stops stand in for it.
+pub const ELLIPSIS: &str = "...";
+
+/// Width in pixels of `text` in a mono `font` - one glyph per character,
+/// not per UTF-8 byte, so accented titles measure what they draw.
+pub fn mono_text_width(text: &str, font: &MonoFont) -> u32 {
+    let advance = font.character_size.width + font.character_spacing;
+    (text.chars().count() as u32 * advance).saturating_sub(font.character_spacing)
+}
+
+/// Left edge that centres `text` across `width` pixels starting at `left`
+pub fn centred_x(left: i32, width: u32, text: &str, font: &MonoFont) -> i32 {
+    left + (width as i32 - mono_text_width(text, font) as i32) / 2
+}
+
+/// Trim `text` to fit `max_width` pixels in a mono `font`, ending with
+/// `ELLIPSIS`. Text that already fits is returned untouched.
+pub fn ellipsize<'a>(text: &'a str, max_width: u32, font: &MonoFont) -> Cow<'a, str> {
+    let advance = font.character_size.width + font.character_spacing;
+    let fits = ((max_width + font.character_spacing) / advance.max(1)) as usize;
+    if text.chars().count() <= fits {
+        return Cow::Borrowed(text);
+    }
+    let keep = fits.saturating_sub(ELLIPSIS.len());
+    let head: String = text.chars().take(keep).collect();
+    Cow::Owned(format!("{}{}", head.trim_end(), ELLIPSIS))
+}
+
 /// Field definition - declarative UI element positioning
 ///
 /// A field defines a rectangular region on the display with
@@ -71,9 +118,21 @@ pub struct Field {
     /// Background color (None for transparent, adapts to display color depth)
     pub bg_color: Option<Color>,
 
+    /// Progress bar fill color (None fills with fg_color, which stays the outline)
+    pub fill_color: Option<Color>,
+
     /// Text alignment/justification within field
     pub horizontal_alignment: HorizontalAlignment,
     pub vertical_alignment: VerticalAlignment,
+
+    /// Handling of text wider than the field
+    pub overflow: Overflow,
+
+    /// Fill style when the field is a progress bar
+    pub progress_style: ProgressStyle,
+
+    /// Blink period - the field is drawn for one period then skipped for the next
+    pub blink: Option<Duration>,
 }
 
 impl Field {
@@ -92,8 +151,12 @@ impl Field {
             font: Some(font),
             fg_color: Color::White,
             bg_color: None,
+            fill_color: None,
             horizontal_alignment: HorizontalAlignment::Left,
             vertical_alignment: VerticalAlignment::Top,
+            overflow: Overflow::Clip,
+            progress_style: ProgressStyle::Filled,
+            blink: None,
         }
     }
 
@@ -111,8 +174,12 @@ impl Field {
             font: None,
             fg_color: Color::White,
             bg_color: None,
+            fill_color: None,
             horizontal_alignment: HorizontalAlignment::Justified,
             vertical_alignment: VerticalAlignment::Top,
+            overflow: Overflow::Clip,
+            progress_style: ProgressStyle::Filled,
+            blink: None,
         }
     }
 
@@ -130,8 +197,12 @@ impl Field {
             font: None,
             fg_color: Color::White,
             bg_color: None,
+            fill_color: None,
             horizontal_alignment: HorizontalAlignment::Justified,
             vertical_alignment: VerticalAlignment::Top,
+            overflow: Overflow::Clip,
+            progress_style: ProgressStyle::Filled,
+            blink: None,
         }
     }
 
@@ -141,6 +212,36 @@ impl Field {
         self
     }
 
+    /// Builder: set overflow handling
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Builder: set progress bar style
+    pub fn progress_style(mut self, style: ProgressStyle) -> Self {
+        self.progress_style = style;
+        self
+    }
+
+    /// Builder: blink the field with the given on/off period
+    pub fn blink(mut self, period: Duration) -> Self {
+        self.blink = Some(period).filter(|p| !p.is_zero());
+        self
+    }
+
+    /// Whether the field is in its drawn phase at `now`. Phases are counted
+    /// from the epoch so every blinking field with the same period flips together.
+    pub fn shown_at(&self, now: &DateTime<Local>) -> bool {
+        match self.blink {
+            Some(period) => {
+                let period_ms = (period.as_millis() as i64).max(1);
+                now.timestamp_millis().div_euclid(period_ms) % 2 == 0
+            }
+            None => true,
+        }
+    }
+
     /// Builder: set border
     pub fn border(mut self, border: u8) -> Self {
         self.border = border;
@@ -161,6 +262,17 @@ impl Field {
         self
     }
 
+    /// Builder: set the progress bar fill color, the outline keeps fg
+    pub fn fill(mut self, fill: Color) -> Self {
+        self.fill_color = Some(fill);
+        self
+    }
+
+    /// Progress bar fill color - fg unless an accent was set
+    pub fn progress_fill(&self) -> Color {
+        self.fill_color.unwrap_or(self.fg_color)
+    }
+
     /// Get foreground color as BinaryColor for monochrome displays
     pub fn fg_binary(&self) -> BinaryColor {
         self.fg_color.to_binary()
@@ -186,3 +298,32 @@ impl Field {
         self.bounds.top_left
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mono_font::iso_8859_13::{FONT_4X6, FONT_5X8};
+
+    #[test]
+    fn ellipsis_trims_to_field_width() {
+        // 40px of 5x8 holds 8 characters
+        assert_eq!(ellipsize("Thunderstorm", 40, &FONT_5X8), "Thund...");
+        assert_eq!(ellipsize("Thunder", 40, &FONT_5X8), "Thunder");
+        // trailing space is dropped before the marker
+        assert_eq!(ellipsize("Light rain showers", 36, &FONT_4X6), "Light...");
+        assert_eq!(ellipsize("Anything", 8, &FONT_5X8), "...");
+    }
+
+    #[test]
+    fn accented_text_centres_by_characters_not_bytes() {
+        let title = "Café Società";
+        assert_eq!((title.chars().count(), title.len()), (12, 14));
+        assert_eq!(mono_text_width(title, &FONT_5X8), 60);
+        // 128px line: (128 - 60) / 2, where the byte count put it at (128 - 70) / 2
+        assert_eq!(centred_x(0, 128, title, &FONT_5X8), 34);
+        assert_ne!(centred_x(0, 128, title, &FONT_5X8), (128 - title.len() as i32 * 5) / 2);
+        // spacing only sits between glyphs
+        assert_eq!(mono_text_width("ab", &FONT_4X6), 8);
+        assert_eq!(mono_text_width("", &FONT_5X8), 0);
+    }
+}
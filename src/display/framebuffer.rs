@@ -25,8 +25,10 @@
 
 use embedded_graphics::prelude::*;
 use embedded_graphics::pixelcolor::{BinaryColor, Gray4, Rgb565, RgbColor};
+use std::borrow::Cow;
 use crate::vframebuf::VarFrameBuf;
 use crate::display::traits::{DisplayCapabilities, ColorDepth};
+use crate::display::error::DisplayError;
 
 /// Enum dispatch for zero-cost color abstraction
 ///
@@ -131,9 +133,18 @@ impl FrameBuffer {
     /// - Monochrome: 8 pixels per byte (LSB first)
     /// - Gray4: 2 pixels per byte (high nibble first)
     pub fn to_packed_bytes(&self) -> Vec<u8> {
+        self.to_packed_bytes_rotated(0)
+    }
+
+    /// Pack the framebuffer in panel scan order for a clockwise rotation
+    ///
+    /// The framebuffer holds the logical (rotated) image; for 90/270 it is
+    /// `height` wide and `width` tall relative to the panel. Any angle other
+    /// than 90/180/270 packs unrotated.
+    pub fn to_packed_bytes_rotated(&self, degrees: u16) -> Vec<u8> {
         match self {
             FrameBuffer::Mono(fb) => {
-                let pixels = fb.as_slice();
+                let pixels = panel_order(fb, degrees);
                 let num_bytes = (pixels.len() + 7) / 8;  // Round up
                 let mut bytes = vec![0u8; num_bytes];
 
@@ -148,7 +159,7 @@ impl FrameBuffer {
                 bytes
             }
             FrameBuffer::Gray4(fb) => {
-                let pixels = fb.as_slice();
+                let pixels = panel_order(fb, degrees);
                 let num_bytes = (pixels.len() + 1) / 2;  // Round up
                 let mut bytes = vec![0u8; num_bytes];
 
@@ -167,7 +178,7 @@ impl FrameBuffer {
                 bytes
             }
             FrameBuffer::Rgb565(fb) => {
-                let pixels = fb.as_slice();
+                let pixels = panel_order(fb, degrees);
                 let mut bytes = vec![0u8; pixels.len() * 2];
                 for (i, &pixel) in pixels.iter().enumerate() {
                     // Pack as big-endian Rgb565
@@ -244,7 +255,190 @@ impl FrameBuffer {
         }
     }
 
+    /// Expand to 8-bit RGBA, row-major - mono is black/white, Gray4 a grey ramp
+    pub fn to_rgba(&self) -> Vec<u8> {
+        match self {
+            FrameBuffer::Mono(fb) => fb.as_slice().iter()
+                .flat_map(|&p| if p.is_on() { [255, 255, 255, 255] } else { [0, 0, 0, 255] })
+                .collect(),
+            FrameBuffer::Gray4(fb) => fb.as_slice().iter()
+                .flat_map(|p| { let v = p.luma() * 17; [v, v, v, 255] })
+                .collect(),
+            FrameBuffer::Rgb565(fb) => fb.as_slice().iter()
+                .flat_map(|p| {
+                    let (r, g, b) = (p.r(), p.g(), p.b());
+                    [(r << 3) | (r >> 2), (g << 2) | (g >> 4), (b << 3) | (b >> 2), 255]
+                })
+                .collect(),
+        }
+    }
+
+    /// Encode the current (logical, unrotated) frame as a PNG
+    pub fn encode_png(&self) -> Result<Vec<u8>, DisplayError> {
+        let (width, height) = self.dimensions();
+        encode_rgba_png(width, height, self.to_rgba())
+    }
+
     // Note: We can't provide a generic draw() method that takes a closure with DrawTarget
     // because DrawTarget is not dyn compatible (it has generic methods).
     // Instead, users should match on the FrameBuffer enum and call the appropriate method.
 }
+
+/// Encode an opaque 8-bit RGBA image as PNG.
+pub fn encode_rgba_png(width: u32, height: u32, rgba: Vec<u8>) -> Result<Vec<u8>, DisplayError> {
+    let size = tiny_skia::IntSize::from_wh(width, height)
+        .ok_or_else(|| DisplayError::DrawingError(format!("invalid frame size {}x{}", width, height)))?;
+    // every pixel is opaque, so straight RGBA is already premultiplied
+    let pixmap = tiny_skia::Pixmap::from_vec(rgba, size)
+        .ok_or_else(|| DisplayError::DrawingError("frame data does not match its size".to_string()))?;
+    pixmap.encode_png()
+        .map_err(|e| DisplayError::DrawingError(format!("PNG encode failed: {}", e)))
+}
+
+/// Timestamped file name for a frame capture, e.g. `lymons-20260115-214502.png`.
+pub fn frame_capture_name() -> String {
+    format!("lymons-{}.png", chrono::Local::now().format("%Y%m%d-%H%M%S"))
+}
+
+/// Height of a controller memory page - SSD1306-style panels address 8 rows at a time.
+pub const PAGE_ROWS: usize = 8;
+
+/// Which pages of a `width` wide frame differ between `shown` (what the panel
+/// last received) and `next`. A `shown` of another size, such as an empty one
+/// before the first transfer, marks every page dirty.
+pub fn dirty_pages<C: PartialEq>(shown: &[C], next: &[C], width: usize) -> Vec<bool> {
+    let page_len = width.max(1) * PAGE_ROWS;
+    let pages = next.len().div_ceil(page_len);
+    if shown.len() != next.len() {
+        return vec![true; pages];
+    }
+    shown.chunks(page_len).zip(next.chunks(page_len)).map(|(a, b)| a != b).collect()
+}
+
+/// Pixels of `fb` in physical panel scan order for a clockwise rotation.
+///
+/// Row-major over the panel: for 90/270 the panel is `fb.height()` wide and
+/// `fb.width()` tall. Unrotated buffers are borrowed as-is.
+fn panel_order<C: PixelColor>(fb: &VarFrameBuf<C>, degrees: u16) -> Cow<'_, [C]> {
+    let (w, h) = (fb.width(), fb.height());
+    let px = fb.as_slice();
+    match degrees {
+        90 => Cow::Owned(
+            (0..w).flat_map(|y| (0..h).map(move |x| px[(h - 1 - x) * w + y])).collect(),
+        ),
+        180 => Cow::Owned(px.iter().rev().copied().collect()),
+        270 => Cow::Owned(
+            (0..w).flat_map(|y| (0..h).map(move |x| px[x * w + (w - 1 - y)])).collect(),
+        ),
+        _ => Cow::Borrowed(px),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 3x2 logical mono buffer with only the top-left pixel lit.
+    fn corner_lit() -> FrameBuffer {
+        let mut fb = VarFrameBuf::new(3, 2, BinaryColor::Off);
+        fb.as_mut_slice()[0] = BinaryColor::On;
+        FrameBuffer::Mono(fb)
+    }
+
+    fn lit_index(bytes: &[u8]) -> usize {
+        let bits: Vec<usize> = (0..bytes.len() * 8)
+            .filter(|i| bytes[i / 8] & (1 << (i % 8)) != 0)
+            .collect();
+        assert_eq!(bits.len(), 1);
+        bits[0]
+    }
+
+    #[test]
+    fn unrotated_matches_plain_packing() {
+        let fb = corner_lit();
+        assert_eq!(fb.to_packed_bytes_rotated(0), fb.to_packed_bytes());
+        assert_eq!(lit_index(&fb.to_packed_bytes()), 0);
+    }
+
+    #[test]
+    fn rotation_moves_top_left_to_expected_panel_corner() {
+        let fb = corner_lit();
+        // 90: panel is 2 wide, 3 tall - top-left lands top-right
+        assert_eq!(lit_index(&fb.to_packed_bytes_rotated(90)), 1);
+        // 180: bottom-right of the unchanged 3x2 panel
+        assert_eq!(lit_index(&fb.to_packed_bytes_rotated(180)), 5);
+        // 270: bottom-left of the 2x3 panel
+        assert_eq!(lit_index(&fb.to_packed_bytes_rotated(270)), 4);
+    }
+
+    #[test]
+    fn quarter_turn_swaps_framebuffer_dimensions() {
+        use crate::display::drivers::mock::MockDriver;
+        use crate::display::traits::DisplayDriver;
+
+        let caps = MockDriver::new_with_size(128, 64).unwrap().capabilities().clone();
+        assert_eq!(FrameBuffer::new(&caps.rotated(90)).dimensions(), (64, 128));
+        assert_eq!(FrameBuffer::new(&caps.rotated(180)).dimensions(), (128, 64));
+        assert_eq!(FrameBuffer::new(&caps.rotated(270)).dimensions(), (64, 128));
+    }
+
+    #[test]
+    fn gray4_rotation_keeps_byte_count() {
+        let mut fb = VarFrameBuf::new(4, 2, Gray4::new(0));
+        fb.as_mut_slice()[3] = Gray4::new(15);
+        let fb = FrameBuffer::Gray4(fb);
+        let packed = fb.to_packed_bytes_rotated(90);
+        assert_eq!(packed.len(), 4);
+        // logical (3,0) -> panel (1,3) in a 2 wide panel: last pixel, low nibble
+        assert_eq!(packed[3], 0x0F);
+    }
+
+    #[test]
+    fn gray4_packs_high_nibble_first_row_by_row() {
+        // 4x2 ramp: row 0 is 1..4, row 1 is 5..8
+        let mut fb = VarFrameBuf::new(4, 2, Gray4::new(0));
+        for (i, px) in fb.as_mut_slice().iter_mut().enumerate() {
+            *px = Gray4::new(i as u8 + 1);
+        }
+        let fb = FrameBuffer::Gray4(fb);
+        // even x in the high nibble, each row width / 2 bytes
+        assert_eq!(fb.to_packed_bytes(), [0x12, 0x34, 0x56, 0x78]);
+        assert_eq!(fb.as_bytes(), fb.to_packed_bytes());
+    }
+
+    #[test]
+    fn small_change_dirties_only_its_pages() {
+        let shown = vec![BinaryColor::Off; 128 * 64];
+        let mut next = shown.clone();
+        assert_eq!(dirty_pages(&shown, &next, 128), vec![false; 8]);
+
+        // a 2px colon dot on rows 15-16 straddles pages 1 and 2
+        for y in 15..17 {
+            next[y * 128 + 60] = BinaryColor::On;
+        }
+        let dirty = dirty_pages(&shown, &next, 128);
+        assert_eq!(dirty.iter().filter(|&&d| d).count(), 2);
+        assert!(dirty[1] && dirty[2]);
+
+        // nothing sent yet - the whole panel goes
+        assert_eq!(dirty_pages(&[], &next, 128), vec![true; 8]);
+    }
+
+    #[test]
+    fn png_export_round_trips() {
+        use crate::display::drivers::mock::MockDriver;
+        use crate::display::traits::DisplayDriver;
+
+        let mut fb = FrameBuffer::new(&MockDriver::new_with_size(4, 2).unwrap().capabilities().clone());
+        fb.as_mono_mut().as_mut_slice()[1] = BinaryColor::On;
+
+        let png = fb.encode_png().unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+
+        let img = tiny_skia::Pixmap::decode_png(&png).unwrap();
+        assert_eq!((img.width(), img.height()), (4, 2));
+        assert_eq!(img.pixel(0, 0).unwrap().red(), 0);
+        assert_eq!(img.pixel(1, 0).unwrap().red(), 255);
+    }
+
+}
@@ -0,0 +1,230 @@
+/*
+ *  display/gif_record.rs
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  Record panel frames to an animated GIF - README captures of the
+ *  easter eggs, scrollers and meters
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *  Public License.
+ *
+ */
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::display::DisplayError;
+
+/// Panel pixels become this many GIF pixels a side
+pub const GIF_SCALE: u32 = 3;
+
+/// Length of a recording started from the emulator keyboard
+pub const GIF_KEY_SECONDS: u64 = 5;
+
+/// GIF delays are in 1/100s; viewers stretch anything shorter than 2
+const MIN_DELAY_CS: u16 = 2;
+
+/// Timestamped file name for a GIF capture, e.g. `lymons-20260115-214502.gif`.
+pub fn gif_capture_name() -> PathBuf {
+    PathBuf::from(format!("lymons-{}.gif", chrono::Local::now().format("%Y%m%d-%H%M%S")))
+}
+
+#[derive(Debug)]
+struct GifFrame {
+    rgba: Vec<u8>,
+    shown: Instant,
+}
+
+/// Collects RGBA panel frames for a fixed length of time, then encodes them.
+/// Frames that repeat the previous one just extend its delay.
+#[derive(Debug)]
+pub struct GifRecorder {
+    width: u32,
+    height: u32,
+    scale: u32,
+    path: PathBuf,
+    started: Instant,
+    until: Instant,
+    frames: Vec<GifFrame>,
+}
+
+impl GifRecorder {
+    pub fn new(width: u32, height: u32, length: Duration, path: impl Into<PathBuf>, now: Instant) -> Self {
+        Self {
+            width,
+            height,
+            scale: GIF_SCALE,
+            path: path.into(),
+            started: now,
+            until: now + length,
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// True once the recording length has passed
+    pub fn is_done(&self, now: Instant) -> bool {
+        now >= self.until
+    }
+
+    /// Distinct frames so far
+    pub fn frame_count(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Add the panel as shown at `now`, `width * height` RGBA pixels
+    pub fn push_rgba(&mut self, rgba: &[u8], now: Instant) {
+        if self.is_done(now) || rgba.len() != (self.width * self.height * 4) as usize {
+            return;
+        }
+        if self.frames.last().is_some_and(|f| f.rgba == rgba) {
+            return;
+        }
+        self.frames.push(GifFrame { rgba: rgba.to_vec(), shown: now });
+    }
+
+    /// Encode the frames as a looping GIF. One-bit and Gray4 panels map
+    /// exactly onto a small palette; colour panels are quantised.
+    pub fn encode(&self) -> Result<Vec<u8>, DisplayError> {
+        if self.frames.is_empty() {
+            return Err(DisplayError::Other("no frames recorded".to_string()));
+        }
+        let (w, h) = (self.width * self.scale, self.height * self.scale);
+        let (gw, gh) = (u16::try_from(w), u16::try_from(h));
+        let (Ok(gw), Ok(gh)) = (gw, gh) else {
+            return Err(DisplayError::Other(format!("{}x{} is too large for a GIF", w, h)));
+        };
+        let gif_err = |e: gif::EncodingError| DisplayError::Other(format!("GIF encode failed: {}", e));
+
+        let mut out = Vec::new();
+        {
+            let mut encoder = gif::Encoder::new(&mut out, gw, gh, &[]).map_err(gif_err)?;
+            encoder.set_repeat(gif::Repeat::Infinite).map_err(gif_err)?;
+            for (i, frame) in self.frames.iter().enumerate() {
+                let next = self.frames.get(i + 1).map_or(self.until, |f| f.shown);
+                let mut rgba = scale_rgba(&frame.rgba, self.width, self.height, self.scale);
+                let mut gif_frame = match palette_of(&rgba) {
+                    Some((palette, indices)) => {
+                        let pixels: Vec<u8> = rgba.chunks_exact(4)
+                            .map(|px| indices[&[px[0], px[1], px[2]]])
+                            .collect();
+                        gif::Frame::from_palette_pixels(gw, gh, pixels, palette, None)
+                    }
+                    None => gif::Frame::from_rgba_speed(gw, gh, &mut rgba, 10),
+                };
+                let cs = (next.saturating_duration_since(frame.shown).as_millis() / 10) as u16;
+                gif_frame.delay = cs.max(MIN_DELAY_CS);
+                encoder.write_frame(&gif_frame).map_err(gif_err)?;
+            }
+        }
+        Ok(out)
+    }
+
+    /// Encode and write to the recording's path
+    pub fn save(&self) -> Result<(), DisplayError> {
+        let gif = self.encode()?;
+        std::fs::write(&self.path, gif)
+            .map_err(|e| DisplayError::Other(format!("Failed to write {}: {}", self.path.display(), e)))?;
+        log::info!("Recorded {} frames over {:?} to {}",
+            self.frames.len(), self.until - self.started, self.path.display());
+        Ok(())
+    }
+}
+
+/// Nearest-neighbour upscale so single pixels stay crisp
+fn scale_rgba(rgba: &[u8], width: u32, height: u32, scale: u32) -> Vec<u8> {
+    let (w, scale) = (width as usize, scale as usize);
+    let mut out = Vec::with_capacity(rgba.len() * scale * scale);
+    for row in rgba.chunks_exact(w * 4).take(height as usize) {
+        let wide: Vec<u8> = row.chunks_exact(4)
+            .flat_map(|px| std::iter::repeat_n(px, scale).flatten().copied())
+            .collect();
+        for _ in 0..scale {
+            out.extend_from_slice(&wide);
+        }
+    }
+    out
+}
+
+/// RGB triples for the GIF, and each colour's index into them
+type Palette = (Vec<u8>, HashMap<[u8; 3], u8>);
+
+/// Exact palette, when the frame has 256 colours or fewer
+fn palette_of(rgba: &[u8]) -> Option<Palette> {
+    let mut indices = HashMap::new();
+    let mut palette = Vec::new();
+    for px in rgba.chunks_exact(4) {
+        let rgb = [px[0], px[1], px[2]];
+        if !indices.contains_key(&rgb) {
+            let index = u8::try_from(indices.len()).ok()?;
+            indices.insert(rgb, index);
+            palette.extend_from_slice(&rgb);
+        }
+    }
+    Some((palette, indices))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(width: u32, height: u32, lit: usize) -> Vec<u8> {
+        let mut rgba = [0, 0, 0, 255].repeat((width * height) as usize);
+        rgba[lit * 4..lit * 4 + 3].copy_from_slice(&[255, 255, 255]);
+        rgba
+    }
+
+    #[test]
+    fn short_recording_is_a_multi_frame_gif() {
+        let start = Instant::now();
+        let mut rec = GifRecorder::new(8, 4, Duration::from_millis(400), "unused.gif", start);
+        for i in 0..4u64 {
+            let at = start + Duration::from_millis(100 * i);
+            rec.push_rgba(&frame(8, 4, i as usize), at);
+            rec.push_rgba(&frame(8, 4, i as usize), at + Duration::from_millis(50)); // repeat, dropped
+        }
+        rec.push_rgba(&frame(8, 4, 9), start + Duration::from_millis(400)); // after the end
+        assert_eq!(rec.frame_count(), 4);
+        assert!(rec.is_done(start + Duration::from_millis(400)));
+
+        let gif = rec.encode().unwrap();
+        assert_eq!(&gif[..6], b"GIF89a");
+
+        let mut decoder = gif::DecodeOptions::new();
+        decoder.set_color_output(gif::ColorOutput::RGBA);
+        let mut decoder = decoder.read_info(gif.as_slice()).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (8 * GIF_SCALE as u16, 4 * GIF_SCALE as u16));
+        let mut delays = Vec::new();
+        while let Some(f) = decoder.read_next_frame().unwrap() {
+            delays.push(f.delay);
+            if delays.len() == 2 {
+                // second frame lights panel pixel 1 - a GIF_SCALE square at x 3..6
+                let px = |x: usize, y: usize| f.buffer[(y * 8 * GIF_SCALE as usize + x) * 4];
+                assert_eq!((px(3, 0), px(5, 2), px(6, 0), px(0, 0)), (255, 255, 0, 0));
+            }
+        }
+        assert_eq!(delays, [10, 10, 10, 10]);
+    }
+
+    #[test]
+    fn empty_recording_is_an_error() {
+        let rec = GifRecorder::new(8, 4, Duration::from_secs(1), "unused.gif", Instant::now());
+        assert!(rec.encode().is_err());
+    }
+}
@@ -23,6 +23,7 @@
 
 #![allow(dead_code)] // adaptive layout config; written for multi-resolution support; may be extended
 
+use crate::assets;
 use crate::display::traits::{DisplayCapabilities, ColorDepth, BusInterface, I2cInfo};
 
 /// Layout configuration for different display resolutions
@@ -44,6 +45,9 @@ pub struct LayoutConfig {
     /// Layout category (determines which preset to use)
     pub category: LayoutCategory,
 
+    /// Panel shape; square and portrait panels stack page fields vertically
+    pub aspect: LayoutAspect,
+
     /// Status bar configuration
     pub status_bar: StatusBarLayout,
 
@@ -83,6 +87,37 @@ pub enum LayoutCategory {
     ExtraLarge,
 }
 
+/// Panel shape, independent of the size category
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutAspect {
+    /// Wider than tall (128x64, 256x64, 320x170...)
+    Landscape,
+
+    /// Sides within 1/8 of each other (128x128, 240x240)
+    Square,
+
+    /// Taller than wide (64x128, a rotated 128x64)
+    Portrait,
+}
+
+impl LayoutAspect {
+    pub fn from_size(width: u32, height: u32) -> Self {
+        let long = width.max(height);
+        if width.abs_diff(height) * 8 <= long {
+            LayoutAspect::Square
+        } else if width > height {
+            LayoutAspect::Landscape
+        } else {
+            LayoutAspect::Portrait
+        }
+    }
+
+    /// Square or portrait - pages stack their fields vertically
+    pub fn is_tall(self) -> bool {
+        !matches!(self, LayoutAspect::Landscape)
+    }
+}
+
 /// Status bar layout configuration
 #[derive(Debug, Clone)]
 pub struct StatusBarLayout {
@@ -245,6 +280,12 @@ impl LayoutConfig {
             LayoutCategory::ExtraLarge => Self::extra_large_layout(width, height, color_depth),
         };
 
+        layout.aspect = LayoutAspect::from_size(width, height);
+        if layout.aspect.is_tall() {
+            // the presets assume a landscape panel; keep the visualizer on-screen
+            layout.visualizer.width = layout.visualizer.width.min(width);
+        }
+
         // Driver name is the authoritative source for asset path — override the
         // category/color_depth guess whenever a concrete driver name is available.
         if !capabilities.driver_name.is_empty() {
@@ -254,15 +295,20 @@ impl LayoutConfig {
         layout
     }
 
+    /// Width between the content area's left and right margins
+    pub fn content_width(&self) -> u32 {
+        self.width.saturating_sub(self.content_area.margin_left + self.content_area.margin_right)
+    }
+
     /// Map a canonical driver name to its asset folder.
     fn driver_asset_path(driver_name: &str) -> String {
         match driver_name {
-            "ssd1306" | "ssd1309" | "sh1106" => "./assets/ssd1309/".to_string(),
-            "ssd1322" | "sh1122"              => "./assets/ssd1322/".to_string(),
-            "st7789"                          => "./assets/st7789/".to_string(),
-            "st7796s"                         => "./assets/st7796s/".to_string(),
-            "sharpmemory"                     => "./assets/sharp400/".to_string(),
-            _                                 => "./assets/ssd1309/".to_string(),
+            "ssd1306" | "ssd1309" | "sh1106" => assets::path("ssd1309/"),
+            "ssd1322" | "sh1122"              => assets::path("ssd1322/"),
+            "st7789"                          => assets::path("st7789/"),
+            "st7796s"                         => assets::path("st7796s/"),
+            "sharpmemory"                     => assets::path("sharp400/"),
+            _                                 => assets::path("ssd1309/"),
         }
     }
 
@@ -286,6 +332,7 @@ impl LayoutConfig {
             height,
             color_depth,
             category: LayoutCategory::Small,
+            aspect: LayoutAspect::Landscape,
             status_bar: StatusBarLayout {
                 height: 8,
                 y: 0,
@@ -308,7 +355,7 @@ impl LayoutConfig {
                 clock_digits: FontSize::Large,
                 weather: FontSize::Small,
             },
-            asset_path: "./assets/ssd1309/".to_string(), // 128-width assets
+            asset_path: assets::path("ssd1309/"), // 128-width assets
             visualizer: VisualizerLayout {
                 width: 128,
                 height: 56,
@@ -350,6 +397,7 @@ impl LayoutConfig {
             height,
             color_depth,
             category: LayoutCategory::Large,
+            aspect: LayoutAspect::Landscape,
             status_bar: StatusBarLayout {
                 height: 10,
                 y: 0,
@@ -373,9 +421,9 @@ impl LayoutConfig {
                 weather: FontSize::Medium,
             },
             asset_path: if color_depth == ColorDepth::Rgb565 {
-                "./assets/st7789/".to_string()
+                assets::path("st7789/")
             } else {
-                "./assets/ssd1322/".to_string()
+                assets::path("ssd1322/")
             },
             visualizer: VisualizerLayout {
                 width: 256,
@@ -410,6 +458,7 @@ impl LayoutConfig {
             height,
             color_depth,
             category: LayoutCategory::ExtraLarge,
+            aspect: LayoutAspect::Landscape,
             status_bar: StatusBarLayout {
                 height: 20,
                 y: 0,
@@ -432,7 +481,7 @@ impl LayoutConfig {
                 clock_digits: FontSize::ExtraLarge,
                 weather: FontSize::Large,
             },
-            asset_path: "./assets/sharp400/".to_string(), // 400-width assets
+            asset_path: assets::path("sharp400/"), // 400-width assets
             visualizer: VisualizerLayout {
                 width: 400,
                 height: 220,
@@ -465,16 +514,16 @@ impl LayoutConfig {
             AssetType::Weather => {
                 // Weather icons are in basic/, mono/ or color/ subdirectories
                 match self.color_depth {
-                    ColorDepth::Monochrome => "./assets/mono/".to_string(),
-                    ColorDepth::Gray4 => "./assets/basic/".to_string(),
-                    ColorDepth::Rgb565 => "./assets/color/".to_string(),
+                    ColorDepth::Monochrome => assets::path("mono/"),
+                    ColorDepth::Gray4 => assets::path("basic/"),
+                    ColorDepth::Rgb565 => assets::path("color/"),
                 }
             }
             AssetType::Visualizer => {
                 self.asset_path.clone()
             }
             AssetType::EasterEgg => {
-                "./assets/".to_string()
+                assets::path("")
             }
         }
     }
@@ -564,6 +613,25 @@ mod tests {
         assert!(layout.asset_path.contains("ssd1309"));
     }
 
+    #[test]
+    fn test_square_gray4_layout() {
+        // 128 wide like the mono panels, so it shares their artwork
+        let layout = layout_for_resolution(128, 128, ColorDepth::Gray4);
+        assert_eq!(layout.category, LayoutCategory::Small);
+        assert_eq!(layout.aspect, LayoutAspect::Square);
+        assert!(!layout.visualizer.is_wide);
+        assert!(layout.content_area.y + layout.content_area.height <= 128);
+        assert!(layout.asset_path_for(AssetType::Weather).contains("basic"));
+    }
+
+    #[test]
+    fn test_aspect_detection() {
+        assert_eq!(LayoutAspect::from_size(128, 64), LayoutAspect::Landscape);
+        assert_eq!(LayoutAspect::from_size(240, 240), LayoutAspect::Square);
+        assert_eq!(LayoutAspect::from_size(280, 240), LayoutAspect::Landscape);
+        assert_eq!(LayoutAspect::from_size(64, 128), LayoutAspect::Portrait);
+    }
+
     #[test]
     fn test_large_layout() {
         let layout = layout_for_resolution(256, 64, ColorDepth::Gray4);
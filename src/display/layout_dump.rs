@@ -0,0 +1,142 @@
+/*
+ *  display/layout_dump.rs
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  --dump-layout - print the resolved field rectangles of one page
+ *
+ *  The page is built exactly as the display manager would build it for the
+ *  configured driver (size, layout overrides, theme, progress bar position)
+ *  but without opening the bus, so a field that lands off-screen can be
+ *  tracked down on a desktop.
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *  Public License.
+ *
+ */
+
+use std::fmt::Write;
+
+use crate::config::DisplayConfig;
+
+use super::factory::DisplayDriverFactory;
+use super::layout::LayoutConfig;
+use super::layout_manager::{LayoutManager, SCROLLING_PAGE};
+use super::page::PageLayout;
+use super::theme::ColorTheme;
+
+/// Page names accepted by `--dump-layout`
+pub const PAGES: &[&str] = &[
+    "scrolling", "aio", "clock", "weather_current", "weather_forecast",
+    "history", "warning", "splash", "big_title",
+];
+
+/// Layout manager for the configured driver, set up as the display manager would
+pub fn layout_manager(config: &DisplayConfig, theme: Option<ColorTheme>) -> LayoutManager {
+    let capabilities = DisplayDriverFactory::nominal_capabilities(config);
+    let mut manager = LayoutManager::new(LayoutConfig::for_display(&capabilities));
+    manager.set_theme(theme.unwrap_or_default());
+    manager.set_progress_position(config.progress_bar.unwrap_or_default());
+    manager.set_track_lines(config.track_lines.clone());
+    manager
+}
+
+/// Build the named page; `None` for an unknown name or a page the panel has no variant for
+pub fn build_page(manager: &LayoutManager, mode: &str) -> Option<PageLayout> {
+    match mode {
+        "scrolling" => Some(manager.create_scrolling_page(SCROLLING_PAGE)),
+        "aio" => Some(manager.create_aio_scrolling_page()),
+        "clock" => Some(manager.create_clock_page()),
+        "weather_current" => Some(manager.create_weather_current_page()),
+        "weather_forecast" => Some(manager.create_weather_forecast_page()),
+        "history" => manager.create_history_page(),
+        "warning" => Some(manager.create_warning_page()),
+        "splash" => Some(manager.create_splash_page()),
+        "big_title" => Some(manager.create_big_title_page()),
+        _ => None,
+    }
+}
+
+/// One line per field: name, type, position, size, font, alignment and colour.
+///
+/// Fields reaching past the panel edge are flagged `OFF-SCREEN`.
+pub fn describe(manager: &LayoutManager, page: &PageLayout) -> String {
+    let layout = manager.layout_config();
+    let (width, height) = (layout.width as i32, layout.height as i32);
+    let mut out = format!(
+        "{} on {}x{} {:?} ({:?}, {:?})\n",
+        page.name, layout.width, layout.height, layout.color_depth, layout.category, layout.aspect
+    );
+    let _ = writeln!(
+        out, "{:<20} {:<6} {:>5} {:>5} {:>5} {:>5}  {:<6} {:<18} colour",
+        "field", "type", "x", "y", "w", "h", "font", "align"
+    );
+    for field in page.fields() {
+        let b = field.bounds;
+        let font = field.font
+            .map(|f| format!("{}x{}", f.character_size.width, f.character_size.height))
+            .unwrap_or_else(|| "-".to_string());
+        let align = format!("{:?}/{:?}", field.horizontal_alignment, field.vertical_alignment);
+        let off_screen = b.top_left.x < 0 || b.top_left.y < 0
+            || b.top_left.x + b.size.width as i32 > width
+            || b.top_left.y + b.size.height as i32 > height;
+        let _ = writeln!(
+            out, "{:<20} {:<6} {:>5} {:>5} {:>5} {:>5}  {:<6} {:<18} {:?}{}",
+            field.name, format!("{:?}", field.field_type),
+            b.top_left.x, b.top_left.y, b.size.width, b.size.height,
+            font, align, field.fg_color,
+            if off_screen { "  OFF-SCREEN" } else { "" }
+        );
+    }
+    out
+}
+
+/// Text printed by `--dump-layout <mode>`
+pub fn dump(config: &DisplayConfig, theme: Option<ColorTheme>, mode: &str) -> Result<String, String> {
+    let manager = layout_manager(config, theme);
+    let page = build_page(&manager, mode).ok_or_else(|| {
+        if PAGES.contains(&mode) {
+            format!("no '{mode}' layout for a {}x{} panel", manager.layout_config().width, manager.layout_config().height)
+        } else {
+            format!("unknown page '{mode}', expected one of: {}", PAGES.join(", "))
+        }
+    })?;
+    Ok(describe(&manager, &page))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field_names(dump: &str) -> Vec<&str> {
+        dump.lines().skip(2).filter_map(|l| l.split_whitespace().next()).collect()
+    }
+
+    #[test]
+    fn clock_dump_lists_every_field() {
+        let config = DisplayConfig::default();
+        let dump = dump(&config, None, "clock").unwrap();
+        assert!(dump.starts_with("clock:small_display on 128x64 Monochrome"), "{dump}");
+        assert_eq!(field_names(&dump), ["clock_digits", "date", "seconds_progress", "metrics"]);
+        assert!(!dump.contains("OFF-SCREEN"), "{dump}");
+    }
+
+    #[test]
+    fn unknown_and_unavailable_pages_are_errors() {
+        let config = DisplayConfig::default();
+        assert!(dump(&config, None, "nope").unwrap_err().contains("expected one of"));
+        // the history page needs a large panel
+        assert!(dump(&config, None, "history").unwrap_err().contains("128x64"));
+    }
+}
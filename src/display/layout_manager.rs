@@ -23,19 +23,60 @@
 
 #![allow(dead_code)] // layout manager helpers; some page-builder fns reserved
 
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+use embedded_graphics::prelude::Size;
+
+use super::components::scrollers::TrackTag;
 use super::page::PageLayout;
-use super::layout::{LayoutConfig, LayoutCategory};
+use super::layout::{LayoutAspect, LayoutConfig, LayoutCategory};
 use super::layout_template::LayoutTemplates;
 use super::layout_resolver::{DisplayProfile, LayoutResolver};
+use super::theme::ColorTheme;
+use super::traits::ColorDepth;
 
 pub const SCROLLING_PAGE: &str = "scrolling";
 pub const SCROLLING_AIO_PAGE: &str = "aio_small";
 pub const SCROLLING_AIO_WIDE_PAGE: &str = "aio_wide";
 
+/// Where the now-playing progress bar and info line sit on the scroller pages
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProgressPosition {
+    /// Under the status bar, track text below
+    Top,
+    /// Along the bottom edge, as laid out in layout.yaml
+    #[default]
+    Bottom,
+}
+
+/// Forecast day group a field belongs to: `day3_temp` is day 3, part `temp`
+pub fn forecast_day_of(field_name: &str) -> Option<(usize, &str)> {
+    let (day, part) = field_name.strip_prefix("day")?.split_once('_')?;
+    Some((day.parse().ok()?, part))
+}
+
+/// Number of forecast day groups laid out on `page`
+pub fn forecast_days_in(page: &PageLayout) -> usize {
+    page.fields().iter()
+        .filter_map(|f| forecast_day_of(&f.name).map(|(day, _)| day))
+        .max()
+        .unwrap_or(0)
+}
+
 pub struct LayoutManager {
     layout_config: LayoutConfig,
     templates: LayoutTemplates,
     profile: DisplayProfile,
+    theme: ColorTheme,
+    progress_position: ProgressPosition,
+    /// Track lines for the scroller rows, None for the rows as laid out
+    track_lines: Option<Vec<TrackTag>>,
+    /// Forecast page day limit, None for all the layout has
+    forecast_days: Option<usize>,
+    /// Pages already checked for overlaps, debug builds only
+    validated: Mutex<HashSet<String>>,
 }
 
 impl LayoutManager {
@@ -47,59 +88,206 @@ impl LayoutManager {
             height:      layout_config.height,
             color_depth: layout_config.color_depth,
             category:    layout_config.category,
+            aspect:      layout_config.aspect,
         };
-        Self { layout_config, templates, profile }
+        Self { layout_config, templates, profile, theme: ColorTheme::default(), progress_position: ProgressPosition::default(), track_lines: None, forecast_days: None, validated: Mutex::default() }
+    }
+
+    /// Recolour fields on every page built from here on.
+    ///
+    /// Monochrome panels ignore the theme - every lit pixel is On anyway.
+    pub fn set_theme(&mut self, theme: ColorTheme) {
+        if !theme.is_empty() && self.profile.color_depth == ColorDepth::Monochrome {
+            log::info!("layout_manager: colour theme ignored on a monochrome panel");
+        }
+        self.theme = theme;
+    }
+
+    /// Move the scroller progress bar and info line to the top or bottom
+    pub fn set_progress_position(&mut self, position: ProgressPosition) {
+        self.progress_position = position;
+    }
+
+    /// Show these track lines, in order, in the scroller rows; None or empty keeps the layout's rows
+    pub fn set_track_lines(&mut self, lines: Option<Vec<TrackTag>>) {
+        self.track_lines = lines.filter(|l| !l.is_empty());
+    }
+
+    /// Show at most `days` on the forecast page; more than the layout fits is clamped
+    pub fn set_forecast_days(&mut self, days: Option<usize>) {
+        self.forecast_days = days.map(|d| d.max(1));
     }
 
     fn resolve(&self, template_name: &str) -> Option<PageLayout> {
-        LayoutResolver::new(&self.templates).resolve(template_name, self.profile)
+        let mut page = LayoutResolver::new(&self.templates).resolve(template_name, self.profile)?;
+        if self.profile.color_depth != ColorDepth::Monochrome {
+            self.theme.apply(template_name, &mut page);
+        }
+        Some(page)
+    }
+
+    /// Debug builds: log overlapping and off-screen fields, once per page variant
+    fn checked(&self, page: PageLayout) -> PageLayout {
+        if cfg!(debug_assertions) && self.validated.lock().unwrap().insert(page.name.clone()) {
+            let panel = Size::new(self.profile.width, self.profile.height);
+            if let Err(problems) = page.validate(panel) {
+                for problem in problems {
+                    log::warn!("layout_manager: {}: {problem}", page.name);
+                }
+            }
+        }
+        page
     }
 
     pub fn create_aio_scrolling_page(&self) -> PageLayout {
-        self.resolve("aio").unwrap_or_else(|| {
+        let mut page = self.resolve("aio").unwrap_or_else(|| {
             log::error!("layout_manager: failed to resolve 'aio' template");
             PageLayout::new(SCROLLING_AIO_PAGE)
-        })
+        });
+        self.place_progress(&mut page);
+        self.place_track_lines(&mut page);
+        self.checked(page)
     }
 
     pub fn create_scrolling_page(&self, page_name: &str) -> PageLayout {
         let template = if page_name == SCROLLING_PAGE { "playback" } else { "aio" };
-        self.resolve(template).unwrap_or_else(|| {
+        let mut page = self.resolve(template).unwrap_or_else(|| {
             log::error!("layout_manager: failed to resolve '{template}' template");
             PageLayout::new(page_name)
-        })
+        });
+        self.place_progress(&mut page);
+        self.place_track_lines(&mut page);
+        self.checked(page)
+    }
+
+    /// Lift the progress bar + info line block to just under the status bar.
+    ///
+    /// Everything else below the status bar drops by the block height; if the
+    /// track rows then run off the panel the 1px gaps between them close up.
+    /// Pages without the scroller panel fields are left alone.
+    fn place_progress(&self, page: &mut PageLayout) {
+        if self.progress_position == ProgressPosition::Bottom {
+            return;
+        }
+        let bounds = |name: &str| page.get_field(name).map(|f| f.bounds);
+        let (Some(status), Some(bar), Some(info), Some(_)) =
+            (bounds("status_bar"), bounds("track_progress_bar"), bounds("info_line"), bounds("album_artist"))
+        else {
+            return;
+        };
+        let bottom = |r: embedded_graphics::primitives::Rectangle| r.top_left.y + r.size.height as i32;
+        let anchor = bottom(status) + 1;
+        let block = bottom(info) - bar.top_left.y;
+
+        for field in page.fields.iter_mut() {
+            match field.name.as_str() {
+                "status_bar" => {}
+                "track_progress_bar" => field.bounds.top_left.y = anchor,
+                "info_line" => field.bounds.top_left.y = anchor + info.top_left.y - bar.top_left.y,
+                _ if field.bounds.top_left.y >= anchor => field.bounds.top_left.y += block,
+                _ => {}
+            }
+        }
+
+        // close up the track rows, top down, until the last one is back on the panel
+        let mut rows: Vec<usize> = (0..page.fields.len())
+            .filter(|&i| page.fields[i].scrollable && page.fields[i].bounds.top_left.y > anchor)
+            .collect();
+        rows.sort_by_key(|&i| page.fields[i].bounds.top_left.y);
+        let Some(&last) = rows.last() else { return };
+        let mut overflow = bottom(page.fields[last].bounds) - self.profile.height as i32;
+        for pair in 1..rows.len() {
+            if overflow <= 0 {
+                break;
+            }
+            let gap = page.fields[rows[pair]].bounds.top_left.y - bottom(page.fields[rows[pair - 1]].bounds);
+            if gap > 0 {
+                let pull = gap.min(overflow);
+                for &i in &rows[pair..] {
+                    page.fields[i].bounds.top_left.y -= pull;
+                }
+                overflow -= pull;
+            }
+        }
+    }
+
+    /// Put the configured track lines into the layout's track rows.
+    ///
+    /// The rows are slots filled top down, so line one takes the top row
+    /// whatever the layout named it. Rows left over are dropped and the
+    /// scroller fields below them (the year) move up into the space.
+    fn place_track_lines(&self, page: &mut PageLayout) {
+        let Some(lines) = &self.track_lines else { return };
+        let mut rows: Vec<usize> = (0..page.fields.len())
+            .filter(|&i| TrackTag::from_field_name(&page.fields[i].name).is_some())
+            .collect();
+        rows.sort_by_key(|&i| page.fields[i].bounds.top_left.y);
+        for (&i, tag) in rows.iter().zip(lines) {
+            page.fields[i].name = tag.field_name().to_string();
+        }
+        if rows.len() <= lines.len() {
+            return;
+        }
+
+        let bottom = |i: usize| page.fields[i].bounds.top_left.y + page.fields[i].bounds.size.height as i32;
+        let block_end = bottom(rows[rows.len() - 1]);
+        let freed = block_end - bottom(rows[lines.len() - 1]);
+        for field in page.fields.iter_mut() {
+            if field.scrollable && TrackTag::from_field_name(&field.name).is_none() && field.bounds.top_left.y >= block_end {
+                field.bounds.top_left.y -= freed;
+            }
+        }
+        let dropped: HashSet<usize> = rows[lines.len()..].iter().copied().collect();
+        let mut index = 0;
+        page.fields.retain(|_| { index += 1; !dropped.contains(&(index - 1)) });
     }
 
     pub fn create_clock_page(&self) -> PageLayout {
-        self.resolve("clock").unwrap_or_else(|| {
+        self.checked(self.resolve("clock").unwrap_or_else(|| {
             log::error!("layout_manager: failed to resolve 'clock' template");
             PageLayout::new("clock")
-        })
+        }))
+    }
+
+    pub fn create_big_title_page(&self) -> PageLayout {
+        self.checked(self.resolve("big_title").unwrap_or_else(|| {
+            log::error!("layout_manager: failed to resolve 'big_title' template");
+            PageLayout::new("big_title")
+        }))
     }
 
     pub fn is_wide(&self) -> bool{
         matches!(self.layout_config.category, LayoutCategory::Large | LayoutCategory::ExtraLarge)
     }
 
+    /// Square and portrait panels resolve the stacked page variants
+    pub fn aspect(&self) -> LayoutAspect {
+        self.layout_config.aspect
+    }
+
     pub fn create_weather_current_page(&self) -> PageLayout {
-        self.resolve("weather_current").unwrap_or_else(|| {
+        self.checked(self.resolve("weather_current").unwrap_or_else(|| {
             log::error!("layout_manager: failed to resolve 'weather_current' template");
             PageLayout::new("weather_current")
-        })
+        }))
     }
 
     pub fn create_weather_forecast_page(&self) -> PageLayout {
-        self.resolve("weather_forecast").unwrap_or_else(|| {
+        let mut page = self.resolve("weather_forecast").unwrap_or_else(|| {
             log::error!("layout_manager: failed to resolve 'weather_forecast' template");
             PageLayout::new("weather_forecast")
-        })
+        });
+        if let Some(days) = self.forecast_days {
+            page.fields.retain(|f| forecast_day_of(&f.name).is_none_or(|(day, _)| day <= days));
+        }
+        self.checked(page)
     }
 
     pub fn create_warning_page(&self) -> PageLayout {
-        self.resolve("warning").unwrap_or_else(|| {
+        self.checked(self.resolve("warning").unwrap_or_else(|| {
             log::error!("layout_manager: failed to resolve 'warning' template");
             PageLayout::new("warning")
-        })
+        }))
     }
 
     /// Resolve the overlay layout for a named easter egg.
@@ -109,14 +297,19 @@ impl LayoutManager {
     /// or "none" egg types produce no text overlay without error.
     pub fn create_egg_page(&self, egg_name: &str) -> PageLayout {
         let template = format!("easter_egg_{egg_name}");
-        self.resolve(&template).unwrap_or_else(|| PageLayout::new(&template))
+        self.checked(self.resolve(&template).unwrap_or_else(|| PageLayout::new(&template)))
+    }
+
+    /// Recently played list; `None` on panels too small to show it
+    pub fn create_history_page(&self) -> Option<PageLayout> {
+        self.resolve("history").map(|page| self.checked(page))
     }
 
     pub fn create_splash_page(&self) -> PageLayout {
-        self.resolve("splash").unwrap_or_else(|| {
+        self.checked(self.resolve("splash").unwrap_or_else(|| {
             log::error!("layout_manager: failed to resolve 'splash' template");
             PageLayout::new("splash")
-        })
+        }))
     }
 
     /// Get the layout configuration
@@ -124,3 +317,254 @@ impl LayoutManager {
         &self.layout_config
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::display::color::Color;
+    use crate::display::traits::{BusInterface, ColorDepth, DisplayCapabilities, SpiInfo};
+    use embedded_graphics::pixelcolor::BinaryColor;
+
+    fn capabilities(width: u32, height: u32, color_depth: ColorDepth, driver: &str) -> DisplayCapabilities {
+        DisplayCapabilities {
+            width,
+            height,
+            color_depth,
+            interface: BusInterface::Spi(SpiInfo {
+                max_speed_hz: 10_000_000,
+                dc_pin_desc:  "",
+                rst_pin_desc: "",
+                rst_required: true,
+            }),
+            supports_rotation:   false,
+            max_fps:             60,
+            supports_brightness: true,
+            supports_invert:     false,
+            driver_name:         driver.to_string(),
+        }
+    }
+
+    fn pages(manager: &LayoutManager) -> Vec<PageLayout> {
+        vec![
+            manager.create_scrolling_page(SCROLLING_PAGE),
+            manager.create_aio_scrolling_page(),
+            manager.create_clock_page(),
+            manager.create_weather_current_page(),
+            manager.create_weather_forecast_page(),
+            manager.create_splash_page(),
+            manager.create_warning_page(),
+        ]
+    }
+
+    fn assert_fields_on_screen(manager: &LayoutManager) {
+        let (w, h) = (manager.layout_config().width as i32, manager.layout_config().height as i32);
+        for page in pages(manager) {
+            assert!(!page.fields().is_empty(), "{} resolved no fields", page.name);
+            for field in page.fields() {
+                let b = field.bounds;
+                let (right, bottom) = (b.top_left.x + b.size.width as i32, b.top_left.y + b.size.height as i32);
+                assert!(
+                    b.top_left.x >= 0 && b.top_left.y >= 0 && right <= w && bottom <= h,
+                    "{}/{} at {:?} {:?} leaves the {w}x{h} panel", page.name, field.name, b.top_left, b.size
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn square_panel_uses_stacked_pages() {
+        let layout = LayoutConfig::for_display(&capabilities(128, 128, ColorDepth::Gray4, "emulator"));
+        assert_eq!(layout.category, LayoutCategory::Small);
+        assert_eq!(layout.aspect, LayoutAspect::Square);
+
+        let manager = LayoutManager::new(layout);
+        assert_eq!(manager.create_clock_page().name, "clock:square");
+        assert_eq!(manager.create_weather_current_page().name, "weather_current:tall");
+        assert_eq!(manager.create_weather_forecast_page().name, "weather_forecast:tall");
+        assert_fields_on_screen(&manager);
+
+        // digits centred between the metrics line and the seconds bar
+        let clock = manager.create_clock_page();
+        let digits = clock.get_field("clock_digits").unwrap().bounds;
+        let top_gap = digits.top_left.y;
+        let bottom_gap = 128 - (digits.top_left.y + digits.size.height as i32);
+        assert!((top_gap - bottom_gap).abs() <= 1, "{top_gap} vs {bottom_gap}");
+        assert!(clock.get_field("metrics").unwrap().bounds.top_left.y < top_gap);
+        assert!(clock.get_field("date").unwrap().bounds.top_left.y >= 128 - bottom_gap);
+    }
+
+    #[test]
+    fn portrait_panel_uses_stacked_pages() {
+        let layout = LayoutConfig::for_display(&capabilities(64, 128, ColorDepth::Monochrome, "ssd1309"));
+        assert_eq!(layout.category, LayoutCategory::Small);
+        assert_eq!(layout.aspect, LayoutAspect::Portrait);
+        assert!(layout.visualizer.width <= 64);
+
+        let manager = LayoutManager::new(layout);
+        assert_eq!(manager.create_clock_page().name, "clock:portrait");
+        assert_eq!(manager.create_aio_scrolling_page().name, "aio:tall");
+        assert_fields_on_screen(&manager);
+    }
+
+    #[test]
+    fn landscape_panels_keep_their_variants() {
+        let manager = LayoutManager::new(LayoutConfig::for_display(
+            &capabilities(128, 64, ColorDepth::Monochrome, "ssd1309")));
+        assert_eq!(manager.aspect(), LayoutAspect::Landscape);
+        assert_eq!(manager.create_clock_page().name, "clock:small_display");
+        assert_eq!(manager.create_weather_current_page().name, "weather_current:default");
+    }
+
+    #[test]
+    fn next_track_only_on_larger_layouts() {
+        let scrolling = |w, h, depth| {
+            let layout = LayoutConfig::for_display(&capabilities(w, h, depth, "mock"));
+            let category = layout.category;
+            (category, LayoutManager::new(layout).create_scrolling_page(SCROLLING_PAGE))
+        };
+
+        for (w, h) in [(128, 64), (132, 64)] {
+            let (category, page) = scrolling(w, h, ColorDepth::Monochrome);
+            assert!(matches!(category, LayoutCategory::Small | LayoutCategory::Medium));
+            assert!(page.get_field("title").is_some(), "{w}x{h} scrolling page did not resolve");
+            assert!(page.get_field("next_track").is_none(), "{w}x{h} should have no next track");
+        }
+
+        let (category, page) = scrolling(256, 64, ColorDepth::Gray4);
+        assert_eq!(category, LayoutCategory::Large);
+        assert!(page.get_field("next_track").is_some());
+
+        let (category, page) = scrolling(400, 240, ColorDepth::Rgb565);
+        assert_eq!(category, LayoutCategory::ExtraLarge);
+        let next = page.get_field("next_track").unwrap().bounds;
+        let year = page.get_field("year").unwrap().bounds;
+        assert!(next.top_left.y > year.top_left.y + year.size.height as i32);
+        assert!(next.top_left.y + next.size.height as i32 <= 240, "room below the year row");
+    }
+
+    #[test]
+    fn pipboy_egg_scrolls_artist_and_title_above_the_art() {
+        let layout = LayoutConfig::for_display(&capabilities(128, 64, ColorDepth::Monochrome, "mock"));
+        let page = LayoutManager::new(layout).create_egg_page("pipboy");
+        let artist = page.get_field("artist").unwrap();
+        let title = page.get_field("title").unwrap();
+        assert!(artist.scrollable && title.scrollable);
+        assert_eq!(title.bounds.top_left.y, artist.bounds.top_left.y + artist.bounds.size.height as i32);
+        // clear of the Vault Boy figure on the right
+        assert!(artist.bounds.top_left.x + artist.bounds.size.width as i32 <= 94);
+        assert!(page.get_field("time").is_some());
+    }
+
+    #[test]
+    fn theme_recolours_gray4_fields_but_not_mono() {
+        let theme: ColorTheme = serde_yaml::from_str("date: DarkGray\n").unwrap();
+
+        let mut manager = LayoutManager::new(LayoutConfig::for_display(
+            &capabilities(256, 64, ColorDepth::Gray4, "ssd1322")));
+        let before = manager.create_clock_page().get_field("date").unwrap().fg_color.to_gray4();
+        manager.set_theme(theme.clone());
+        let after = manager.create_clock_page().get_field("date").unwrap().fg_color.to_gray4();
+        assert_ne!(before, after);
+        assert_eq!(after, Color::DarkGray.to_gray4());
+
+        let mut mono = LayoutManager::new(LayoutConfig::for_display(
+            &capabilities(128, 64, ColorDepth::Monochrome, "ssd1309")));
+        mono.set_theme(theme);
+        let date = mono.create_clock_page().get_field("date").unwrap().fg_color;
+        assert_eq!(date.to_binary(), BinaryColor::On);
+    }
+
+    #[test]
+    fn forecast_days_limit_the_day_groups() {
+        let mut wide = LayoutManager::new(LayoutConfig::for_display(
+            &capabilities(256, 64, ColorDepth::Gray4, "ssd1322")));
+        assert_eq!(forecast_days_in(&wide.create_weather_forecast_page()), 6);
+
+        wide.set_forecast_days(Some(5));
+        let page = wide.create_weather_forecast_page();
+        assert_eq!(forecast_days_in(&page), 5);
+        for part in ["icon", "name", "data_box", "temp", "precip"] {
+            assert!(page.get_field(&format!("day5_{part}")).is_some());
+            assert!(page.get_field(&format!("day6_{part}")).is_none());
+        }
+
+        // more than the layout fits is clamped to it
+        let mut narrow = LayoutManager::new(LayoutConfig::for_display(
+            &capabilities(128, 64, ColorDepth::Monochrome, "ssd1309")));
+        narrow.set_forecast_days(Some(5));
+        assert_eq!(forecast_days_in(&narrow.create_weather_forecast_page()), 3);
+        assert_eq!(forecast_day_of("day12_precip"), Some((12, "precip")));
+        assert_eq!(forecast_day_of("daylight"), None);
+    }
+
+    #[test]
+    fn history_page_only_on_large_panels() {
+        let small = LayoutManager::new(LayoutConfig::for_display(
+            &capabilities(128, 64, ColorDepth::Monochrome, "ssd1309")));
+        assert!(small.create_history_page().is_none());
+
+        let large = LayoutManager::new(LayoutConfig::for_display(
+            &capabilities(256, 64, ColorDepth::Gray4, "ssd1322")));
+        let page = large.create_history_page().unwrap();
+        let title = page.get_field("history_title").unwrap().bounds;
+        let list = page.get_field("history_list").unwrap().bounds;
+        assert!(list.top_left.y >= title.top_left.y + title.size.height as i32);
+        assert!(list.top_left.y + list.size.height as i32 <= 64);
+        // room for several tracks in 5x8
+        assert!(list.size.height >= 4 * 8, "{:?}", list.size);
+    }
+
+    #[test]
+    fn track_lines_fill_the_rows_top_down() {
+        let mut manager = LayoutManager::new(LayoutConfig::for_display(
+            &capabilities(128, 64, ColorDepth::Monochrome, "ssd1309")));
+        let default = manager.create_scrolling_page(SCROLLING_PAGE);
+        let row_y = |page: &PageLayout, name: &str| page.get_field(name).map(|f| f.bounds.top_left.y);
+
+        manager.set_track_lines(Some(vec![TrackTag::Composer, TrackTag::Title]));
+        let page = manager.create_scrolling_page(SCROLLING_PAGE);
+        assert_eq!(row_y(&page, "composer"), row_y(&default, "album_artist"));
+        assert_eq!(row_y(&page, "title"), row_y(&default, "album"));
+        for hidden in ["album_artist", "album", "artist"] {
+            assert!(page.get_field(hidden).is_none(), "{hidden}");
+        }
+        // the year follows the last line shown
+        assert_eq!(row_y(&page, "year"), row_y(&default, "title"));
+
+        // unset keeps the layout's rows
+        manager.set_track_lines(None);
+        assert_eq!(manager.create_scrolling_page(SCROLLING_PAGE).fields.len(), default.fields.len());
+    }
+
+    #[test]
+    fn progress_block_moves_under_the_status_bar() {
+        let rows = ["album_artist", "album", "title", "artist"];
+        for (w, h, depth, page_name) in [
+            (128, 64, ColorDepth::Monochrome, SCROLLING_PAGE),
+            (256, 64, ColorDepth::Gray4, SCROLLING_AIO_WIDE_PAGE),
+            (400, 240, ColorDepth::Rgb565, SCROLLING_PAGE),
+        ] {
+            let mut manager = LayoutManager::new(LayoutConfig::for_display(&capabilities(w, h, depth, "mock")));
+            let bottom = manager.create_scrolling_page(page_name);
+            manager.set_progress_position(ProgressPosition::Top);
+            let top = manager.create_scrolling_page(page_name);
+            let at = |page: &PageLayout, name: &str| page.get_field(name).unwrap().bounds;
+
+            let (status, bar, info) = (at(&top, "status_bar"), at(&top, "track_progress_bar"), at(&top, "info_line"));
+            assert_eq!(bar.top_left.y, status.top_left.y + status.size.height as i32 + 1, "{w}x{h}");
+            assert!(at(&bottom, "track_progress_bar").top_left.y > at(&bottom, "artist").top_left.y);
+            assert!(bar.top_left.y < info.top_left.y);
+
+            let mut last = info.top_left.y;
+            for name in rows {
+                let row = at(&top, name);
+                assert!(row.top_left.y >= info.top_left.y + info.size.height as i32,
+                    "{w}x{h} {name} at {} collides with the info line", row.top_left.y);
+                assert!(row.intersection(&bar).is_zero_sized() && row.intersection(&info).is_zero_sized());
+                assert!(row.top_left.y > last, "{w}x{h} rows keep their order");
+                assert!(row.top_left.y + row.size.height as i32 <= h as i32, "{w}x{h} {name} off the panel");
+                last = row.top_left.y;
+            }
+        }
+    }
+}
@@ -35,15 +35,15 @@ use embedded_graphics::prelude::{Point, Size};
 use embedded_graphics::primitives::Rectangle;
 
 use crate::display::{
-    field::{Field, FieldType},
+    field::{Field, FieldType, Overflow},
     page::PageLayout,
     layout_template::{
-        CategoryFilter, ColorDepthFilter, FieldKind, LayoutTemplates, MatchRule,
+        AspectFilter, CategoryFilter, ColorDepthFilter, FieldKind, LayoutTemplates, MatchRule,
     },
     layout_expr::{eval, ExprContext, FieldGeom},
 };
 use crate::display::traits::ColorDepth;
-use crate::display::layout::LayoutCategory;
+use crate::display::layout::{LayoutAspect, LayoutCategory};
 
 /// Characteristics of the target display, used for variant matching and
 /// expression evaluation.
@@ -53,6 +53,7 @@ pub struct DisplayProfile {
     pub height:   u32,
     pub color_depth: ColorDepth,
     pub category: LayoutCategory,
+    pub aspect:   LayoutAspect,
 }
 
 /// Resolves a named template for a given display profile.
@@ -174,6 +175,7 @@ impl<'t> LayoutResolver<'t> {
                 let field_type = kind_to_field_type(&field_def.field_type);
                 let fg = crate::display::color::Color::from(&field_def.fg_color);
                 let bg = field_def.bg_color.as_ref().map(crate::display::color::Color::from);
+                let fill = field_def.fill_color.as_ref().map(crate::display::color::Color::from);
                 let font = field_def.font.as_ref().map(|f| f.to_mono_font());
 
                 let field = Field {
@@ -181,12 +183,16 @@ impl<'t> LayoutResolver<'t> {
                     field_type,
                     bounds,
                     border:               field_def.border,
-                    scrollable:           field_def.scrollable,
+                    scrollable:           field_def.scrollable || field_def.overflow == Overflow::Scroll,
                     font,
                     fg_color:             fg,
                     bg_color:             bg,
+                    fill_color:           fill,
                     horizontal_alignment: field_def.horizontal_alignment.clone().into(),
                     vertical_alignment:   field_def.vertical_alignment.clone().into(),
+                    overflow:             field_def.overflow,
+                    progress_style:       field_def.progress_style,
+                    blink:                field_def.blink_ms.filter(|&ms| ms > 0).map(std::time::Duration::from_millis),
                 };
 
                 all_fields.push(field);
@@ -224,6 +230,7 @@ impl<'t> LayoutResolver<'t> {
                 let field_type = kind_to_field_type(&field_def.field_type);
                 let fg = crate::display::color::Color::from(&field_def.fg_color);
                 let bg = field_def.bg_color.as_ref().map(crate::display::color::Color::from);
+                let fill = field_def.fill_color.as_ref().map(crate::display::color::Color::from);
                 let font = field_def.font.as_ref().map(|f| f.to_mono_font());
 
                 all_fields.push(Field {
@@ -231,12 +238,16 @@ impl<'t> LayoutResolver<'t> {
                     field_type,
                     bounds,
                     border:               field_def.border,
-                    scrollable:           field_def.scrollable,
+                    scrollable:           field_def.scrollable || field_def.overflow == Overflow::Scroll,
                     font,
                     fg_color:             fg,
                     bg_color:             bg,
+                    fill_color:           fill,
                     horizontal_alignment: field_def.horizontal_alignment.clone().into(),
                     vertical_alignment:   field_def.vertical_alignment.clone().into(),
+                    overflow:             field_def.overflow,
+                    progress_style:       field_def.progress_style,
+                    blink:                field_def.blink_ms.filter(|&ms| ms > 0).map(std::time::Duration::from_millis),
                 });
             }
         }
@@ -253,6 +264,9 @@ fn matches_profile(rule: &MatchRule, profile: DisplayProfile) -> bool {
     if !rule.color_depth.is_empty() && !rule.color_depth.iter().any(|d| depth_matches(d, profile.color_depth)) {
         return false;
     }
+    if !rule.aspect.is_empty() && !rule.aspect.iter().any(|a| aspect_matches(a, profile.aspect)) {
+        return false;
+    }
     true
 }
 
@@ -275,6 +289,15 @@ fn depth_matches(filter: &ColorDepthFilter, depth: ColorDepth) -> bool {
     }
 }
 
+fn aspect_matches(filter: &AspectFilter, aspect: LayoutAspect) -> bool {
+    matches!(
+        (filter, aspect),
+        (AspectFilter::Landscape, LayoutAspect::Landscape)
+            | (AspectFilter::Square, LayoutAspect::Square)
+            | (AspectFilter::Portrait, LayoutAspect::Portrait)
+    )
+}
+
 fn kind_to_field_type(kind: &FieldKind) -> FieldType {
     match kind {
         FieldKind::WeatherIcon | FieldKind::WeatherGlyph | FieldKind::CoverImage => FieldType::Glyph,
@@ -292,7 +315,7 @@ fn kind_to_field_type(kind: &FieldKind) -> FieldType {
 mod tests {
     use super::*;
     use crate::display::traits::ColorDepth;
-    use crate::display::layout::LayoutCategory;
+    use crate::display::layout::{LayoutAspect, LayoutCategory};
 
     const MINIMAL_YAML: &str = r#"
 components:
@@ -332,6 +355,7 @@ templates:
             height: 64,
             color_depth: ColorDepth::Monochrome,
             category: LayoutCategory::Small,
+            aspect: LayoutAspect::Landscape,
         }
     }
 
@@ -405,6 +429,7 @@ templates:
             width: 256, height: 64,
             color_depth: ColorDepth::Gray4,
             category: LayoutCategory::Large,
+            aspect: LayoutAspect::Landscape,
         };
         let wide = resolver.resolve("page", wide_profile).unwrap();
         assert_eq!(wide.fields()[0].bounds.size.width, 128); // half of 256
@@ -450,6 +475,7 @@ templates:
             width: 320, height: 170,
             color_depth: ColorDepth::Rgb565,
             category: LayoutCategory::ExtraLarge,
+            aspect: LayoutAspect::Landscape,
         };
         let page = resolver.resolve("page", rgb_profile).unwrap();
         // x = display.height = 170, width = 320 - 170 = 150
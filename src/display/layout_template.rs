@@ -241,6 +241,10 @@ pub struct FieldDef {
     #[serde(default)]
     pub bg_color: Option<ColorSpec>,
 
+    /// Progress bar fill color (default follows `fg_color`, the outline).
+    #[serde(default)]
+    pub fill_color: Option<ColorSpec>,
+
     /// Font name override.  When omitted the field type provides a default.
     #[serde(default)]
     pub font: Option<FontSpec>,
@@ -253,6 +257,18 @@ pub struct FieldDef {
     #[serde(default)]
     pub scrollable: bool,
 
+    /// Overflow handling for text wider than the field: clip (default), ellipsis or scroll.
+    #[serde(default)]
+    pub overflow: crate::display::field::Overflow,
+
+    /// Progress bar fill: filled (default), segmented or line.
+    #[serde(default)]
+    pub progress_style: crate::display::progress::ProgressStyle,
+
+    /// Blink period in milliseconds - drawn one period, skipped the next (default off).
+    #[serde(default)]
+    pub blink_ms: Option<u64>,
+
     /// Horizontal text alignment (default Left).
     #[serde(default)]
     pub horizontal_alignment: AlignH,
@@ -334,11 +350,15 @@ pub struct MatchRule {
     /// Restrict to specific color depths.
     #[serde(default)]
     pub color_depth: Vec<ColorDepthFilter>,
+
+    /// Restrict to specific panel shapes.
+    #[serde(default)]
+    pub aspect: Vec<AspectFilter>,
 }
 
 impl MatchRule {
     pub fn is_catch_all(&self) -> bool {
-        self.category.is_empty() && self.color_depth.is_empty()
+        self.category.is_empty() && self.color_depth.is_empty() && self.aspect.is_empty()
     }
 }
 
@@ -360,6 +380,14 @@ pub enum ColorDepthFilter {
     Rgb565,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub enum AspectFilter {
+    Landscape,
+    Square,
+    Portrait,
+}
+
 /// A component instance placed inside a template variant.
 ///
 /// `x`, `y`, `width`, `height` are expression strings resolved against
@@ -408,6 +436,9 @@ pub enum NamedColor {
     Green,
     Orange,
     Magenta,
+    LightGray,
+    Gray,
+    DarkGray,
 }
 
 impl From<&ColorSpec> for crate::display::color::Color {
@@ -424,6 +455,9 @@ impl From<&ColorSpec> for crate::display::color::Color {
                 NamedColor::Green   => Color::Green,
                 NamedColor::Orange  => Color::Orange,
                 NamedColor::Magenta => Color::Magenta,
+                NamedColor::LightGray => Color::LightGray,
+                NamedColor::Gray      => Color::Gray,
+                NamedColor::DarkGray  => Color::DarkGray,
             },
             ColorSpec::Rgb { r, g, b } => Color::Rgb(*r, *g, *b),
         }
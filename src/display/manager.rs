@@ -24,35 +24,52 @@
 #![allow(dead_code)] // display manager helpers; some utility methods reserved
 
 use log::info;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use arrayvec::ArrayString;
 use core::fmt::Write;
 use embedded_graphics::pixelcolor::{BinaryColor, Gray4, Rgb565};
 use embedded_graphics::prelude::*;
 
 use crate::config::DisplayConfig;
-use crate::display::layout_manager::{SCROLLING_AIO_WIDE_PAGE, SCROLLING_PAGE};
+use crate::display::field::{centred_x, ellipsize, mono_text_width, Overflow};
+use crate::display::progress::{draw_progress_bar, draw_progress_fill};
+use crate::display::time_source::{seconds_fraction, system_time, SharedTimeSource};
+use crate::display::burnin::BurnInShift;
+use crate::translate::UiText;
+use crate::display::components::info_line::{InfoLineData, InfoLineSlots, InfoText};
+use crate::display::layout_manager::{forecast_day_of, forecast_days_in, SCROLLING_AIO_WIDE_PAGE, SCROLLING_PAGE};
 use crate::display::{
     BoxedDriver,
+    BusRetry,
+    BusWatchdog,
     DisplayCapabilities,
+    DisplayDriver,
     DisplayDriverFactory,
     DisplayError,
     DisplayMode,
     FrameBuffer,
+    IdleBehavior,
     LayoutConfig,
     LayoutManager,
+    WatchdogAction,
 };
+use crate::display::mode_controller::{idle_action, IdleAction};
 use crate::display::components::{
     StatusBar,
     ScrollingText,
     ClockDisplay,
     WeatherDisplay as WeatherComponent,
     VisualizerComponent,
+    Slideshow,
+    PlayHistory,
+    BigTitle,
 };
+use crate::display::components::sleep_timer::SleepTimer;
+use crate::display::components::weather::TemperatureShown;
 
 use crate::clock_font_svg::set_clock_font;
 use crate::eggs::{Eggs, set_easter_egg};
-use crate::metrics::MachineMetrics;
+use crate::metrics::{MachineMetrics, MetricsFormat};
 use crate::vision::LastVizState;
 use crate::glyphs::{RepeatMode, ShuffleMode};
 
@@ -67,6 +84,8 @@ pub struct RenderBuffers {
     pub track_buffer: ArrayString<128>,
     /// Buffer for temp calculations
     pub temp_buffer: ArrayString<64>,
+    /// Info line left, centre and right text
+    pub info_line: [InfoText; 3],
 }
 
 impl Default for RenderBuffers {
@@ -76,6 +95,7 @@ impl Default for RenderBuffers {
             status_buffer: ArrayString::new(),
             track_buffer: ArrayString::new(),
             temp_buffer: ArrayString::new(),
+            info_line: [InfoText::new(); 3],
         }
     }
 }
@@ -105,6 +125,48 @@ impl RenderBuffers {
     }
 }
 
+/// Panel brightness when nothing else has set one
+const AWAKE_BRIGHTNESS: u8 = 255;
+
+/// Panel brightness of the `dim_clock` idle screen
+pub const IDLE_DIM_BRIGHTNESS: u8 = 16;
+
+/// How long the splash screen holds when nothing is configured
+pub const DEFAULT_SPLASH_HOLD: Duration = Duration::from_millis(2000);
+
+/// How long the volume overlay stays up after a volume or mute change
+pub const VOLUME_OVERLAY_HOLD: Duration = Duration::from_millis(1500);
+
+/// How long the now playing QR code stays up once shown
+pub const QR_OVERLAY_HOLD: Duration = Duration::from_secs(10);
+
+/// Splash activity spinner frames, plain ASCII so every mono font has them
+const SPLASH_SPINNER: [char; 4] = ['|', '/', '-', '\\'];
+
+/// Spinner glyph for the given frame index
+fn splash_spinner_frame(frame: usize) -> char {
+    SPLASH_SPINNER[frame % SPLASH_SPINNER.len()]
+}
+
+/// One forecast day group's text, ready to draw
+struct ForecastDayText {
+    name: String,
+    temp: String,
+    precip: String,
+    svg: String,
+}
+
+impl ForecastDayText {
+    fn new(day: &crate::weather::WeatherData) -> Self {
+        Self {
+            name: day.day.format("%a").to_string(),
+            temp: format!("{}°|{}°", day.temperature_min.round() as i32, day.temperature_max.round() as i32),
+            precip: format!("{}%", day.precipitation_probability_avg.round() as i32),
+            svg: day.weather_code.svg.clone(),
+        }
+    }
+}
+
 /// Performance metrics for display rendering
 #[derive(Debug, Clone)]
 pub struct PerformanceMetrics {
@@ -157,6 +219,16 @@ impl PerformanceMetrics {
         //}
     }
 
+    /// Rest of the target frame time left after the last frame, zero when it ran long
+    pub fn pacing_delay(&self) -> Duration {
+        Duration::from_micros(self.target_frame_time_us.saturating_sub(self.frame_time_us))
+    }
+
+    /// Cap at `fps`, the pace `pacing_delay` works to
+    pub fn set_target_fps(&mut self, fps: u32) {
+        self.target_frame_time_us = 1_000_000 / fps.max(1) as u64;
+    }
+
     pub fn fps(&self) -> f32 {
         if self.avg_frame_time_us == 0 {
             0.0
@@ -235,8 +307,10 @@ pub struct DisplayManager {
     driver: BoxedDriver,
     /// Framebuffer for rendering
     framebuffer: FrameBuffer,
-    /// Display capabilities
+    /// Display capabilities (logical - width/height swapped for 90/270)
     capabilities: DisplayCapabilities,
+    /// Clockwise panel rotation applied when packing the framebuffer
+    rotation: u16,
     /// Layout configuration
     layout: LayoutConfig,
     /// Layout manager - owns all page definitions
@@ -251,10 +325,63 @@ pub struct DisplayManager {
     clock_display: ClockDisplay,
     /// Weather display component
     weather_display: WeatherComponent,
+    /// Idle slideshow of user images, empty unless configured
+    slideshow: Slideshow,
+    /// Recently played tracks, None unless configured
+    history: Option<PlayHistory>,
+    /// Title alone in big text, the now playing page when `show_big_title` is set
+    big_title: BigTitle,
+    show_big_title: bool,
     /// Visualizer component
     visualizer: VisualizerComponent,
     /// Easter egg animations
     pub easter_egg: Eggs,
+    /// Advance to the next egg this often while in easter egg mode; None keeps one egg
+    egg_rotation_interval: Option<Duration>,
+    /// When the current egg went up, anchors the rotation schedule
+    egg_shown_at: Option<Instant>,
+    /// Volume and mute last reported by LMS, None until the first status
+    last_volume: Option<(u8, bool)>,
+    /// Volume bar drawn over every mode until this deadline
+    volume_overlay_until: Option<Instant>,
+    /// Now playing QR code and the URL template it's built from
+    now_playing_qr: crate::display::components::NowPlayingQr,
+    qr_template: Option<String>,
+    /// Show the QR code this often while playing, None on demand only
+    qr_interval: Option<Duration>,
+    /// QR code drawn over the playing modes until this deadline
+    qr_overlay_until: Option<Instant>,
+    qr_shown_at: Option<Instant>,
+    /// Sleep out the rest of each frame's target time after rendering
+    frame_pacing: bool,
+    /// Retries for frame transfers that hit a transient bus error
+    bus_retry: BusRetry,
+    /// Re-inits the driver when frames keep failing to reach the panel
+    bus_watchdog: BusWatchdog,
+    /// Audio feed for the visualizer worker
+    viz_source: crate::visualizer::VizSource,
+    /// Spectrum band AGC, None for raw levels
+    spectrum_agc: Option<crate::spectrum::AgcSettings>,
+    /// Stereo to mono for the mono meters and histograms, None for each one's default
+    viz_downmix: Option<crate::visualizer::DownmixMode>,
+    /// Clock the time-dependent render paths read
+    time: SharedTimeSource,
+    /// Actual, feels like or both on the current conditions page
+    temperature_shown: TemperatureShown,
+    /// What the panel does while nothing plays
+    idle_behavior: IdleBehavior,
+    /// Panel brightness outside the dimmed idle screen
+    awake_brightness: u8,
+    /// Panel currently lowered to `IDLE_DIM_BRIGHTNESS`
+    idle_dimmed: bool,
+    /// Panel cleared for the blank idle screen
+    idle_blanked: bool,
+    /// Power the panel off after this long idle with no activity, None never
+    inactivity_timeout: Option<Duration>,
+    /// Last playback or interaction, None until the first frame
+    last_activity: Option<Instant>,
+    /// Panel powered off by the inactivity timeout
+    panel_off: bool,
     /// Whether to show system metrics
     pub show_metrics: bool,
     /// Emulator state (for keyboard shortcuts)
@@ -263,6 +390,10 @@ pub struct DisplayManager {
 
     /// Device metrics
     pub device_metrics: MachineMetrics,
+    /// Which metrics the clock page shows and in what units
+    metrics_format: MetricsFormat,
+    /// Slow pixel shift of the clock and weather pages
+    burn_in: BurnInShift,
     /// Last visualizer state
     pub last_viz_state: LastVizState,
     /// Track duration in seconds
@@ -273,6 +404,20 @@ pub struct DisplayManager {
     pub remaining_time_secs: f32,
     /// Mode text (e.g., "Paused", "Playing")
     pub mode_text: String,
+    /// Labels the raw LMS mode is shown as
+    mode_labels: crate::translate::ModeLabels,
+    /// Language of the fixed display strings
+    locale: crate::translate::Locale,
+    /// Countdown to pausing for the night
+    sleep_timer: SleepTimer,
+    /// Set by the sleep timer running out, taken by the main loop
+    sleep_expired: std::sync::Arc<std::sync::atomic::AtomicBool>,
+    /// What the info line's left, centre and right slots show
+    info_slots: InfoLineSlots,
+    /// Resolution label for the info line, e.g. "24/96"
+    info_bitrate: String,
+    /// Track number from 1 and playlist length, once LMS reports them
+    playlist_pos: Option<(u32, u32)>,
     /// Whether to show remaining time
     pub show_remaining: bool,
     /// Audio quality level (SD=1, HD=2, DSD=3, None=0) for easter egg animations
@@ -287,6 +432,8 @@ pub struct DisplayManager {
     pub artist: String,
     /// Current track year - displayed in specific modes
     pub year: String,
+    /// Upcoming track ("Artist – Title") for the scrolling page, None when unknown
+    next_track: Option<String>,
     /// Performance metrics
     pub metrics: PerformanceMetrics,
     /// Pre-allocated render buffers (zero allocations in render loop!)
@@ -295,6 +442,8 @@ pub struct DisplayManager {
     pub weather_temp_units: String,
     /// Weather wind speed units ("mph" or "km/h")
     pub weather_wind_speed_units: String,
+    /// Units chosen at runtime, overriding the fetched units (None = as fetched)
+    display_temp_units: Option<crate::weather::TempUnits>,
     /// Weather location name
     pub weather_location_name: String,
     /// Weather data receiver (watch channel for lock-free updates)
@@ -303,12 +452,18 @@ pub struct DisplayManager {
     splash_active: bool,
     splash_version: String,
     splash_build_date: String,
+    /// How long `splash` blocks after drawing, zero to carry straight on
+    splash_hold: Duration,
+    /// Spinner frame, advanced on each splash status update
+    splash_spinner: usize,
     /// Astral service for auto-brightness and moon data fallback
     astral_service: Option<crate::astral::AstralService>,
     /// Last time auto-brightness was applied (rate-limits hardware writes)
     last_brightness_check: Option<std::time::Instant>,
     /// Optional TTF font for scrolling-text and label rendering
     ttf_font: Option<std::sync::Arc<crate::display::ttf_font::TtfFont>>,
+    /// Fit track text to the bitmap fonts' glyphs before drawing
+    sanitize_text: bool,
     /// SVG weather glyphs loaded from data/weather_glyphs.zip (Gray4 / Rgb565 only)
     weather_glyphs: Option<crate::weather_glyph::WeatherGlyphSet>,
     /// SVG moon phase glyphs loaded from data/moonphase.zip (Gray4 / Rgb565 only)
@@ -357,7 +512,22 @@ impl DisplayManager {
         let mut driver = DisplayDriverFactory::create_from_config(config)?;
         driver.init()?;
 
-        Self::new_with_driver(driver, scroll_mode, clock_font, show_metrics, egg_name, hist_scheme)
+        let mut manager = Self::new_with_driver(
+            driver,
+            config.rotate_deg.unwrap_or(0),
+            scroll_mode,
+            clock_font,
+            show_metrics,
+            egg_name,
+            hist_scheme,
+        )?;
+        manager.awake_brightness = config.brightness.unwrap_or(AWAKE_BRIGHTNESS);
+        manager.bus_retry = BusRetry::new(config.bus_retries.unwrap_or(BusRetry::DEFAULT_RETRIES));
+        manager.bus_watchdog = BusWatchdog::new(
+            config.watchdog_failures.unwrap_or(BusWatchdog::DEFAULT_FAILURES),
+            config.watchdog_timeout_secs.map_or(BusWatchdog::DEFAULT_STALL, Duration::from_secs),
+        );
+        Ok(manager)
     }
 
     /// Create a new display manager with an existing driver
@@ -368,6 +538,7 @@ impl DisplayManager {
     /// # Arguments
     ///
     /// * `driver` - Pre-initialized display driver
+    /// * `rotate_deg` - Clockwise panel rotation (0, 90, 180 or 270)
     /// * `scroll_mode` - Text scrolling mode
     /// * `clock_font` - Clock font name
     /// * `show_metrics` - Whether to show system metrics
@@ -378,19 +549,26 @@ impl DisplayManager {
     /// A configured DisplayManager or an error
     pub fn new_with_driver(
         driver: BoxedDriver,
+        rotate_deg: u16,
         scroll_mode: &str,
         clock_font: &str,
         show_metrics: bool,
         egg_name: &str,
         hist_scheme: &str,
     ) -> Result<Self, DisplayError> {
-        // Get capabilities and create layout
-        let capabilities = driver.capabilities().clone();
+        if !matches!(rotate_deg, 0 | 90 | 180 | 270) {
+            return Err(DisplayError::InvalidRotation(rotate_deg));
+        }
+
+        // Get capabilities and create layout - everything downstream of the
+        // driver works in the rotated (logical) orientation
+        let capabilities = driver.capabilities().rotated(rotate_deg);
         let layout = LayoutConfig::for_display(&capabilities);
 
-        info!("Display: {}x{}, Layout: {:?}, Assets: {}",
+        info!("Display: {}x{} (rotation {}), Layout: {:?}, Assets: {}",
               capabilities.width,
               capabilities.height,
+              rotate_deg,
               layout.category,
               layout.asset_path);
 
@@ -414,6 +592,11 @@ impl DisplayManager {
         let clock_display = ClockDisplay::new(layout.clone(), clock_font_data, show_metrics);
 
         let weather_display = WeatherComponent::new(layout.clone());
+        let slideshow = Slideshow::new(capabilities.width, capabilities.height);
+        let sleep_expired = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let mut sleep_timer = SleepTimer::new();
+        let expired = sleep_expired.clone();
+        sleep_timer.set_on_expiry(move || expired.store(true, std::sync::atomic::Ordering::Relaxed));
 
         // init to NoVisualization
         let visualizer = VisualizerComponent::new(
@@ -442,6 +625,7 @@ impl DisplayManager {
             driver,
             framebuffer,
             capabilities,
+            rotation: rotate_deg,
             layout,
             layout_manager,
             current_mode: DisplayMode::Scrolling,
@@ -449,15 +633,30 @@ impl DisplayManager {
             scrolling_text,
             clock_display,
             weather_display,
+            slideshow,
+            history: None,
+            big_title: BigTitle::new(scroll_mode_enum),
+            show_big_title: false,
             visualizer,
             easter_egg,
+            egg_rotation_interval: None,
+            egg_shown_at: None,
             show_metrics,
             device_metrics: MachineMetrics::default(),
+            metrics_format: MetricsFormat::default(),
+            burn_in: BurnInShift::default(),
             last_viz_state: LastVizState::default(),
             track_duration_secs: 0.0,
             current_track_time_secs: 0.0,
             remaining_time_secs: 0.0,
             mode_text: String::new(),
+            mode_labels: crate::translate::ModeLabels::default(),
+            locale: crate::translate::Locale::default(),
+            sleep_timer,
+            sleep_expired,
+            info_slots: InfoLineSlots::default(),
+            info_bitrate: String::new(),
+            playlist_pos: None,
             show_remaining: false,
             audio_level: 0,
             album_artist: String::new(),
@@ -465,27 +664,54 @@ impl DisplayManager {
             title: String::new(),
             artist: String::new(),
             year: String::new(),
+            next_track: None,
             metrics,
             render_buffers: RenderBuffers::default(),
             weather_temp_units: String::from("C"),
             weather_wind_speed_units: String::from("km/h"),
+            display_temp_units: None,
             weather_location_name: String::new(),
             weather_rx: None,
             splash_active: false,
+            splash_hold: DEFAULT_SPLASH_HOLD,
             splash_version: String::new(),
             splash_build_date: String::new(),
+            splash_spinner: 0,
             astral_service: None,
             last_brightness_check: None,
             ttf_font: None,
+            sanitize_text: true,
             weather_glyphs: crate::weather_glyph::WeatherGlyphSet::load_from_zip("./data/weather_glyphs.zip"),
             moon_phase_glyphs: crate::weather_glyph::MoonPhaseGlyphSet::load_from_zip("./data/moonphase.zip"),
             warning_title: String::new(),
             warning_detail: String::new(),
             warning_retry_text: String::new(),
-            alert_svg: std::fs::read("./assets/alert.svg").ok(),
+            alert_svg: std::fs::read(crate::assets::path("alert.svg")).ok(),
             cover_art_cache,
             cover_art: None,
             current_coverid: String::new(),
+            last_volume: None,
+            volume_overlay_until: None,
+            now_playing_qr: crate::display::components::NowPlayingQr::new(),
+            qr_template: None,
+            qr_interval: None,
+            qr_overlay_until: None,
+            qr_shown_at: None,
+            frame_pacing: false,
+            bus_retry: BusRetry::default(),
+            bus_watchdog: BusWatchdog::default(),
+            viz_source: crate::visualizer::VizSource::default(),
+            spectrum_agc: None,
+            viz_downmix: None,
+            time: system_time(),
+            temperature_shown: TemperatureShown::default(),
+            idle_behavior: IdleBehavior::default(),
+            awake_brightness: AWAKE_BRIGHTNESS,
+            idle_dimmed: false,
+            idle_blanked: false,
+            inactivity_timeout: None,
+            last_activity: None,
+            panel_off: false,
             #[cfg(feature = "emulator")]
             emulator_state: None,
         })
@@ -496,6 +722,29 @@ impl DisplayManager {
         &self.capabilities
     }
 
+    /// Current clockwise panel rotation in degrees
+    pub fn rotation(&self) -> u16 {
+        self.rotation
+    }
+
+    /// Change the panel rotation at runtime
+    ///
+    /// Only flips that keep the layout (0 <-> 180, 90 <-> 270) can be applied
+    /// live; switching between landscape and portrait needs a restart.
+    pub fn set_rotation(&mut self, degrees: u16) -> Result<(), DisplayError> {
+        if !matches!(degrees, 0 | 90 | 180 | 270) {
+            return Err(DisplayError::InvalidRotation(degrees));
+        }
+        if degrees % 180 != self.rotation % 180 {
+            return Err(DisplayError::InvalidConfiguration(format!(
+                "rotation {} -> {} changes the layout orientation, restart required",
+                self.rotation, degrees
+            )));
+        }
+        self.rotation = degrees;
+        Ok(())
+    }
+
     /// Get layout configuration
     pub fn layout(&self) -> &LayoutConfig {
         &self.layout
@@ -526,22 +775,132 @@ impl DisplayManager {
         &mut self.visualizer
     }
 
+    /// Write the last rendered frame to `path` as a PNG (logical orientation,
+    /// mono as black/white, Gray4 as a grey ramp)
+    pub fn export_frame(&self, path: impl AsRef<std::path::Path>) -> Result<(), DisplayError> {
+        let path = path.as_ref();
+        let png = self.framebuffer.encode_png()?;
+        std::fs::write(path, png)
+            .map_err(|e| DisplayError::Other(format!("Failed to write {}: {}", path.display(), e)))?;
+        info!("Frame exported to {}", path.display());
+        Ok(())
+    }
+
+    /// Get reference to the driver - downcast via `as_any()` to inspect a
+    /// concrete driver, e.g. the headless mock in integration tests
+    pub fn driver(&self) -> &dyn DisplayDriver {
+        self.driver.as_ref()
+    }
+
+    /// Replace the bus watchdog thresholds
+    pub fn set_bus_watchdog(&mut self, watchdog: BusWatchdog) {
+        self.bus_watchdog = watchdog;
+    }
+
+    /// Send a packed frame to the panel, retrying transient bus errors after
+    /// an async backoff so the worker is free while the bus recovers.
+    async fn transfer_frame(&mut self, buffer: &[u8]) -> Result<(), DisplayError> {
+        let mut attempt = 0;
+        let result = loop {
+            match self.driver.transfer(buffer) {
+                Err(e) if e.is_transient() && attempt < self.bus_retry.retries => {
+                    attempt += 1;
+                    let delay = self.bus_retry.delay(attempt);
+                    log::debug!("{e}, retry {attempt}/{} in {delay:?}", self.bus_retry.retries);
+                    tokio::time::sleep(delay).await;
+                }
+                result => break result,
+            }
+        };
+        self.settle_transfer(buffer, result)
+    }
+
+    /// Send a packed frame once, for the sync paths that can't wait out a backoff
+    fn send_frame(&mut self, buffer: &[u8]) -> Result<(), DisplayError> {
+        let result = self.driver.transfer(buffer);
+        self.settle_transfer(buffer, result)
+    }
+
+    /// Feed a transfer's outcome to the watchdog. When frames keep failing it
+    /// calls the bus wedged, and the driver is re-initialised and the frame
+    /// sent once more.
+    fn settle_transfer(&mut self, buffer: &[u8], result: Result<(), DisplayError>) -> Result<(), DisplayError> {
+        let err = match result {
+            Ok(()) => {
+                self.bus_watchdog.record_ok();
+                return Ok(());
+            }
+            Err(e) => e,
+        };
+        match self.bus_watchdog.record_failure(Instant::now()) {
+            WatchdogAction::Wait => Err(err),
+            WatchdogAction::Reinit(attempt) => {
+                log::warn!("Display bus looks wedged ({}), re-initialising the driver (attempt {}/{})",
+                    err, attempt, self.bus_watchdog.max_reinits);
+                self.driver.init()?;
+                self.driver.transfer(buffer)?;
+                info!("Display bus recovered after re-init");
+                self.bus_watchdog.record_ok();
+                Ok(())
+            }
+            WatchdogAction::GiveUp => {
+                log::error!("Display bus still failing after {} re-inits, giving up until a frame gets through",
+                    self.bus_watchdog.max_reinits);
+                Err(err)
+            }
+        }
+    }
+
     /// Clear the display
     pub fn clear(&mut self) -> Result<(), DisplayError> {
         self.render_buffers.temp_buffer.clear();
         self.framebuffer.clear();
-        let buffer_data = self.framebuffer.to_packed_bytes();
-        self.driver.write_buffer(&buffer_data)?;
-        self.driver.flush()
+        let buffer_data = self.framebuffer.to_packed_bytes_rotated(self.rotation);
+        self.send_frame(&buffer_data)
     }
 
     /// Render the current display mode (fast, sync-only path)
+    ///
+    /// The frame gets one attempt at the bus; `present` retries it.
     pub fn render(&mut self) -> Result<(), DisplayError> {
         let frame_start = Instant::now();
+        let buffer_data = self.compose_frame()?;
+        let render_time = frame_start.elapsed().as_micros() as u64;
+
+        let transfer_start = Instant::now();
+        self.send_frame(&buffer_data)?;
+        let transfer_time = transfer_start.elapsed().as_micros() as u64;
+
+        // Record performance metrics
+        self.metrics.record_frame(render_time, transfer_time);
+
+        Ok(())
+    }
+
+    /// Render the current display mode, riding out transient bus errors
+    pub async fn present(&mut self) -> Result<(), DisplayError> {
+        let frame_start = Instant::now();
+        let buffer_data = self.compose_frame()?;
+        let render_time = frame_start.elapsed().as_micros() as u64;
+
+        let transfer_start = Instant::now();
+        self.transfer_frame(&buffer_data).await?;
+        let transfer_time = transfer_start.elapsed().as_micros() as u64;
+
+        self.metrics.record_frame(render_time, transfer_time);
+
+        Ok(())
+    }
+
+    /// Draw the current mode and overlays, packed for the driver
+    fn compose_frame(&mut self) -> Result<Vec<u8>, DisplayError> {
 
         // Adjust display brightness based on time of day (rate-limited internally).
         self.update_auto_brightness();
 
+        // Refresh weather if the poller has new data
+        self.sync_weather();
+
         // Clear framebuffer
         self.framebuffer.clear();
 
@@ -554,67 +913,48 @@ impl DisplayManager {
             DisplayMode::Visualizer => self.render_visualizer(),
             DisplayMode::EasterEggs => self.render_easter_eggs(),
             DisplayMode::Warning => self.render_warning(),
+            DisplayMode::Slideshow => self.render_slideshow(),
+            DisplayMode::History => self.render_history(),
+            DisplayMode::BigTitle => self.render_big_title(),
         }?;
 
-        let render_time = frame_start.elapsed().as_micros() as u64;
-
-        // Transfer framebuffer to driver and flush to hardware
-        let transfer_start = Instant::now();
+        self.render_qr_overlay(self.time.instant())?;
+        self.render_volume_overlay(Instant::now())?;
+        self.render_sleep_timer(self.time.instant())?;
 
         // Pack framebuffer into bytes for driver
-        let buffer_data = self.framebuffer.to_packed_bytes();
-
-        // Write buffer to driver
-        self.driver.write_buffer(&buffer_data)?;
-
-        // Flush to hardware
-        self.driver.flush()?;
-        let transfer_time = transfer_start.elapsed().as_micros() as u64;
-
-        // Record performance metrics
-        self.metrics.record_frame(render_time, transfer_time);
-
-        Ok(())
+        Ok(self.framebuffer.to_packed_bytes_rotated(self.rotation))
     }
 
     /// Render scrolling text mode
     fn render_scrolling(&mut self) -> Result<(), DisplayError> {
-        let page = self.layout_manager.create_scrolling_page(SCROLLING_PAGE);
+        let page = self.layout_manager.create_scrolling_page(SCROLLING_PAGE).shown_at(&self.time.now());
 
-        if let (Some(aa), Some(al), Some(ti), Some(ar), Some(yr)) = (
-            page.get_field("album_artist"),
-            page.get_field("album"),
-            page.get_field("title"),
-            page.get_field("artist"),
-            page.get_field("year"),
-        ) {
-            self.scrolling_text.update_with_fields(aa, al, ti, ar, yr);
-        }
+        self.scrolling_text.update_with_page(&page);
 
         // Pre-compute display data before the framebuffer borrow
         let progress = (self.track_duration_secs > 0.0).then(||
             (self.current_track_time_secs / self.track_duration_secs).clamp(0.0, 1.0)
         );
-        let elapsed = self.render_buffers.format_time(self.current_track_time_secs).to_string();
-        let time_secs = if self.show_remaining { self.remaining_time_secs } else { self.track_duration_secs };
-        let (m, s) = ((time_secs as u32) / 60, (time_secs as u32) % 60);
-        let end = if self.show_remaining { format!("-{}:{:02}", m, s) } else { format!("{}:{:02}", m, s) };
-        let mode = self.mode_text.clone();
+        self.fill_info_line();
+        let info = &self.render_buffers.info_line;
         let year = self.year.clone();
+        let next_track = self.next_track.as_ref().map(|t| format!("Next: {t}"));
+        let next_track = next_track.as_deref();
 
         let cover_art = self.cover_art.as_ref();
         match &mut self.framebuffer {
             crate::display::framebuffer::FrameBuffer::Mono(fb) =>
-                Self::render_scrolling_page(fb, &page, &mut self.status_bar, &mut self.scrolling_text, progress, &mode, &elapsed, &end, &year),
+                Self::render_scrolling_page(fb, &page, &mut self.status_bar, &mut self.scrolling_text, progress, info, &year, next_track),
             crate::display::framebuffer::FrameBuffer::Gray4(fb) =>
-                Self::render_scrolling_page(fb, &page, &mut self.status_bar, &mut self.scrolling_text, progress, &mode, &elapsed, &end, &year),
+                Self::render_scrolling_page(fb, &page, &mut self.status_bar, &mut self.scrolling_text, progress, info, &year, next_track),
             crate::display::framebuffer::FrameBuffer::Rgb565(fb) => {
                 if let (Some(field), Some(art)) = (page.get_field("cover_art"), cover_art) {
                     let mut clipped = fb.clipped(&field.bounds);
                     art.draw_to(&mut clipped, field.position())
                         .map_err(|_| crate::display::error::DisplayError::DrawingError("cover art".to_string()))?;
                 }
-                Self::render_scrolling_page(fb, &page, &mut self.status_bar, &mut self.scrolling_text, progress, &mode, &elapsed, &end, &year)
+                Self::render_scrolling_page(fb, &page, &mut self.status_bar, &mut self.scrolling_text, progress, info, &year, next_track)
             },
         }
     }
@@ -626,10 +966,9 @@ impl DisplayManager {
         status_bar: &mut StatusBar,
         scrolling_text: &mut ScrollingText,
         progress: Option<f32>,
-        mode_text: &str,
-        elapsed_str: &str,
-        end_str: &str,
+        info: &[InfoText; 3],
         year_text: &str,
+        next_track: Option<&str>,
     ) -> Result<(), DisplayError>
     where
         D: DrawTarget,
@@ -638,7 +977,7 @@ impl DisplayManager {
         crate::display::color::Color: crate::display::color_proxy::ConvertColor<D::Color>,
     {
         use embedded_graphics::prelude::*;
-        use embedded_graphics::primitives::{Rectangle, PrimitiveStyleBuilder};
+        use embedded_graphics::primitives::Rectangle;
         use embedded_graphics::mono_font::{iso_8859_13::FONT_5X8, MonoTextStyle};
         use embedded_text::{
             alignment::{HorizontalAlignment, VerticalAlignment},
@@ -653,7 +992,7 @@ impl DisplayManager {
                     status_bar.render_field(field, fb)
                         .map_err(|_| DisplayError::DrawingError("Failed to render status bar".to_string()))?;
                 }
-                "album_artist" | "album" | "title" | "artist" => {
+                "album_artist" | "album" | "title" | "artist" | "composer" | "conductor" => {
                     //println!("{:>13} ..: {:#?}",field.name.as_str(), field.bounds);
                     scrolling_text.render_field(field, fb)
                         .map_err(|_| DisplayError::DrawingError(format!("Failed to render {}", field.name)))?;
@@ -668,23 +1007,23 @@ impl DisplayManager {
                         .draw(fb).map_err(|_| DisplayError::DrawingError("Failed to draw mode text".to_string()))?;
 
                 }
+                "next_track" => {
+                    // hidden when nothing is queued or the row would run off the panel
+                    let fits = fb.bounding_box().contains(field.bounds.bottom_right().unwrap_or(field.position()));
+                    if let Some(text) = next_track.filter(|_| fits) {
+                        let font = field.font.unwrap_or(&FONT_5X8);
+                        let style = MonoTextStyle::new(font, field.fg_color.to_color());
+                        Self::draw_field_text(fb, field, text, style)?;
+                    }
+                }
                 "track_progress_bar" => {
                     if let Some(p) = progress {
                         let pos = field.position();
                         let fw = field.width();
                         let fh = field.height();
-                        let color: D::Color = field.fg_color.to_color();
-                        Rectangle::new(Point::new(pos.x + 2, pos.y), Size::new(fw - 4, fh))
-                            .into_styled(PrimitiveStyleBuilder::new().stroke_color(color).stroke_width(1).build())
-                            .draw(fb)
+                        let bar = Rectangle::new(Point::new(pos.x + 2, pos.y), Size::new(fw - 4, fh));
+                        draw_progress_bar(fb, bar, p, field.progress_style, field.fg_color.to_color(), field.progress_fill().to_color())
                             .map_err(|_| DisplayError::DrawingError("Failed to draw progress bar".to_string()))?;
-                        let fill_w = ((fw - 6) as f32 * p) as u32;
-                        if fill_w > 0 {
-                            Rectangle::new(Point::new(pos.x + 3, pos.y + 1), Size::new(fill_w, fh.saturating_sub(2)))
-                                .into_styled(PrimitiveStyleBuilder::new().fill_color(color).build())
-                                .draw(fb)
-                                .map_err(|_| DisplayError::DrawingError("Failed to draw progress fill".to_string()))?;
-                        }
                     }
                 }
                 "info_line" => {
@@ -697,25 +1036,27 @@ impl DisplayManager {
                     // time strings assume a  maximum of hh:mm:ss - 8 char * the character width
                     let fixed_width = 12 * font.character_size.width;
 
-                    // Center: mode text (full field width)
+                    let [left, centre, right] = info;
+
+                    // Center: mode text by default (full field width)
                     let mut rect = field.bounds;
                     rect.size.height += 1;
-                    TextBox::with_textbox_style(mode_text, rect, style, tb_center)
+                    TextBox::with_textbox_style(centre, rect, style, tb_center)
                         .draw(fb).map_err(|_| DisplayError::DrawingError("Failed to draw mode text".to_string()))?;
 
-                    // Left: elapsed time
+                    // Left: elapsed time by default
                     let mut rect = field.bounds;
                     rect.size.width = fixed_width;
                     rect.size.height += 1;
-                    TextBox::with_textbox_style(elapsed_str, rect, style, tb_left)
+                    TextBox::with_textbox_style(left, rect, style, tb_left)
                         .draw(fb).map_err(|_| DisplayError::DrawingError("Failed to draw elapsed time".to_string()))?;
 
-                    // Right: end/remaining time
+                    // Right: end/remaining time by default
                     let mut rect = field.bounds;
                     rect.top_left.x = (rect.size.width - fixed_width) as i32;
                     rect.size.width = fixed_width;
                     rect.size.height += 1;
-                    TextBox::with_textbox_style(end_str, rect, style, tb_right)
+                    TextBox::with_textbox_style(right, rect, style, tb_right)
                         .draw(fb).map_err(|_| DisplayError::DrawingError("Failed to draw end time".to_string()))?;
                 }
                 _ => {}
@@ -756,19 +1097,12 @@ impl DisplayManager {
         // Draw fill
         if track_duration > 0.0 {
             let progress = (current_time / track_duration).clamp(0.0, 1.0);
-            let fill_width = ((field_width - 6) as f32 * progress) as u32;
-
-            if fill_width > 0 {
-                Rectangle::new(
-                    Point::new(field_pos.x + 3, field_pos.y + 1),
-                    Size::new(fill_width, field_height.saturating_sub(2)),
-                )
-                .into_styled(PrimitiveStyleBuilder::new()
-                    .fill_color(BinaryColor::On)
-                    .build())
-                .draw(fb)
+            let inner = Rectangle::new(
+                Point::new(field_pos.x + 3, field_pos.y + 1),
+                Size::new(field_width - 6, field_height.saturating_sub(2)),
+            );
+            draw_progress_fill(fb, inner, progress, field.progress_style, BinaryColor::On)
                 .map_err(|_| DisplayError::DrawingError("Failed to draw progress fill".to_string()))?;
-            }
         }
 
         Ok(())
@@ -862,7 +1196,7 @@ impl DisplayManager {
         .map_err(|_| DisplayError::DrawingError("Failed to draw current time".to_string()))?;
 
         // Mode text (center)
-        let mode_x = field_pos.x + (field_width as i32 - (mode_text.len() * 5) as i32) / 2;
+        let mode_x = centred_x(field_pos.x, field_width, mode_text, font);
         Text::with_baseline(
             mode_text,
             Point::new(mode_x, info_y),
@@ -888,7 +1222,7 @@ impl DisplayManager {
         }
         let time_str = self.render_buffers.temp_buffer.as_str();
 
-        let time_x = field_pos.x + field_width as i32 - (time_str.len() * 5) as i32 - 2;
+        let time_x = field_pos.x + field_width as i32 - mono_text_width(time_str, font) as i32 - 2;
         Text::with_baseline(
             time_str,
             Point::new(time_x, info_y),
@@ -939,7 +1273,7 @@ impl DisplayManager {
         .map_err(|_| DisplayError::DrawingError("Failed to draw current time".to_string()))?;
 
         // Mode text (center)
-        let mode_x = (self.layout.width as i32 - (self.mode_text.len() * 5) as i32) / 2;
+        let mode_x = centred_x(0, self.layout.width, &self.mode_text, &FONT_5X8);
         Text::with_baseline(
             &self.mode_text,
             Point::new(mode_x, info_y as i32),
@@ -966,7 +1300,7 @@ impl DisplayManager {
         }
         let time_str = self.render_buffers.temp_buffer.as_str();
 
-        let time_x = self.layout.width as i32 - (time_str.len() * 5) as i32 - 2;
+        let time_x = self.layout.width as i32 - mono_text_width(time_str, &FONT_5X8) as i32 - 2;
         Text::with_baseline(
             time_str,
             Point::new(time_x, info_y as i32),
@@ -983,7 +1317,9 @@ impl DisplayManager {
     fn render_clock(&mut self) -> Result<(), DisplayError> {
 
         // Get the clock page layout
-        let page = self.layout_manager.create_clock_page();
+        let mut page = self.layout_manager.create_clock_page().shown_at(&self.time.now());
+        let shift = self.burn_in.offset();
+        page.translate(shift);
         let mut progress_top = Point::zero();
         for field in page.fields() {
             match field.name.as_str() {
@@ -995,20 +1331,20 @@ impl DisplayManager {
             }
         }
 
-        // Extract current second, millisecond fidelity, for progress bar
-        let current_second: f32 = chrono::Local::now().format("%S.%3f").to_string().parse().unwrap_or(0.0);
+        // One reading per frame: seconds bar, millisecond fidelity, and the date line
+        let now = self.time.now();
+        let seconds_progress = seconds_fraction(&now);
 
         let metrics_str = if self.show_metrics {
             self.device_metrics.check();
-            format!("CPU {:.1}%  {:.1}C {}", 
-                self.device_metrics.cpu_load, 
-                self.device_metrics.cpu_temp,
-                self.device_metrics.up_time,
-            )
+            self.metrics_format.format(&self.device_metrics, self.metrics.fps())
         } else {
             String::new()
         };
 
+        // HH:MM:SS digits stand in for the seconds bar
+        let seconds_shown = self.clock_display.seconds_shown();
+
         // Clock must work on all display types, and render to supported color depth
         match &mut self.framebuffer {
             crate::display::framebuffer::FrameBuffer::Mono(fb) => {
@@ -1026,10 +1362,11 @@ impl DisplayManager {
                         }
                         "clock_digits" => {
                             // Clock renders digits color defined in SVG -> transposed to color depth
-                            self.clock_display.render(fb, field.position().y, progress_top)
+                            // digits centre on the panel width, so carry the x shift via the target
+                            self.clock_display.render(&mut fb.translated(Point::new(shift.x, 0)), field.position().y, progress_top)
                                 .map_err(|_| DisplayError::DrawingError("Failed to render clock".to_string()))?;
                         }
-                        "seconds_progress" => {
+                        "seconds_progress" if !seconds_shown => {
                             use embedded_graphics::primitives::{Rectangle as EgRectangle, PrimitiveStyleBuilder};
                             use embedded_graphics::prelude::*;
 
@@ -1050,27 +1387,20 @@ impl DisplayManager {
                             .map_err(|_| DisplayError::DrawingError("Failed to draw progress bar outline".to_string()))?;
 
                             // milliseconds in play for buttery smooth rendering (rather than the bump every second)
-                            let progress = current_second / 60.0;
-                            let fill_width = (((field_width - 2) as f32) * progress) as u32;
-
-                            if fill_width > 0 {
-                                EgRectangle::new(
-                                    Point::new(field_pos.x + 1, field_pos.y + 1),
-                                    Size::new(fill_width, field_height - 2),
-                                )
-                                .into_styled(PrimitiveStyleBuilder::new()
-                                    .fill_color(bar_color)
-                                    .build())
-                                .draw(fb)
+                            let progress = seconds_progress;
+                            let inner = EgRectangle::new(
+                                Point::new(field_pos.x + 1, field_pos.y + 1),
+                                Size::new(field_width.saturating_sub(2), field_height.saturating_sub(2)),
+                            );
+                            draw_progress_fill(fb, inner, progress, field.progress_style, bar_color)
                                 .map_err(|_| DisplayError::DrawingError("Failed to draw progress fill".to_string()))?;
-                            }
                         }
                         "date" => {
                             // Render date text using field color (e.g., cyan)
                             use embedded_graphics::mono_font::MonoTextStyle;
                             let font = field.font.unwrap_or(&embedded_graphics::mono_font::iso_8859_13::FONT_6X10);
                             let style = MonoTextStyle::new(font, field.fg_binary());
-                            let date_str = chrono::Local::now().format("%a %b %d").to_string();
+                            let date_str = self.clock_display.format_date(&now);
                             // Use DRY helper for text rendering
                             Self::draw_field_text(fb, field, &date_str, style)
                                 .map_err(|_| DisplayError::DrawingError("Failed to draw date".to_string()))?;
@@ -1095,50 +1425,22 @@ impl DisplayManager {
                         }
                         "clock_digits" => {
                             // Clock renders digits with field color (e.g., green → gray4 value 8)
-                            self.clock_display.render_gray4(fb, field.position().y, progress_top)
+                            // digits centre on the panel width, so carry the x shift via the target
+                            self.clock_display.render_gray4(&mut fb.translated(Point::new(shift.x, 0)), field.position().y, progress_top)
                                 .map_err(|_| DisplayError::DrawingError("Failed to render clock".to_string()))?;
                         }
-                        "seconds_progress" => {
-                            use embedded_graphics::primitives::{Rectangle as EgRectangle, PrimitiveStyleBuilder};
-                            use embedded_graphics::prelude::*;
-
-                            let field_pos = field.position();
-                            let field_width = field.width();
-                            let field_height = field.height();
-                            let bar_color = field.fg_color.to_gray4();
-
-                            EgRectangle::new(
-                                Point::new(field_pos.x, field_pos.y),
-                                Size::new(field_width, field_height),
-                            )
-                            .into_styled(PrimitiveStyleBuilder::new()
-                                .stroke_color(bar_color)
-                                .stroke_width(1)
-                                .build())
-                            .draw(fb)
-                            .map_err(|_| DisplayError::DrawingError("Failed to draw progress bar outline".to_string()))?;
-
-                            let progress = (current_second as f32) / 60.0;
-                            let fill_width = (((field_width - 2) as f32) * progress) as u32;
-
-                            if fill_width > 0 {
-                                EgRectangle::new(
-                                    Point::new(field_pos.x + 1, field_pos.y + 1),
-                                    Size::new(fill_width, field_height - 2),
-                                )
-                                .into_styled(PrimitiveStyleBuilder::new()
-                                    .fill_color(bar_color)
-                                    .build())
-                                .draw(fb)
-                                .map_err(|_| DisplayError::DrawingError("Failed to draw progress fill".to_string()))?;
-                            }
+                        "seconds_progress" if !seconds_shown => {
+                            // outline in the field color, fill in its accent - same level unless set
+                            draw_progress_bar(fb, field.bounds, seconds_progress, field.progress_style,
+                                field.fg_color.to_gray4(), field.progress_fill().to_gray4())
+                                .map_err(|_| DisplayError::DrawingError("Failed to draw progress bar".to_string()))?;
                         }
                         "date" => {
                             // Render date text using field color (e.g., cyan → gray4 value 11)
                             use embedded_graphics::mono_font::MonoTextStyle;
                             let font = field.font.unwrap_or(&embedded_graphics::mono_font::iso_8859_13::FONT_6X10);
                             let style = MonoTextStyle::new(font, field.fg_color.to_gray4());
-                            let date_str = chrono::Local::now().format("%a %b %d").to_string();
+                            let date_str = self.clock_display.format_date(&now);
                             // Use DRY helper for text rendering
                             Self::draw_field_text(fb, field, &date_str, style)
                                 .map_err(|_| DisplayError::DrawingError("Failed to draw date".to_string()))?;
@@ -1161,50 +1463,23 @@ impl DisplayManager {
                             }
                          }
                         "clock_digits" => {
-                            self.clock_display.render_rgb565(fb, field.position().y, progress_top)
+                            // digits centre on the panel width, so carry the x shift via the target
+                            self.clock_display.render_rgb565(&mut fb.translated(Point::new(shift.x, 0)), field.position().y, progress_top)
                                 .map_err(|_| DisplayError::DrawingError("Failed to render clock".to_string()))?;
                         }
-                        "seconds_progress" => {
-                            use embedded_graphics::primitives::{Rectangle as EgRectangle, PrimitiveStyleBuilder};
+                        "seconds_progress" if !seconds_shown => {
                             use crate::display::color_proxy::ConvertColor;
-
-                            let field_pos = field.position();
-                            let field_width = field.width();
-                            let field_height = field.height();
-                            let bar_color: Rgb565 = field.fg_color.to_color();
-
-                            EgRectangle::new(
-                                Point::new(field_pos.x, field_pos.y),
-                                Size::new(field_width, field_height),
-                            )
-                            .into_styled(PrimitiveStyleBuilder::new()
-                                .stroke_color(bar_color)
-                                .stroke_width(1)
-                                .build())
-                            .draw(fb)
-                            .map_err(|_| DisplayError::DrawingError("Failed to draw progress bar outline".to_string()))?;
-
-                            let progress = (current_second as f32) / 60.0;
-                            let fill_width = (((field_width - 2) as f32) * progress) as u32;
-
-                            if fill_width > 0 {
-                                EgRectangle::new(
-                                    Point::new(field_pos.x + 1, field_pos.y + 1),
-                                    Size::new(fill_width, field_height - 2),
-                                )
-                                .into_styled(PrimitiveStyleBuilder::new()
-                                    .fill_color(bar_color)
-                                    .build())
-                                .draw(fb)
-                                .map_err(|_| DisplayError::DrawingError("Failed to draw progress fill".to_string()))?;
-                            }
+                            let outline: Rgb565 = field.fg_color.to_color();
+                            let fill: Rgb565 = field.progress_fill().to_color();
+                            draw_progress_bar(fb, field.bounds, seconds_progress, field.progress_style, outline, fill)
+                                .map_err(|_| DisplayError::DrawingError("Failed to draw progress bar".to_string()))?;
                         }
                         "date" => {
                             use embedded_graphics::mono_font::MonoTextStyle;
                             use crate::display::color_proxy::ConvertColor;
                             let font = field.font.unwrap_or(&embedded_graphics::mono_font::iso_8859_13::FONT_6X10);
                             let style = MonoTextStyle::new(font, field.fg_color.to_color());
-                            let date_str = chrono::Local::now().format("%a %b %d").to_string();
+                            let date_str = self.clock_display.format_date(&now);
                             Self::draw_field_text(fb, field, &date_str, style)
                                 .map_err(|_| DisplayError::DrawingError("Failed to draw date".to_string()))?;
                         }
@@ -1225,14 +1500,15 @@ impl DisplayManager {
         use embedded_graphics::mono_font::iso_8859_13::FONT_6X10;
 
         // Get weather page layout
-        let page = self.layout_manager.create_weather_current_page();
+        let mut page = self.layout_manager.create_weather_current_page().shown_at(&self.time.now());
+        page.translate(self.burn_in.offset());
 
         // Extract all weather data needed to avoid borrow conflicts
         let weather_data = if let Some(current) = self.weather_display.weather_data().first() {
-            current.clone()
+            self.weather_in_display_units(current)
         } else {
             // No weather data available - show message
-            let msg = "No Weather Data\n\nConfigure -W option\nwith API key";
+            let msg = &format!("{}\n\n{}", self.locale.text(UiText::NoWeatherData), self.locale.text(UiText::ConfigureWeather));
             match &mut self.framebuffer {
                 crate::display::framebuffer::FrameBuffer::Mono(fb) => {
                     let style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
@@ -1259,56 +1535,46 @@ impl DisplayManager {
         // Get weather icon SVG path
         let svg_path = weather_data.weather_code.svg.clone();
 
-        // Format text strings
-        let conditions_text = weather_data.weather_code.description.clone();
-        let temp_text = format!("{}({}) °{}",
-            weather_data.temperature_avg.round() as i32,
-            weather_data.temperature_apparent_avg.round() as i32,
-            weather_data.temperature_units.clone()
-        );
-        let humidity_text = format!("{}%", weather_data.humidity_avg);
-        let wind_text = format!("{} {} {}",
-            weather_data.wind_speed_avg.round() as i32,
-            weather_data.wind_speed_units.clone(),
-            weather_data.wind_direction
-        );
-        let precip_text = format!("{}%", weather_data.precipitation_probability_avg.round() as i32);
-
-        let pressure_text = format!("{}{}", weather_data.pressure_sea_level_avg, weather_data.pressure_sea_level_units);
+        // Format text strings - flag cached data that has outlived the TTL
+        let conditions_text = if self.weather_display.is_stale() {
+            format!("{} ({})", weather_data.weather_code.description, self.locale.text(UiText::Stale))
+        } else {
+            weather_data.weather_code.description.clone()
+        };
+        let crate::display::components::weather::CurrentWeatherText {
+            temperature: temp_text,
+            humidity: humidity_text,
+            wind: wind_text,
+            precipitation: precip_text,
+            pressure: pressure_text,
+        } = crate::display::components::weather::CurrentWeatherText::new(&weather_data, self.temperature_shown);
 
         // Wide display fields (sunrise, sunset, moonrise, moonset).
-        // For sun times: prefer weather API data; fall back to astral calculations.
-        // For moon times: weather API rarely populates these, so astral is primary.
         let astral_today = self.astral_service.as_ref().map(|a| a.get_today());
-
-        let sunrise_text = weather_data.sunrise_time
-            .or_else(|| astral_today.as_ref().and_then(|a| a.sunrise))
-            .map(|t| t.format("%H:%M").to_string())
-            .unwrap_or_else(|| "--:--".to_string());
-
-        let sunset_text = weather_data.sunset_time
-            .or_else(|| astral_today.as_ref().and_then(|a| a.sunset))
-            .map(|t| t.format("%H:%M").to_string())
-            .unwrap_or_else(|| "--:--".to_string());
-
-        let moonrise_text = weather_data.moonrise_time
-            .or_else(|| astral_today.as_ref().and_then(|a| a.moonrise))
-            .map(|t| t.format("%H:%M").to_string())
-            .unwrap_or_else(|| "--:--".to_string());
-
-        let moonset_text = weather_data.moonset_time
-            .or_else(|| astral_today.as_ref().and_then(|a| a.moonset))
-            .map(|t| t.format("%H:%M").to_string())
-            .unwrap_or_else(|| "--:--".to_string());
-
+        let crate::display::components::weather::AstralTimesText {
+            sunrise: sunrise_text,
+            sunset: sunset_text,
+            moonrise: moonrise_text,
+            moonset: moonset_text,
+        } = crate::display::components::weather::AstralTimesText::new(&weather_data, astral_today.as_ref());
+
+        // Phase is pure date arithmetic, no location needed when astral isn't set up
         let moon_phase_index = self.astral_service.as_ref()
             .map(|a| a.moon_phase_index_today())
-            .unwrap_or(0);
+            .unwrap_or_else(|| crate::sun::moon_phase_index(self.time.now().date_naive()));
 
         let moonphase_text = self.astral_service.as_ref()
             .map(|a| a.moon_phase_description())
             .unwrap_or("");
 
+        // Advance the conditions marquee; it only moves when the text overflows the field
+        if let Some(field) = page.fields().iter().find(|f| f.name == "conditions") {
+            let marquee = self.weather_display.conditions_marquee_mut();
+            marquee.set_text(&conditions_text);
+            marquee.update(field);
+        }
+        let (conditions_marquee, svg_scratch) = self.weather_display.render_parts();
+
             let weather_glyphs    = self.weather_glyphs.as_ref();
             let moon_phase_glyphs = self.moon_phase_glyphs.as_ref();
 
@@ -1320,6 +1586,7 @@ impl DisplayManager {
                     &page,
                     &svg_path,
                     &conditions_text,
+                    conditions_marquee,
                     &temp_text,
                     &humidity_text,
                     &wind_text,
@@ -1333,6 +1600,7 @@ impl DisplayManager {
                     &moonphase_text,
                     weather_glyphs,
                     moon_phase_glyphs,
+                    svg_scratch,
                 )?;
             }
             crate::display::framebuffer::FrameBuffer::Gray4(fb) => {
@@ -1341,6 +1609,7 @@ impl DisplayManager {
                     &page,
                     &svg_path,
                     &conditions_text,
+                    conditions_marquee,
                     &temp_text,
                     &humidity_text,
                     &wind_text,
@@ -1354,6 +1623,7 @@ impl DisplayManager {
                     &moonphase_text,
                     weather_glyphs,
                     moon_phase_glyphs,
+                    svg_scratch,
                 )?;
             }
             crate::display::framebuffer::FrameBuffer::Rgb565(fb) => {
@@ -1362,6 +1632,7 @@ impl DisplayManager {
                     &page,
                     &svg_path,
                     &conditions_text,
+                    conditions_marquee,
                     &temp_text,
                     &humidity_text,
                     &wind_text,
@@ -1375,10 +1646,71 @@ impl DisplayManager {
                     &moonphase_text,
                     weather_glyphs,
                     moon_phase_glyphs,
+                    svg_scratch,
                 )?;
             }
         }
 
+        // Severe weather alert banner over the top of the page
+        if let Some(alert) = self.weather_display.alert() {
+            let banner = Self::alert_banner_field(&page, self.capabilities.width, self.burn_in.offset());
+            let text = alert.banner_text();
+            match &mut self.framebuffer {
+                crate::display::framebuffer::FrameBuffer::Mono(fb) => Self::render_alert_banner(fb, &banner, &text)?,
+                crate::display::framebuffer::FrameBuffer::Gray4(fb) => Self::render_alert_banner(fb, &banner, &text)?,
+                crate::display::framebuffer::FrameBuffer::Rgb565(fb) => Self::render_alert_banner(fb, &banner, &text)?,
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Alert banner placement - the layout's `weather_alert` field, else a
+    /// full width strip across the top of the panel, shifted with the page.
+    fn alert_banner_field(page: &crate::display::PageLayout, panel_width: u32, offset: Point) -> crate::display::Field {
+        use embedded_graphics::mono_font::iso_8859_13::FONT_5X8;
+        use embedded_graphics::primitives::Rectangle;
+        page.get_field("weather_alert").cloned().unwrap_or_else(|| {
+            crate::display::Field::new_text(
+                "weather_alert",
+                Rectangle::new(offset, Size::new(panel_width, FONT_5X8.character_size.height + 2)),
+                &FONT_5X8,
+            )
+            .styled_alignment(embedded_text::alignment::HorizontalAlignment::Center, embedded_text::alignment::VerticalAlignment::Middle)
+        })
+    }
+
+    /// High contrast alert banner: the field filled at full brightness (or its
+    /// `bg_color`) with the text knocked out in black and trimmed to fit.
+    fn render_alert_banner<D>(target: &mut D, field: &crate::display::Field, text: &str) -> Result<(), DisplayError>
+    where
+        D: DrawTarget,
+        crate::display::color::Color: crate::display::color_proxy::ConvertColor<D::Color>,
+    {
+        use embedded_graphics::mono_font::MonoTextStyle;
+        use embedded_graphics::mono_font::iso_8859_13::FONT_5X8;
+        use embedded_graphics::primitives::{PrimitiveStyle, Rectangle as EgRect};
+        use embedded_graphics::text::{Baseline, Text};
+        use crate::display::color::Color;
+        use crate::display::color_proxy::ConvertColor;
+
+        let fill: D::Color = field.bg_color.unwrap_or(Color::White).to_color();
+        EgRect::new(field.bounds.top_left, field.bounds.size)
+            .into_styled(PrimitiveStyle::with_fill(fill))
+            .draw(target)
+            .map_err(|_| DisplayError::DrawingError("Failed to draw alert banner".to_string()))?;
+
+        let font = field.font.unwrap_or(&FONT_5X8);
+        let text = ellipsize(text, field.width(), font);
+        let x = match field.horizontal_alignment {
+            embedded_text::alignment::HorizontalAlignment::Left => field.bounds.top_left.x,
+            _ => centred_x(field.bounds.top_left.x, field.width(), &text, font),
+        };
+        let y = field.bounds.top_left.y + (field.height() as i32 - font.character_size.height as i32) / 2;
+        let style = MonoTextStyle::new(font, Color::Black.to_color());
+        Text::with_baseline(&text, Point::new(x, y), style, Baseline::Top)
+            .draw(target)
+            .map_err(|_| DisplayError::DrawingError("Failed to draw alert text".to_string()))?;
         Ok(())
     }
 
@@ -1388,6 +1720,7 @@ impl DisplayManager {
         page: &crate::display::PageLayout,
         svg_path: &str,
         conditions_text: &str,
+        conditions_marquee: &crate::display::components::scrollers::Marquee,
         temp_text: &str,
         humidity_text: &str,
         wind_text: &str,
@@ -1401,6 +1734,7 @@ impl DisplayManager {
         moonphase_text: &str,
         weather_glyphs: Option<&crate::weather_glyph::WeatherGlyphSet>,
         moon_phase_glyphs: Option<&crate::weather_glyph::MoonPhaseGlyphSet>,
+        svg_scratch: &mut Vec<u8>,
     ) -> Result<(), DisplayError>
     where
         D: DrawTarget,
@@ -1440,9 +1774,9 @@ impl DisplayManager {
 
                         if let Ok(data) = std::fs::read_to_string(&full_path) {
                             if let Ok(renderer) = crate::svgimage::SvgImageRenderer::new(&data, icon_width, icon_height) {
-                                let mut svg_buffer = vec![0u8; D::Color::required_buffer_size(icon_width, icon_height)];
-                                if D::Color::render_to_buffer(&renderer, &mut svg_buffer).is_ok() {
-                                    D::Color::draw_buffer_to_display(&svg_buffer, icon_width, Point::new(pos.x, pos.y), target)
+                                let svg_buffer = crate::svgimage::scratch(svg_scratch, D::Color::required_buffer_size(icon_width, icon_height));
+                                if D::Color::render_to_buffer(&renderer, svg_buffer).is_ok() {
+                                    D::Color::draw_buffer_to_display(svg_buffer, icon_width, Point::new(pos.x, pos.y), target)
                                         .map_err(|_| DisplayError::DrawingError("Failed to draw weather icon".to_string()))?;
                                 }
                             }
@@ -1461,6 +1795,7 @@ impl DisplayManager {
                                 target, svg_data,
                                 pos.x, pos.y,
                                 field.width(), field.height(),
+                                svg_scratch,
                             )?;
                             used_svg = true;
                         }
@@ -1504,6 +1839,10 @@ impl DisplayManager {
                         .draw(target)
                         .map_err(|_| DisplayError::DrawingError("Failed to write pressure text".to_string()))?;
                 }
+                "conditions" if conditions_marquee.is_scrolling() => {
+                    conditions_marquee.render_field(field, target)
+                        .map_err(|_| DisplayError::DrawingError("Failed to write conditions".to_string()))?;
+                }
                 "conditions" => {
                     let style = MonoTextStyle::new(field.font.unwrap_or(&FONT_7X14), field.fg_color.to_color());
                     TextBox::with_textbox_style(conditions_text, field.bounds, style, tbstyle_center)
@@ -1534,12 +1873,12 @@ impl DisplayManager {
                         .draw(target)
                         .map_err(|_| DisplayError::DrawingError("Failed to write moonset text".to_string()))?;
                 }
-                "moonphase_svg" => {
+                "moonphase_svg" | "moon_phase" => {
                     use crate::visualization::SvgColorDepth;
                     let mut used_svg = false;
                     if D::Color::use_svg_glyphs() {
                         if let Some(svg_data) = moon_phase_glyphs.and_then(|g| g.get(moon_phase_index)) {
-                            Self::draw_svg_weather_glyph(target, svg_data, pos.x, pos.y, field.width(), field.height())?;
+                            Self::draw_svg_weather_glyph(target, svg_data, pos.x, pos.y, field.width(), field.height(), svg_scratch)?;
                             used_svg = true;
                         }
                     }
@@ -1564,7 +1903,7 @@ impl DisplayManager {
     /// Render an SVG weather glyph at the given position and pixel dimensions.
     ///
     /// Used for Gray4 / Rgb565 displays when `D::Color::use_svg_glyphs()` is true.
-    /// The SVG is scaled to fit `width × height` exactly.
+    /// The SVG is scaled to fit `width × height` exactly, rasterized into `scratch`.
     fn draw_svg_weather_glyph<D>(
         target:    &mut D,
         svg_data:  &[u8],
@@ -1572,6 +1911,7 @@ impl DisplayManager {
         y:         i32,
         width:     u32,
         height:    u32,
+        scratch:   &mut Vec<u8>,
     ) -> Result<(), DisplayError>
     where
         D: DrawTarget,
@@ -1583,11 +1923,11 @@ impl DisplayManager {
             Ok(svg_str) => match crate::svgimage::SvgImageRenderer::new(svg_str, width, height) {
                 Err(e) => log::warn!("draw_svg_weather_glyph: renderer init failed ({}x{}): {:?}", width, height, e),
                 Ok(renderer) => {
-                    let mut buf = vec![0u8; D::Color::required_buffer_size(width, height)];
-                    match D::Color::render_to_buffer(&renderer, &mut buf) {
+                    let buf = crate::svgimage::scratch(scratch, D::Color::required_buffer_size(width, height));
+                    match D::Color::render_to_buffer(&renderer, buf) {
                         Err(e) => log::warn!("draw_svg_weather_glyph: render_to_buffer failed: {:?}", e),
                         Ok(_) => {
-                            D::Color::draw_buffer_to_display(&buf, width, Point::new(x, y), target)
+                            D::Color::draw_buffer_to_display(buf, width, Point::new(x, y), target)
                                 .map_err(|_| DisplayError::DrawingError("svg weather glyph".to_string()))?;
                         }
                     }
@@ -1670,14 +2010,23 @@ impl DisplayManager {
         use embedded_graphics::mono_font::iso_8859_13::FONT_6X10;
 
         // Get the weather forecast page layout
-        let page = self.layout_manager.create_weather_forecast_page();
+        let mut page = self.layout_manager.create_weather_forecast_page().shown_at(&self.time.now());
+        page.translate(self.burn_in.offset());
 
         // Get forecast data
-        let forecast_data = self.weather_display.weather_data();
-
-        if forecast_data.len() < 4 {
-            // Not enough forecast data (need current + 3 days)
-            let msg = "Loading Forecast...";
+        let forecast_data: Vec<_> = self.weather_display.weather_data().iter()
+            .map(|d| self.weather_in_display_units(d))
+            .collect();
+
+        // Index 0 is current conditions; show the days the layout has room for,
+        // no more than configured, and only as many as the provider returned
+        let days: Vec<ForecastDayText> = forecast_data.iter().skip(1)
+            .take(forecast_days_in(&page))
+            .map(ForecastDayText::new)
+            .collect();
+
+        if days.is_empty() {
+            let msg = self.locale.text(UiText::LoadingForecast);
             match &mut self.framebuffer {
                 crate::display::framebuffer::FrameBuffer::Mono(fb) => {
                     let style = MonoTextStyle::new(&FONT_6X10, BinaryColor::On);
@@ -1701,108 +2050,23 @@ impl DisplayManager {
             return Ok(());
         }
 
-        // Get forecast days (skip index 0 which is current)
-        let day1 = &forecast_data[1];
-        let day2 = &forecast_data[2];
-        let day3 = &forecast_data[3];
-
-        // Format data for each day
-        let day1_name = day1.day.format("%a").to_string();
-        let day1_temp = format!("{}°|{}°", day1.temperature_min.round() as i32, day1.temperature_max.round() as i32);
-        let day1_precip = format!("{}%", day1.precipitation_probability_avg.round() as i32);
-        let day1_svg = day1.weather_code.svg.clone();
-
-        let day2_name = day2.day.format("%a").to_string();
-        let day2_temp = format!("{}°|{}°", day2.temperature_min.round() as i32, day2.temperature_max.round() as i32);
-        let day2_precip = format!("{}%", day2.precipitation_probability_avg.round() as i32);
-        let day2_svg = day2.weather_code.svg.clone();
-
-        let day3_name = day3.day.format("%a").to_string();
-        let day3_temp = format!("{}°|{}°", day3.temperature_min.round() as i32, day3.temperature_max.round() as i32);
-        let day3_precip = format!("{}%", day3.precipitation_probability_avg.round() as i32);
-        let day3_svg = day3.weather_code.svg.clone();
-
-        // Days 4-6 for wide displays (conditional)
-        let (day4_name, day4_temp, day4_precip, day4_svg) = if forecast_data.len() > 4 {
-            let day4 = &forecast_data[4];
-            (day4.day.format("%a").to_string(),
-             format!("{}°|{}°", day4.temperature_min.round() as i32, day4.temperature_max.round() as i32),
-             format!("{}%", day4.precipitation_probability_avg.round() as i32),
-             day4.weather_code.svg.clone())
-        } else {
-            ("".to_string(), "".to_string(), "".to_string(), "".to_string())
-        };
-
-        let (day5_name, day5_temp, day5_precip, day5_svg) = if forecast_data.len() > 5 {
-            let day5 = &forecast_data[5];
-            (day5.day.format("%a").to_string(),
-             format!("{}°|{}°", day5.temperature_min.round() as i32, day5.temperature_max.round() as i32),
-             format!("{}%", day5.precipitation_probability_avg.round() as i32),
-             day5.weather_code.svg.clone())
-        } else {
-            ("".to_string(), "".to_string(), "".to_string(), "".to_string())
-        };
-
-        let (day6_name, day6_temp, day6_precip, day6_svg) = if forecast_data.len() > 6 {
-            let day6 = &forecast_data[6];
-            (day6.day.format("%a").to_string(),
-             format!("{}°|{}°", day6.temperature_min.round() as i32, day6.temperature_max.round() as i32),
-             format!("{}%", day6.precipitation_probability_avg.round() as i32),
-             day6.weather_code.svg.clone())
-        } else {
-            ("".to_string(), "".to_string(), "".to_string(), "".to_string())
-        };
-
         // Dispatch rendering
+        let svg_scratch = self.weather_display.svg_scratch_mut();
         match &mut self.framebuffer {
-            crate::display::framebuffer::FrameBuffer::Mono(fb) => {
-                Self::render_forecast_fields(
-                    fb, &page,
-                    &day1_name, &day1_temp, &day1_precip, &day1_svg,
-                    &day2_name, &day2_temp, &day2_precip, &day2_svg,
-                    &day3_name, &day3_temp, &day3_precip, &day3_svg,
-                    &day4_name, &day4_temp, &day4_precip, &day4_svg,
-                    &day5_name, &day5_temp, &day5_precip, &day5_svg,
-                    &day6_name, &day6_temp, &day6_precip, &day6_svg,
-                )?;
-            }
-            crate::display::framebuffer::FrameBuffer::Gray4(fb) => {
-                Self::render_forecast_fields(
-                    fb, &page,
-                    &day1_name, &day1_temp, &day1_precip, &day1_svg,
-                    &day2_name, &day2_temp, &day2_precip, &day2_svg,
-                    &day3_name, &day3_temp, &day3_precip, &day3_svg,
-                    &day4_name, &day4_temp, &day4_precip, &day4_svg,
-                    &day5_name, &day5_temp, &day5_precip, &day5_svg,
-                    &day6_name, &day6_temp, &day6_precip, &day6_svg,
-                )?;
-            }
-            crate::display::framebuffer::FrameBuffer::Rgb565(fb) => {
-                Self::render_forecast_fields(
-                    fb, &page,
-                    &day1_name, &day1_temp, &day1_precip, &day1_svg,
-                    &day2_name, &day2_temp, &day2_precip, &day2_svg,
-                    &day3_name, &day3_temp, &day3_precip, &day3_svg,
-                    &day4_name, &day4_temp, &day4_precip, &day4_svg,
-                    &day5_name, &day5_temp, &day5_precip, &day5_svg,
-                    &day6_name, &day6_temp, &day6_precip, &day6_svg,
-                )?;
-            }
+            crate::display::framebuffer::FrameBuffer::Mono(fb) => Self::render_forecast_fields(fb, &page, &days, svg_scratch)?,
+            crate::display::framebuffer::FrameBuffer::Gray4(fb) => Self::render_forecast_fields(fb, &page, &days, svg_scratch)?,
+            crate::display::framebuffer::FrameBuffer::Rgb565(fb) => Self::render_forecast_fields(fb, &page, &days, svg_scratch)?,
         }
 
         Ok(())
     }
 
-    /// Render forecast fields (generic over color depth)
+    /// Render forecast fields (generic over color depth); `dayN_*` fields take `days[N - 1]`
     fn render_forecast_fields<D>(
         target: &mut D,
         page: &crate::display::PageLayout,
-        day1_name: &str, day1_temp: &str, day1_precip: &str, day1_svg: &str,
-        day2_name: &str, day2_temp: &str, day2_precip: &str, day2_svg: &str,
-        day3_name: &str, day3_temp: &str, day3_precip: &str, day3_svg: &str,
-        day4_name: &str, day4_temp: &str, day4_precip: &str, day4_svg: &str,
-        day5_name: &str, day5_temp: &str, day5_precip: &str, day5_svg: &str,
-        day6_name: &str, day6_temp: &str, day6_precip: &str, day6_svg: &str,
+        days: &[ForecastDayText],
+        svg_scratch: &mut Vec<u8>,
     ) -> Result<(), DisplayError>
     where
         D: DrawTarget,
@@ -1810,50 +2074,14 @@ impl DisplayManager {
         crate::display::color::Color: crate::display::color_proxy::ConvertColor<D::Color>,
     {
         for field in page.fields() {
-
-            match field.name.as_str() {
-                // Day 1
-                "day1_icon" => Self::render_forecast_icon(target, field, day1_svg)?,
-                "day1_name" => Self::render_centered_text(target, field, day1_name)?,
-                "day1_data_box" => Self::render_box(target, field)?,
-                "day1_temp" => Self::render_centered_text(target, field, day1_temp)?,
-                "day1_precip" => Self::render_centered_text(target, field, day1_precip)?,
-
-                // Day 2
-                "day2_icon" => Self::render_forecast_icon(target, field, day2_svg)?,
-                "day2_name" => Self::render_centered_text(target, field, day2_name)?,
-                "day2_data_box" => Self::render_box(target, field)?,
-                "day2_temp" => Self::render_centered_text(target, field, day2_temp)?,
-                "day2_precip" => Self::render_centered_text(target, field, day2_precip)?,
-
-                // Day 3
-                "day3_icon" => Self::render_forecast_icon(target, field, day3_svg)?,
-                "day3_name" => Self::render_centered_text(target, field, day3_name)?,
-                "day3_data_box" => Self::render_box(target, field)?,
-                "day3_temp" => Self::render_centered_text(target, field, day3_temp)?,
-                "day3_precip" => Self::render_centered_text(target, field, day3_precip)?,
-
-                // Day 4 (wide display)
-                "day4_icon" => Self::render_forecast_icon(target, field, day4_svg)?,
-                "day4_name" => Self::render_centered_text(target, field, day4_name)?,
-                "day4_data_box" => Self::render_box(target, field)?,
-                "day4_temp" => Self::render_centered_text(target, field, day4_temp)?,
-                "day4_precip" => Self::render_centered_text(target, field, day4_precip)?,
-
-                // Day 5 (wide display)
-                "day5_icon" => Self::render_forecast_icon(target, field, day5_svg)?,
-                "day5_name" => Self::render_centered_text(target, field, day5_name)?,
-                "day5_data_box" => Self::render_box(target, field)?,
-                "day5_temp" => Self::render_centered_text(target, field, day5_temp)?,
-                "day5_precip" => Self::render_centered_text(target, field, day5_precip)?,
-
-                // Day 6 (wide display)
-                "day6_icon" => Self::render_forecast_icon(target, field, day6_svg)?,
-                "day6_name" => Self::render_centered_text(target, field, day6_name)?,
-                "day6_data_box" => Self::render_box(target, field)?,
-                "day6_temp" => Self::render_centered_text(target, field, day6_temp)?,
-                "day6_precip" => Self::render_centered_text(target, field, day6_precip)?,
-
+            let Some((n, part)) = forecast_day_of(&field.name) else { continue };
+            let Some(day) = n.checked_sub(1).and_then(|i| days.get(i)) else { continue };
+            match part {
+                "icon" => Self::render_forecast_icon(target, field, &day.svg, svg_scratch)?,
+                "name" => Self::render_centered_text(target, field, &day.name)?,
+                "data_box" => Self::render_box(target, field)?,
+                "temp" => Self::render_centered_text(target, field, &day.temp)?,
+                "precip" => Self::render_centered_text(target, field, &day.precip)?,
                 _ => {}
             }
         }
@@ -1866,6 +2094,7 @@ impl DisplayManager {
         target: &mut D,
         field: &crate::display::Field,
         svg_path: &str,
+        svg_scratch: &mut Vec<u8>,
     ) -> Result<(), DisplayError>
     where
         D: DrawTarget,
@@ -1880,9 +2109,9 @@ impl DisplayManager {
 
             if let Ok(data) = std::fs::read_to_string(&full_path) {
                 if let Ok(renderer) = crate::svgimage::SvgImageRenderer::new(&data, icon_width, icon_height) {
-                    let mut svg_buffer = vec![0u8; D::Color::required_buffer_size(icon_width, icon_height)];
-                    if D::Color::render_to_buffer(&renderer, &mut svg_buffer).is_ok() {
-                        D::Color::draw_buffer_to_display(&svg_buffer, icon_width, field.position(), target)
+                    let svg_buffer = crate::svgimage::scratch(svg_scratch, D::Color::required_buffer_size(icon_width, icon_height));
+                    if D::Color::render_to_buffer(&renderer, svg_buffer).is_ok() {
+                        D::Color::draw_buffer_to_display(svg_buffer, icon_width, field.position(), target)
                             .map_err(|_| DisplayError::DrawingError("Failed to draw forecast icon".to_string()))?;
                     }
                 }
@@ -1910,7 +2139,12 @@ impl DisplayManager {
             .vertical_alignment(field.vertical_alignment)
             .build();
 
-        TextBox::with_textbox_style(text, field.bounds, style, textbox_style)
+        let text = match field.overflow {
+            Overflow::Ellipsis => ellipsize(text, field.width(), style.font),
+            _ => std::borrow::Cow::Borrowed(text),
+        };
+
+        TextBox::with_textbox_style(&text, field.bounds, style, textbox_style)
             .draw(target)
             .map(|_| ())
             .map_err(|_| DisplayError::DrawingError("Failed to draw text".to_string()))
@@ -1970,6 +2204,7 @@ impl DisplayManager {
         version: &str,
         build_date: &str,
         status: Option<&str>,
+        spinner: Option<char>,
     ) -> Result<(), DisplayError>
     where
         D: DrawTarget,
@@ -1983,7 +2218,7 @@ impl DisplayManager {
         for field in page.fields() {
             match field.name.as_str() {
                 "logo_svg" => {
-                    let svg_path = "./assets/lymonslogo.svg";
+                    let svg_path = crate::assets::path("lymonslogo.svg");
                     if let Ok(data) = std::fs::read_to_string(svg_path) {
                         if let Ok(renderer) = crate::svgimage::SvgImageRenderer::new(
                             &data, field.bounds.size.width, field.bounds.size.height
@@ -2013,6 +2248,12 @@ impl DisplayManager {
                         Self::draw_field_text(target, field, status_text, style)?;
                     }
                 }
+                "spinner" => {
+                    if let Some(frame) = spinner {
+                        let style = MonoTextStyle::new(field.font.unwrap_or(&embedded_graphics::mono_font::iso_8859_13::FONT_5X8), field.fg_color.to_color());
+                        Self::draw_field_text(target, field, frame.encode_utf8(&mut [0u8; 4]), style)?;
+                    }
+                }
                 _ => {}
             }
         }
@@ -2109,6 +2350,9 @@ impl DisplayManager {
                             viz_state.spectrum_history.pop_front();
                         }
                     }
+                    VizPayload::Oscilloscope { waveform } => {
+                        self.visualizer.viz_state_mut().last_waveform_m = waveform;
+                    }
                     _ => {
                         // TODO: Handle other visualization types
                     }
@@ -2161,30 +2405,12 @@ impl DisplayManager {
         if is_wide {
 
             // Wide: full scroller page layout rendered in the left half (width/2)
-            let page = self.layout_manager.create_scrolling_page(SCROLLING_AIO_WIDE_PAGE);
+            let page = self.layout_manager.create_scrolling_page(SCROLLING_AIO_WIDE_PAGE).shown_at(&self.time.now());
 
             // Update scroll positions using field widths
-            if let (
-                Some(album_artist_field), 
-                Some(album_field), 
-                Some(title_field), 
-                Some(artist_field),
-                Some(year_field)
-            ) = (
-                page.get_field("album_artist"),
-                page.get_field("album"),
-                page.get_field("title"),
-                page.get_field("artist"),
-                page.get_field("year"),
-            ) {
-                self.scrolling_text.update_with_fields(
-                    album_artist_field, 
-                    album_field, 
-                    title_field, 
-                    artist_field,
-                    year_field
-                );
-            }
+            self.scrolling_text.update_with_page(&page);
+            self.fill_info_line();
+            let info = &self.render_buffers.info_line;
 
             match &mut self.framebuffer {
                 crate::display::framebuffer::FrameBuffer::Mono(fb) => {
@@ -2200,7 +2426,7 @@ impl DisplayManager {
                                 self.status_bar.render_field(field, fb)
                                     .map_err(|_| DisplayError::DrawingError("aio status_bar".to_string()))?;
                             }
-                            "album_artist" | "album" | "title" | "artist" | "combination" | "year" => {
+                            "album_artist" | "album" | "title" | "artist" | "composer" | "conductor" | "combination" | "year" => {
                                 self.scrolling_text.render_field(field, fb)
                                     .map_err(|_| DisplayError::DrawingError(format!("aio {}", field.name)))?;
                             }
@@ -2255,30 +2481,20 @@ impl DisplayManager {
                                     .alignment(HorizontalAlignment::Right)
                                     .vertical_alignment(VerticalAlignment::Top)
                                     .build();
-                                let current_time_str = self.render_buffers.format_time(self.current_track_time_secs);
-                                TextBox::with_textbox_style(&self.mode_text, field.bounds, style, tbs_center)
+                                let [left, centre, right] = info;
+                                TextBox::with_textbox_style(centre, field.bounds, style, tbs_center)
                                     .draw(fb)
                                     .map_err(|_| DisplayError::DrawingError("aio mode text".to_string()))?;
                                 let fix_width = 30u32;
                                 let mut rect = field.bounds;
                                 rect.size.width = fix_width;
-                                TextBox::with_textbox_style(current_time_str, rect, style, tbs_left)
+                                TextBox::with_textbox_style(left, rect, style, tbs_left)
                                     .draw(fb)
                                     .map_err(|_| DisplayError::DrawingError("aio elapsed".to_string()))?;
                                 let mut rect = field.bounds;
                                 rect.top_left.x = (rect.size.width - fix_width) as i32;
                                 rect.size.width = fix_width;
-                                self.render_buffers.temp_buffer.clear();
-                                let time_secs = if self.show_remaining { self.remaining_time_secs } else { self.track_duration_secs };
-                                let mins = (time_secs as u32) / 60;
-                                let secs = (time_secs as u32) % 60;
-                                let _ = if self.show_remaining {
-                                    write!(&mut self.render_buffers.temp_buffer, "-{}:{:02}", mins, secs)
-                                } else {
-                                    write!(&mut self.render_buffers.temp_buffer, "{}:{:02}", mins, secs)
-                                };
-                                let time_str = self.render_buffers.temp_buffer.as_str();
-                                TextBox::with_textbox_style(time_str, rect, style, tbs_right)
+                                TextBox::with_textbox_style(right, rect, style, tbs_right)
                                     .draw(fb)
                                     .map_err(|_| DisplayError::DrawingError("aio duration".to_string()))?;
                             }
@@ -2300,7 +2516,7 @@ impl DisplayManager {
                                 self.status_bar.render_field(field, fb)
                                     .map_err(|_| DisplayError::DrawingError("aio status_bar".to_string()))?;
                             }
-                            "album_artist" | "album" | "title" | "artist" | "combination" | "year" => {
+                            "album_artist" | "album" | "title" | "artist" | "composer" | "conductor" | "combination" | "year" => {
                                 self.scrolling_text.render_field(field, fb)
                                     .map_err(|_| DisplayError::DrawingError(format!("aio {}", field.name)))?;
                             }
@@ -2358,30 +2574,20 @@ impl DisplayManager {
                                     .alignment(HorizontalAlignment::Right)
                                     .vertical_alignment(VerticalAlignment::Top)
                                     .build();
-                                let current_time_str = self.render_buffers.format_time(self.current_track_time_secs);
-                                TextBox::with_textbox_style(&self.mode_text, field.bounds, style, tbs_center)
+                                let [left, centre, right] = info;
+                                TextBox::with_textbox_style(centre, field.bounds, style, tbs_center)
                                     .draw(fb)
                                     .map_err(|_| DisplayError::DrawingError("aio mode text".to_string()))?;
                                 let fix_width = 30u32;
                                 let mut rect = field.bounds;
                                 rect.size.width = fix_width;
-                                TextBox::with_textbox_style(current_time_str, rect, style, tbs_left)
+                                TextBox::with_textbox_style(left, rect, style, tbs_left)
                                     .draw(fb)
                                     .map_err(|_| DisplayError::DrawingError("aio elapsed".to_string()))?;
                                 let mut rect = field.bounds;
                                 rect.top_left.x = (rect.size.width - fix_width) as i32;
                                 rect.size.width = fix_width;
-                                self.render_buffers.temp_buffer.clear();
-                                let time_secs = if self.show_remaining { self.remaining_time_secs } else { self.track_duration_secs };
-                                let mins = (time_secs as u32) / 60;
-                                let secs = (time_secs as u32) % 60;
-                                let _ = if self.show_remaining {
-                                    write!(&mut self.render_buffers.temp_buffer, "-{}:{:02}", mins, secs)
-                                } else {
-                                    write!(&mut self.render_buffers.temp_buffer, "{}:{:02}", mins, secs)
-                                };
-                                let time_str = self.render_buffers.temp_buffer.as_str();
-                                TextBox::with_textbox_style(time_str, rect, style, tbs_right)
+                                TextBox::with_textbox_style(right, rect, style, tbs_right)
                                     .draw(fb)
                                     .map_err(|_| DisplayError::DrawingError("aio duration".to_string()))?;
                             }
@@ -2403,7 +2609,7 @@ impl DisplayManager {
                                 self.status_bar.render_field(field, fb)
                                     .map_err(|_| DisplayError::DrawingError("aio status_bar".to_string()))?;
                             }
-                            "album_artist" | "album" | "title" | "artist" | "combination" | "year" => {
+                            "album_artist" | "album" | "title" | "artist" | "composer" | "conductor" | "combination" | "year" => {
                                 self.scrolling_text.render_field(field, fb)
                                     .map_err(|_| DisplayError::DrawingError(format!("aio {}", field.name)))?;
                             }
@@ -2461,30 +2667,20 @@ impl DisplayManager {
                                     .alignment(HorizontalAlignment::Right)
                                     .vertical_alignment(VerticalAlignment::Top)
                                     .build();
-                                let current_time_str = self.render_buffers.format_time(self.current_track_time_secs);
-                                TextBox::with_textbox_style(&self.mode_text, field.bounds, style, tbs_center)
+                                let [left, centre, right] = info;
+                                TextBox::with_textbox_style(centre, field.bounds, style, tbs_center)
                                     .draw(fb)
                                     .map_err(|_| DisplayError::DrawingError("aio mode text".to_string()))?;
                                 let fix_width = 30u32;
                                 let mut rect = field.bounds;
                                 rect.size.width = fix_width;
-                                TextBox::with_textbox_style(current_time_str, rect, style, tbs_left)
+                                TextBox::with_textbox_style(left, rect, style, tbs_left)
                                     .draw(fb)
                                     .map_err(|_| DisplayError::DrawingError("aio elapsed".to_string()))?;
                                 let mut rect = field.bounds;
                                 rect.top_left.x = (rect.size.width - fix_width) as i32;
                                 rect.size.width = fix_width;
-                                self.render_buffers.temp_buffer.clear();
-                                let time_secs = if self.show_remaining { self.remaining_time_secs } else { self.track_duration_secs };
-                                let mins = (time_secs as u32) / 60;
-                                let secs = (time_secs as u32) % 60;
-                                let _ = if self.show_remaining {
-                                    write!(&mut self.render_buffers.temp_buffer, "-{}:{:02}", mins, secs)
-                                } else {
-                                    write!(&mut self.render_buffers.temp_buffer, "{}:{:02}", mins, secs)
-                                };
-                                let time_str = self.render_buffers.temp_buffer.as_str();
-                                TextBox::with_textbox_style(time_str, rect, style, tbs_right)
+                                TextBox::with_textbox_style(right, rect, style, tbs_right)
                                     .draw(fb)
                                     .map_err(|_| DisplayError::DrawingError("aio duration".to_string()))?;
                             }
@@ -2501,7 +2697,7 @@ impl DisplayManager {
             use crate::display::color_proxy::ConvertColor;
 
             // Narrow: compact AIO layout (status_bar_small, current_time, track_time, combination)
-            let page = self.layout_manager.create_aio_scrolling_page();
+            let page = self.layout_manager.create_aio_scrolling_page().shown_at(&self.time.now());
 
             // Update combination scroll using field width (not full display width)
             // note combination was maintained as part of track detail update
@@ -2510,7 +2706,7 @@ impl DisplayManager {
             }
 
             // Pre-compute time strings before borrowing framebuffer
-            let time_str = chrono::Local::now().format("%H:%M").to_string();
+            let time_str = self.time.now().format("%H:%M").to_string();
             let track_secs = if self.show_remaining { self.remaining_time_secs } else { self.current_track_time_secs };
             let track_str = if self.show_remaining {
                 format!("-{}", crate::deutils::seconds_to_hms(track_secs))
@@ -2703,6 +2899,8 @@ impl DisplayManager {
 
     /// Render easter eggs
     fn render_easter_eggs(&mut self) -> Result<(), DisplayError> {
+        self.rotate_easter_egg_at(Instant::now());
+
         // Calculate track progress percentage
         let track_percent = if self.track_duration_secs > 0.0 {
             (self.current_track_time_secs / self.track_duration_secs).clamp(0.0, 1.0) as f64
@@ -2734,7 +2932,7 @@ impl DisplayManager {
 
         // Layout-driven text overlay — resolve per-egg template from layout.yaml
         let egg_name = self.easter_egg.egg_type_name().to_string();
-        let page = self.layout_manager.create_egg_page(&egg_name);
+        let page = self.layout_manager.create_egg_page(&egg_name).shown_at(&self.time.now());
 
         // Advance scroll states for every scrollable field in the egg layout
         for field in page.fields() {
@@ -2981,51 +3179,157 @@ impl DisplayManager {
         self.driver.set_brightness(brightness)
     }
 
-    /// Attach an astral service.  Enables auto-brightness and moon data fallback.
-    pub fn set_astral_service(&mut self, service: crate::astral::AstralService) {
-        self.astral_service = Some(service);
+    /// Set the hold at the start position after each loop scroll pass
+    pub fn set_scroll_loop_pause(&mut self, pause: std::time::Duration, tick: std::time::Duration) {
+        self.scrolling_text.set_loop_pause(pause, tick);
     }
 
-    /// Attach a TTF font for scrolling-text and label rendering.
-    ///
-    /// Once set, all scroller fields will render using the TTF font instead of
-    /// the MonoFont bitmaps.  Text-width measurement (for scroll loop gaps and
-    /// centering) will also use TTF metrics, giving correct results for CJK and
-    /// other variable-width scripts.
-    pub fn set_text_font(&mut self, text_font: std::sync::Arc<crate::display::ttf_font::TtfFont>) {
-        self.scrolling_text.set_ttf_font(std::sync::Arc::clone(&text_font));
-        self.ttf_font = Some(text_font);
+    /// Set the space between looping text and its repeat, `None` for the font default
+    pub fn set_scroll_loop_gap(&mut self, gap: Option<crate::textable::LoopGap>) {
+        self.scrolling_text.set_loop_gap(gap);
     }
 
-    /// Apply auto-brightness based on local sunrise/sunset.
-    /// Rate-limited to at most one hardware write every 5 minutes.
-    fn update_auto_brightness(&mut self) {
-        use std::time::Instant;
-        const CHECK_INTERVAL_SECS: u64 = 300; // 5 minutes
-        const BRIGHTNESS_DAY:   u8 = 255;
-        const BRIGHTNESS_NIGHT: u8 = 128;
+    /// Fade cylon scrolled text out over its last `frames` positions; only
+    /// grayscale panels take it, mono keeps the plain scroll
+    pub fn set_cylon_trail(&mut self, frames: usize) {
+        let gray = matches!(self.framebuffer, FrameBuffer::Gray4(_));
+        self.scrolling_text.set_cylon_trail(if gray { frames } else { 0 });
+    }
 
-        let now = Instant::now();
-        if let Some(last) = self.last_brightness_check {
-            if now.duration_since(last).as_secs() < CHECK_INTERVAL_SECS {
-                return;
-            }
-        }
-        self.last_brightness_check = Some(now);
+    /// Cycle the images in `dir` on the idle screen, one every `interval`
+    pub fn set_slideshow(&mut self, dir: &std::path::Path, interval: Duration) {
+        self.slideshow.load_dir(dir, interval);
+    }
 
-        if let Some(ref astral) = self.astral_service {
-            let brightness = if astral.is_daytime() { BRIGHTNESS_DAY } else { BRIGHTNESS_NIGHT };
-            let _ = self.set_brightness(brightness);
-        }
+    /// Whether the slideshow has anything to show
+    pub fn has_slides(&self) -> bool {
+        !self.slideshow.is_empty()
     }
 
-    /// Get current display mode
+    /// Scroll these track lines, in order, on the now-playing pages; None keeps the layout's rows
+    pub fn set_track_lines(&mut self, lines: Option<Vec<crate::display::components::scrollers::TrackTag>>) {
+        self.scrolling_text.set_lines(lines.as_deref().unwrap_or_default());
+        self.layout_manager.set_track_lines(lines);
+    }
+
+    /// Put the now-playing progress bar and info line at the top or bottom
+    pub fn set_progress_position(&mut self, position: crate::display::layout_manager::ProgressPosition) {
+        self.layout_manager.set_progress_position(position);
+    }
+
+    /// Override field foreground colours on greyscale and colour panels
+    pub fn set_color_theme(&mut self, theme: crate::display::theme::ColorTheme) {
+        self.layout_manager.set_theme(theme);
+    }
+
+    /// Keep the last `length` played tracks, persisted to `file` when given
+    pub fn set_history(&mut self, length: usize, file: Option<&std::path::Path>) {
+        self.history = Some(match file {
+            Some(path) => PlayHistory::with_file(length, path),
+            None => PlayHistory::new(length),
+        });
+    }
+
+    /// Show now playing as the title alone in big text
+    pub fn set_big_title(&mut self, on: bool) {
+        self.show_big_title = on;
+    }
+
+    pub fn shows_big_title(&self) -> bool {
+        self.show_big_title
+    }
+
+    /// Whether the recently played screen is configured and fits this panel
+    pub fn shows_history(&self) -> bool {
+        self.history.is_some() && self.layout_manager.create_history_page().is_some()
+    }
+
+    /// Note the current track in the recently played list
+    pub fn record_played(&mut self, artist: &str, title: &str) {
+        if let Some(history) = &mut self.history {
+            history.push(artist, title);
+        }
+    }
+
+    /// Shift the clock and weather pages by up to `max_shift_px` every `interval`
+    /// to spread OLED wear
+    pub fn set_burn_in_protection(&mut self, enabled: bool, max_shift_px: u8, interval: std::time::Duration) {
+        self.burn_in.configure(enabled, max_shift_px, interval);
+    }
+
+    /// Choose the metrics line content and temperature units
+    pub fn set_metrics_format(&mut self, format: MetricsFormat) {
+        self.metrics_format = format;
+    }
+
+    /// Calibrate the VU needles for sources that run hot or quiet
+    pub fn set_vu_calibration(&mut self, cal: &crate::vuphysics_new::VuCalibration) {
+        self.visualizer.set_vu_calibration(cal);
+    }
+
+    /// Peak meter hold marker: hold for `hold`, then fall at `decay_rate` LEDs per second
+    pub fn set_peak_hold(&mut self, hold: Duration, decay_rate: f32) {
+        self.visualizer.set_peak_hold(hold, decay_rate);
+    }
+
+    /// Arrange the combination visualizer's VU faces and peak column
+    pub fn set_combi_layout(&mut self, combi: crate::visualization::CombiLayout) {
+        self.visualizer.set_combi_layout(combi);
+    }
+
+    /// Attach an astral service.  Enables auto-brightness and moon data fallback.
+    pub fn set_astral_service(&mut self, service: crate::astral::AstralService) {
+        self.astral_service = Some(service);
+    }
+
+    /// Attach a TTF font for scrolling-text and label rendering.
+    ///
+    /// Once set, all scroller fields will render using the TTF font instead of
+    /// the MonoFont bitmaps.  Text-width measurement (for scroll loop gaps and
+    /// centering) will also use TTF metrics, giving correct results for CJK and
+    /// other variable-width scripts.
+    pub fn set_text_font(&mut self, text_font: std::sync::Arc<crate::display::ttf_font::TtfFont>) {
+        self.scrolling_text.set_ttf_font(std::sync::Arc::clone(&text_font));
+        self.ttf_font = Some(text_font);
+    }
+
+    /// Apply auto-brightness based on local sunrise/sunset.
+    /// Rate-limited to at most one hardware write every 5 minutes.
+    fn update_auto_brightness(&mut self) {
+        use std::time::Instant;
+        const CHECK_INTERVAL_SECS: u64 = 300; // 5 minutes
+        const BRIGHTNESS_DAY:   u8 = 255;
+        const BRIGHTNESS_NIGHT: u8 = 128;
+
+        let now = Instant::now();
+        if let Some(last) = self.last_brightness_check {
+            if now.duration_since(last).as_secs() < CHECK_INTERVAL_SECS {
+                return;
+            }
+        }
+        self.last_brightness_check = Some(now);
+
+        if let Some(ref astral) = self.astral_service {
+            let brightness = if astral.is_daytime() { BRIGHTNESS_DAY } else { BRIGHTNESS_NIGHT };
+            // a dimmed idle screen picks this up when playback wakes it
+            self.awake_brightness = brightness;
+            if !self.idle_dimmed {
+                let _ = self.set_brightness(brightness);
+            }
+        }
+    }
+
+    /// Get current display mode
     pub fn display_mode(&self) -> DisplayMode {
         self.current_mode
     }
 
     /// Set display mode
     pub fn set_display_mode(&mut self, mode: DisplayMode) {
+        if mode != DisplayMode::EasterEggs {
+            // egg rotation restarts its interval the next time eggs come up
+            self.egg_shown_at = None;
+        }
         self.current_mode = mode;
     }
 
@@ -3096,9 +3400,218 @@ impl DisplayManager {
         self.current_mode = crate::display::DisplayMode::Clock;
     }
 
+    /// Current slide, or the clock when there is nothing left to show
+    fn render_slideshow(&mut self) -> Result<(), DisplayError> {
+        self.slideshow.advance_at(Instant::now());
+        let drawn = match &mut self.framebuffer {
+            crate::display::framebuffer::FrameBuffer::Mono(fb) => self.slideshow.draw(fb),
+            crate::display::framebuffer::FrameBuffer::Gray4(fb) => self.slideshow.draw(fb),
+            crate::display::framebuffer::FrameBuffer::Rgb565(fb) => self.slideshow.draw(fb),
+        }
+        .map_err(|_| DisplayError::DrawingError("Failed to draw slide".to_string()))?;
+        if !drawn {
+            return self.render_clock();
+        }
+        Ok(())
+    }
+
+    /// Recently played list, or the clock until something has played
+    fn render_history(&mut self) -> Result<(), DisplayError> {
+        let page = match (&self.history, self.layout_manager.create_history_page()) {
+            (Some(history), Some(page)) if !history.is_empty() => page.shown_at(&self.time.now()),
+            _ => return self.render_clock(),
+        };
+        let lines: Vec<String> = self.history.iter()
+            .flat_map(|h| h.recent().map(|p| p.line()))
+            .collect();
+        let title = self.locale.text(UiText::RecentlyPlayed);
+        match &mut self.framebuffer {
+            crate::display::framebuffer::FrameBuffer::Mono(fb) => Self::render_history_page(fb, &page, title, &lines),
+            crate::display::framebuffer::FrameBuffer::Gray4(fb) => Self::render_history_page(fb, &page, title, &lines),
+            crate::display::framebuffer::FrameBuffer::Rgb565(fb) => Self::render_history_page(fb, &page, title, &lines),
+        }
+    }
+
+    /// Title alone, in the largest font that fits, over a thin progress line
+    fn render_big_title(&mut self) -> Result<(), DisplayError> {
+        let page = self.layout_manager.create_big_title_page().shown_at(&self.time.now());
+        let row = page.get_field("big_title").map(|field| self.big_title.row(field));
+        if let Some((row, true)) = &row {
+            self.big_title.update(row);
+        }
+        let progress = (self.track_duration_secs > 0.0).then(||
+            (self.current_track_time_secs / self.track_duration_secs).clamp(0.0, 1.0)
+        );
+        let row = row.as_ref().map(|(row, scrolls)| (row, *scrolls));
+        match &mut self.framebuffer {
+            crate::display::framebuffer::FrameBuffer::Mono(fb) => Self::render_big_title_page(fb, &page, &self.big_title, row, progress),
+            crate::display::framebuffer::FrameBuffer::Gray4(fb) => Self::render_big_title_page(fb, &page, &self.big_title, row, progress),
+            crate::display::framebuffer::FrameBuffer::Rgb565(fb) => Self::render_big_title_page(fb, &page, &self.big_title, row, progress),
+        }
+    }
+
+    fn render_big_title_page<D>(
+        fb: &mut D,
+        page: &crate::display::PageLayout,
+        big_title: &BigTitle,
+        row: Option<(&crate::display::Field, bool)>,
+        progress: Option<f32>,
+    ) -> Result<(), DisplayError>
+    where
+        D: DrawTarget,
+        D::Color: PixelColor + Default,
+        crate::display::color::Color: crate::display::color_proxy::ConvertColor<D::Color>,
+    {
+        use embedded_graphics::mono_font::{MonoTextStyle, iso_8859_13::FONT_5X8};
+        use crate::display::color_proxy::ConvertColor;
+
+        if let Some((row, scrolls)) = row {
+            if scrolls {
+                big_title.render_scrolling(row, fb)
+                    .map_err(|_| DisplayError::DrawingError("Failed to render big title".to_string()))?;
+            } else {
+                let style = MonoTextStyle::new(row.font.unwrap_or(&FONT_5X8), row.fg_color.to_color());
+                Self::draw_field_text(fb, row, big_title.title(), style)?;
+            }
+        }
+        // too thin for an outline: just the elapsed part
+        if let (Some(field), Some(p)) = (page.get_field("track_progress_bar"), progress) {
+            draw_progress_fill(fb, field.bounds, p, field.progress_style, field.progress_fill().to_color())
+                .map_err(|_| DisplayError::DrawingError("Failed to draw progress bar".to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Now playing QR code over the playing modes, raised on demand or on the interval
+    fn render_qr_overlay(&mut self, now: Instant) -> Result<(), DisplayError> {
+        let playing = matches!(self.current_mode, DisplayMode::Scrolling | DisplayMode::BigTitle | DisplayMode::Visualizer);
+        if let Some(every) = self.qr_interval
+            && playing
+            && self.qr_shown_at.is_none_or(|at| now.duration_since(at) >= every)
+        {
+            self.show_qr_at(now);
+        }
+        if !playing || !self.qr_overlay_active_at(now) {
+            self.qr_overlay_until = None;
+            return Ok(());
+        }
+        let area = embedded_graphics::primitives::Rectangle::new(Point::zero(), Size::new(self.capabilities.width, self.capabilities.height));
+        let qr = &self.now_playing_qr;
+        let err = |_| DisplayError::DrawingError("qr_overlay".to_string());
+        match &mut self.framebuffer {
+            crate::display::framebuffer::FrameBuffer::Mono(fb) => qr.render(fb, area).map_err(err),
+            crate::display::framebuffer::FrameBuffer::Gray4(fb) => qr.render(fb, area).map_err(err),
+            crate::display::framebuffer::FrameBuffer::Rgb565(fb) => qr.render(fb, area).map_err(err),
+        }?;
+        Ok(())
+    }
+
+    /// Sleep countdown in the corner of every mode
+    fn render_sleep_timer(&mut self, now: Instant) -> Result<(), DisplayError> {
+        let area = embedded_graphics::primitives::Rectangle::new(Point::zero(), Size::new(self.capabilities.width, self.capabilities.height));
+        let timer = &self.sleep_timer;
+        let err = |_| DisplayError::DrawingError("sleep_timer".to_string());
+        match &mut self.framebuffer {
+            crate::display::framebuffer::FrameBuffer::Mono(fb) => timer.render(fb, area, now).map_err(err),
+            crate::display::framebuffer::FrameBuffer::Gray4(fb) => timer.render(fb, area, now).map_err(err),
+            crate::display::framebuffer::FrameBuffer::Rgb565(fb) => timer.render(fb, area, now).map_err(err),
+        }
+    }
+
+    /// Centred volume bar and percentage over whatever the mode drew
+    fn render_volume_overlay(&mut self, now: Instant) -> Result<(), DisplayError> {
+        if !self.volume_overlay_active_at(now) {
+            self.volume_overlay_until = None;
+            return Ok(());
+        }
+        let Some((volume, muted)) = self.last_volume else { return Ok(()) };
+        let (width, height) = (self.capabilities.width, self.capabilities.height);
+        match &mut self.framebuffer {
+            crate::display::framebuffer::FrameBuffer::Mono(fb) => Self::render_volume_box(fb, width, height, volume, muted),
+            crate::display::framebuffer::FrameBuffer::Gray4(fb) => Self::render_volume_box(fb, width, height, volume, muted),
+            crate::display::framebuffer::FrameBuffer::Rgb565(fb) => Self::render_volume_box(fb, width, height, volume, muted),
+        }
+    }
+
+    fn render_volume_box<D>(fb: &mut D, width: u32, height: u32, volume: u8, muted: bool) -> Result<(), DisplayError>
+    where
+        D: DrawTarget,
+        crate::display::color::Color: crate::display::color_proxy::ConvertColor<D::Color>,
+    {
+        use embedded_graphics::mono_font::{MonoTextStyle, iso_8859_13::FONT_6X10};
+        use embedded_graphics::primitives::{PrimitiveStyle, PrimitiveStyleBuilder, Rectangle};
+        use embedded_graphics::text::{Alignment, Baseline, Text, TextStyleBuilder};
+        use crate::display::color::Color;
+        use crate::display::color_proxy::ConvertColor;
+        use crate::display::progress::ProgressStyle;
+
+        let fg: D::Color = Color::White.to_color();
+        let bg: D::Color = Color::Black.to_color();
+        let err = |_| DisplayError::DrawingError("volume_overlay".to_string());
+
+        // box is 3/4 of the panel wide, text row over a bar
+        let box_w = (width * 3 / 4).max(40);
+        let box_h = 26.min(height);
+        let top_left = Point::new((width as i32 - box_w as i32) / 2, (height as i32 - box_h as i32) / 2);
+        Rectangle::new(top_left, Size::new(box_w, box_h))
+            .into_styled(PrimitiveStyleBuilder::new().fill_color(bg).stroke_color(fg).stroke_width(1).build())
+            .draw(fb)
+            .map_err(err)?;
+
+        let label = if muted || volume == 0 { "Mute".to_string() } else { format!("Volume {volume}%") };
+        let text_style = TextStyleBuilder::new().alignment(Alignment::Center).baseline(Baseline::Top).build();
+        Text::with_text_style(&label, top_left + Point::new(box_w as i32 / 2, 3), MonoTextStyle::new(&FONT_6X10, fg), text_style)
+            .draw(fb)
+            .map_err(err)?;
+
+        let bar = Rectangle::new(top_left + Point::new(4, 15), Size::new(box_w.saturating_sub(8), 7));
+        bar.into_styled(PrimitiveStyle::with_stroke(fg, 1)).draw(fb).map_err(err)?;
+        let level = if muted { 0.0 } else { volume as f32 / 100.0 };
+        draw_progress_fill(fb, bar.offset(-2), level, ProgressStyle::Filled, fg).map_err(err)
+    }
+
+    fn render_history_page<D>(
+        fb: &mut D,
+        page: &crate::display::PageLayout,
+        title: &str,
+        lines: &[String],
+    ) -> Result<(), DisplayError>
+    where
+        D: DrawTarget,
+        crate::display::color::Color: crate::display::color_proxy::ConvertColor<D::Color>,
+    {
+        use embedded_graphics::mono_font::{MonoTextStyle, iso_8859_13::FONT_5X8};
+        use embedded_graphics::text::{Text, Baseline};
+        use crate::display::color_proxy::ConvertColor;
+
+        for field in page.fields() {
+            let color: D::Color = field.fg_color.to_color();
+            let font = field.font.unwrap_or(&FONT_5X8);
+            let style = MonoTextStyle::new(font, color);
+            let rows: Vec<&str> = match field.name.as_str() {
+                "history_title" => vec![title],
+                "history_list" => lines.iter().map(String::as_str).collect(),
+                _ => continue,
+            };
+            // one row per track, cut at the field edge rather than wrapped
+            let pitch = font.character_size.width + font.character_spacing;
+            let max_chars = ((field.width() + font.character_spacing) / pitch.max(1)) as usize;
+            let row_h = font.character_size.height as i32;
+            let max_rows = (field.height() as i32 / row_h.max(1)) as usize;
+            for (row, text) in rows.into_iter().take(max_rows).enumerate() {
+                let text: String = text.chars().take(max_chars).collect();
+                let at = field.position() + Point::new(0, row as i32 * row_h);
+                Text::with_baseline(&text, at, style, Baseline::Top)
+                    .draw(fb)
+                    .map_err(|_| DisplayError::DrawingError(field.name.clone()))?;
+            }
+        }
+        Ok(())
+    }
+
     /// Render the warning/error page.
     fn render_warning(&mut self) -> Result<(), DisplayError> {
-        let page = self.layout_manager.create_warning_page();
+        let page = self.layout_manager.create_warning_page().shown_at(&self.time.now());
 
         let title  = self.warning_title.clone();
         let detail = self.warning_detail.clone();
@@ -3147,6 +3660,7 @@ impl DisplayManager {
                     use crate::visualization::SvgColorDepth;
                     if D::Color::use_svg_glyphs() {
                         if let Some(svg_bytes) = alert_svg {
+                            // warnings are rare, no scratch kept for them
                             let _ = Self::draw_svg_weather_glyph(
                                 fb,
                                 svg_bytes,
@@ -3154,6 +3668,7 @@ impl DisplayManager {
                                 field.position().y,
                                 field.width(),
                                 field.height(),
+                                &mut Vec::new(),
                             );
                         }
                     }
@@ -3235,6 +3750,7 @@ impl DisplayManager {
 
         self.status_bar.set_volume(volume);
         self.status_bar.set_muted(is_muted);
+        self.note_volume_at(volume, is_muted, Instant::now());
 
         let sb_repeat = match repeat {
             RepeatMode::Off => SBRepeatMode::Off,
@@ -3251,13 +3767,14 @@ impl DisplayManager {
         self.status_bar.set_shuffle_mode(sb_shuffle);
 
         self.status_bar.set_bitrate(&samplerate, &samplesize);
+        self.info_bitrate = crate::display::components::info_line::bitrate_text(&samplesize, &samplerate);
 
         // Determine audio level for easter eggs (SD=1, HD=2, DSD=3, None=0)
         let samp_size: u32 = samplesize.parse().unwrap_or(0);
         let samp_rate: u32 = samplerate.parse().unwrap_or(0);
 
-        self.audio_level = if samplesize.to_uppercase().contains("DSD") || samplerate.to_uppercase().contains("DSD") {
-            3 // DSD
+        self.audio_level = if crate::display::components::status_bar::dsd_label(&samplesize, &samplerate).is_some() {
+            3 // DSD, any rate
         } else if samp_size >= 24 || samp_rate > 44100 {
             2 // HD
         } else if samp_size > 0 && samp_rate > 0 {
@@ -3267,35 +3784,146 @@ impl DisplayManager {
         };
     }
 
-    /// Set track details (artist, album, title, album_artist)
+    /// Set the track's LMS content type, drawn as a lossless/lossy glyph in the status bar
+    pub fn set_audio_format(&mut self, format: &str) {
+        self.status_bar.set_format(format);
+    }
+
+    /// Raise the volume overlay when volume or mute differ from the last status.
+    ///
+    /// The first status after start-up only records the level, so connecting
+    /// to a player does not flash the bar.
+    pub fn note_volume_at(&mut self, volume: u8, is_muted: bool, now: Instant) {
+        let current = (volume.min(100), is_muted);
+        if self.last_volume.is_some_and(|last| last != current) {
+            self.volume_overlay_until = Some(now + VOLUME_OVERLAY_HOLD);
+        }
+        self.last_volume = Some(current);
+    }
+
+    /// Build the now playing QR code from `template` (a URL with {artist},
+    /// {title} and {album}, None for the plain text) and show it every
+    /// `interval` while playing, None on demand only
+    pub fn set_qr_code(&mut self, template: Option<String>, interval: Option<Duration>) {
+        self.qr_template = template;
+        self.qr_interval = interval;
+    }
+
+    /// Count down `duration` to sleep, replacing a running timer
+    pub fn start_sleep_timer(&mut self, duration: Duration) {
+        self.sleep_timer.start(duration, self.time.instant());
+        self.sleep_expired.store(false, std::sync::atomic::Ordering::Relaxed);
+    }
+
+    pub fn cancel_sleep_timer(&mut self) {
+        self.sleep_timer.cancel();
+    }
+
+    pub fn sleep_timer_running(&self) -> bool {
+        self.sleep_timer.is_running()
+    }
+
+    /// Time left on the sleep timer, None when not running
+    pub fn sleep_timer_remaining(&self) -> Option<Duration> {
+        self.sleep_timer.remaining_at(self.time.instant())
+    }
+
+    /// Expire the sleep timer once its deadline passes; called every loop
+    /// iteration so it runs out on time even while the panel is blank or off
+    pub fn update_sleep_timer(&mut self) {
+        if self.sleep_timer.tick(self.time.instant()) {
+            info!("Sleep timer ran out");
+        }
+    }
+
+    /// True once after the sleep timer runs out
+    pub fn take_sleep_timer_expired(&self) -> bool {
+        self.sleep_expired.swap(false, std::sync::atomic::Ordering::Relaxed)
+    }
+
+    /// Raise the now playing QR code for `QR_OVERLAY_HOLD`; nothing without a track
+    pub fn show_qr_at(&mut self, now: Instant) {
+        if self.now_playing_qr.code().is_some() {
+            self.qr_overlay_until = Some(now + QR_OVERLAY_HOLD);
+            self.qr_shown_at = Some(now);
+        }
+    }
+
+    /// Whether the QR code is drawn at `now`
+    pub fn qr_overlay_active_at(&self, now: Instant) -> bool {
+        self.qr_overlay_until.is_some_and(|until| now < until)
+    }
+
+    /// Text the now playing QR code holds
+    pub fn qr_text(&self) -> &str {
+        self.now_playing_qr.text()
+    }
+
+    /// Deadline of the volume overlay, None when it has not been raised
+    pub fn volume_overlay_until(&self) -> Option<Instant> {
+        self.volume_overlay_until
+    }
+
+    /// Whether the volume overlay is drawn at `now`
+    pub fn volume_overlay_active_at(&self, now: Instant) -> bool {
+        self.volume_overlay_until.is_some_and(|until| now < until)
+    }
+
+    /// Set the upcoming track shown on the larger scrolling layouts; None hides the line.
+    pub fn set_next_track(&mut self, next_track: Option<String>) {
+        self.next_track = next_track
+            .filter(|t| !t.trim().is_empty())
+            .map(|t| match self.bitmap_font_coverage() {
+                Some(font) => crate::textable::sanitize_for_font(&t, font).into_owned(),
+                None => t,
+            });
+    }
+
+    /// Swap glyphs the bitmap fonts lack for stand-ins or a placeholder (the default);
+    /// off passes track text through as tagged
+    pub fn set_sanitize_text(&mut self, on: bool) {
+        self.sanitize_text = on;
+    }
+
+    /// Font whose glyphs track text is fitted to, None when sanitizing is off.
+    /// The layout fonts are all ISO 8859-13, so one stands for them all.
+    fn bitmap_font_coverage(&self) -> Option<&'static embedded_graphics::mono_font::MonoFont<'static>> {
+        self.sanitize_text.then_some(&embedded_graphics::mono_font::iso_8859_13::FONT_5X8)
+    }
+
+    /// Set track details (artist, album, title, album_artist, ...)
     pub async fn set_track_details(
         &mut self,
-        album_artist: String,
-        album: String,
-        title: String,
-        artist: String,
-        year: String,
+        track: crate::display::components::scrollers::TrackInfo,
         _scroll_mode: &str,
         coverid: &str,
         lms_host: &str,
         lms_port: u16,
         player_mac: &str,
     ) {
+        // the QR code carries the tags as they are
+        self.now_playing_qr.set_text(&crate::display::components::qr_code::qr_text(
+            self.qr_template.as_deref(), &track.artist, &track.title, &track.album,
+        ));
+
+        // the big title is always bitmap; a TTF text font brings its own coverage to the scrollers
+        let mono = self.bitmap_font_coverage();
+        let big_title = mono.map_or(track.title.clone(), |font| crate::textable::sanitize_for_font(&track.title, font).into_owned());
+        let track = match mono {
+            Some(font) if self.ttf_font.is_none() => track.sanitized_for(font),
+            _ => track,
+        };
+
         // Store for easter eggs
-        self.artist = artist.clone();
-        self.title = title.clone();
-        self.album_artist = album_artist.clone();
-        self.album = album.clone();
-        self.year = year.clone();
+        self.artist = track.artist.clone();
+        self.title = track.title.clone();
+        self.album_artist = track.album_artist.clone();
+        self.album = track.album.clone();
+        self.year = track.year.clone();
 
         // Update scrolling text component
-        self.scrolling_text.set_full_track_info(
-            album_artist,
-            album,
-            title,
-            artist,
-            year,
-        );
+        self.scrolling_text.set_full_track_info(&track);
+        self.big_title.set_title(&big_title);
 
         // Note: update() is called in render_scrolling() on each frame
 
@@ -3315,6 +3943,20 @@ impl DisplayManager {
         }
     }
 
+    /// Flip the right-hand track time between total and remaining; returns the new setting.
+    /// Picked up by the next frame.
+    pub fn toggle_show_remaining(&mut self) -> bool {
+        self.show_remaining = !self.show_remaining;
+        self.show_remaining
+    }
+
+    /// Right-hand track time: `-m:ss` remaining or `m:ss` total
+    pub fn end_time_text(&self) -> String {
+        let time_secs = if self.show_remaining { self.remaining_time_secs } else { self.track_duration_secs };
+        let (m, s) = ((time_secs as u32) / 60, (time_secs as u32) % 60);
+        if self.show_remaining { format!("-{}:{:02}", m, s) } else { format!("{}:{:02}", m, s) }
+    }
+
     /// Set track progress data (duration, elapsed, remaining, mode)
     pub fn set_track_progress_data(
         &mut self,
@@ -3328,12 +3970,101 @@ impl DisplayManager {
         self.track_duration_secs = duration;
         self.current_track_time_secs = elapsed;
         self.remaining_time_secs = remaining;
-        self.mode_text = mode;
+        self.mode_text = self.mode_labels.text_for(&mode);
+    }
+
+    /// Show player states with these labels from the next update, e.g. translated ones
+    pub fn set_mode_labels(&mut self, labels: crate::translate::ModeLabels) {
+        self.mode_labels = labels;
+    }
+
+    /// Choose what the info line's three slots show
+    pub fn set_info_line(&mut self, slots: InfoLineSlots) {
+        self.info_slots = slots;
+    }
+
+    /// Format the current track's info line into `render_buffers.info_line`
+    fn fill_info_line(&mut self) {
+        self.info_slots.fill(&InfoLineData {
+            elapsed: self.current_track_time_secs,
+            duration: self.track_duration_secs,
+            remaining: self.remaining_time_secs,
+            show_remaining: self.show_remaining,
+            mode: &self.mode_text,
+            bitrate: &self.info_bitrate,
+            playlist_pos: self.playlist_pos,
+        }, &mut self.render_buffers.info_line)
+    }
+
+    /// Draw the fixed display strings and player states in `locale`
+    pub fn set_locale(&mut self, locale: crate::translate::Locale) {
+        self.locale = locale;
+        self.mode_labels = crate::translate::ModeLabels::for_locale(locale);
+    }
+
+    /// Push a player update into the status bar, track lines and progress.
+    ///
+    /// Every panel takes the same `NowPlaying`, so several managers can be fed
+    /// from one LMS connection.
+    pub async fn apply_now_playing(&mut self, now: &crate::sliminfo::NowPlaying, show_remaining: bool) {
+        let info = &now.info;
+        let repeat = match info.repeat {
+            1 => RepeatMode::RepeatAll,
+            2 => RepeatMode::RepeatOne,
+            _ => RepeatMode::Off,
+        };
+        let shuffle = match info.shuffle {
+            1 => ShuffleMode::ByTracks,
+            2 => ShuffleMode::ByAlbums,
+            _ => ShuffleMode::Off,
+        };
+        self.set_status_line_data(
+            info.volume,
+            info.volume == 0,
+            info.samplesize.to_string(),
+            info.samplerate.to_string(),
+            repeat,
+            shuffle,
+        );
+        self.set_audio_format(&info.format);
+
+        self.set_track_details(
+            crate::display::components::scrollers::TrackInfo {
+                album_artist: info.albumartist.clone(),
+                album: info.album.clone(),
+                title: info.title.clone(),
+                artist: info.artist.clone(),
+                composer: info.composer.clone(),
+                conductor: info.conductor.clone(),
+                year: info.year.clone(),
+            },
+            "",
+            &info.coverid,
+            &now.lms_host,
+            now.lms_port,
+            &now.player_mac,
+        ).await;
+        self.set_next_track(info.next_track_text());
+        self.playlist_pos = info.playlist_position();
+        self.record_played(&info.artist, &info.title);
+
+        self.set_track_progress_data(
+            show_remaining,
+            info.duration.raw as f32,
+            info.tracktime.raw as f32,
+            info.remaining.raw as f32,
+            info.mode.clone(),
+        );
     }
 
     /// Stub methods for OledDisplay compatibility
     pub fn connections(&mut self, _inet: &str, _eth0: &str, _wlan0: &str) {}
 
+    /// Hold the splash this long after drawing it; zero draws it and returns
+    pub fn set_splash_hold(&mut self, hold: Duration) {
+        self.splash_hold = hold;
+    }
+
     /// Display splash screen with logo, version, and build date
     pub async fn splash(&mut self, show: bool, version: &str, build_date: &str) -> Result<(), DisplayError> {
         if !show {
@@ -3343,6 +4074,7 @@ impl DisplayManager {
 
         // Store splash state for status updates
         self.splash_active = true;
+        self.splash_spinner = 0;
         self.splash_version = version.to_string();
         self.splash_build_date = build_date.to_string();
 
@@ -3357,24 +4089,57 @@ impl DisplayManager {
         // Render based on framebuffer type
         match &mut self.framebuffer {
             crate::display::framebuffer::FrameBuffer::Mono(fb) => {
-                Self::render_splash(fb, &splash_page, version, build_date, None)?;
+                Self::render_splash(fb, &splash_page, version, build_date, None, None)?;
             }
             crate::display::framebuffer::FrameBuffer::Gray4(fb) => {
-                Self::render_splash(fb, &splash_page, version, build_date, None)?;
+                Self::render_splash(fb, &splash_page, version, build_date, None, None)?;
             }
             crate::display::framebuffer::FrameBuffer::Rgb565(fb) => {
-                Self::render_splash(fb, &splash_page, version, build_date, None)?;
+                Self::render_splash(fb, &splash_page, version, build_date, None, None)?;
             }
         }
 
         // Transfer framebuffer to driver, then flush to hardware/window
-        let buffer_data = self.framebuffer.to_packed_bytes();
-        self.driver.write_buffer(&buffer_data)?;
-        self.driver.flush()?;
+        let buffer_data = self.framebuffer.to_packed_bytes_rotated(self.rotation);
+        self.transfer_frame(&buffer_data).await?;
+
+        // Hold splash for a moment, unless init should get on with it
+        if !self.splash_hold.is_zero() {
+            tokio::time::sleep(self.splash_hold).await;
+        }
+
+        Ok(())
+    }
+
+    /// Run the panel self-test: full-on, full-off, checkerboard, sweep and a border box.
+    /// Gray4 panels sweep through all 16 gray levels. Leaves the panel cleared.
+    pub async fn run_panel_test(&mut self) -> Result<(), DisplayError> {
+        use crate::display::framebuffer::FrameBuffer;
+        use crate::display::selftest::{draw_panel_test_step, panel_test_steps};
+
+        info!("Running panel self-test...");
+        for step in panel_test_steps() {
+            match &mut self.framebuffer {
+                FrameBuffer::Mono(fb) => draw_panel_test_step(fb, step, |k| {
+                    if k == 0 { BinaryColor::Off } else { BinaryColor::On }
+                }),
+                FrameBuffer::Gray4(fb) => draw_panel_test_step(fb, step, Gray4::new),
+                FrameBuffer::Rgb565(fb) => draw_panel_test_step(fb, step, |k| {
+                    let v = k * 17; // 0..=255
+                    Rgb565::new(v >> 3, v >> 2, v >> 3)
+                }),
+            }
+            .map_err(|_| DisplayError::DrawingError(format!("Failed to draw self-test {:?}", step)))?;
 
-        // Hold splash for a moment
-        tokio::time::sleep(std::time::Duration::from_millis(2000)).await;
+            let buffer_data = self.framebuffer.to_packed_bytes_rotated(self.rotation);
+            self.transfer_frame(&buffer_data).await?;
+            tokio::time::sleep(std::time::Duration::from_millis(step.hold_ms())).await;
+        }
 
+        self.framebuffer.clear();
+        let buffer_data = self.framebuffer.to_packed_bytes_rotated(self.rotation);
+        self.transfer_frame(&buffer_data).await?;
+        info!("Panel self-test complete");
         Ok(())
     }
 
@@ -3387,23 +4152,26 @@ impl DisplayManager {
         // Get splash page layout
         let splash_page = self.layout_manager.create_splash_page();
 
+        // Advance the spinner so a slow step still shows signs of life
+        let spinner = splash_spinner_frame(self.splash_spinner);
+        self.splash_spinner = self.splash_spinner.wrapping_add(1);
+
         // Render with status message (text routine clears its own rect — no full clear needed)
         match &mut self.framebuffer {
             crate::display::framebuffer::FrameBuffer::Mono(fb) => {
-                Self::render_splash(fb, &splash_page, &self.splash_version, &self.splash_build_date, Some(status))?;
+                Self::render_splash(fb, &splash_page, &self.splash_version, &self.splash_build_date, Some(status), Some(spinner))?;
             }
             crate::display::framebuffer::FrameBuffer::Gray4(fb) => {
-                Self::render_splash(fb, &splash_page, &self.splash_version, &self.splash_build_date, Some(status))?;
+                Self::render_splash(fb, &splash_page, &self.splash_version, &self.splash_build_date, Some(status), Some(spinner))?;
             }
             crate::display::framebuffer::FrameBuffer::Rgb565(fb) => {
-                Self::render_splash(fb, &splash_page, &self.splash_version, &self.splash_build_date, Some(status))?;
+                Self::render_splash(fb, &splash_page, &self.splash_version, &self.splash_build_date, Some(status), Some(spinner))?;
             }
         }
 
         // Transfer framebuffer to driver, then flush to hardware/window
-        let buffer_data = self.framebuffer.to_packed_bytes();
-        self.driver.write_buffer(&buffer_data)?;
-        self.driver.flush()?;
+        let buffer_data = self.framebuffer.to_packed_bytes_rotated(self.rotation);
+        self.send_frame(&buffer_data)?;
 
         Ok(())
     }
@@ -3419,24 +4187,24 @@ impl DisplayManager {
         }
 
         info!("Setting up weather (api configured, lat={:?}, lon={:?})", cfg.latitude, cfg.longitude);
+        self.layout_manager.set_forecast_days(cfg.forecast_days);
+        self.temperature_shown = cfg.temperature.unwrap_or_default();
 
         let mut weather = Weather::new(cfg).await
             .map_err(|e| DisplayError::InitializationFailed(format!("Failed to create Weather: {}", e)))?;
 
+        // Seed from the disk cache so an offline boot still has something to show
+        let cached = weather.load_cached();
+
         match weather.fetch_weather_data().await {
             Ok(_) => info!("Initial weather data fetched successfully"),
+            Err(e) if cached => error!("Failed initial weather data fetch, showing cached data: {}", e),
             Err(e) => error!("Failed initial weather data fetch: {}", e),
         }
 
-        let weather_display = weather.weather_data.get_weather_display();
-        self.weather_temp_units = weather_display.temp_units.clone();
-        self.weather_wind_speed_units = weather_display.wind_speed_units.clone();
+        self.apply_weather_conditions(&weather.weather_data);
         self.weather_location_name = "Local".to_string();
 
-        let mut weather_vec = vec![weather_display.current.clone()];
-        weather_vec.extend(weather_display.forecasts.clone());
-        self.weather_display.update(weather_vec);
-
         let (_poll_handle, weather_rx) = weather.start_polling_with_watch().await
             .map_err(|e| DisplayError::InitializationFailed(format!("Failed to start weather polling: {}", e)))?;
 
@@ -3445,6 +4213,95 @@ impl DisplayManager {
         Ok(())
     }
 
+    /// Subscribe to this manager's weather polling, None before `setup_weather`
+    pub fn weather_watch(&self) -> Option<tokio::sync::watch::Receiver<crate::weather::WeatherConditions>> {
+        self.weather_rx.clone()
+    }
+
+    /// Show weather polled by another manager rather than starting a second poller
+    pub fn share_weather(
+        &mut self,
+        cfg: &crate::config::WeatherConfig,
+        mut weather_rx: tokio::sync::watch::Receiver<crate::weather::WeatherConditions>,
+    ) {
+        self.layout_manager.set_forecast_days(cfg.forecast_days);
+        self.temperature_shown = cfg.temperature.unwrap_or_default();
+        let conditions = weather_rx.borrow_and_update().clone();
+        self.apply_weather_conditions(&conditions);
+        self.weather_location_name = "Local".to_string();
+        self.weather_rx = Some(weather_rx);
+    }
+
+    /// Push weather conditions into the weather component
+    fn apply_weather_conditions(&mut self, conditions: &crate::weather::WeatherConditions) {
+        let weather_display = conditions.get_weather_display();
+        if self.display_temp_units.is_none() {
+            self.weather_temp_units = weather_display.temp_units.clone();
+            self.weather_wind_speed_units = weather_display.wind_speed_units.clone();
+        }
+
+        let mut weather_vec = vec![weather_display.current.clone()];
+        weather_vec.extend(weather_display.forecasts.clone());
+        self.weather_display.update(weather_vec);
+        self.weather_display.set_last_updated(conditions.last_updated);
+        self.weather_display.set_alert(conditions.alert.clone());
+    }
+
+    /// Show weather in `units` from now on; cached data is converted as it is drawn.
+    pub fn set_temp_units(&mut self, units: crate::weather::TempUnits) {
+        self.display_temp_units = Some(units);
+        self.weather_temp_units = units.label().to_string();
+        self.weather_wind_speed_units = units.wind_label().to_string();
+    }
+
+    /// Flip between Celsius and Fahrenheit (wind between km/h and mph); returns the new units.
+    pub fn toggle_temp_units(&mut self) -> crate::weather::TempUnits {
+        let units = crate::weather::TempUnits::parse(&self.weather_temp_units)
+            .unwrap_or(crate::weather::TempUnits::Celsius)
+            .toggled();
+        self.set_temp_units(units);
+        units
+    }
+
+    /// Weather data in the units chosen at runtime
+    fn weather_in_display_units(&self, data: &crate::weather::WeatherData) -> crate::weather::WeatherData {
+        match self.display_temp_units {
+            Some(units) => data.in_units(units),
+            None => data.clone(),
+        }
+    }
+
+    /// Pick up any update published by the weather polling task
+    fn sync_weather(&mut self) {
+        let conditions = match self.weather_rx.as_mut() {
+            Some(rx) if rx.has_changed().unwrap_or(false) => rx.borrow_and_update().clone(),
+            _ => return,
+        };
+        self.apply_weather_conditions(&conditions);
+    }
+
+    /// Read visualizer audio from `source` instead of shared memory; set before `setup_visualizer`
+    pub fn set_viz_source(&mut self, source: crate::visualizer::VizSource) {
+        self.viz_source = source;
+    }
+
+    /// Normalize histogram bands with an AGC; set before `setup_visualizer`
+    pub fn set_spectrum_agc(&mut self, agc: Option<crate::spectrum::AgcSettings>) {
+        self.spectrum_agc = agc;
+    }
+
+    /// Collapse stereo to mono this way; set before `setup_visualizer`
+    pub fn set_viz_downmix(&mut self, downmix: Option<crate::visualizer::DownmixMode>) {
+        self.viz_downmix = downmix;
+    }
+
+    /// Read the time from `time` - clock, seconds bar, date, meter caps - rather than the system clock
+    pub fn set_time_source(&mut self, time: SharedTimeSource) {
+        self.clock_display.set_time_source(time.clone());
+        self.visualizer.set_time_source(time.clone());
+        self.time = time;
+    }
+
     pub async fn test(&mut self, _run: bool) {}
     /// Setup visualizer with playing state receiver.
     ///
@@ -3465,7 +4322,7 @@ impl DisplayManager {
 
         // Probe data source before committing to setup — avoids a visualizer
         // that is configured but will never receive audio data.
-        if !crate::visualizer::Visualizer::data_source_available(player_ip, sse_config.as_ref()) {
+        if !crate::visualizer::Visualizer::data_source_available(player_ip, sse_config.as_ref(), &self.viz_source) {
             info!("Visualizer '{}' requested but no data source available (player: {}, no SSE, not local or no SHM) — skipping", viz_type, player_ip);
             return Ok(());
         }
@@ -3486,7 +4343,7 @@ impl DisplayManager {
         }
 
         // Spawn the visualizer worker (falls back to SSE if sse_config is given)
-        let visualizer = crate::visualizer::Visualizer::spawn(viz_type, playing_rx, sse_config)
+        let visualizer = crate::visualizer::Visualizer::spawn(viz_type, playing_rx, sse_config, self.viz_source.clone())
             .map_err(|e| DisplayError::InitializationFailed(format!("Failed to spawn visualizer: {}", e)))?;
 
         // Set the visualizer in the component
@@ -3496,8 +4353,11 @@ impl DisplayManager {
         self.visualizer.set_visualization_type(viz_kind);
         self.visualizer.update_visual();
 
-        // Enable the visualizer
+        // Enable the visualizer, with the spectrum band count sized to the panel
         if let Some(viz) = self.visualizer.visualizer() {
+            viz.set_band_count(crate::spectrum::band_count_for_width(self.capabilities.width));
+            viz.set_agc(self.spectrum_agc);
+            viz.set_downmix(self.viz_downmix);
             viz.enable(true);
         }
 
@@ -3514,7 +4374,146 @@ impl DisplayManager {
 
     /// Render frame (called from main loop)
     pub async fn render_frame(&mut self) -> Result<(), DisplayError> {
-        self.render()
+        self.note_activity()?;
+        self.draw_frame().await
+    }
+
+    /// Render at normal brightness and pace, without counting as activity
+    async fn draw_frame(&mut self) -> Result<(), DisplayError> {
+        self.wake_from_idle()?;
+        self.present().await
+    }
+
+    /// Cap rendering at `fps` frames per second; 0 turns pacing off.
+    ///
+    /// The main loop then sleeps out whatever `pacing_delay` leaves of the frame time.
+    pub fn set_frame_cap(&mut self, fps: u32) {
+        self.frame_pacing = fps > 0;
+        if self.frame_pacing {
+            self.metrics.set_target_fps(fps);
+            info!("Frame rate capped at {} FPS", fps);
+        }
+    }
+
+    /// Remainder of the target frame time after the last frame; zero when pacing is off
+    pub fn pacing_delay(&self) -> Duration {
+        if self.frame_pacing {
+            self.metrics.pacing_delay()
+        } else {
+            Duration::ZERO
+        }
+    }
+
+    /// What the panel does while nothing plays: clock, blank or dimmed clock
+    pub fn set_idle_behavior(&mut self, behavior: IdleBehavior) {
+        self.idle_behavior = behavior;
+    }
+
+    /// Render a frame while nothing plays, honouring the idle behaviour.
+    ///
+    /// `blank` clears the panel once and then holds it dark; `dim_clock`
+    /// lowers the brightness and draws as usual. `render_frame` undoes both.
+    /// With an inactivity timeout the panel is switched off once it passes.
+    pub async fn render_idle_frame(&mut self) -> Result<(), DisplayError> {
+        if self.inactive() {
+            if !self.panel_off {
+                self.power_off_panel()?;
+            }
+            return Ok(());
+        }
+        match idle_action(self.idle_behavior, false) {
+            IdleAction::Render => self.draw_frame().await,
+            IdleAction::RenderDimmed => {
+                self.idle_blanked = false;
+                if !self.idle_dimmed {
+                    self.set_brightness(IDLE_DIM_BRIGHTNESS)?;
+                    self.idle_dimmed = true;
+                }
+                self.present().await
+            }
+            IdleAction::Blank => {
+                if !self.idle_blanked {
+                    self.clear()?;
+                    self.idle_blanked = true;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Whether the idle screen is currently held blank
+    pub fn is_idle_blanked(&self) -> bool {
+        self.idle_blanked
+    }
+
+    /// Power the panel off after `timeout` of idle frames with no activity; None never does
+    pub fn set_inactivity_timeout(&mut self, timeout: Option<Duration>) {
+        self.inactivity_timeout = timeout.filter(|t| !t.is_zero());
+    }
+
+    /// Record playback or an interaction, powering the panel back on if the
+    /// timeout had switched it off
+    pub fn note_activity(&mut self) -> Result<(), DisplayError> {
+        self.last_activity = Some(self.time.instant());
+        if self.panel_off {
+            self.power_on_panel()?;
+        }
+        Ok(())
+    }
+
+    /// Whether the panel is powered off for inactivity
+    pub fn is_panel_off(&self) -> bool {
+        self.panel_off
+    }
+
+    /// True once the timeout has passed since the last activity; the clock
+    /// starts with the first idle frame when nothing has played yet
+    fn inactive(&mut self) -> bool {
+        let now = self.time.instant();
+        let since = *self.last_activity.get_or_insert(now);
+        self.inactivity_timeout.is_some_and(|t| now.duration_since(since) >= t)
+    }
+
+    /// Switch the panel off, or clear it when the driver has no power control
+    fn power_off_panel(&mut self) -> Result<(), DisplayError> {
+        match self.driver.set_display_on(false) {
+            Ok(()) => info!("No activity, panel off"),
+            Err(DisplayError::UnsupportedOperation) => {
+                info!("No activity, panel cleared (no power control)");
+                self.clear()?;
+            }
+            Err(e) => return Err(e),
+        }
+        self.panel_off = true;
+        Ok(())
+    }
+
+    /// Switch the panel back on, re-initialising a controller that does not answer
+    fn power_on_panel(&mut self) -> Result<(), DisplayError> {
+        match self.driver.set_display_on(true) {
+            Ok(()) | Err(DisplayError::UnsupportedOperation) => {}
+            Err(e) => {
+                log::warn!("Panel did not wake ({}), re-initialising", e);
+                self.driver.init()?;
+                match self.driver.set_display_on(true) {
+                    Ok(()) | Err(DisplayError::UnsupportedOperation) => {}
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+        info!("Activity, panel on");
+        self.panel_off = false;
+        Ok(())
+    }
+
+    /// Restore the brightness a dimmed idle screen lowered
+    fn wake_from_idle(&mut self) -> Result<(), DisplayError> {
+        self.idle_blanked = false;
+        if self.idle_dimmed {
+            self.idle_dimmed = false;
+            self.set_brightness(self.awake_brightness)?;
+        }
+        Ok(())
     }
 
     /// Get emulator state for window (only available with emulator feature)
@@ -3528,30 +4527,44 @@ impl DisplayManager {
 
     /// Cycle to next easter egg animation
     pub fn cycle_easter_egg(&mut self) {
-        use crate::eggs::set_easter_egg;
-
-        let egg_names = [
-            "bass", "blackfly", "cassette", "ibmpc", "moog", "pipboy",
-            "reel2reel", "radio40", "radio50", "scope", "technics", 
-            "tubeamp", "tvtime", "vcr", "none"
-        ];
-
-        // Find current egg name
-        let current_type = self.easter_egg.egg_type;
-        let current_idx = egg_names.iter().position(|&name| {
-            let egg = set_easter_egg(name);
-            egg.egg_type == current_type
-        }).unwrap_or(0);
+        self.advance_easter_egg(false, Instant::now());
+        // Switch to easter eggs mode
+        self.current_mode = crate::display::DisplayMode::EasterEggs;
+    }
 
-        // Get next egg
-        let next_idx = (current_idx + 1) % egg_names.len();
-        let next_egg_name = egg_names[next_idx];
+    /// Rotate to a new egg every `interval` while in easter egg mode; None
+    /// stays on the configured egg
+    pub fn set_egg_rotation_interval(&mut self, interval: Option<Duration>) {
+        self.egg_rotation_interval = interval.filter(|i| !i.is_zero());
+        self.egg_shown_at = None;
+    }
 
-        info!("Cycling easter egg: {} -> {}", egg_names[current_idx], next_egg_name);
-        self.easter_egg = set_easter_egg(next_egg_name);
+    /// Advance the egg if the rotation interval has elapsed at `now`. The first
+    /// call anchors the schedule; returns true when the egg changed.
+    pub fn rotate_easter_egg_at(&mut self, now: Instant) -> bool {
+        let Some(interval) = self.egg_rotation_interval else {
+            return false;
+        };
+        if self.current_mode != DisplayMode::EasterEggs {
+            return false;
+        }
+        let shown_at = *self.egg_shown_at.get_or_insert(now);
+        if now.duration_since(shown_at) < interval {
+            return false;
+        }
+        self.advance_easter_egg(true, now);
+        true
+    }
 
-        // Switch to easter eggs mode
-        self.current_mode = crate::display::DisplayMode::EasterEggs;
+    /// Load the next egg with fresh animation state and restart the overlay
+    /// scrollers; the rotation interval counts from here
+    fn advance_easter_egg(&mut self, skip_none: bool, now: Instant) {
+        let current = self.easter_egg.egg_type_name().to_string();
+        let next = crate::eggs::next_egg_name(&current, skip_none);
+        info!("Cycling easter egg: {} -> {}", current, next);
+        self.easter_egg = set_easter_egg(next);
+        self.scrolling_text.restart();
+        self.egg_shown_at = Some(now);
     }
 
     /// Cycle to next visualization type
@@ -3565,9 +4578,11 @@ impl DisplayManager {
             Visualization::PeakMono,
             Visualization::HistStereo,
             Visualization::HistMono,
+            Visualization::HistMirror,
             Visualization::VuStereoWithCenterPeak,
             Visualization::VuAio,
             Visualization::HistAio,
+            Visualization::Oscilloscope,
         ];
 
         // Find current viz index
@@ -3613,4 +4628,174 @@ impl DisplayManager {
         }
         false
     }
+
+    /// Check and clear weather units toggle request from emulator
+    #[cfg(feature = "emulator")]
+    pub fn check_and_clear_toggle_temp_units(&mut self) -> bool {
+        if let Some(emu_state) = &self.emulator_state {
+            let mut state = emu_state.lock().unwrap();
+            if state.toggle_temp_units {
+                state.toggle_temp_units = false;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Check and clear total/remaining time toggle request from emulator
+    #[cfg(feature = "emulator")]
+    pub fn check_and_clear_toggle_show_remaining(&mut self) -> bool {
+        if let Some(emu_state) = &self.emulator_state {
+            let mut state = emu_state.lock().unwrap();
+            if state.toggle_show_remaining {
+                state.toggle_show_remaining = false;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Check and clear the sleep timer start/cancel request from the emulator
+    #[cfg(feature = "emulator")]
+    pub fn check_and_clear_toggle_sleep_timer(&mut self) -> bool {
+        if let Some(emu_state) = &self.emulator_state {
+            let mut state = emu_state.lock().unwrap();
+            if state.toggle_sleep_timer {
+                state.toggle_sleep_timer = false;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Check and clear the now playing QR code request from the emulator
+    #[cfg(feature = "emulator")]
+    pub fn check_and_clear_show_qr(&mut self) -> bool {
+        if let Some(emu_state) = &self.emulator_state {
+            let mut state = emu_state.lock().unwrap();
+            if state.show_qr {
+                state.show_qr = false;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Take a pending transport command (play/pause, next, prev) from the emulator
+    #[cfg(feature = "emulator")]
+    pub fn take_emulator_transport_request(&mut self) -> Option<crate::sliminfo::TransportCommand> {
+        self.emulator_state.as_ref()
+            .and_then(|emu_state| emu_state.lock().unwrap().transport_request.take())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_cap_sleeps_out_the_rest_of_the_frame() {
+        let mut metrics = PerformanceMetrics::new(60);
+        metrics.set_target_fps(30);
+        metrics.record_frame(800, 200);
+        let delay = metrics.pacing_delay();
+        assert!(delay > Duration::from_millis(32) && delay < Duration::from_millis(33), "{delay:?}");
+
+        // a slow frame is not paced at all
+        metrics.record_frame(30_000, 10_000);
+        assert_eq!(metrics.pacing_delay(), Duration::ZERO);
+    }
+
+    #[test]
+    fn svg_scratch_is_reused_across_renders() {
+        use embedded_graphics::mock_display::MockDisplay;
+        let svg = br#"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16"><circle cx="8" cy="8" r="6" fill="white"/></svg>"#;
+        let mut display: MockDisplay<Gray4> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        let mut scratch = Vec::new();
+
+        DisplayManager::draw_svg_weather_glyph(&mut display, svg, 0, 0, 16, 16, &mut scratch).unwrap();
+        let (capacity, at) = (scratch.capacity(), scratch.as_ptr());
+        assert!(capacity > 0);
+        DisplayManager::draw_svg_weather_glyph(&mut display, svg, 0, 0, 16, 16, &mut scratch).unwrap();
+        assert_eq!((scratch.capacity(), scratch.as_ptr()), (capacity, at));
+        assert_eq!(display.get_pixel(Point::new(8, 8)), Some(Gray4::WHITE));
+    }
+
+    #[test]
+    fn weather_alert_banners_the_current_conditions() {
+        use crate::display::drivers::mock::MockDriver;
+        use crate::weather::{AlertSeverity, WeatherAlert, WeatherData};
+
+        let driver = MockDriver::new_with_size(128, 64).unwrap();
+        let mut mgr = DisplayManager::new_with_driver(Box::new(driver), 0, "cylon", "7seg", false, "none", "classic").unwrap();
+        mgr.weather_display.update(vec![WeatherData::default()]);
+        mgr.set_display_mode(DisplayMode::WeatherCurrent);
+
+        let banner = DisplayManager::alert_banner_field(&crate::display::PageLayout::new("empty"), 128, Point::zero());
+        assert_eq!((banner.bounds.top_left, banner.bounds.size), (Point::zero(), Size::new(128, 10)));
+        let lit = |mgr: &DisplayManager| {
+            let mock = mgr.driver().as_any().downcast_ref::<MockDriver>().unwrap();
+            (0..128).flat_map(|x| (0..10).map(move |y| (x, y)))
+                .filter(|&(x, y)| mock.get_pixel(x, y) == Some(BinaryColor::On))
+                .count()
+        };
+
+        mgr.render().unwrap();
+        let quiet = lit(&mgr);
+
+        mgr.weather_display.set_alert(Some(WeatherAlert {
+            title: "Flood Warning".into(),
+            severity: AlertSeverity::Severe,
+        }));
+        mgr.render().unwrap();
+        let alerted = lit(&mgr);
+        // a solid strip with the text knocked out of it
+        assert!(alerted > 128 * 10 * 2 / 3, "{quiet} -> {alerted}");
+        assert!(alerted < 128 * 10);
+        assert!(alerted > quiet);
+    }
+
+    #[test]
+    fn alert_banner_uses_the_brightest_gray_level() {
+        use embedded_graphics::mock_display::MockDisplay;
+        let banner = DisplayManager::alert_banner_field(&crate::display::PageLayout::new("empty"), 64, Point::zero());
+        let mut display: MockDisplay<Gray4> = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        DisplayManager::render_alert_banner(&mut display, &banner, "SEVERE: Flood Warning").unwrap();
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Gray4::WHITE));
+        assert_eq!(display.get_pixel(Point::new(63, 9)), Some(Gray4::WHITE));
+        assert!(display.affected_area().size.height == 10);
+    }
+
+    #[cfg(feature = "driver-ssd1306")]
+    #[test]
+    fn watchdog_reinit_sets_up_a_wedged_ssd1306_again() {
+        use crate::display::drivers::i2c_bus::FakeBus;
+        use crate::display::drivers::ssd1306::{Ssd1306Driver, DEFAULT_I2C_ADDRESS};
+        use std::sync::atomic::Ordering;
+
+        let bus = FakeBus::default();
+        let driver = Ssd1306Driver::with_opener(&bus, "/dev/i2c-1", DEFAULT_I2C_ADDRESS, &Ssd1306Driver::default_config()).unwrap();
+        let mut mgr = DisplayManager::new_with_driver(Box::new(driver), 0, "cylon", "7seg", false, "none", "classic").unwrap();
+        mgr.set_bus_watchdog(BusWatchdog::new(3, Duration::from_secs(60)));
+        mgr.set_display_mode(DisplayMode::Clock);
+        mgr.render().unwrap();
+
+        // the controller NACKs frame data until it is set up again
+        bus.wedged.store(true, Ordering::SeqCst);
+        assert!(mgr.clear().is_err());
+        assert!(mgr.clear().is_err());
+        bus.clear();
+        mgr.clear().expect("re-init brings the panel back and the frame goes through");
+
+        assert!(!bus.wedged.load(Ordering::SeqCst));
+        let commands = bus.commands();
+        let pump = commands.iter().position(|c| c[..] == [0x8D, 0x14]).expect("init sequence sent");
+        // the configured brightness (200) goes back on after the crate's default
+        let contrast = commands.iter().rposition(|c| c[0] == 0x81).unwrap();
+        assert!(contrast > pump);
+        assert_eq!(commands[contrast], [0x81, 0xFF]);
+        assert!(bus.data_len() > 0);
+    }
 }
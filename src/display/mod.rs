@@ -35,7 +35,8 @@ pub mod color_proxy;
     feature = "driver-ssd1309",
     feature = "driver-ssd1322",
     feature = "driver-sh1106",
-    feature = "driver-sh1122"
+    feature = "driver-sh1122",
+    feature = "headless"
 ))]
 pub mod drivers;
 
@@ -68,6 +69,31 @@ pub mod layout_resolver;
 // Display mode controller
 pub mod mode_controller;
 
+// Panel self-test patterns
+pub mod selftest;
+
+// Progress bar fill styles
+pub mod progress;
+
+// OLED burn-in pixel shift
+pub mod burnin;
+
+// Per-field colour theme from config
+pub mod theme;
+
+// --dump-layout field rectangle report
+pub mod layout_dump;
+
+// Animated GIF capture of panel frames
+#[cfg(feature = "emulator")]
+pub mod gif_record;
+
+// Wedged display bus detection and recovery
+pub mod watchdog;
+
+// Wall clock and monotonic time for the render paths
+pub mod time_source;
+
 // Emulator window (only with emulator feature)
 #[cfg(feature = "emulator")]
 pub mod emulator_window;
@@ -77,7 +103,9 @@ pub mod emulator_window;
 pub mod emulator_controller;
 
 // Re-exports for convenience
-pub use traits::{DisplayDriver, DisplayCapabilities, ColorDepth};
+pub use traits::{BusRetry, DisplayDriver, DisplayCapabilities, ColorDepth};
+pub use watchdog::{BusWatchdog, WatchdogAction};
+pub use time_source::{SharedTimeSource, SystemTimeSource, TimeSource};
 pub use error::DisplayError;
 pub use framebuffer::FrameBuffer;
 pub use factory::{DisplayDriverFactory, BoxedDriver};
@@ -86,10 +114,11 @@ pub use manager::DisplayManager;
 pub use field::Field;
 pub use page::PageLayout;
 pub use layout_manager::LayoutManager;
-pub use mode_controller::{DisplayModeController, ModeControllerConfig};
+pub use mode_controller::{DisplayModeController, IdleBehavior, ModeControllerConfig, PollIntervals};
 
 /// Display mode enum - controls what content is shown on the display
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, PartialEq, Clone, Copy, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum DisplayMode {
     Clock,           // Clock mode
     Scrolling,       // Now Playing mode
@@ -98,6 +127,9 @@ pub enum DisplayMode {
     WeatherCurrent,  // Current Weather mode
     WeatherForecast, // Weather Forecast mode
     Warning,         // Warning/error state — server unavailable etc.
+    Slideshow,       // Idle slideshow of user images
+    History,         // Recently played tracks
+    BigTitle,        // Now Playing as the title alone, in big text
 }
 
 // Re-export OledDisplay from the old display module when available
@@ -26,7 +26,96 @@
 
 use super::DisplayMode;
 use chrono::{Local, Timelike};
-use std::time::Instant;
+use std::time::{Duration, Instant};
+
+/// What the panel does while nothing is playing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IdleBehavior {
+    /// Clock (and weather, slideshow...) at normal brightness
+    #[default]
+    Clock,
+    /// Clear the panel and leave it dark until playback resumes
+    Blank,
+    /// The usual idle screens at low brightness
+    DimClock,
+}
+
+/// How the main loop handles one frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleAction {
+    /// Draw the current mode at normal brightness
+    Render,
+    /// Draw the current mode with the panel dimmed
+    RenderDimmed,
+    /// Clear once, then draw nothing
+    Blank,
+}
+
+/// Frame handling for the configured idle behaviour; playback always renders normally
+pub fn idle_action(behavior: IdleBehavior, is_playing: bool) -> IdleAction {
+    match (is_playing, behavior) {
+        (true, _) | (false, IdleBehavior::Clock) => IdleAction::Render,
+        (false, IdleBehavior::Blank) => IdleAction::Blank,
+        (false, IdleBehavior::DimClock) => IdleAction::RenderDimmed,
+    }
+}
+
+/// How long the main loop sleeps between frames, per display mode
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PollIntervals {
+    /// Now playing, easter eggs and everything not listed below
+    pub scrolling: Duration,
+    pub clock: Duration,
+    /// ~30Hz balances meter motion against I2C refresh (16ms = 60Hz)
+    pub visualizer: Duration,
+}
+
+impl PollIntervals {
+    /// Faster than this just spins the CPU - no panel refreshes that quickly
+    pub const MIN: Duration = Duration::from_millis(10);
+    /// Slower than this and the clock skips seconds
+    pub const MAX: Duration = Duration::from_millis(1000);
+
+    /// Intervals from configured milliseconds; unset keeps the default, out
+    /// of range values are clamped with a warning.
+    pub fn from_millis(scrolling: Option<u64>, clock: Option<u64>, visualizer: Option<u64>) -> Self {
+        let std = Self::default();
+        let pick = |name: &str, ms: Option<u64>, default: Duration| {
+            let Some(ms) = ms else { return default };
+            let wanted = Duration::from_millis(ms);
+            let interval = wanted.clamp(Self::MIN, Self::MAX);
+            if interval != wanted {
+                log::warn!("{name} poll interval {ms}ms out of range, using {}ms", interval.as_millis());
+            }
+            interval
+        };
+        Self {
+            scrolling: pick("scroll", scrolling, std.scrolling),
+            clock: pick("clock", clock, std.clock),
+            visualizer: pick("visualizer", visualizer, std.visualizer),
+        }
+    }
+
+    /// Sleep between frames while showing `mode`
+    pub fn for_mode(&self, mode: DisplayMode) -> Duration {
+        match mode {
+            DisplayMode::Clock => self.clock,
+            DisplayMode::Visualizer => self.visualizer,
+            _ => self.scrolling,
+        }
+    }
+}
+
+impl Default for PollIntervals {
+    fn default() -> Self {
+        Self {
+            scrolling: Duration::from_millis(50),
+            clock: Duration::from_millis(100),
+            visualizer: Duration::from_millis(36),
+        }
+    }
+}
 
 /// Configuration for display mode controller
 #[derive(Debug, Clone)]
@@ -48,6 +137,15 @@ pub struct ModeControllerConfig {
 
     /// Duration to show forecast weather in seconds
     pub weather_forecast_duration_secs: u32,
+
+    /// Idle screen is the image slideshow rather than the clock
+    pub slideshow: bool,
+
+    /// Idle screen is the recently played list (the slideshow wins when both are set)
+    pub history: bool,
+
+    /// Now playing is the title alone in big text rather than the track scroller
+    pub big_title: bool,
 }
 
 impl Default for ModeControllerConfig {
@@ -59,6 +157,9 @@ impl Default for ModeControllerConfig {
             weather_interval_mins: 20,
             weather_current_duration_secs: 30,
             weather_forecast_duration_secs: 30,
+            slideshow: false,
+            history: false,
+            big_title: false,
         }
     }
 }
@@ -117,11 +218,13 @@ impl DisplayModeController {
 
     /// Determine display mode when player is playing
     fn determine_playing_mode(&self) -> DisplayMode {
-        // Priority: EasterEggs > Visualizer > Scrolling
+        // Priority: EasterEggs > Visualizer > BigTitle > Scrolling
         if self.config.egg_type != 255 { // EGGS_TYPE_UNKNOWN
             DisplayMode::EasterEggs
         } else if self.config.visualizer_type != "no_viz" {
             DisplayMode::Visualizer
+        } else if self.config.big_title {
+            DisplayMode::BigTitle
         } else {
             DisplayMode::Scrolling
         }
@@ -129,9 +232,9 @@ impl DisplayModeController {
 
     /// Determine display mode when player is idle (not playing)
     fn determine_idle_mode(&self) -> DisplayMode {
-        // If weather not configured or not active, always show clock
+        // If weather not configured or not active, always show the idle screen
         if self.config.weather_config.is_empty() || !self.weather_active {
-            return DisplayMode::Clock;
+            return self.idle_screen();
         }
 
         // Check if we're in a weather display window
@@ -141,7 +244,21 @@ impl DisplayModeController {
 
         // Check if current minute is a weather display minute
         if self.is_weather_minute(minute) {
-            self.determine_weather_mode(second)
+            match self.determine_weather_mode(second) {
+                DisplayMode::Clock => self.idle_screen(),
+                mode => mode,
+            }
+        } else {
+            self.idle_screen()
+        }
+    }
+
+    /// Screen shown between weather windows
+    fn idle_screen(&self) -> DisplayMode {
+        if self.config.slideshow {
+            DisplayMode::Slideshow
+        } else if self.config.history {
+            DisplayMode::History
         } else {
             DisplayMode::Clock
         }
@@ -184,6 +301,33 @@ impl DisplayModeController {
 mod tests {
     use super::*;
 
+    #[test]
+    fn poll_interval_follows_the_mode() {
+        let polls = PollIntervals::from_millis(Some(80), None, Some(20));
+        assert_eq!(polls.for_mode(DisplayMode::Scrolling), Duration::from_millis(80));
+        assert_eq!(polls.for_mode(DisplayMode::EasterEggs), Duration::from_millis(80));
+        assert_eq!(polls.for_mode(DisplayMode::Clock), Duration::from_millis(100));
+        assert_eq!(polls.for_mode(DisplayMode::Visualizer), Duration::from_millis(20));
+
+        // out of range values are clamped
+        let polls = PollIntervals::from_millis(Some(0), Some(60_000), None);
+        assert_eq!(polls.for_mode(DisplayMode::Scrolling), PollIntervals::MIN);
+        assert_eq!(polls.for_mode(DisplayMode::Clock), PollIntervals::MAX);
+        assert_eq!(polls.for_mode(DisplayMode::Visualizer), PollIntervals::default().visualizer);
+    }
+
+    #[test]
+    fn idle_behavior_picks_the_stopped_branch() {
+        assert_eq!(idle_action(IdleBehavior::Clock, false), IdleAction::Render);
+        assert_eq!(idle_action(IdleBehavior::Blank, false), IdleAction::Blank);
+        assert_eq!(idle_action(IdleBehavior::DimClock, false), IdleAction::RenderDimmed);
+        for behavior in [IdleBehavior::Clock, IdleBehavior::Blank, IdleBehavior::DimClock] {
+            assert_eq!(idle_action(behavior, true), IdleAction::Render, "{behavior:?} while playing");
+        }
+        let parsed: IdleBehavior = serde_yaml::from_str("dim_clock").unwrap();
+        assert_eq!(parsed, IdleBehavior::DimClock);
+    }
+
     #[test]
     fn test_playing_mode_priority() {
         // Test: EasterEggs has highest priority
@@ -258,4 +402,45 @@ mod tests {
         // Second update with playing=true should not change
         assert!(!controller.update_mode(true));
     }
+
+    #[test]
+    fn idle_shows_slideshow_when_configured() {
+        let config = ModeControllerConfig { slideshow: true, ..Default::default() };
+        let mut controller = DisplayModeController::new(config);
+        assert!(controller.update_mode(false));
+        assert_eq!(controller.current_mode(), DisplayMode::Slideshow);
+
+        // playing still wins
+        assert!(controller.update_mode(true));
+        assert_eq!(controller.current_mode(), DisplayMode::Scrolling);
+    }
+
+    #[test]
+    fn big_title_replaces_the_scroller_while_playing() {
+        let config = ModeControllerConfig { big_title: true, ..Default::default() };
+        let mut controller = DisplayModeController::new(config);
+        controller.update_mode(true);
+        assert_eq!(controller.current_mode(), DisplayMode::BigTitle);
+        controller.update_mode(false);
+        assert_eq!(controller.current_mode(), DisplayMode::Clock);
+
+        // a visualizer still wins
+        let config = ModeControllerConfig { big_title: true, visualizer_type: "vu_mono".into(), ..Default::default() };
+        let mut controller = DisplayModeController::new(config);
+        controller.update_mode(true);
+        assert_eq!(controller.current_mode(), DisplayMode::Visualizer);
+    }
+
+    #[test]
+    fn idle_shows_history_unless_slideshow_is_set() {
+        let config = ModeControllerConfig { history: true, ..Default::default() };
+        let mut controller = DisplayModeController::new(config);
+        controller.update_mode(false);
+        assert_eq!(controller.current_mode(), DisplayMode::History);
+
+        let config = ModeControllerConfig { history: true, slideshow: true, ..Default::default() };
+        let mut controller = DisplayModeController::new(config);
+        controller.update_mode(false);
+        assert_eq!(controller.current_mode(), DisplayMode::Slideshow);
+    }
 }
@@ -23,8 +23,35 @@
 
 #![allow(dead_code)] // page layout infrastructure; some methods reserved
 
+use embedded_graphics::prelude::Size;
+use embedded_graphics::primitives::Rectangle;
+
 use super::field::Field;
 
+/// Layout problem found by `PageLayout::validate`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FieldOverlap {
+    /// Two fields claim the same pixels
+    Fields { first: String, second: String, area: Rectangle },
+    /// A field reaches past the panel edge
+    OffScreen { field: String, bounds: Rectangle },
+}
+
+impl std::fmt::Display for FieldOverlap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Fields { first, second, area } => write!(
+                f, "'{first}' overlaps '{second}' ({}x{} at {},{})",
+                area.size.width, area.size.height, area.top_left.x, area.top_left.y
+            ),
+            Self::OffScreen { field, bounds } => write!(
+                f, "'{field}' ({}x{} at {},{}) is off-screen",
+                bounds.size.width, bounds.size.height, bounds.top_left.x, bounds.top_left.y
+            ),
+        }
+    }
+}
+
 /// Page layout - collection of fields defining a display mode
 ///
 /// Each DisplayMode has a corresponding PageLayout that defines
@@ -73,4 +100,114 @@ impl PageLayout {
     pub fn fields(&self) -> &[Field] {
         &self.fields
     }
+
+    /// Check for overlapping fields and fields reaching past a `panel` sized display.
+    ///
+    /// Bordered boxes are decoration drawn around other fields and only get the
+    /// edge check; empty fields are ignored.
+    pub fn validate(&self, panel: Size) -> Result<(), Vec<FieldOverlap>> {
+        let screen = Rectangle::new(embedded_graphics::prelude::Point::zero(), panel);
+        let mut problems = Vec::new();
+        let fields: Vec<&Field> = self.fields.iter()
+            .filter(|f| f.bounds.size.width > 0 && f.bounds.size.height > 0)
+            .collect();
+        for field in &fields {
+            if screen.intersection(&field.bounds) != field.bounds {
+                problems.push(FieldOverlap::OffScreen { field: field.name.clone(), bounds: field.bounds });
+            }
+        }
+        let content: Vec<&&Field> = fields.iter().filter(|f| f.border == 0).collect();
+        for (i, first) in content.iter().enumerate() {
+            for second in &content[i + 1..] {
+                let area = first.bounds.intersection(&second.bounds);
+                if area.size.width > 0 && area.size.height > 0 {
+                    problems.push(FieldOverlap::Fields {
+                        first: first.name.clone(),
+                        second: second.name.clone(),
+                        area,
+                    });
+                }
+            }
+        }
+        if problems.is_empty() { Ok(()) } else { Err(problems) }
+    }
+
+    /// Shift every field by `offset` (burn-in protection)
+    pub fn translate(&mut self, offset: embedded_graphics::prelude::Point) {
+        for field in &mut self.fields {
+            field.bounds.top_left += offset;
+        }
+    }
+
+    /// Drop blinking fields that are in their off phase at `now`
+    pub fn shown_at(mut self, now: &chrono::DateTime<chrono::Local>) -> Self {
+        self.fields.retain(|f| f.shown_at(now));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mono_font::ascii::FONT_5X8;
+    use embedded_graphics::prelude::Point;
+
+    fn text(name: &str, x: i32, y: i32, w: u32, h: u32) -> Field {
+        Field::new_text(name, Rectangle::new(Point::new(x, y), Size::new(w, h)), &FONT_5X8)
+    }
+
+    #[test]
+    fn overlapping_fields_are_reported_by_name() {
+        let page = PageLayout::new("test")
+            .add_field(text("title", 0, 0, 100, 9))
+            .add_field(text("artist", 0, 10, 100, 9))
+            .add_field(text("weather_icon", 90, 5, 20, 20));
+        let problems = page.validate(Size::new(128, 64)).unwrap_err();
+        assert_eq!(problems.len(), 2, "{problems:?}");
+        assert!(matches!(&problems[0], FieldOverlap::Fields { first, second, area }
+            if first == "title" && second == "weather_icon" && *area == Rectangle::new(Point::new(90, 5), Size::new(10, 4))));
+        assert!(problems[1].to_string().contains("'artist' overlaps 'weather_icon'"));
+    }
+
+    #[test]
+    fn off_screen_fields_and_bordered_boxes() {
+        let mut frame = text("day1_box", 0, 0, 60, 40);
+        frame.border = 1;
+        let page = PageLayout::new("test")
+            .add_field(frame)
+            .add_field(text("day1_temp", 2, 2, 50, 9))
+            .add_field(text("day1_precip", 2, 58, 50, 9));
+        let problems = page.validate(Size::new(128, 64)).unwrap_err();
+        assert_eq!(problems, [FieldOverlap::OffScreen {
+            field: "day1_precip".into(),
+            bounds: Rectangle::new(Point::new(2, 58), Size::new(50, 9)),
+        }]);
+
+        let page = PageLayout::new("test").add_field(text("day1_temp", 2, 2, 50, 9));
+        assert_eq!(page.validate(Size::new(128, 64)), Ok(()));
+    }
+
+    #[test]
+    fn blinking_field_alternates_with_the_time_source() {
+        use crate::display::time_source::{MockTimeSource, TimeSource};
+        use chrono::TimeZone;
+        use std::time::Duration;
+
+        let page = PageLayout::new("test")
+            .add_field(text("alarm", 0, 0, 40, 9).blink(Duration::from_millis(500)))
+            .add_field(text("clock", 0, 10, 40, 9));
+        // on a whole second, so the first half period is the drawn phase
+        let time = MockTimeSource::new(chrono::Local.timestamp_opt(1_700_000_000, 0).unwrap());
+
+        let shown = page.clone().shown_at(&time.now());
+        assert!(shown.get_field("alarm").is_some());
+
+        time.advance(Duration::from_millis(500));
+        let skipped = page.clone().shown_at(&time.now());
+        assert!(skipped.get_field("alarm").is_none());
+        assert!(skipped.get_field("clock").is_some());
+
+        time.advance(Duration::from_millis(500));
+        assert!(page.shown_at(&time.now()).get_field("alarm").is_some());
+    }
 }
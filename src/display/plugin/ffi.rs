@@ -36,10 +36,14 @@ use crate::display::{DisplayCapabilities, ColorDepth};
 use crate::display::error::DisplayError;
 
 /// Plugin ABI version
-pub const LYMONS_PLUGIN_ABI_VERSION_MAJOR: u32 = 1;
+pub const LYMONS_PLUGIN_ABI_VERSION_MAJOR: u32 = 2;
 pub const LYMONS_PLUGIN_ABI_VERSION_MINOR: u32 = 0;
 pub const LYMONS_PLUGIN_ABI_VERSION_PATCH: u32 = 0;
 
+/// First word of every vtable ("LYMS") - lets the host tell a vtable from a
+/// stale layout before calling through any of its function pointers
+pub const LYMONS_PLUGIN_ABI_MAGIC: u32 = 0x4C59_4D53;
+
 /// Maximum length for error messages
 pub const LYMONS_ERROR_MESSAGE_SIZE: usize = 256;
 
@@ -151,7 +155,7 @@ impl From<DisplayError> for LyMonsError {
                 (LyMonsErrorCode::ErrorUnsupportedOperation, "Unsupported operation".to_string()),
             DisplayError::InvalidRotation(deg) =>
                 (LyMonsErrorCode::ErrorInvalidRotation, format!("Invalid rotation: {}", deg)),
-            DisplayError::I2cError(msg) | DisplayError::SpiError(msg) =>
+            DisplayError::I2cError(msg) | DisplayError::SpiError(msg) | DisplayError::BusTransient(msg) =>
                 (LyMonsErrorCode::ErrorCommunication, msg),
             DisplayError::InitializationFailed(msg) =>
                 (LyMonsErrorCode::ErrorInitialization, msg),
@@ -341,8 +345,9 @@ pub fn display_config_to_ffi(config: &DisplayConfig) -> Result<LyMonsDisplayConf
 
     Ok(LyMonsDisplayConfig {
         bus,
-        rotation: config.rotate_deg.unwrap_or(0),
-        has_rotation: config.rotate_deg.is_some(),
+        // rotation is done host side when packing the framebuffer
+        rotation: 0,
+        has_rotation: false,
         brightness: config.brightness.unwrap_or(128),
         has_brightness: config.brightness.is_some(),
         inverted: config.invert.unwrap_or(false),
@@ -400,6 +405,13 @@ impl From<LyMonsDisplayCapabilities> for DisplayCapabilities {
 /// Plugin vtable - function pointers for all driver operations
 #[repr(C)]
 pub struct LyMonsPluginVTable {
+    /// Must be `LYMONS_PLUGIN_ABI_MAGIC`
+    pub abi_magic: u32,
+
+    /// `LYMONS_PLUGIN_ABI_VERSION_MAJOR` the plugin was built against,
+    /// checked before any function pointer is called
+    pub abi_major: u32,
+
     /// Get plugin ABI version (major, minor, patch)
     pub abi_version: extern "C" fn(
         major: *mut u32,
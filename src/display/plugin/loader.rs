@@ -30,6 +30,7 @@ use libloading::{Library, Symbol};
 use super::ffi::{
     LyMonsPluginVTable,
     PluginRegisterFn,
+    LYMONS_PLUGIN_ABI_MAGIC,
     LYMONS_PLUGIN_ABI_VERSION_MAJOR,
     LYMONS_PLUGIN_ABI_VERSION_MINOR,
     LYMONS_PLUGIN_ABI_VERSION_PATCH,
@@ -79,6 +80,32 @@ impl LoadedPlugin {
     }
 }
 
+/// Whether a discovered plugin file could be loaded
+#[derive(Debug, Clone)]
+pub enum PluginStatus {
+    /// Loaded and ABI compatible - metadata read from the vtable
+    Loaded(PluginMetadata),
+
+    /// Found on disk but failed to load or register
+    Failed(String),
+
+    /// Same driver type already found earlier in the search path, never loaded
+    Shadowed,
+}
+
+/// A plugin file found in one of the search paths
+#[derive(Debug, Clone)]
+pub struct DiscoveredPlugin {
+    /// Driver type taken from the filename (e.g., "ssd1306")
+    pub driver_type: String,
+
+    /// Full path to the shared library
+    pub path: PathBuf,
+
+    /// Load result
+    pub status: PluginStatus,
+}
+
 /// Plugin loader - searches for and loads display driver plugins
 pub struct PluginLoader;
 
@@ -139,6 +166,67 @@ impl PluginLoader {
         names
     }
 
+    /// Driver type for a plugin filename, or None if it doesn't follow the
+    /// naming convention of [`plugin_filenames`](Self::plugin_filenames)
+    pub fn driver_type_from_filename(filename: &str) -> Option<String> {
+        #[cfg(target_os = "linux")]
+        let (prefix, ext) = ("liblymons", ".so");
+        #[cfg(target_os = "macos")]
+        let (prefix, ext) = ("liblymons", ".dylib");
+        #[cfg(target_os = "windows")]
+        let (prefix, ext) = ("lymons", ".dll");
+
+        let stem = filename.strip_prefix(prefix)?.strip_suffix(ext)?;
+        let driver_type = stem.strip_prefix('_').or_else(|| stem.strip_prefix('-'))?;
+        if driver_type.is_empty() {
+            return None;
+        }
+        Some(driver_type.to_string())
+    }
+
+    /// Enumerate every plugin file in `dirs` and try loading each one
+    ///
+    /// Directories are walked in order, so a driver type found in an earlier
+    /// directory shadows later copies - the same priority `find_plugin` uses.
+    pub fn discover_in(dirs: &[PathBuf]) -> Vec<DiscoveredPlugin> {
+        let mut found: Vec<DiscoveredPlugin> = Vec::new();
+
+        for dir in dirs {
+            let Ok(entries) = std::fs::read_dir(dir) else {
+                continue;
+            };
+
+            let mut files: Vec<(String, PathBuf)> = entries
+                .filter_map(|e| e.ok())
+                .filter(|e| e.path().is_file())
+                .filter_map(|e| {
+                    let name = e.file_name().to_string_lossy().into_owned();
+                    Self::driver_type_from_filename(&name).map(|t| (t, e.path()))
+                })
+                .collect();
+            files.sort();
+
+            for (driver_type, path) in files {
+                let status = if found.iter().any(|p| p.driver_type == driver_type) {
+                    PluginStatus::Shadowed
+                } else {
+                    match Self::load_plugin(&path) {
+                        Ok(plugin) => PluginStatus::Loaded(plugin.metadata().clone()),
+                        Err(e) => PluginStatus::Failed(e),
+                    }
+                };
+                found.push(DiscoveredPlugin { driver_type, path, status });
+            }
+        }
+
+        found
+    }
+
+    /// Enumerate plugins across the standard search paths
+    pub fn discover() -> Vec<DiscoveredPlugin> {
+        Self::discover_in(&Self::search_paths())
+    }
+
     /// Find a plugin file for the given driver type
     ///
     /// Returns the path to the plugin if found, or None if not found.
@@ -195,8 +283,63 @@ impl PluginLoader {
         }
 
         let vtable: &'static LyMonsPluginVTable = unsafe { &*vtable_ptr };
+        let (major, minor, patch) = Self::check_vtable(vtable)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        // Extract plugin metadata
+        let mut name_buf = vec![0 as std::ffi::c_char; LYMONS_PLUGIN_NAME_SIZE];
+        let mut version_buf = vec![0 as std::ffi::c_char; LYMONS_PLUGIN_VERSION_SIZE];
+        let mut driver_type_buf = vec![0 as std::ffi::c_char; LYMONS_PLUGIN_DRIVER_TYPE_SIZE];
+
+        (vtable.plugin_info)(
+            name_buf.as_mut_ptr(),
+            version_buf.as_mut_ptr(),
+            driver_type_buf.as_mut_ptr()
+        );
+
+        let name = Self::extract_string(&name_buf);
+        let version = Self::extract_string(&version_buf);
+        let driver_type = Self::extract_string(&driver_type_buf);
+
+        info!("Loaded plugin: {} v{} ({})", name, version, driver_type);
+
+        let metadata = PluginMetadata {
+            name,
+            version,
+            driver_type,
+            abi_version: (major, minor, patch),
+        };
+
+        Ok(LoadedPlugin {
+            library,
+            vtable,
+            metadata,
+        })
+    }
+
+    /// Verify a plugin vtable matches the host ABI
+    ///
+    /// The magic and major version are plain data at the head of the vtable,
+    /// so a plugin built against an older layout is rejected before any of
+    /// its function pointers are called. Returns the full plugin version.
+    pub fn check_vtable(vtable: &LyMonsPluginVTable) -> Result<(u32, u32, u32), String> {
+        if vtable.abi_magic != LYMONS_PLUGIN_ABI_MAGIC {
+            return Err(format!(
+                "not a LyMonS {}.x plugin vtable (stale build or foreign library) - rebuild the plugin",
+                LYMONS_PLUGIN_ABI_VERSION_MAJOR
+            ));
+        }
+
+        if vtable.abi_major != LYMONS_PLUGIN_ABI_VERSION_MAJOR {
+            return Err(format!(
+                "ABI version mismatch: plugin built for {}.x, host is {}.{}.{}",
+                vtable.abi_major,
+                LYMONS_PLUGIN_ABI_VERSION_MAJOR,
+                LYMONS_PLUGIN_ABI_VERSION_MINOR,
+                LYMONS_PLUGIN_ABI_VERSION_PATCH
+            ));
+        }
 
-        // Verify ABI version
         let mut major = 0u32;
         let mut minor = 0u32;
         let mut patch = 0u32;
@@ -230,35 +373,7 @@ impl PluginLoader {
             );
         }
 
-        // Extract plugin metadata
-        let mut name_buf = vec![0 as std::ffi::c_char; LYMONS_PLUGIN_NAME_SIZE];
-        let mut version_buf = vec![0 as std::ffi::c_char; LYMONS_PLUGIN_VERSION_SIZE];
-        let mut driver_type_buf = vec![0 as std::ffi::c_char; LYMONS_PLUGIN_DRIVER_TYPE_SIZE];
-
-        (vtable.plugin_info)(
-            name_buf.as_mut_ptr(),
-            version_buf.as_mut_ptr(),
-            driver_type_buf.as_mut_ptr()
-        );
-
-        let name = Self::extract_string(&name_buf);
-        let version = Self::extract_string(&version_buf);
-        let driver_type = Self::extract_string(&driver_type_buf);
-
-        info!("Loaded plugin: {} v{} ({})", name, version, driver_type);
-
-        let metadata = PluginMetadata {
-            name,
-            version,
-            driver_type,
-            abi_version: (major, minor, patch),
-        };
-
-        Ok(LoadedPlugin {
-            library,
-            vtable,
-            metadata,
-        })
+        Ok((major, minor, patch))
     }
 
     /// Load a plugin by driver type
@@ -291,6 +406,90 @@ impl PluginLoader {
 mod tests {
     use super::*;
 
+    use crate::display::plugin::ffi::{
+        LyMonsDisplayCapabilities, LyMonsDisplayConfig, LyMonsDriverHandle,
+        LyMonsError, LyMonsErrorCode,
+    };
+    use std::ffi::c_char;
+
+    extern "C" fn fake_abi(major: *mut u32, minor: *mut u32, patch: *mut u32) {
+        unsafe {
+            *major = LYMONS_PLUGIN_ABI_VERSION_MAJOR;
+            *minor = 0;
+            *patch = 0;
+        }
+    }
+    extern "C" fn fake_abi_v1(major: *mut u32, minor: *mut u32, patch: *mut u32) {
+        unsafe { *major = 1; *minor = 0; *patch = 0; }
+    }
+    extern "C" fn fake_info(_: *mut c_char, _: *mut c_char, _: *mut c_char) {}
+    extern "C" fn fake_create(_: *const LyMonsDisplayConfig, _: *mut *mut LyMonsDriverHandle, _: *mut LyMonsError) -> LyMonsErrorCode {
+        LyMonsErrorCode::ErrorGeneric
+    }
+    extern "C" fn fake_destroy(_: *mut LyMonsDriverHandle) {}
+    extern "C" fn fake_caps(_: *const LyMonsDriverHandle, _: *mut LyMonsDisplayCapabilities, _: *mut LyMonsError) -> LyMonsErrorCode {
+        LyMonsErrorCode::ErrorGeneric
+    }
+    extern "C" fn fake_op(_: *mut LyMonsDriverHandle, _: *mut LyMonsError) -> LyMonsErrorCode {
+        LyMonsErrorCode::ErrorGeneric
+    }
+    extern "C" fn fake_u8(_: *mut LyMonsDriverHandle, _: u8, _: *mut LyMonsError) -> LyMonsErrorCode {
+        LyMonsErrorCode::ErrorGeneric
+    }
+    extern "C" fn fake_write(_: *mut LyMonsDriverHandle, _: *const u8, _: usize, _: *mut LyMonsError) -> LyMonsErrorCode {
+        LyMonsErrorCode::ErrorGeneric
+    }
+    extern "C" fn fake_bool(_: *mut LyMonsDriverHandle, _: bool, _: *mut LyMonsError) -> LyMonsErrorCode {
+        LyMonsErrorCode::ErrorGeneric
+    }
+    extern "C" fn fake_u16(_: *mut LyMonsDriverHandle, _: u16, _: *mut LyMonsError) -> LyMonsErrorCode {
+        LyMonsErrorCode::ErrorGeneric
+    }
+
+    fn fake_vtable(abi_magic: u32, abi_major: u32) -> LyMonsPluginVTable {
+        LyMonsPluginVTable {
+            abi_magic,
+            abi_major,
+            abi_version: fake_abi,
+            plugin_info: fake_info,
+            create: fake_create,
+            destroy: fake_destroy,
+            capabilities: fake_caps,
+            init: fake_op,
+            set_brightness: fake_u8,
+            flush: fake_op,
+            clear: fake_op,
+            write_buffer: fake_write,
+            set_invert: fake_bool,
+            set_rotation: fake_u16,
+        }
+    }
+
+    #[test]
+    fn test_check_vtable_accepts_current_abi() {
+        let vtable = fake_vtable(LYMONS_PLUGIN_ABI_MAGIC, LYMONS_PLUGIN_ABI_VERSION_MAJOR);
+        assert_eq!(PluginLoader::check_vtable(&vtable), Ok((LYMONS_PLUGIN_ABI_VERSION_MAJOR, 0, 0)));
+    }
+
+    #[test]
+    fn test_check_vtable_rejects_wrong_version() {
+        let vtable = fake_vtable(LYMONS_PLUGIN_ABI_MAGIC, LYMONS_PLUGIN_ABI_VERSION_MAJOR + 1);
+        let err = PluginLoader::check_vtable(&vtable).unwrap_err();
+        assert!(err.contains("ABI version mismatch"), "{err}");
+
+        // header agrees but the reported version doesn't
+        let mut vtable = fake_vtable(LYMONS_PLUGIN_ABI_MAGIC, LYMONS_PLUGIN_ABI_VERSION_MAJOR);
+        vtable.abi_version = fake_abi_v1;
+        assert!(PluginLoader::check_vtable(&vtable).is_err());
+    }
+
+    #[test]
+    fn test_check_vtable_rejects_missing_magic() {
+        let vtable = fake_vtable(0, LYMONS_PLUGIN_ABI_VERSION_MAJOR);
+        let err = PluginLoader::check_vtable(&vtable).unwrap_err();
+        assert!(err.contains("rebuild the plugin"), "{err}");
+    }
+
     #[test]
     fn test_search_paths() {
         let paths = PluginLoader::search_paths();
@@ -300,6 +499,44 @@ mod tests {
         assert!(paths.iter().any(|p| p.to_string_lossy().contains("target/release/drivers")));
     }
 
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_driver_type_from_filename() {
+        assert_eq!(PluginLoader::driver_type_from_filename("liblymons_ssd1306.so").as_deref(), Some("ssd1306"));
+        assert_eq!(PluginLoader::driver_type_from_filename("liblymons-sh1122.so").as_deref(), Some("sh1122"));
+        assert_eq!(PluginLoader::driver_type_from_filename("liblymons_.so"), None);
+        assert_eq!(PluginLoader::driver_type_from_filename("libssd1306.so"), None);
+        assert_eq!(PluginLoader::driver_type_from_filename("liblymons_ssd1306.so.1"), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_discover_in_filters_and_shadows() {
+        let base = std::env::temp_dir().join(format!("lymons-plugin-test-{}", std::process::id()));
+        let (first, second) = (base.join("a"), base.join("b"));
+        std::fs::create_dir_all(&first).unwrap();
+        std::fs::create_dir_all(&second).unwrap();
+        for name in ["liblymons_fake.so", "README.txt", "libother.so"] {
+            std::fs::write(first.join(name), b"not a library").unwrap();
+        }
+        std::fs::write(second.join("liblymons-fake.so"), b"not a library").unwrap();
+        std::fs::write(second.join("liblymons_extra.so"), b"not a library").unwrap();
+
+        let found = PluginLoader::discover_in(&[first.clone(), second.clone(), base.join("missing")]);
+        let _ = std::fs::remove_dir_all(&base);
+
+        let summary: Vec<(&str, &Path)> = found.iter()
+            .map(|p| (p.driver_type.as_str(), p.path.parent().unwrap()))
+            .collect();
+        assert_eq!(summary, vec![
+            ("fake", first.as_path()),
+            ("extra", second.as_path()),
+            ("fake", second.as_path()),
+        ]);
+        assert!(matches!(found[0].status, PluginStatus::Failed(_)));
+        assert!(matches!(found[2].status, PluginStatus::Shadowed));
+    }
+
     #[test]
     fn test_plugin_filenames() {
         let names = PluginLoader::plugin_filenames("ssd1306");
@@ -62,5 +62,5 @@ pub mod adapter;
 
 // Re-exports for convenience
 
-pub use loader::PluginLoader;
+pub use loader::{PluginLoader, DiscoveredPlugin, PluginStatus};
 pub use adapter::PluginDriverAdapter;
@@ -0,0 +1,181 @@
+/*
+ *  display/progress.rs
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  Progress bar fill styles - filled, segmented cells and a moving tick
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *  Public License.
+ *
+ */
+
+use embedded_graphics::{
+    prelude::*,
+    primitives::{PrimitiveStyle, PrimitiveStyleBuilder, Rectangle},
+};
+use serde::{Deserialize, Serialize};
+
+/// Width of one cell in the segmented style, in pixels.
+pub const SEGMENT_WIDTH: u32 = 4;
+/// Gap between cells in the segmented style, in pixels.
+pub const SEGMENT_GAP: u32 = 1;
+
+/// How the inside of a progress bar shows the elapsed fraction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProgressStyle {
+    /// Solid fill from the left edge
+    #[default]
+    Filled,
+    /// Discrete cells, lit left to right
+    Segmented,
+    /// A single moving tick
+    Line,
+}
+
+/// Cells that fit across `inner_width`, at least one.
+pub fn segment_count(inner_width: u32) -> u32 {
+    ((inner_width + SEGMENT_GAP) / (SEGMENT_WIDTH + SEGMENT_GAP)).max(1)
+}
+
+/// Cells lit for `progress` (0.0 - 1.0); a cell lights once fully reached.
+pub fn lit_segments(inner_width: u32, progress: f32) -> u32 {
+    let count = segment_count(inner_width);
+    ((count as f32 * progress.clamp(0.0, 1.0)) as u32).min(count)
+}
+
+/// Draw the progress indicator inside `inner` (the area within any outline).
+pub fn draw_progress_fill<D>(
+    target: &mut D,
+    inner: Rectangle,
+    progress: f32,
+    style: ProgressStyle,
+    color: D::Color,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget,
+{
+    let progress = progress.clamp(0.0, 1.0);
+    let width = inner.size.width;
+    let height = inner.size.height;
+    if width == 0 || height == 0 {
+        return Ok(());
+    }
+    let fill = PrimitiveStyle::with_fill(color);
+
+    match style {
+        ProgressStyle::Filled => {
+            let fill_width = (width as f32 * progress) as u32;
+            if fill_width > 0 {
+                Rectangle::new(inner.top_left, Size::new(fill_width, height))
+                    .into_styled(fill)
+                    .draw(target)?;
+            }
+        }
+        ProgressStyle::Segmented => {
+            // centre the cells so leftover pixels split evenly either side
+            let count = segment_count(width);
+            let used = (count * (SEGMENT_WIDTH + SEGMENT_GAP)).saturating_sub(SEGMENT_GAP).min(width);
+            let cell_width = SEGMENT_WIDTH.min(width);
+            let x0 = inner.top_left.x + ((width - used) / 2) as i32;
+            for i in 0..lit_segments(width, progress) {
+                let x = x0 + (i * (SEGMENT_WIDTH + SEGMENT_GAP)) as i32;
+                Rectangle::new(Point::new(x, inner.top_left.y), Size::new(cell_width, height))
+                    .into_styled(fill)
+                    .draw(target)?;
+            }
+        }
+        ProgressStyle::Line => {
+            let x = inner.top_left.x + ((width - 1) as f32 * progress) as i32;
+            Rectangle::new(Point::new(x, inner.top_left.y), Size::new(1, height))
+                .into_styled(fill)
+                .draw(target)?;
+        }
+    }
+    Ok(())
+}
+
+/// Draw a 1px `outline` around `bar` and the indicator inside it in `fill`.
+pub fn draw_progress_bar<D>(
+    target: &mut D,
+    bar: Rectangle,
+    progress: f32,
+    style: ProgressStyle,
+    outline: D::Color,
+    fill: D::Color,
+) -> Result<(), D::Error>
+where
+    D: DrawTarget,
+{
+    bar.into_styled(PrimitiveStyleBuilder::new().stroke_color(outline).stroke_width(1).build())
+        .draw(target)?;
+    let inner = Rectangle::new(
+        bar.top_left + Point::new(1, 1),
+        Size::new(bar.size.width.saturating_sub(2), bar.size.height.saturating_sub(2)),
+    );
+    draw_progress_fill(target, inner, progress, style, fill)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::{BinaryColor, Gray4};
+
+    #[test]
+    fn segment_count_tracks_width() {
+        // 4px cells on a 5px pitch, the last cell needs no trailing gap
+        assert_eq!(segment_count(4), 1);
+        assert_eq!(segment_count(9), 2);
+        assert_eq!(segment_count(10), 2);
+        assert_eq!(segment_count(122), 24);
+        assert_eq!(segment_count(124), 25);
+        assert_eq!(segment_count(250), 50);
+        assert_eq!(segment_count(0), 1);
+    }
+
+    #[test]
+    fn lit_segments_follow_progress() {
+        assert_eq!(lit_segments(124, 0.0), 0);
+        assert_eq!(lit_segments(124, 0.5), 12);
+        assert_eq!(lit_segments(124, 1.0), 25);
+        assert_eq!(lit_segments(124, 2.0), 25);
+        assert_eq!(lit_segments(9, 0.49), 0);
+        assert_eq!(lit_segments(9, 0.5), 1);
+    }
+
+    #[test]
+    fn line_style_draws_a_single_column() {
+        let mut display: MockDisplay<BinaryColor> = MockDisplay::new();
+        let inner = Rectangle::new(Point::new(1, 1), Size::new(20, 3));
+        draw_progress_fill(&mut display, inner, 0.5, ProgressStyle::Line, BinaryColor::On).unwrap();
+        let lit = display.affected_area();
+        assert_eq!(lit.size, Size::new(1, 3));
+        assert_eq!(lit.top_left, Point::new(10, 1));
+    }
+
+    #[test]
+    fn outline_and_fill_take_their_own_levels() {
+        let mut display: MockDisplay<Gray4> = MockDisplay::new();
+        let bar = Rectangle::new(Point::new(0, 0), Size::new(12, 4));
+        draw_progress_bar(&mut display, bar, 0.5, ProgressStyle::Filled, Gray4::new(6), Gray4::WHITE).unwrap();
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Gray4::new(6)));
+        assert_eq!(display.get_pixel(Point::new(11, 3)), Some(Gray4::new(6)));
+        // inner is 10px wide, half lit
+        assert_eq!(display.get_pixel(Point::new(1, 1)), Some(Gray4::WHITE));
+        assert_eq!(display.get_pixel(Point::new(5, 2)), Some(Gray4::WHITE));
+        assert_eq!(display.get_pixel(Point::new(6, 1)), None);
+    }
+}
@@ -0,0 +1,164 @@
+/*
+ *  display/selftest.rs
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  Panel self-test patterns - dead pixel/row and resolution checks
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *  Public License.
+ *
+ */
+
+use embedded_graphics::{
+    prelude::*,
+    primitives::{PrimitiveStyle, Rectangle},
+    Pixel,
+};
+
+/// Number of bands in the sweep - one per Gray4 level.
+pub const SWEEP_BANDS: u8 = 16;
+
+/// One frame of the panel self-test.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanelTestStep {
+    FullOn,
+    FullOff,
+    Checkerboard,
+    /// Sweep progress - bands `0..=n` are lit, band k at level k on grayscale panels.
+    Sweep(u8),
+    Border,
+}
+
+impl PanelTestStep {
+    /// How long the frame is held on the panel.
+    pub fn hold_ms(&self) -> u64 {
+        match self {
+            PanelTestStep::Sweep(_) => 80,
+            _ => 1000,
+        }
+    }
+}
+
+/// The full test sequence, in run order.
+pub fn panel_test_steps() -> Vec<PanelTestStep> {
+    let mut steps = vec![
+        PanelTestStep::FullOn,
+        PanelTestStep::FullOff,
+        PanelTestStep::Checkerboard,
+    ];
+    steps.extend((0..SWEEP_BANDS).map(PanelTestStep::Sweep));
+    steps.push(PanelTestStep::Border);
+    steps
+}
+
+/// Draw one test frame. `level(k)` maps a 0..=15 intensity to a panel color,
+/// 0 being off - mono panels simply return on for anything above zero.
+pub fn draw_panel_test_step<D, F>(target: &mut D, step: PanelTestStep, level: F) -> Result<(), D::Error>
+where
+    D: DrawTarget,
+    F: Fn(u8) -> D::Color,
+{
+    let on = level(SWEEP_BANDS - 1);
+    let off = level(0);
+    let size = target.bounding_box().size;
+    target.clear(off)?;
+
+    match step {
+        PanelTestStep::FullOn => target.clear(on),
+        PanelTestStep::FullOff => Ok(()),
+        PanelTestStep::Checkerboard => {
+            let pixels = (0..size.height as i32).flat_map(move |y| {
+                (0..size.width as i32)
+                    .filter(move |x| (x + y) % 2 == 0)
+                    .map(move |x| Pixel(Point::new(x, y), on))
+            });
+            target.draw_iter(pixels)
+        }
+        PanelTestStep::Sweep(n) => {
+            for band in 0..=n.min(SWEEP_BANDS - 1) {
+                let x0 = size.width * band as u32 / SWEEP_BANDS as u32;
+                let x1 = size.width * (band as u32 + 1) / SWEEP_BANDS as u32;
+                Rectangle::new(Point::new(x0 as i32, 0), Size::new(x1 - x0, size.height))
+                    .into_styled(PrimitiveStyle::with_fill(level(band.max(1))))
+                    .draw(target)?;
+            }
+            Ok(())
+        }
+        PanelTestStep::Border => {
+            Rectangle::new(Point::zero(), size)
+                .into_styled(PrimitiveStyle::with_stroke(on, 1))
+                .draw(target)?;
+            // centre cross confirms the reported resolution is the real one
+            let c = Point::new(size.width as i32 / 2, size.height as i32 / 2);
+            Rectangle::new(c - Point::new(4, 0), Size::new(9, 1))
+                .into_styled(PrimitiveStyle::with_fill(on))
+                .draw(target)?;
+            Rectangle::new(c - Point::new(0, 4), Size::new(1, 9))
+                .into_styled(PrimitiveStyle::with_fill(on))
+                .draw(target)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use embedded_graphics::mock_display::MockDisplay;
+    use embedded_graphics::pixelcolor::{BinaryColor, Gray4};
+
+    fn mono(k: u8) -> BinaryColor {
+        if k == 0 { BinaryColor::Off } else { BinaryColor::On }
+    }
+
+    fn mock<C: PixelColor>() -> MockDisplay<C> {
+        let mut display = MockDisplay::new();
+        display.set_allow_overdraw(true);
+        display
+    }
+
+    #[test]
+    fn sequence_ends_with_border() {
+        let steps = panel_test_steps();
+        assert_eq!(steps[0], PanelTestStep::FullOn);
+        assert_eq!(steps.last(), Some(&PanelTestStep::Border));
+        assert_eq!(steps.iter().filter(|s| matches!(s, PanelTestStep::Sweep(_))).count(), 16);
+    }
+
+    #[test]
+    fn checkerboard_alternates() {
+        let mut display = mock::<BinaryColor>();
+        draw_panel_test_step(&mut display, PanelTestStep::Checkerboard, mono).unwrap();
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(display.get_pixel(Point::new(1, 0)), Some(BinaryColor::Off));
+        assert_eq!(display.get_pixel(Point::new(1, 1)), Some(BinaryColor::On));
+    }
+
+    #[test]
+    fn border_lights_edges_only() {
+        let mut display = mock::<BinaryColor>();
+        draw_panel_test_step(&mut display, PanelTestStep::Border, mono).unwrap();
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(BinaryColor::On));
+        assert_eq!(display.get_pixel(Point::new(63, 63)), Some(BinaryColor::On));
+        assert_eq!(display.get_pixel(Point::new(10, 10)), Some(BinaryColor::Off));
+    }
+
+    #[test]
+    fn gray_sweep_ramps_levels() {
+        let mut display = mock::<Gray4>();
+        draw_panel_test_step(&mut display, PanelTestStep::Sweep(SWEEP_BANDS - 1), Gray4::new).unwrap();
+        assert_eq!(display.get_pixel(Point::new(0, 0)), Some(Gray4::new(1)));
+        assert_eq!(display.get_pixel(Point::new(63, 0)), Some(Gray4::new(15)));
+    }
+}
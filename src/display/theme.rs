@@ -0,0 +1,78 @@
+/*
+ *  display/theme.rs
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  Colour theme - per-field foreground overrides from config
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *  Public License.
+ *
+ */
+
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+
+use super::color::Color;
+use super::layout_template::ColorSpec;
+use super::page::PageLayout;
+
+/// Foreground overrides keyed by field name, e.g. `date: LightGray`.
+///
+/// A `template.field` key (`clock.date`) targets one page only and wins over
+/// the bare field name.  Values take the same colour syntax as layout.yaml.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ColorTheme {
+    fields: BTreeMap<String, ColorSpec>,
+}
+
+impl ColorTheme {
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Override for `field` on pages built from `template`, if any.
+    pub fn color_for(&self, template: &str, field: &str) -> Option<Color> {
+        self.fields.get(&format!("{template}.{field}"))
+            .or_else(|| self.fields.get(field))
+            .map(Color::from)
+    }
+
+    /// Recolour the matching fields of a page resolved from `template`.
+    pub fn apply(&self, template: &str, page: &mut PageLayout) {
+        if self.is_empty() {
+            return;
+        }
+        for field in &mut page.fields {
+            if let Some(color) = self.color_for(template, &field.name) {
+                field.fg_color = color;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn template_key_beats_bare_field_name() {
+        let theme: ColorTheme = serde_yaml::from_str(
+            "date: DarkGray\nclock.date: {r: 0, g: 200, b: 0}\n").unwrap();
+        assert_eq!(theme.color_for("clock", "date"), Some(Color::Rgb(0, 200, 0)));
+        assert_eq!(theme.color_for("weather_current", "date"), Some(Color::DarkGray));
+        assert_eq!(theme.color_for("clock", "metrics"), None);
+    }
+}
@@ -0,0 +1,120 @@
+/*
+ *  display/time_source.rs
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  Where the render paths get the time - the system clock, or a fixed
+ *  clock for tests
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *  Public License.
+ *
+ */
+
+use chrono::{DateTime, Local, Timelike};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Wall clock and monotonic time for rendering.
+pub trait TimeSource: Send + Sync {
+    /// Local wall-clock time - clock digits, date, seconds bar
+    fn now(&self) -> DateTime<Local>;
+    /// Monotonic time - blink and peak cap decay
+    fn instant(&self) -> Instant;
+}
+
+/// Shared handle, one source for the manager and its components
+pub type SharedTimeSource = Arc<dyn TimeSource>;
+
+/// The real clocks
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemTimeSource;
+
+impl TimeSource for SystemTimeSource {
+    fn now(&self) -> DateTime<Local> {
+        Local::now()
+    }
+
+    fn instant(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// The system clocks, shared
+pub fn system_time() -> SharedTimeSource {
+    Arc::new(SystemTimeSource)
+}
+
+/// Seconds bar fill (0.0 - 1.0) at `now`, millisecond fidelity
+pub fn seconds_fraction(now: &DateTime<Local>) -> f32 {
+    let millis = now.second() * 1000 + now.timestamp_subsec_millis().min(999);
+    millis as f32 / 60_000.0
+}
+
+/// A clock that only moves when told to - both readings advance together.
+#[cfg(any(test, feature = "headless"))]
+#[derive(Debug)]
+pub struct MockTimeSource {
+    at: std::sync::Mutex<(DateTime<Local>, Instant)>,
+}
+
+#[cfg(any(test, feature = "headless"))]
+impl MockTimeSource {
+    pub fn new(now: DateTime<Local>) -> Self {
+        Self { at: std::sync::Mutex::new((now, Instant::now())) }
+    }
+
+    /// Move both clocks on by `by`
+    pub fn advance(&self, by: std::time::Duration) {
+        let mut at = self.at.lock().unwrap();
+        at.0 += by;
+        at.1 += by;
+    }
+
+    /// Jump the wall clock to `now`; the monotonic clock is left alone
+    pub fn set(&self, now: DateTime<Local>) {
+        self.at.lock().unwrap().0 = now;
+    }
+}
+
+#[cfg(any(test, feature = "headless"))]
+impl TimeSource for MockTimeSource {
+    fn now(&self) -> DateTime<Local> {
+        self.at.lock().unwrap().0
+    }
+
+    fn instant(&self) -> Instant {
+        self.at.lock().unwrap().1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use std::time::Duration;
+
+    #[test]
+    fn mock_source_moves_only_when_advanced() {
+        let start = Local.with_ymd_and_hms(2026, 1, 15, 21, 45, 15).unwrap();
+        let mock = MockTimeSource::new(start);
+        let (t0, i0) = (mock.now(), mock.instant());
+        assert_eq!((mock.now(), mock.instant()), (t0, i0));
+        assert_eq!(seconds_fraction(&mock.now()), 0.25);
+
+        mock.advance(Duration::from_millis(15_500));
+        assert_eq!(mock.instant() - i0, Duration::from_millis(15_500));
+        assert_eq!(seconds_fraction(&mock.now()), 30_500.0 / 60_000.0);
+    }
+}
@@ -23,9 +23,44 @@
 
 #![allow(dead_code)] // display driver trait abstractions; written for multi-driver support; may be extended
 
+use std::time::Duration;
+
 use embedded_graphics::prelude::*;
 use crate::display::error::DisplayError;
 
+/// Transfer retries for transient bus errors
+///
+/// The display manager sleeps out the backoff on its async render path, so
+/// the tokio worker is free while the bus recovers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BusRetry {
+    /// Extra attempts after the first failure; 0 gives up straight away
+    pub retries: u32,
+    /// Wait before the first retry, doubled for each one after
+    pub backoff: Duration,
+}
+
+impl BusRetry {
+    pub const DEFAULT_RETRIES: u32 = 3;
+    /// Ten doublings of the default backoff already wait out two seconds
+    pub const MAX_RETRIES: u32 = 10;
+
+    pub fn new(retries: u32) -> Self {
+        Self { retries: retries.min(Self::MAX_RETRIES), ..Self::default() }
+    }
+
+    /// Wait before retry `attempt`, counting from 1
+    pub fn delay(&self, attempt: u32) -> Duration {
+        self.backoff.saturating_mul(1 << attempt.saturating_sub(1).min(Self::MAX_RETRIES))
+    }
+}
+
+impl Default for BusRetry {
+    fn default() -> Self {
+        Self { retries: Self::DEFAULT_RETRIES, backoff: Duration::from_millis(2) }
+    }
+}
+
 /// Color depth capabilities of different display drivers
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ColorDepth {
@@ -130,6 +165,17 @@ pub struct DisplayCapabilities {
     pub driver_name: String,
 }
 
+impl DisplayCapabilities {
+    /// Logical capabilities for a clockwise rotation - 90/270 swap width and height.
+    pub fn rotated(&self, degrees: u16) -> Self {
+        let mut caps = self.clone();
+        if degrees == 90 || degrees == 270 {
+            std::mem::swap(&mut caps.width, &mut caps.height);
+        }
+        caps
+    }
+}
+
 /// Minimal hardware abstraction - all display drivers must implement this trait
 ///
 /// This trait defines the core operations that every display driver must support,
@@ -180,6 +226,11 @@ pub trait DisplayDriver: Send + Sync {
     /// For grayscale displays, this may be 4 bits per pixel or other formats.
     fn write_buffer(&mut self, buffer: &[u8]) -> Result<(), DisplayError>;
 
+    /// Write `buffer` and flush it - one attempt, the manager owns the retries
+    fn transfer(&mut self, buffer: &[u8]) -> Result<(), DisplayError> {
+        self.write_buffer(buffer).and_then(|_| self.flush())
+    }
+
     /// Set display inversion (if supported)
     ///
     /// When inverted, light pixels become dark and vice versa.
@@ -191,9 +242,18 @@ pub trait DisplayDriver: Send + Sync {
         Err(DisplayError::UnsupportedOperation)
     }
 
+    /// Power the panel on or off (if supported)
+    ///
+    /// Off keeps the controller RAM, so switching back on shows the last frame.
+    fn set_display_on(&mut self, _on: bool) -> Result<(), DisplayError> {
+        Err(DisplayError::UnsupportedOperation)
+    }
+
     /// Set display rotation (if supported)
     ///
-    /// Rotation angle should be 0, 90, 180, or 270 degrees.
+    /// Rotation angle should be 0, 90, 180, or 270 degrees. `DisplayManager`
+    /// rotates the framebuffer in software, so this is only for hardware
+    /// orientation when a driver is used standalone.
     fn set_rotation(&mut self, degrees: u16) -> Result<(), DisplayError> {
         if !self.capabilities().supports_rotation {
             return Err(DisplayError::UnsupportedOperation);
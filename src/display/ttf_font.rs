@@ -56,6 +56,9 @@ struct CachedGlyph {
 /// - `Rgb565`      — per-channel scale (assumes black background)
 pub trait BlendCoverage: PixelColor + Copy {
     fn blend(color: Self, coverage: f32) -> Self;
+
+    /// Scale an already drawn color towards black by `level` (0.0–1.0)
+    fn dim(color: Self, level: f32) -> Self;
 }
 
 impl BlendCoverage for BinaryColor {
@@ -63,6 +66,11 @@ impl BlendCoverage for BinaryColor {
     fn blend(_color: Self, coverage: f32) -> Self {
         if coverage >= 0.5 { BinaryColor::On } else { BinaryColor::Off }
     }
+
+    #[inline]
+    fn dim(color: Self, level: f32) -> Self {
+        if level >= 0.5 { color } else { BinaryColor::Off }
+    }
 }
 
 impl BlendCoverage for Gray4 {
@@ -70,6 +78,11 @@ impl BlendCoverage for Gray4 {
     fn blend(_color: Self, coverage: f32) -> Self {
         Gray4::new((coverage * 15.0).round().min(15.0) as u8)
     }
+
+    #[inline]
+    fn dim(color: Self, level: f32) -> Self {
+        Gray4::new((color.luma() as f32 * level).round().min(15.0) as u8)
+    }
 }
 
 impl BlendCoverage for Rgb565 {
@@ -81,6 +94,11 @@ impl BlendCoverage for Rgb565 {
             ((color.b() as f32) * coverage).round() as u8,
         )
     }
+
+    #[inline]
+    fn dim(color: Self, level: f32) -> Self {
+        Self::blend(color, level)
+    }
 }
 
 /// A TTF/OTF font loaded at a fixed pixel size, with a lazy per-character
@@ -0,0 +1,149 @@
+/*
+ *  display/watchdog.rs
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  Display bus watchdog - spots a wedged bus and asks for a driver re-init
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *  Public License.
+ *
+ */
+
+use std::time::{Duration, Instant};
+
+/// What to do about a failed frame transfer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchdogAction {
+    /// Not wedged yet - drop the frame and carry on
+    Wait,
+    /// Re-initialise the driver; carries the attempt number, from 1
+    Reinit(u32),
+    /// Re-inits have not helped; reported once, until a frame gets through
+    GiveUp,
+}
+
+/// Counts failed transfers and calls a wedged bus once there are
+/// `max_failures` in a row, or none has got through for `stall_timeout`.
+/// Retries inside a transfer (`BusRetry`) happen first - this only sees
+/// frames that were lost anyway.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BusWatchdog {
+    /// Consecutive failed transfers before a re-init; 0 disables the watchdog
+    pub max_failures: u32,
+    /// Re-init once transfers have been failing this long, whatever the count
+    pub stall_timeout: Duration,
+    /// Re-inits without a good frame in between before giving up
+    pub max_reinits: u32,
+    failures: u32,
+    failing_since: Option<Instant>,
+    reinits: u32,
+}
+
+impl BusWatchdog {
+    pub const DEFAULT_FAILURES: u32 = 5;
+    pub const DEFAULT_STALL: Duration = Duration::from_secs(10);
+    pub const DEFAULT_REINITS: u32 = 3;
+
+    pub fn new(max_failures: u32, stall_timeout: Duration) -> Self {
+        Self {
+            max_failures,
+            stall_timeout,
+            max_reinits: Self::DEFAULT_REINITS,
+            failures: 0,
+            failing_since: None,
+            reinits: 0,
+        }
+    }
+
+    /// A frame got through - the bus is healthy again.
+    pub fn record_ok(&mut self) {
+        self.failures = 0;
+        self.failing_since = None;
+        self.reinits = 0;
+    }
+
+    /// A frame was lost at `now`.
+    pub fn record_failure(&mut self, now: Instant) -> WatchdogAction {
+        if self.max_failures == 0 {
+            return WatchdogAction::Wait;
+        }
+        self.failures += 1;
+        let since = *self.failing_since.get_or_insert(now);
+        let wedged = self.failures >= self.max_failures
+            || now.saturating_duration_since(since) >= self.stall_timeout;
+        if !wedged {
+            return WatchdogAction::Wait;
+        }
+
+        // start counting afresh against the re-initialised driver
+        self.failures = 0;
+        self.failing_since = Some(now);
+        if self.reinits < self.max_reinits {
+            self.reinits += 1;
+            WatchdogAction::Reinit(self.reinits)
+        } else if self.reinits == self.max_reinits {
+            self.reinits += 1;
+            WatchdogAction::GiveUp
+        } else {
+            WatchdogAction::Wait
+        }
+    }
+}
+
+impl Default for BusWatchdog {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_FAILURES, Self::DEFAULT_STALL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reinits_after_consecutive_failures_then_gives_up() {
+        let mut dog = BusWatchdog::new(3, Duration::from_secs(60));
+        let now = Instant::now();
+        let mut actions = Vec::new();
+        for _ in 0..15 {
+            actions.push(dog.record_failure(now));
+        }
+        let wait = WatchdogAction::Wait;
+        assert_eq!(actions[..3], [wait, wait, WatchdogAction::Reinit(1)]);
+        assert_eq!(actions[5], WatchdogAction::Reinit(2));
+        assert_eq!(actions[8], WatchdogAction::Reinit(3));
+        assert_eq!(actions[11], WatchdogAction::GiveUp);
+        assert_eq!(actions[14], wait);
+
+        // a good frame re-arms it
+        dog.record_ok();
+        assert_eq!((0..3).map(|_| dog.record_failure(now)).last(), Some(WatchdogAction::Reinit(1)));
+    }
+
+    #[test]
+    fn stall_timeout_reinits_before_the_count() {
+        let mut dog = BusWatchdog::new(100, Duration::from_secs(5));
+        let start = Instant::now();
+        assert_eq!(dog.record_failure(start), WatchdogAction::Wait);
+        assert_eq!(dog.record_failure(start + Duration::from_secs(4)), WatchdogAction::Wait);
+        assert_eq!(dog.record_failure(start + Duration::from_secs(5)), WatchdogAction::Reinit(1));
+    }
+
+    #[test]
+    fn zero_failures_disables_it() {
+        let mut dog = BusWatchdog::new(0, Duration::ZERO);
+        assert!((0..50).all(|_| dog.record_failure(Instant::now()) == WatchdogAction::Wait));
+    }
+}
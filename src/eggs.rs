@@ -36,6 +36,7 @@ use std::error::Error;
 use std::fmt;
 use std::fs;
 
+use crate::assets;
 use crate::svgimage::SvgImageRenderer;
 use crate::visualization::SvgColorDepth;
 
@@ -57,6 +58,25 @@ pub const EGGS_TYPE_UNKNOWN: u8 = 255;
 
 pub const NO_WIDE_ASSETS: [u8; 1] = [EGGS_TYPE_CASSETTE];
 
+/// Egg names in cycle order; "none" (no egg) comes last.
+pub const EGG_NAMES: [&str; 15] = [
+    "bass", "blackfly", "cassette", "ibmpc", "moog", "pipboy",
+    "reel2reel", "radio40", "radio50", "scope", "technics",
+    "tubeamp", "tvtime", "vcr", "none"
+];
+
+/// Name of the egg after `current`, wrapping around. With `skip_none` the
+/// "none" entry is passed over, as the timed rotation wants an egg on screen.
+pub fn next_egg_name(current: &str, skip_none: bool) -> &'static str {
+    let idx = EGG_NAMES.iter().position(|&name| name == current).unwrap_or(EGG_NAMES.len() - 1);
+    let next = EGG_NAMES[(idx + 1) % EGG_NAMES.len()];
+    if skip_none && next == "none" {
+        EGG_NAMES[0]
+    } else {
+        next
+    }
+}
+
 /// Custom error type for Eggs rendering operations.
 #[derive(Debug)]
 pub enum EggsError {
@@ -161,7 +181,7 @@ pub fn set_easter_egg(egg_name: &str) -> Eggs {
             let tw = 120;
             Eggs::new(
                 EGGS_TYPE_BASS,
-                "./assets/bass.svg",
+                &assets::path("bass.svg"),
                 Rectangle::new(Point::zero(), Size::new(128,64)),
                 Rectangle::new(Point::new(xpos,4), Size::new(tw,6)),
                 Rectangle::new(Point::new(xpos,12), Size::new(tw,6)),
@@ -177,7 +197,7 @@ pub fn set_easter_egg(egg_name: &str) -> Eggs {
             let tw = 128 - xpos as u32 - 2;
             Eggs::new(
                 EGGS_TYPE_BLACKFLY,
-                "./assets/blackfly_faustus.svg",
+                &assets::path("blackfly_faustus.svg"),
                 Rectangle::new(Point::zero(), Size::new(128,64)),
                 Rectangle::new(Point::new(xpos,ypos), Size::new(tw,58)), 
                 Rectangle::new(Point::zero(), Size::new(0,0)),
@@ -193,7 +213,7 @@ pub fn set_easter_egg(egg_name: &str) -> Eggs {
             let tw = 90;
             Eggs::new(
                 EGGS_TYPE_CASSETTE,
-                "./assets/compactcassette.svg",
+                &assets::path("compactcassette.svg"),
                 Rectangle::new(Point::zero(), Size::new(128,64)),
                 Rectangle::new(Point::new(xpos,6), Size::new(tw,6)), 
                 Rectangle::new(Point::new(xpos,12), Size::new(tw,6)), 
@@ -212,7 +232,7 @@ pub fn set_easter_egg(egg_name: &str) -> Eggs {
             let tw = 128 - xpos as u32 - 2;
             Eggs::new(
                 EGGS_TYPE_IBMPC,
-                "./assets/ibmpc.svg", 
+                &assets::path("ibmpc.svg"), 
                 Rectangle::new(Point::zero(), Size::new(128,64)),
                 Rectangle::new(Point::new(xpos,ypos), Size::new(tw,58)), 
                 Rectangle::new(Point::zero(), Size::new(0,0)),
@@ -228,7 +248,7 @@ pub fn set_easter_egg(egg_name: &str) -> Eggs {
             let tw = 128 - xpos as u32 - 2;
             Eggs::new(
                 EGGS_TYPE_MOOG,
-                "./assets/moog.svg",
+                &assets::path("moog.svg"),
                 Rectangle::new(Point::zero(), Size::new(128,64)),
                 Rectangle::new(Point::new(xpos,ypos), Size::new(tw,58)), 
                 Rectangle::new(Point::zero(), Size::new(0,0)), 
@@ -240,14 +260,16 @@ pub fn set_easter_egg(egg_name: &str) -> Eggs {
             )
         },
         "pipboy" => {
+            // artist and title share the strip above the figures,
+            // Vault Boy's thumbs-up flips on the ripple tags
             let xpos = 72;
             let tw = 128 - xpos as u32 - 2;
             Eggs::new(
                 EGGS_TYPE_PIPBOY,
-                "./assets/pipboy.svg", 
+                &assets::path("pipboy.svg"), 
                 Rectangle::new(Point::zero(), Size::new(128,64)),
-                Rectangle::new(Point::zero(), Size::new(0,0)), 
-                Rectangle::new(Point::zero(), Size::new(0,0)),
+                Rectangle::new(Point::new(2,0), Size::new(90,6)), 
+                Rectangle::new(Point::new(2,6), Size::new(90,6)),
                 0.0, 
                 0.0, 
                 false,
@@ -260,7 +282,7 @@ pub fn set_easter_egg(egg_name: &str) -> Eggs {
             let tw = 128 - xpos as u32 - 2;
             Eggs::new(
                 EGGS_TYPE_REEL2REEL,
-                "./assets/reel2reels.svg", 
+                &assets::path("reel2reels.svg"), 
                 Rectangle::new(Point::zero(), Size::new(128,64)),
                 Rectangle::new(Point::new(xpos,ypos), Size::new(tw,58)), 
                 Rectangle::new(Point::zero(), Size::new(0,0)),
@@ -276,7 +298,7 @@ pub fn set_easter_egg(egg_name: &str) -> Eggs {
             let tw = 128 - xpos as u32 - 2;
             Eggs::new(
                 EGGS_TYPE_RADIO40,
-                "./assets/radio40s.svg", 
+                &assets::path("radio40s.svg"), 
                 Rectangle::new(Point::zero(), Size::new(128,64)),
                 Rectangle::new(Point::new(xpos,ypos), Size::new(tw,58)), 
                 Rectangle::new(Point::zero(), Size::new(0,0)),
@@ -292,7 +314,7 @@ pub fn set_easter_egg(egg_name: &str) -> Eggs {
             let tw = 128 - xpos as u32 - 2;
             Eggs::new(
                 EGGS_TYPE_RADIO50,
-                "./assets/radio50s.svg", 
+                &assets::path("radio50s.svg"), 
                 Rectangle::new(Point::zero(), Size::new(128,64)),
                 Rectangle::new(Point::new(xpos,ypos), Size::new(tw,58)), 
                 Rectangle::new(Point::zero(), Size::new(0,0)),
@@ -308,7 +330,7 @@ pub fn set_easter_egg(egg_name: &str) -> Eggs {
             let tw = 128 - xpos as u32 - 2;
             Eggs::new(
                 EGGS_TYPE_SCOPE,
-                "./assets/scope.svg", 
+                &assets::path("scope.svg"), 
                 Rectangle::new(Point::zero(), Size::new(128,64)),
                 Rectangle::new(Point::new(xpos,ypos), Size::new(tw,58)), 
                 Rectangle::new(Point::zero(), Size::new(0,0)),
@@ -324,7 +346,7 @@ pub fn set_easter_egg(egg_name: &str) -> Eggs {
             let tw = 128 - xpos as u32 - 2;
             Eggs::new(
                 EGGS_TYPE_TECHNICS,
-                "./assets/sl1200.svg",
+                &assets::path("sl1200.svg"),
                 Rectangle::new(Point::zero(), Size::new(128,64)),
                 Rectangle::new(Point::new(xpos,ypos), Size::new(tw,56)),
                 Rectangle::new(Point::zero(), Size::new(0,0)),
@@ -340,7 +362,7 @@ pub fn set_easter_egg(egg_name: &str) -> Eggs {
             let tw = 128 - xpos as u32 - 2;
             Eggs::new(
                 EGGS_TYPE_TUBEAMP,
-                "./assets/tubeampd.svg", 
+                &assets::path("tubeampd.svg"), 
                 Rectangle::new(Point::zero(), Size::new(128,64)),
                 Rectangle::new(Point::new(xpos,ypos), Size::new(tw,58)), 
                 Rectangle::new(Point::zero(), Size::new(0,0)),
@@ -356,7 +378,7 @@ pub fn set_easter_egg(egg_name: &str) -> Eggs {
             let tw = 128 - xpos as u32 - 2;
             Eggs::new(
                 EGGS_TYPE_TVTIME,
-                "./assets/tvtime.svg",
+                &assets::path("tvtime.svg"),
                 Rectangle::new(Point::zero(), Size::new(128,64)),
                 Rectangle::new(Point::new(xpos,ypos), Size::new(tw,58)),
                 Rectangle::new(Point::zero(), Size::new(0,0)),
@@ -372,7 +394,7 @@ pub fn set_easter_egg(egg_name: &str) -> Eggs {
             //let tw = 128 - xpos as u32 - 2;
             Eggs::new(
                 EGGS_TYPE_VCR,
-                "./assets/vcr2000.svg", 
+                &assets::path("vcr2000.svg"), 
                 Rectangle::new(Point::zero(), Size::new(128,64)),
                 Rectangle::new(Point::new(4,2), Size::new(120,6)), 
                 Rectangle::new(Point::new(4,10), Size::new(120,6)), 
@@ -386,7 +408,7 @@ pub fn set_easter_egg(egg_name: &str) -> Eggs {
         _ => {
             Eggs::new(
                 EGGS_TYPE_UNKNOWN,
-                "./assets/none.svg", 
+                &assets::path("none.svg"), 
                 Rectangle::new(Point::zero(), Size::new(128,64)),
                 Rectangle::new(Point::zero(), Size::new(0,0)), 
                 Rectangle::new(Point::zero(), Size::new(0,0)),
@@ -742,3 +764,44 @@ impl Eggs {
     }
 
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotation_skips_none() {
+        assert_eq!(next_egg_name("vcr", false), "none");
+        assert_eq!(next_egg_name("vcr", true), "bass");
+        assert_eq!(next_egg_name("none", true), "bass");
+        assert_eq!(next_egg_name("cassette", true), "ibmpc");
+
+        // a full rotation visits every egg once and never lands on "none"
+        let mut name = "bass";
+        for _ in 0..EGG_NAMES.len() - 1 {
+            name = next_egg_name(name, true);
+            assert_ne!(name, "none");
+        }
+        assert_eq!(name, "bass");
+    }
+
+    #[test]
+    fn pipboy_renders_mono_and_gray4() {
+        use embedded_graphics::pixelcolor::{BinaryColor, Gray4};
+        use crate::vframebuf::VarFrameBuf;
+
+        let mut egg = set_easter_egg("pipboy");
+        assert_eq!(egg.egg_type_name(), "pipboy");
+        assert!(!egg.combine);
+        assert!(egg.artist_rect.size.width > 0 && egg.title_rect.size.width > 0);
+
+        let mut mono = VarFrameBuf::new(128, 64, BinaryColor::Off);
+        egg.render_and_draw(&mut mono, "Artist", "Title", "", "Album", 2, 0.5, 30.0).unwrap();
+        assert!(!egg.get_svg_data().contains("{{"), "every template tag is substituted");
+        assert!(mono.as_slice().contains(&BinaryColor::On));
+
+        let mut gray = VarFrameBuf::new(128, 64, Gray4::new(0));
+        egg.render_and_draw(&mut gray, "Artist", "Title", "", "Album", 2, 0.5, 30.0).unwrap();
+        assert!(gray.as_slice().iter().any(|&p| p != Gray4::new(0)));
+    }
+}
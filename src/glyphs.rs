@@ -88,3 +88,56 @@ pub const GLYPH_SHUFFLE_ALBUMS: [u8; 8] = [0xfc, 0x00, 0x7e, 0x00, 0xfe, 0x12, 0
 pub const GLYPH_AUDIO_HD: [u8; 8] = [0x00, 0x66, 0x66, 0x7e, 0x7e, 0x66, 0x66, 0x00,];
 pub const GLYPH_AUDIO_SD: [u8; 8] = [0x00, 0x3c, 0x66, 0x60, 0x1c, 0x46, 0x66, 0x3c,];
 pub const GLYPH_AUDIO_DSD: [u8; 8] = [0x00, 0x78, 0x6c, 0x66, 0x66, 0x6c, 0x78, 0x00,];
+
+// codec family: filled diamond lossless, hollow diamond lossy
+pub const GLYPH_FORMAT_LOSSLESS: [u8; 8] = [0x00, 0x18, 0x3c, 0x7e, 0x7e, 0x3c, 0x18, 0x00,];
+pub const GLYPH_FORMAT_LOSSY: [u8; 8] = [0x00, 0x18, 0x24, 0x42, 0x42, 0x24, 0x18, 0x00,];
+
+/// Codec family of the playing track, from the LMS `type` tag.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum FormatGlyph {
+    Lossless,
+    Lossy,
+    /// 1-bit DSD - the fidelity glyph already reads D, so nothing extra is drawn
+    Dsd,
+    #[default]
+    Unknown,
+}
+
+impl FormatGlyph {
+    /// Bitmap drawn in the status bar
+    pub fn glyph(self) -> &'static [u8; 8] {
+        match self {
+            FormatGlyph::Lossless => &GLYPH_FORMAT_LOSSLESS,
+            FormatGlyph::Lossy => &GLYPH_FORMAT_LOSSY,
+            FormatGlyph::Dsd | FormatGlyph::Unknown => &GLYPH_NONE,
+        }
+    }
+}
+
+/// Map an LMS content type (`flc`, `mp3`, `dsf`, ...) or codec name to its glyph.
+pub fn format_to_glyph(format: &str) -> FormatGlyph {
+    match format.trim().to_ascii_lowercase().as_str() {
+        "flc" | "flac" | "alc" | "alac" | "wav" | "aif" | "aiff" | "pcm" | "wvp" | "wavpack"
+        | "ape" | "shn" | "wmal" => FormatGlyph::Lossless,
+        "mp3" | "aac" | "mp4" | "m4a" | "ogg" | "ops" | "opus" | "wma" | "wmap" | "mpc" => FormatGlyph::Lossy,
+        "dsf" | "dff" | "dsd" => FormatGlyph::Dsd,
+        _ => FormatGlyph::Unknown,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn codecs_map_to_their_format_glyph() {
+        assert_eq!(format_to_glyph("flac"), FormatGlyph::Lossless);
+        assert_eq!(format_to_glyph("flc"), FormatGlyph::Lossless);
+        assert_eq!(format_to_glyph("mp3"), FormatGlyph::Lossy);
+        assert_eq!(format_to_glyph("AAC"), FormatGlyph::Lossy);
+        assert_eq!(format_to_glyph("dsf"), FormatGlyph::Dsd);
+        assert_eq!(format_to_glyph(""), FormatGlyph::Unknown);
+        assert_eq!(format_to_glyph("spt").glyph(), &GLYPH_NONE);
+    }
+}
@@ -0,0 +1,252 @@
+/*
+ *  http_status.rs
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  Tiny HTTP status endpoint for remote monitoring
+ *
+ *  The render loop writes a snapshot into shared state once per frame and the
+ *  server only ever clones it out, so a slow client never holds up a frame.
+ *  Readers hold the lock just for that clone, so updates wait it out rather
+ *  than skip and lose a track change.
+ *
+ *  Routes, GET only, JSON bodies:
+ *    /healthz   {"status":"ok","lms_connected":true}
+ *    /state     display mode, fps, LMS connection and current track
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *  Public License.
+ *
+ */
+
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use log::{debug, info, warn};
+use serde::Serialize;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+use crate::config::HttpStatusConfig;
+use crate::sliminfo::SlimInfo;
+
+/// Requests larger than this are rejected; we only need the request line.
+const MAX_REQUEST: usize = 2048;
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Current track as reported on `/state`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct TrackState {
+    pub state:         String,
+    pub title:         String,
+    pub artist:        String,
+    pub album:         String,
+    pub volume:        u8,
+    pub duration_secs: u32,
+}
+
+impl TrackState {
+    pub fn new(info: &SlimInfo) -> Self {
+        Self {
+            state:         info.mode.clone(),
+            title:         info.title.clone(),
+            artist:        info.artist.clone(),
+            album:         info.album.clone(),
+            volume:        info.volume,
+            duration_secs: info.duration.raw.max(0.0) as u32,
+        }
+    }
+}
+
+/// Body of `/state`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct StatusSnapshot {
+    pub display_mode:  String,
+    pub fps:           f32,
+    pub lms_connected: bool,
+    pub track:         TrackState,
+}
+
+#[derive(Serialize)]
+struct Health {
+    status:        &'static str,
+    lms_connected: bool,
+}
+
+pub type SharedStatus = Arc<RwLock<StatusSnapshot>>;
+
+/// Handle the render loop uses to refresh the served snapshot.
+pub struct HttpStatus {
+    state: SharedStatus,
+}
+
+impl HttpStatus {
+    /// Start the server in the background. `None` when no port is configured.
+    ///
+    /// Must be called from within the tokio runtime; bind failures are logged.
+    pub fn spawn(config: &HttpStatusConfig) -> Option<Self> {
+        let addr = config.listen_addr()?;
+        let state = SharedStatus::default();
+        let served = state.clone();
+        tokio::spawn(async move {
+            match TcpListener::bind(&addr).await {
+                Ok(listener) => {
+                    info!("http-status: listening on {addr}");
+                    serve(listener, served).await;
+                }
+                Err(e) => warn!("http-status: cannot listen on {addr}: {e}"),
+            }
+        });
+        Some(Self { state })
+    }
+
+    /// Apply `f` to the snapshot, waiting out a request cloning it.
+    pub fn update(&self, f: impl FnOnce(&mut StatusSnapshot)) {
+        let mut snapshot = self.state.write().unwrap_or_else(|poisoned| poisoned.into_inner());
+        f(&mut snapshot);
+    }
+}
+
+/// Accept connections until the listener fails, one task per client.
+pub async fn serve(listener: TcpListener, state: SharedStatus) {
+    loop {
+        match listener.accept().await {
+            Ok((stream, peer)) => {
+                let state = state.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = handle(stream, &state).await {
+                        debug!("http-status: {peer}: {e}");
+                    }
+                });
+            }
+            Err(e) => {
+                warn!("http-status: accept failed: {e}");
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+        }
+    }
+}
+
+async fn handle(mut stream: TcpStream, state: &SharedStatus) -> std::io::Result<()> {
+    let mut buf = vec![0u8; MAX_REQUEST];
+    let mut len = 0;
+    // read until the end of the headers; the body, if any, is ignored
+    while !buf[..len].windows(4).any(|w| w == b"\r\n\r\n") {
+        if len == buf.len() {
+            break;
+        }
+        let n = tokio::time::timeout(READ_TIMEOUT, stream.read(&mut buf[len..])).await
+            .map_err(|_| std::io::Error::new(std::io::ErrorKind::TimedOut, "request timed out"))??;
+        if n == 0 {
+            break;
+        }
+        len += n;
+    }
+
+    let request = String::from_utf8_lossy(&buf[..len]);
+    let mut parts = request.lines().next().unwrap_or("").split_whitespace();
+    let method = parts.next().unwrap_or("");
+    let path = parts.next().unwrap_or("");
+
+    // clone out under the read lock so the render loop is never waiting on the socket
+    let snapshot = state.read().map(|s| s.clone()).unwrap_or_default();
+    let (status, body) = route(method, path, &snapshot);
+
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+/// Status line and JSON body for a request.
+fn route(method: &str, path: &str, snapshot: &StatusSnapshot) -> (&'static str, String) {
+    if method != "GET" {
+        return ("405 Method Not Allowed", r#"{"error":"method not allowed"}"#.to_string());
+    }
+    // ignore any query string
+    match path.split('?').next().unwrap_or("") {
+        "/healthz" => {
+            let health = Health { status: "ok", lms_connected: snapshot.lms_connected };
+            ("200 OK", serde_json::to_string(&health).unwrap_or_default())
+        }
+        "/state" => ("200 OK", serde_json::to_string(snapshot).unwrap_or_default()),
+        _ => ("404 Not Found", r#"{"error":"not found"}"#.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> StatusSnapshot {
+        StatusSnapshot {
+            display_mode:  "scrolling".to_string(),
+            fps:           30.0,
+            lms_connected: true,
+            track: TrackState {
+                state:         "play".to_string(),
+                title:         "So What".to_string(),
+                artist:        "Miles Davis".to_string(),
+                album:         "Kind of Blue".to_string(),
+                volume:        42,
+                duration_secs: 562,
+            },
+        }
+    }
+
+    async fn get(addr: std::net::SocketAddr, path: &str) -> (String, serde_json::Value) {
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream.write_all(format!("GET {path} HTTP/1.1\r\nHost: test\r\n\r\n").as_bytes()).await.unwrap();
+        let mut raw = String::new();
+        stream.read_to_string(&mut raw).await.unwrap();
+        let (head, body) = raw.split_once("\r\n\r\n").unwrap();
+        (head.lines().next().unwrap().to_string(), serde_json::from_str(body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn state_endpoint_serves_snapshot() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let state = SharedStatus::new(RwLock::new(fixture()));
+        tokio::spawn(serve(listener, state));
+
+        let (status, json) = get(addr, "/state").await;
+        assert_eq!(status, "HTTP/1.1 200 OK");
+        assert_eq!(json["display_mode"], "scrolling");
+        assert_eq!(json["fps"], 30.0);
+        assert_eq!(json["lms_connected"], true);
+        let track = json["track"].as_object().unwrap();
+        let mut keys: Vec<_> = track.keys().map(String::as_str).collect();
+        keys.sort();
+        assert_eq!(keys, ["album", "artist", "duration_secs", "state", "title", "volume"]);
+        assert_eq!(track["title"], "So What");
+
+        let (status, json) = get(addr, "/healthz").await;
+        assert_eq!(status, "HTTP/1.1 200 OK");
+        assert_eq!(json["status"], "ok");
+
+        let (status, _) = get(addr, "/nope").await;
+        assert_eq!(status, "HTTP/1.1 404 Not Found");
+    }
+
+    #[test]
+    fn only_get_is_allowed() {
+        let (status, _) = route("POST", "/state", &fixture());
+        assert_eq!(status, "405 Method Not Allowed");
+        let (status, _) = route("GET", "/state?pretty=1", &fixture());
+        assert_eq!(status, "200 OK");
+    }
+}
@@ -22,6 +22,7 @@ pub mod deutils;
 pub mod httprpc;
 pub mod sliminfo;
 pub mod weather;
+pub mod openweathermap;
 pub mod textable;
 pub mod weather_glyph;
 pub mod geoloc;
@@ -36,10 +37,16 @@ pub mod visualization;
 pub mod visualizer;
 pub mod sse_client;
 pub mod visionon;
+pub mod visfifo;
 pub mod vuphysics_new;
 pub mod svgimage;
 pub mod shm_path;
+pub mod assets;
 pub mod sun;
 pub mod coverart;
+#[cfg(feature = "mqtt")]
+pub mod mqtt;
+#[cfg(feature = "http-status")]
+pub mod http_status;
 
 include!(concat!(env!("OUT_DIR"), "/build_info.rs"));
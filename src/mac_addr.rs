@@ -23,6 +23,10 @@
 // mac_addrs.rs (linux-only, no extra deps)
 use std::fs;
 use std::io;
+use std::net::IpAddr;
+
+use local_ip_address::local_ip;
+use log::warn;
 
 pub fn get_mac_addr_for(ifname: &str) -> io::Result<String> {
     let p = format!("/sys/class/net/{}/address", ifname);
@@ -30,8 +34,105 @@ pub fn get_mac_addr_for(ifname: &str) -> io::Result<String> {
     Ok(s.trim().to_ascii_lowercase())
 }
 
-// keep your existing fallback if you like:
-pub fn get_mac_addr() -> String {
+/// MAC of `ifname`, None when the interface doesn't exist
+pub fn mac_for(ifname: &str) -> Option<String> {
+    get_mac_addr_for(ifname).ok()
+}
+
+/// First MAC the system reports, None when it has none
+pub fn get_mac_addr() -> Option<String> {
     use mac_address::get_mac_address;
-    get_mac_address().unwrap().unwrap().to_string().to_ascii_lowercase()
+    get_mac_address().ok().flatten().map(|m| m.to_string().to_ascii_lowercase())
+}
+
+/// MAC identifying this player to LMS: the configured interface, else
+/// whatever the system reports first - unchanged from before the option
+/// existed so an upgrade keeps the player's identity.
+/// A configured interface that's missing is skipped with a warning.
+pub fn identity_mac(interface: Option<&str>) -> Option<String> {
+    if let Some(ifname) = interface {
+        match mac_for(ifname) {
+            Some(mac) => return Some(mac),
+            None => warn!("Network interface {} not found, falling back for the player MAC", ifname),
+        }
+    }
+    get_mac_addr()
+}
+
+/// Addresses for the connection info; whatever the host lacks is None
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ConnectionInfo {
+    pub inet: Option<IpAddr>,
+    pub eth0: Option<String>,
+    pub wlan0: Option<String>,
+}
+
+impl ConnectionInfo {
+    /// Shown in place of a missing address
+    pub const UNKNOWN_IP: &'static str = "0.0.0.0";
+    pub const UNKNOWN_MAC: &'static str = "00:00:00:00:00:00";
+
+    /// This host's local IP and Pi interface MACs - never fails, a headless
+    /// box with no route or no eth0/wlan0 just gets the gaps logged
+    pub fn gather() -> Self {
+        Self::gather_with(
+            || local_ip().map_err(|e| warn!("No local IP address: {}", e)).ok(),
+            mac_for,
+        )
+    }
+
+    /// `gather` over the given lookups
+    pub fn gather_with<I, M>(ip: I, mac: M) -> Self
+    where
+        I: FnOnce() -> Option<IpAddr>,
+        M: Fn(&str) -> Option<String>,
+    {
+        Self { inet: ip(), eth0: mac("eth0"), wlan0: mac("wlan0") }
+    }
+
+    pub fn inet_text(&self) -> String {
+        self.inet.map_or_else(|| Self::UNKNOWN_IP.to_string(), |ip| ip.to_string())
+    }
+
+    pub fn eth0_text(&self) -> &str {
+        self.eth0.as_deref().unwrap_or(Self::UNKNOWN_MAC)
+    }
+
+    pub fn wlan0_text(&self) -> &str {
+        self.wlan0.as_deref().unwrap_or(Self::UNKNOWN_MAC)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_interface_resolves_to_none() {
+        assert_eq!(mac_for("lymons-nope0"), None);
+        assert!(get_mac_addr_for("lymons-nope0").is_err());
+        // a bad configured name falls back rather than panics
+        assert_eq!(identity_mac(Some("lymons-nope0")), identity_mac(None));
+    }
+
+    #[test]
+    fn connection_info_fills_gaps_with_placeholders() {
+        let none = ConnectionInfo::gather_with(|| None, |_| None);
+        assert_eq!(none, ConnectionInfo::default());
+        assert_eq!(none.inet_text(), "0.0.0.0");
+        assert_eq!(none.eth0_text(), "00:00:00:00:00:00");
+        assert_eq!(none.wlan0_text(), "00:00:00:00:00:00");
+
+        // wired only - wlan0 stays a placeholder
+        let wired = ConnectionInfo::gather_with(
+            || Some(IpAddr::from([192, 168, 1, 20])),
+            |ifname| (ifname == "eth0").then(|| "dc:a6:32:00:00:01".to_string()),
+        );
+        assert_eq!(wired.inet_text(), "192.168.1.20");
+        assert_eq!(wired.eth0_text(), "dc:a6:32:00:00:01");
+        assert_eq!(wired.wlan0_text(), ConnectionInfo::UNKNOWN_MAC);
+
+        // the real host may lack any of them but must not panic
+        let _ = ConnectionInfo::gather();
+    }
 }
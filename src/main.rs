@@ -30,16 +30,15 @@ compile_error!("LyMonS currently requires the 'driver-ssd1306' feature. Use --fe
 use std::{time::Duration};
 use log::{info, error, warn};
 use env_logger::Env;
-use local_ip_address::{local_ip};
 
 #[cfg(unix)] // Only compile this block on Unix-like systems
 use tokio::signal::unix::{signal, SignalKind}; // Import specific Unix signals
 
-use lymons::{config, display, location, astral, glyphs};
-#[cfg(feature = "emulator")]
+use lymons::{config, display, location, astral};
 use lymons::visualizer;
-use lymons::sliminfo::LMSServer;
-use lymons::mac_addr::{get_mac_addr, get_mac_addr_for};
+use lymons::sliminfo::{LMSServer, NowPlaying, TransportCommand};
+use lymons::mac_addr::{identity_mac, ConnectionInfo};
+use lymons::translate::{Locale, ModeLabels, Translation};
 use lymons::BUILD_DATE;
 //use singles::SingleInstance;
 
@@ -49,30 +48,37 @@ use lymons::BUILD_DATE;
 /// and waits for any of them to be received. Once a signal is caught, it logs
 /// the event and returns, allowing for graceful shutdown.
 /// Unified display loop that works with DisplayManager
-/// Uses same has_changed() logic as hardware path
+/// Uses same has_changed() logic as hardware path.
+/// `player_name` None runs without LMS - clock, weather and slideshow only.
 #[cfg(feature = "emulator")]
 async fn unified_display_loop(
     display: std::sync::Arc<tokio::sync::Mutex<display::DisplayManager>>,
-    player_name: &str,
-    show_remaining: bool,
+    player_name: Option<&str>,
+    mut show_remaining: bool,
     weather: Option<config::WeatherConfig>,
     viz_type: &str,
+    loop_pause: Duration,
+    poll: display::PollIntervals,
+    mac_addr: &str,
+    sleep: config::SleepTimerConfig,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    use std::time::Duration;
 
     info!("Starting unified display loop with DisplayManager");
 
-    // Get MAC address for LMS discovery
-    let mac_addr = get_mac_addr();
-
     // Connect to LMS server — shows warning and retries every 5s until connected
-    let mut lms_arc = {
-        let mut display_lock = display.lock().await;
-        establish_lms_connection(&mut *display_lock, player_name, mac_addr.as_str()).await
+    let mut lms_arc = match player_name {
+        Some(player_name) => {
+            let mut display_lock = display.lock().await;
+            let lms = establish_lms_connection(&mut *display_lock, player_name, mac_addr).await;
+            info!("LMS Server communication initialized.");
+            Some(lms)
+        }
+        None => {
+            info!("Running without LMS - clock, weather and slideshow only");
+            None
+        }
     };
 
-    info!("LMS Server communication initialized.");
-
     // Setup weather if configured
     if let Some(ref wc) = weather {
         info!("Setting up weather...");
@@ -83,7 +89,7 @@ async fn unified_display_loop(
     }
 
     // Setup visualizer if requested
-    if viz_type != "no_viz" {
+    if viz_type != "no_viz" && let Some(lms_arc) = &lms_arc {
         let lms = lms_arc.lock().await;
 
         // Build SSE fallback config from the active player's IP.
@@ -108,16 +114,13 @@ async fn unified_display_loop(
 
     info!("Setting up polling intervals and display mode");
 
-    // Polling intervals
-    let scrolling_poll_duration = Duration::from_millis(50);
-    let clock_poll_duration = Duration::from_millis(100);
-    let viz_poll_duration = Duration::from_millis(36);
+    display.lock().await.set_scroll_loop_pause(loop_pause, poll.scrolling);
 
     info!("Getting easter egg type");
     // Get easter egg type and create mode controller
-    let egg_type = {
+    let (egg_type, slideshow, history, big_title) = {
         let display_lock = display.lock().await;
-        display_lock.get_egg_type()
+        (display_lock.get_egg_type(), display_lock.has_slides(), display_lock.shows_history(), display_lock.shows_big_title())
     };
 
     info!("Egg type: {}", egg_type);
@@ -130,6 +133,9 @@ async fn unified_display_loop(
         weather_interval_mins: 20,
         weather_current_duration_secs: 30,
         weather_forecast_duration_secs: 30,
+        slideshow,
+        history,
+        big_title,
     };
     let mut mode_controller = display::DisplayModeController::new(mode_config);
 
@@ -142,24 +148,29 @@ async fn unified_display_loop(
         let is_weather_active = display_lock.is_weather_active().await;
         mode_controller.set_weather_active(is_weather_active);
 
-        let mut lms_guard = lms_arc.lock().await;
+        let mut lms_guard = match &lms_arc {
+            Some(lms_arc) => Some(lms_arc.lock().await),
+            None => None,
+        };
 
         // Mid-session health check — reconnect if server/player connection lost
-        if !lms_guard.is_healthy() {
+        if let (Some(guard), Some(player_name)) = (lms_guard.as_mut(), player_name)
+            && !guard.is_healthy()
+        {
             warn!("LMS connection unhealthy — reconnecting...");
-            lms_guard.stop_polling();
+            guard.stop_polling();
             drop(lms_guard);
-            lms_arc = establish_lms_connection(
-                &mut *display_lock, player_name, mac_addr.as_str()
-            ).await;
+            lms_arc = Some(establish_lms_connection(
+                &mut *display_lock, player_name, mac_addr
+            ).await);
             info!("LMS reconnected.");
             drop(display_lock);
             continue;
         }
 
-        // Determine and set display mode
+        // Determine and set display mode - never playing without LMS
         let mut mode = display_lock.display_mode();
-        let is_playing = lms_guard.is_playing();
+        let is_playing = lms_guard.as_ref().is_some_and(|g| g.is_playing());
 
         // Check if manual mode override is active (keyboard locked)
         let manual_override = display_lock.is_manual_mode_override();
@@ -206,6 +217,58 @@ async fn unified_display_loop(
             }
         }
 
+        // Flip weather units live, cached data is converted as it is drawn
+        #[cfg(feature = "emulator")]
+        if display_lock.check_and_clear_toggle_temp_units() {
+            let units = display_lock.toggle_temp_units();
+            info!("Weather units: {}", units.label());
+        }
+
+        // Flip total/remaining track time, kept for later track updates
+        #[cfg(feature = "emulator")]
+        if display_lock.check_and_clear_toggle_show_remaining() {
+            show_remaining = display_lock.toggle_show_remaining();
+            info!("Track time: {}", if show_remaining { "remaining" } else { "total" });
+        }
+
+        // Now playing QR code on demand
+        #[cfg(feature = "emulator")]
+        if display_lock.check_and_clear_show_qr() {
+            display_lock.show_qr_at(std::time::Instant::now());
+            info!("QR code: {}", display_lock.qr_text());
+        }
+
+        // Forward emulator transport keys to the monitored player
+        #[cfg(feature = "emulator")]
+        if let Some(cmd) = display_lock.take_emulator_transport_request()
+            && let Some(guard) = lms_guard.as_mut()
+            && let Err(e) = guard.send_transport(cmd).await
+        {
+            warn!("Transport {:?} failed: {}", cmd, e);
+        }
+
+        // Start or cancel the sleep timer on demand
+        #[cfg(feature = "emulator")]
+        if display_lock.check_and_clear_toggle_sleep_timer() {
+            if display_lock.sleep_timer_running() {
+                display_lock.cancel_sleep_timer();
+                info!("Sleep timer cancelled");
+            } else {
+                display_lock.start_sleep_timer(sleep.length());
+                info!("Sleep timer started: {} min", sleep.length().as_secs() / 60);
+            }
+        }
+
+        // Pause the player once the sleep timer runs out, when asked to
+        display_lock.update_sleep_timer();
+        if display_lock.take_sleep_timer_expired()
+            && sleep.pause.unwrap_or(false)
+            && let Some(guard) = lms_guard.as_mut()
+            && let Err(e) = guard.send_transport(TransportCommand::Pause).await
+        {
+            warn!("Sleep timer pause failed: {}", e);
+        }
+
         display_lock.set_display_mode(mode);
         display_lock.update_emulator_current_mode(mode);
 
@@ -218,58 +281,19 @@ async fn unified_display_loop(
             display::DisplayMode::WeatherCurrent => "weather_current",
             display::DisplayMode::WeatherForecast => "weather_forecast",
             display::DisplayMode::Warning => "warning",
+            display::DisplayMode::Slideshow => "slideshow",
+            display::DisplayMode::History => "history",
+            display::DisplayMode::BigTitle => "big_title",
         };
 
-        if is_playing {
+        if let Some(lms_guard) = lms_guard.as_deref_mut()
+            && is_playing
+        {
 
             // Update display data whenever LMS tags change (all modes, including Visualizer)
             if lms_guard.has_changed() {
-                let current_volume_percent = lms_guard.sliminfo.volume.clone();
-                let current_is_muted = current_volume_percent == 0;
-
-                let repeat_mode = match lms_guard.sliminfo.repeat {
-                    0 => glyphs::RepeatMode::Off,
-                    1 => glyphs::RepeatMode::RepeatAll,
-                    2 => glyphs::RepeatMode::RepeatOne,
-                    _ => glyphs::RepeatMode::Off,
-                };
-                let shuffle_mode = match lms_guard.sliminfo.shuffle {
-                    0 => glyphs::ShuffleMode::Off,
-                    1 => glyphs::ShuffleMode::ByTracks,
-                    2 => glyphs::ShuffleMode::ByAlbums,
-                    _ => glyphs::ShuffleMode::Off,
-                };
-
-                display_lock.set_status_line_data(
-                    current_volume_percent,
-                    current_is_muted,
-                    lms_guard.sliminfo.samplesize.clone().to_string(),
-                    lms_guard.sliminfo.samplerate.clone().to_string(),
-                    repeat_mode,
-                    shuffle_mode,
-                );
-
-                display_lock.set_track_details(
-                    lms_guard.sliminfo.albumartist.clone(),
-                    lms_guard.sliminfo.album.clone(),
-                    lms_guard.sliminfo.title.clone(),
-                    lms_guard.sliminfo.artist.clone(),
-                    lms_guard.sliminfo.year.clone(),
-                    "scroll_mode",
-                    &lms_guard.sliminfo.coverid.clone(),
-                    &lms_guard.host.to_string(),
-                    lms_guard.port,
-                    lms_guard.player_mac(),
-                ).await;
-
-                display_lock.set_track_progress_data(
-                    show_remaining,
-                    lms_guard.sliminfo.duration.raw.clone() as f32,
-                    lms_guard.sliminfo.tracktime.raw.clone() as f32,
-                    lms_guard.sliminfo.remaining.raw.clone() as f32,
-                    lms_guard.sliminfo.mode.clone(),
-                );
-
+                let now_playing = NowPlaying::from_server(lms_guard);
+                display_lock.apply_now_playing(&now_playing, show_remaining).await;
                 lms_guard.reset_changed();
             }
 
@@ -277,21 +301,18 @@ async fn unified_display_loop(
             display_lock.render_frame().await.unwrap_or_else(|e|
                 error!("Failed to render display frame in {} mode: {}", this_mode, e));
         } else {
-            // Not playing - mode controller has already set Clock or Weather mode
-            display_lock.render_frame().await.unwrap_or_else(|e|
+            // Not playing - mode controller has already set Clock or Weather mode;
+            // the idle behaviour may dim or blank it
+            display_lock.render_idle_frame().await.unwrap_or_else(|e|
                 error!("Failed to render display frame in {} mode: {}", this_mode, e));
         }
 
-        // Determine sleep duration based on display mode
-        let current_poll_duration = if display_lock.display_mode() == display::DisplayMode::Clock {
-            clock_poll_duration
-        } else if display_lock.display_mode() == display::DisplayMode::Visualizer {
-            viz_poll_duration
-        } else {
-            scrolling_poll_duration
-        };
+        // Determine sleep duration based on display mode, no shorter than the frame cap allows
+        let current_poll_duration = poll.for_mode(display_lock.display_mode()).max(display_lock.pacing_delay());
 
-        lms_guard.ask_refresh();
+        if let Some(guard) = lms_guard.as_mut() {
+            guard.ask_refresh();
+        }
         drop(lms_guard);
         drop(display_lock);
 
@@ -453,17 +474,15 @@ async fn emulator_render_loop(
 
     // Connect to LMS - use MAC address "" to get all players
     // Convert error to String immediately to ensure Send trait
-    let lms_result = LMSServer::init_server(player_name, "").await
-        .map_err(|e| format!("{}", e));
-
-    if lms_result.is_err() {
-        error!("Failed to connect to LMS. Running in demo mode.");
-        info!("Continuing with demo content...");
-        // Run demo mode in a separate function (this never returns)
-        return emulator_demo_loop(driver, clock_font).await;
-    }
-
-    let lms_arc = lms_result.unwrap();
+    let lms_arc = match LMSServer::init_server(player_name, "").await.map_err(|e| format!("{}", e)) {
+        Ok(lms) => lms,
+        Err(e) => {
+            error!("Failed to connect to LMS ({}). Running in demo mode.", e);
+            info!("Continuing with demo content...");
+            // Run demo mode in a separate function (this never returns)
+            return emulator_demo_loop(driver, clock_font).await;
+        }
+    };
     info!("Connected to LMS server");
     info!("LMS server initialized, getting player info...");
 
@@ -501,8 +520,11 @@ async fn emulator_render_loop(
             let samplerate = lms.sliminfo.samplerate;
             let samplesize = lms.sliminfo.samplesize;
 
-            // Format bitrate display (e.g., "24/192" or "320k")
-            let bitrate_display = if samplesize > 0 && samplerate > 0 {
+            // Format bitrate display (e.g., "24/192", "DSD128" or "320k")
+            let dsd = display::components::status_bar::dsd_label(&samplesize.to_string(), &samplerate.to_string());
+            let bitrate_display = if let Some(dsd) = dsd {
+                dsd
+            } else if samplesize > 0 && samplerate > 0 {
                 format!("{}/{}", samplesize, samplerate / 1000)
             } else if !bitrate.is_empty() {
                 bitrate.clone()
@@ -728,14 +750,9 @@ async fn emulator_render_loop(
                     .draw(&mut *driver_lock)?;
 
                 // Mode text (center)
-                let mode_text = match player_info.1.as_str() {
-                    "play" => "playing",
-                    "pause" => "paused",
-                    "stop" => "stopped",
-                    _ => &player_info.1,
-                };
+                let mode_text = lymons::translate::ModeLabels::default().text_for(&player_info.1);
                 let mode_x = 50; // Approximate center
-                Text::new(mode_text, Point::new(mode_x, 61), tiny_style)
+                Text::new(&mode_text, Point::new(mode_x, 61), tiny_style)
                     .draw(&mut *driver_lock)?;
 
                 // Remaining OR duration (right) - based on show_remaining flag
@@ -806,20 +823,58 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Load config (CLI + YAML file, merged and validated)
     let cfg = config::load().map_err(|e| -> Box<dyn std::error::Error> { e.to_string().into() })?;
 
-    let name_filter     = cfg.player.as_deref().unwrap_or("-");
+    // no player to monitor when running as a desk clock
+    let name_filter     = match cfg.no_lms {
+        Some(true) => None,
+        _ => Some(cfg.player.as_deref().unwrap_or("-")),
+    };
     let scroll_mode     = cfg.scroll_mode.as_deref().unwrap_or("cylon");
+    let loop_pause      = Duration::from_millis(cfg.loop_pause_ms.unwrap_or(0));
+    let loop_gap        = cfg.loop_gap;
+    let cylon_trail     = cfg.cylon_trail.unwrap_or(0);
+    let info_line       = cfg.info_line_slots();
+    let sanitize_text   = cfg.sanitize_text.unwrap_or(true);
+    let vu_calibration  = cfg.vu.clone().unwrap_or_default().calibration();
+    let peak_hold       = cfg.vu.clone().unwrap_or_default().peak_hold();
+    let combi_layout    = cfg.vu.clone().unwrap_or_default().combi_layout();
     let clock_font      = cfg.clock_font.as_deref().unwrap_or("7seg");
+    let date_format     = cfg.date_format.clone();
+    let show_seconds    = cfg.show_seconds.unwrap_or(false);
     let text_font     = cfg.text_font.as_deref().unwrap_or("").to_string();
     let text_font_size   = cfg.text_font_size.unwrap_or(9.0_f32);
     let easter_egg      = cfg.easter_egg.as_deref().unwrap_or("none");
+    let egg_rotation    = cfg.egg_rotate_mins.map(|m| Duration::from_secs(m * 60));
     let viz_type        = cfg.visualizer.as_deref().unwrap_or("no_viz");
     let hist_scheme     = cfg.hist_scheme.as_deref().unwrap_or("classic");
+    let spectrum_agc    = cfg.spectrum_agc.as_ref().map(|a| a.settings());
+    let viz_downmix     = cfg.viz_downmix;
     let show_splash     = cfg.show_splash.unwrap_or(true);
+    let splash_hold     = cfg.splash_hold();
     let show_metrics    = cfg.show_metrics.unwrap_or(false);
+    let metrics_format  = cfg.metrics.clone().unwrap_or_default().format();
+    let net_interface   = cfg.metrics.as_ref().and_then(|m| m.interface.clone());
+    let burn_in         = cfg.burn_in.clone();
+    let slideshow       = cfg.slideshow.clone();
+    let history         = cfg.history.clone();
+    let big_title       = cfg.big_title.unwrap_or(false);
+    let qr_template     = cfg.qr_code.as_ref().and_then(|q| q.template.clone());
+    let qr_interval     = cfg.qr_code.as_ref().and_then(|q| q.interval());
+    let sleep_timer     = cfg.sleep_timer.clone().unwrap_or_default();
+    let idle_behavior   = cfg.idle_behavior.unwrap_or_default();
+    let inactivity      = cfg.inactivity_timeout_mins.map(|m| Duration::from_secs(m as u64 * 60));
+    let color_theme     = cfg.theme.clone().unwrap_or_default();
+    let second_display  = cfg.second_display.clone();
+    let mqtt_config     = cfg.mqtt.clone();
+    let http_status     = cfg.http_status.clone();
+    let svg_dither      = cfg.svg_dither.as_deref()
+        .map(lymons::svgimage::Gray4Dither::parse)
+        .unwrap_or_default();
     let show_remaining  = cfg.show_remaining.unwrap_or(false);
     let debug_enabled   = cfg.log_level.as_deref().map(|l| l == "debug").unwrap_or(false);
-    let emulated        = cfg.display.as_ref().and_then(|d| d.emulated).unwrap_or(false);
-    let mac_addr      = get_mac_addr();
+    // a GIF capture records the emulator panel, so it implies emulation
+    let emulated        = cfg.display.as_ref().and_then(|d| d.emulated).unwrap_or(false)
+        || cfg.record_gif.is_some();
+    let mac_interface   = cfg.mac_interface.clone();
     let effective_weather = cfg.effective_weather();
     let (astral_lat, astral_lon) = cfg.effective_lat_lng();
 
@@ -828,6 +883,40 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .format_timestamp_secs()
         .init();
     
+    lymons::svgimage::set_svg_dither(svg_dither);
+
+    // MAC identifying this player to LMS
+    let mac_addr = identity_mac(mac_interface.as_deref()).unwrap_or_else(|| {
+        warn!("No network interface MAC found, LMS player identity unmatched");
+        "00:00:00:00:00:00".to_string()
+    });
+
+    // Built in locales label offline; other languages translate the player states, English when unreachable
+    let language = cfg.language().map(str::to_string);
+    let locale = language.as_deref().and_then(Locale::parse);
+    let mode_labels = match (locale, language.as_deref()) {
+        (Some(locale), _) => ModeLabels::for_locale(locale),
+        (None, Some(lang)) => match Translation::new(lang) {
+            Ok(mut translation) => tokio::time::timeout(Duration::from_secs(5), ModeLabels::translated(&mut translation))
+                .await
+                .unwrap_or_else(|_| {
+                    warn!("Translating player state labels to '{}' timed out", lang);
+                    ModeLabels::default()
+                }),
+            Err(e) => {
+                warn!("No translation to '{}': {}", lang, e);
+                ModeLabels::default()
+            }
+        },
+        (None, None) => ModeLabels::default(),
+    };
+    let locale = locale.unwrap_or_default();
+
+    // Resolved once the logger is up, so bad values get reported
+    let poll       = display::PollIntervals::from_millis(cfg.scroll_poll_ms, cfg.clock_poll_ms, cfg.viz_poll_ms);
+    let viz_source = cfg.viz_source.as_deref().unwrap_or("shm").parse::<visualizer::VizSource>()
+        .unwrap_or_else(|e| { warn!("{e}, using shm"); visualizer::VizSource::Shm });
+
     info!("This {} worth the Squeeze", env!("CARGO_PKG_NAME"));
     info!("v.{} built {}", env!("CARGO_PKG_VERSION"), BUILD_DATE);
 
@@ -847,6 +936,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         // For emulator: create EmulatorDriver with specs from config
         info!("Creating emulator with DisplayManager (unified approach)");
+        if second_display.is_some() {
+            warn!("second_display is driven on hardware only, the emulator shows the main panel");
+        }
 
         // Determine display specs: driver defaults, overridden by config width/height.
         // Useful for displays that ship in multiple sizes (ST7789: 320×170, 300×170, 240×240…)
@@ -927,6 +1019,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         // Create DisplayManager with the emulator driver
         let mut display_manager = display::DisplayManager::new_with_driver(
             emulator_driver,
+            display_config.rotate_deg.unwrap_or(0),
             scroll_mode,
             clock_font,
             show_metrics,
@@ -936,11 +1029,59 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         // Set emulator state for keyboard shortcuts
         display_manager.set_emulator_state(emulator_state.clone());
+        display_manager.set_vu_calibration(&vu_calibration);
+        display_manager.set_peak_hold(peak_hold.hold, peak_hold.decay_rate);
+        display_manager.set_combi_layout(combi_layout);
+        display_manager.set_metrics_format(metrics_format.clone());
+        display_manager.device_metrics.set_net_interface(net_interface.clone());
+        display_manager.set_egg_rotation_interval(egg_rotation);
+        if let Some(format) = &date_format {
+            display_manager.clock_display_mut().set_date_format(format);
+        }
+        display_manager.clock_display_mut().set_show_seconds(show_seconds);
+        display_manager.set_scroll_loop_gap(loop_gap);
+        display_manager.set_cylon_trail(cylon_trail);
+        display_manager.set_info_line(info_line);
+        display_manager.set_locale(locale);
+        display_manager.set_mode_labels(mode_labels.clone());
+        display_manager.set_sanitize_text(sanitize_text);
+        display_manager.set_viz_source(viz_source.clone());
+        display_manager.set_spectrum_agc(spectrum_agc);
+        display_manager.set_viz_downmix(viz_downmix);
+        display_manager.set_color_theme(color_theme.clone());
+        display_manager.set_progress_position(display_config.progress_bar.unwrap_or_default());
+        display_manager.set_track_lines(display_config.track_lines.clone());
+        display_manager.set_frame_cap(display_config.frame_cap.unwrap_or(0));
+        display_manager.set_idle_behavior(idle_behavior);
+        display_manager.set_inactivity_timeout(inactivity);
+        if let Some(s) = &slideshow && let Some(dir) = &s.dir {
+            display_manager.set_slideshow(std::path::Path::new(dir), s.interval());
+        }
+        if let Some(h) = &history {
+            display_manager.set_history(h.length(), h.file.as_deref().map(std::path::Path::new));
+        }
+        display_manager.set_big_title(big_title);
+        display_manager.set_qr_code(qr_template.clone(), qr_interval);
+        if let Some(length) = sleep_timer.at_launch() {
+            display_manager.start_sleep_timer(length);
+        }
+        if let Some(b) = &burn_in {
+            display_manager.set_burn_in_protection(
+                b.enabled.unwrap_or(true),
+                b.max_shift_px.unwrap_or(1),
+                Duration::from_secs(b.interval_secs.unwrap_or(60)),
+            );
+        }
+
+        if cfg.self_test.unwrap_or(false) {
+            warn!("--selftest needs a hardware panel, ignored in emulation mode");
+        }
 
         info!("DisplayManager created - using unified display loop");
 
         // I N I T I A L I Z A T I O N   S E Q U E N C E   W I T H   S P L A S H
         // Show splash screen during initialization (unless user opted out)
+        display_manager.set_splash_hold(splash_hold);
         display_manager.splash(
             show_splash,
             &format!("v:{}", env!("CARGO_PKG_VERSION")).as_str(),
@@ -1010,7 +1151,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         if show_splash {
             display_manager.update_splash_status("Initialization complete")?;
-            tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
+            if !splash_hold.is_zero() {
+                tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
+            }
         }else{
             info!("Initialization complete")
         }
@@ -1020,18 +1163,24 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         let display_clone = display_arc.clone();
 
         // Clone parameters for the unified loop
-        let name_filter_clone = name_filter.to_string();
+        let name_filter_clone = name_filter.map(str::to_string);
         let weather_clone = effective_weather.clone();
         let viz_clone = viz_type.to_string();
+        let mac_clone = mac_addr.clone();
+        let sleep_clone = sleep_timer.clone();
 
         // Spawn unified display loop in background (SAME AS HARDWARE!)
         tokio::spawn(async move {
             if let Err(e) = unified_display_loop(
                 display_clone,
-                &name_filter_clone,
+                name_filter_clone.as_deref(),
                 show_remaining,
                 weather_clone,
                 &viz_clone,
+                loop_pause,
+                poll,
+                &mac_clone,
+                sleep_clone,
             ).await {
                 error!("Unified display loop error: {}", e);
             }
@@ -1053,12 +1202,28 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         info!("  Close window or press Ctrl+C to exit");
         info!("═══════════════════════════════════════════════════");
 
+        // Headless capture: record, wait for the GIF to be written, exit
+        if let Some(capture) = &cfg.record_gif {
+            emulator_state.lock().unwrap()
+                .start_gif_recording(Duration::from_secs(capture.seconds), capture.path.clone());
+            while emulator_state.lock().unwrap().gif_recording.is_some() {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+            return Ok(());
+        }
+
         // Run window on main thread (required by winit)
         let window = EmulatorWindow::new(emulator_state, EmulatorWindowConfig::default());
         return window.run().map_err(|e| e.into());
 
     }
 
+    #[cfg(not(feature = "emulator"))]
+    if cfg.record_gif.is_some() {
+        error!("--record-gif needs the emulator but not compiled with --features emulator");
+        return Err("Build with --features emulator to record a GIF".into());
+    }
+
     #[cfg(not(feature = "emulator"))]
     if emulated {
         error!("Emulation mode requested but not compiled with --features emulator");
@@ -1068,8 +1233,14 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Create DisplayManager (works with any driver from config)
     info!("Creating DisplayManager with dynamic driver loading");
 
-    // Load display config from merged cfg
-    let display_config = cfg.display.clone().unwrap_or_default();
+    // Load display config from merged cfg, ssd1306 on I2C when nothing is named
+    let display_config = display::DisplayDriverFactory::with_defaults(
+        cfg.display.clone().unwrap_or_default(),
+        cfg.i2c_bus.as_deref().unwrap_or(config::DEFAULT_I2C_BUS),
+    );
+    if let Some(driver) = &display_config.driver {
+        info!("Display driver: {:?} on {:?}", driver, display_config.bus);
+    }
 
     let mut display_manager = display::DisplayManager::new(
         &display_config,
@@ -1080,18 +1251,17 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         hist_scheme,
     )?;
 
-    let inet =  local_ip().unwrap();
-    // specific to Pi nomenclature
-    let eth0_mac_addr = get_mac_addr_for("eth0").unwrap_or_else(|_| "00:00:00:00:00:00".to_string());
-    let wlan0_mac_addr = get_mac_addr_for("wlan0").unwrap_or_else(|_| "00:00:00:00:00:00".to_string());
+    if cfg.self_test.unwrap_or(false) {
+        display_manager.run_panel_test().await?;
+        return Ok(());
+    }
 
-    display_manager.connections(
-        inet.to_string().as_str(),
-        eth0_mac_addr.clone().as_str(),
-        wlan0_mac_addr.clone().as_str()
-    );
+    // informational only - missing addresses show as placeholders
+    let net = ConnectionInfo::gather();
+    display_manager.connections(&net.inet_text(), net.eth0_text(), net.wlan0_text());
 
     // Show splash screen during initialization (unless user opted out)
+    display_manager.set_splash_hold(splash_hold);
     display_manager.splash(
         show_splash,
         &format!("v{}",env!("CARGO_PKG_VERSION")).as_str(),
@@ -1154,13 +1324,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let text_font_name = text_font.clone();
     let font_size = text_font_size.clamp(9.0, 30.0);
     let zip_path = format!("./data/{}-text.zip", text_font_name);
-    if let Some(ttf) = display::ttf_font::TtfFont::load_from_zip(&zip_path, font_size) {
-        display_manager.set_text_font(ttf);
+    let text_ttf = display::ttf_font::TtfFont::load_from_zip(&zip_path, font_size);
+    if let Some(ttf) = &text_ttf {
+        display_manager.set_text_font(ttf.clone());
     }
 
     if show_splash {
         display_manager.update_splash_status("Initialization complete")?;
-        tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
+        if !splash_hold.is_zero() {
+            tokio::time::sleep(std::time::Duration::from_millis(1000)).await;
+        }
     } else{
         info!("Initialization complete");
     }
@@ -1176,29 +1349,154 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     display_manager.test(false).await;
 
-    // sleep duration for playing, visualizer, and easter eggs
-    let scrolling_poll_duration = Duration::from_millis(50);
-    // clock display sleep duration
-    let clock_poll_duration = Duration::from_millis(100);
-    let viz_poll_duration = Duration::from_millis(36); // ~30Hz balance I2C refresh (16=60Hz)
+    display_manager.set_scroll_loop_pause(loop_pause, poll.scrolling);
+    display_manager.set_vu_calibration(&vu_calibration);
+    display_manager.set_peak_hold(peak_hold.hold, peak_hold.decay_rate);
+    display_manager.set_combi_layout(combi_layout);
+    display_manager.set_metrics_format(metrics_format);
+    display_manager.device_metrics.set_net_interface(net_interface);
+    display_manager.set_egg_rotation_interval(egg_rotation);
+    if let Some(format) = &date_format {
+        display_manager.clock_display_mut().set_date_format(format);
+    }
+    display_manager.clock_display_mut().set_show_seconds(show_seconds);
+    display_manager.set_scroll_loop_gap(loop_gap);
+    display_manager.set_cylon_trail(cylon_trail);
+    display_manager.set_info_line(info_line);
+    display_manager.set_locale(locale);
+    display_manager.set_mode_labels(mode_labels.clone());
+    display_manager.set_sanitize_text(sanitize_text);
+    display_manager.set_viz_source(viz_source.clone());
+    display_manager.set_spectrum_agc(spectrum_agc);
+    display_manager.set_viz_downmix(viz_downmix);
+    display_manager.set_color_theme(color_theme.clone());
+    display_manager.set_progress_position(display_config.progress_bar.unwrap_or_default());
+    display_manager.set_track_lines(display_config.track_lines.clone());
+    display_manager.set_frame_cap(display_config.frame_cap.unwrap_or(0));
+    display_manager.set_idle_behavior(idle_behavior);
+    display_manager.set_inactivity_timeout(inactivity);
+    if let Some(s) = &slideshow && let Some(dir) = &s.dir {
+        display_manager.set_slideshow(std::path::Path::new(dir), s.interval());
+    }
+    if let Some(h) = &history {
+        display_manager.set_history(h.length(), h.file.as_deref().map(std::path::Path::new));
+    }
+    display_manager.set_big_title(big_title);
+    display_manager.set_qr_code(qr_template.clone(), qr_interval);
+    if let Some(length) = sleep_timer.at_launch() {
+        display_manager.start_sleep_timer(length);
+    }
+    if let Some(b) = &burn_in {
+        display_manager.set_burn_in_protection(
+            b.enabled.unwrap_or(true),
+            b.max_shift_px.unwrap_or(1),
+            Duration::from_secs(b.interval_secs.unwrap_or(60)),
+        );
+    }
+
+    // Optional second panel - same player and weather feed, its own driver and bus,
+    // following the main panel's mode unless it holds one of its own
+    let mut second_display = match &second_display {
+        Some(second) => {
+            let second_config = display::DisplayDriverFactory::with_defaults(
+                second.display.clone(),
+                cfg.i2c_bus.as_deref().unwrap_or(config::DEFAULT_I2C_BUS),
+            );
+            match display::DisplayManager::new(&second_config, scroll_mode, clock_font, false, easter_egg, hist_scheme) {
+                Ok(mut second_manager) => {
+                    if let Some(loc) = location.clone() {
+                        second_manager.set_astral_service(astral::AstralService::new(loc));
+                    }
+                    if let Some(ttf) = &text_ttf {
+                        second_manager.set_text_font(ttf.clone());
+                    }
+                    if let (Some(wc), Some(weather_rx)) = (&effective_weather, display_manager.weather_watch()) {
+                        second_manager.share_weather(wc, weather_rx);
+                    }
+                    second_manager.set_scroll_loop_pause(loop_pause, poll.scrolling);
+                    second_manager.set_scroll_loop_gap(loop_gap);
+                    second_manager.set_cylon_trail(cylon_trail);
+                    second_manager.set_info_line(info_line);
+                    second_manager.set_locale(locale);
+                    second_manager.set_mode_labels(mode_labels.clone());
+                    second_manager.set_sanitize_text(sanitize_text);
+                    if let Some(format) = &date_format {
+                        second_manager.clock_display_mut().set_date_format(format);
+                    }
+                    second_manager.clock_display_mut().set_show_seconds(show_seconds);
+                    second_manager.set_color_theme(color_theme.clone());
+                    second_manager.set_progress_position(second_config.progress_bar.unwrap_or_default());
+                    second_manager.set_track_lines(second_config.track_lines.clone());
+                    second_manager.set_frame_cap(second_config.frame_cap.unwrap_or(0));
+                    second_manager.set_idle_behavior(idle_behavior);
+                    second_manager.set_inactivity_timeout(inactivity);
+                    if let Some(s) = &slideshow && let Some(dir) = &s.dir {
+                        second_manager.set_slideshow(std::path::Path::new(dir), s.interval());
+                    }
+                    // in memory only - the main panel owns the history file
+                    if let Some(h) = &history {
+                        second_manager.set_history(h.length(), None);
+                    }
+                    second_manager.set_big_title(big_title);
+                    second_manager.set_qr_code(qr_template.clone(), qr_interval);
+                    if let Some(b) = &burn_in {
+                        second_manager.set_burn_in_protection(
+                            b.enabled.unwrap_or(true),
+                            b.max_shift_px.unwrap_or(1),
+                            Duration::from_secs(b.interval_secs.unwrap_or(60)),
+                        );
+                    }
+                    info!("Second display: {:?} on {:?}, mode {:?}", second_config.driver, second_config.bus, second.mode);
+                    Some((second_manager, second.mode))
+                }
+                Err(e) => {
+                    warn!("Second display unavailable: {}", e);
+                    None
+                }
+            }
+        }
+        None => None,
+    };
+
+    #[cfg(feature = "mqtt")]
+    let mut mqtt = mqtt_config.as_ref().and_then(lymons::mqtt::MqttPublisher::spawn);
+    #[cfg(not(feature = "mqtt"))]
+    if mqtt_config.is_some_and(|m| m.broker.is_some()) {
+        warn!("mqtt: broker configured but this build lacks the mqtt feature");
+    }
+    #[cfg(feature = "http-status")]
+    let status_server = http_status.as_ref().and_then(lymons::http_status::HttpStatus::spawn);
+    #[cfg(not(feature = "http-status"))]
+    if http_status.is_some_and(|h| h.port.is_some()) {
+        warn!("http-status: port configured but this build lacks the http-status feature");
+    }
 
     // Initialize the LMS server, discover it, fetch players, init tags, and start polling
     // init_server now returns Arc<TokMutex<LMSServer>>
-    if show_splash {
-        display_manager.update_splash_status("LMS communication...")?;
-    } else{
-        info!("LMS communication...");
-    }
-    // Connect to LMS server — shows warning and retries every 5s until connected
-    let mut lms_arc = establish_lms_connection(
-        &mut display_manager, name_filter, mac_addr.as_str()
-    ).await;
-    info!("LMS Server communication initialized.");
+    let mut lms_arc = match name_filter {
+        Some(name_filter) => {
+            if show_splash {
+                display_manager.update_splash_status("LMS communication...")?;
+            } else{
+                info!("LMS communication...");
+            }
+            // Connect to LMS server — shows warning and retries every 5s until connected
+            let lms_arc = establish_lms_connection(
+                &mut display_manager, name_filter, mac_addr.as_str()
+            ).await;
+            info!("LMS Server communication initialized.");
 
-    let lms = lms_arc.lock().await;
-    // TODO: Fix visualizer receiver type mismatch
-    // display_manager.setup_visualizer(viz_type, lms.subscribe_playing()).await?;
-    drop(lms);
+            let lms = lms_arc.lock().await;
+            // TODO: Fix visualizer receiver type mismatch
+            // display_manager.setup_visualizer(viz_type, lms.subscribe_playing()).await?;
+            drop(lms);
+            Some(lms_arc)
+        }
+        None => {
+            info!("Running without LMS - clock, weather and slideshow only");
+            None
+        }
+    };
 
     if show_splash {
         display_manager.update_splash_status("LyMonS is worth the squeeze")?;
@@ -1226,6 +1524,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 weather_interval_mins: 20,
                 weather_current_duration_secs: 30,
                 weather_forecast_duration_secs: 30,
+                slideshow: display_manager.has_slides(),
+                history: display_manager.shows_history(),
+                big_title: display_manager.shows_big_title(),
             };
             let mut mode_controller = display::DisplayModeController::new(mode_config);
 
@@ -1235,22 +1536,41 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                 mode_controller.set_weather_active(is_weather_active);
 
                 // Acquire a lock on the LMSServer instance to access its methods and data
-                let mut lms_guard = lms_arc.lock().await;
+                let mut lms_guard = match &lms_arc {
+                    Some(lms_arc) => Some(lms_arc.lock().await),
+                    None => None,
+                };
 
                 // Mid-session health check — reconnect if server/player connection lost
-                if !lms_guard.is_healthy() {
+                if let (Some(guard), Some(name_filter)) = (lms_guard.as_mut(), name_filter)
+                    && !guard.is_healthy()
+                {
                     warn!("LMS connection unhealthy — reconnecting...");
-                    lms_guard.stop_polling();
+                    guard.stop_polling();
                     drop(lms_guard);
-                    lms_arc = establish_lms_connection(
+                    #[cfg(feature = "http-status")]
+                    if let Some(status) = &status_server {
+                        status.update(|s| s.lms_connected = false);
+                    }
+                    lms_arc = Some(establish_lms_connection(
                         &mut display_manager, name_filter, mac_addr.as_str()
-                    ).await;
+                    ).await);
                     info!("LMS reconnected.");
                     continue;
                 }
 
-                // Determine and set display mode using controller
-                let is_playing = lms_guard.is_playing();
+                // Determine and set display mode using controller - never playing without LMS
+                // Pause the player once the sleep timer runs out, when asked to
+                display_manager.update_sleep_timer();
+                if display_manager.take_sleep_timer_expired()
+                    && sleep_timer.pause.unwrap_or(false)
+                    && let Some(guard) = lms_guard.as_mut()
+                    && let Err(e) = guard.send_transport(TransportCommand::Pause).await
+                {
+                    warn!("Sleep timer pause failed: {}", e);
+                }
+
+                let is_playing = lms_guard.as_ref().is_some_and(|g| g.is_playing());
                 mode_controller.update_mode(is_playing);
                 let mode = mode_controller.current_mode();
                 display_manager.set_display_mode(mode);
@@ -1264,82 +1584,89 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                     display::DisplayMode::WeatherCurrent => "weather_current",
                     display::DisplayMode::WeatherForecast => "weather_forecast",
                     display::DisplayMode::Warning => "warning",
+                    display::DisplayMode::Slideshow => "slideshow",
+                    display::DisplayMode::History => "history",
+                    display::DisplayMode::BigTitle => "big_title",
                 };
 
-                if is_playing {
+                #[cfg(feature = "mqtt")]
+                if let Some(mqtt) = mqtt.as_mut() {
+                    // publish on LMS tag changes and display mode transitions
+                    let mode_changed = mqtt.publish_display_mode(this_mode);
+                    if let Some(guard) = lms_guard.as_ref()
+                        && (mode_changed || guard.has_changed())
+                    {
+                        mqtt.publish_now_playing(&guard.sliminfo, this_mode);
+                    }
+                }
 
-                    // Update display data whenever LMS tags change (all modes, including Visualizer)
-                    if lms_guard.has_changed() {
-                        let current_volume_percent = lms_guard.sliminfo.volume.clone();
-                        let current_is_muted = current_volume_percent == 0;
-
-                        let repeat_mode = match lms_guard.sliminfo.repeat {
-                            0 => glyphs::RepeatMode::Off,
-                            1 => glyphs::RepeatMode::RepeatAll,
-                            2 => glyphs::RepeatMode::RepeatOne,
-                            _ => glyphs::RepeatMode::Off,
-                        };
-                        let shuffle_mode = match lms_guard.sliminfo.shuffle {
-                            0 => glyphs::ShuffleMode::Off,
-                            1 => glyphs::ShuffleMode::ByTracks,
-                            2 => glyphs::ShuffleMode::ByAlbums,
-                            _ => glyphs::ShuffleMode::Off,
-                        };
-
-                        display_manager.set_status_line_data(
-                            current_volume_percent,
-                            current_is_muted,
-                            lms_guard.sliminfo.samplesize.clone().to_string(),
-                            lms_guard.sliminfo.samplerate.clone().to_string(),
-                            repeat_mode,
-                            shuffle_mode,
-                        );
+                #[cfg(feature = "http-status")]
+                if let Some(status) = &status_server {
+                    let track_changed = lms_guard.as_ref().is_some_and(|g| g.has_changed());
+                    status.update(|s| {
+                        if s.display_mode != this_mode {
+                            s.display_mode = this_mode.to_string();
+                        }
+                        s.fps = display_manager.metrics.fps();
+                        s.lms_connected = lms_guard.is_some();
+                        if let Some(guard) = lms_guard.as_ref()
+                            && (track_changed || s.track.state.is_empty())
+                        {
+                            s.track = lymons::http_status::TrackState::new(&guard.sliminfo);
+                        }
+                    });
+                }
 
-                        display_manager.set_track_details(
-                            lms_guard.sliminfo.albumartist.clone(),
-                            lms_guard.sliminfo.album.clone(),
-                            lms_guard.sliminfo.title.clone(),
-                            lms_guard.sliminfo.artist.clone(),
-                            lms_guard.sliminfo.year.clone(),
-                            scroll_mode,
-                            &lms_guard.sliminfo.coverid.clone(),
-                            &lms_guard.host.to_string(),
-                            lms_guard.port,
-                            lms_guard.player_mac(),
-                        ).await;
-
-                        display_manager.set_track_progress_data(
-                            show_remaining,
-                            lms_guard.sliminfo.duration.raw.clone() as f32,
-                            lms_guard.sliminfo.tracktime.raw.clone() as f32,
-                            lms_guard.sliminfo.remaining.raw.clone() as f32,
-                            lms_guard.sliminfo.mode.clone(),
-                        );
+                if let Some(lms_guard) = lms_guard.as_deref_mut()
+                    && is_playing
+                {
 
+                    // Update display data whenever LMS tags change (all modes, including Visualizer)
+                    if lms_guard.has_changed() {
+                        let now_playing = NowPlaying::from_server(lms_guard);
+                        display_manager.apply_now_playing(&now_playing, show_remaining).await;
+                        if let Some((second, _)) = second_display.as_mut() {
+                            second.apply_now_playing(&now_playing, show_remaining).await;
+                        }
                         lms_guard.reset_changed();
                     }
 
                     // Always render when playing (visualizer needs every frame for animation)
-                    display_manager.render_frame().await.unwrap_or_else(|e| error!("Failed to render display frame in {} mode: {}", this_mode, e));
+                    display_manager.render_frame().await.unwrap_or_else(|e| if e.is_transient() {
+                        warn!("Dropped a display frame in {} mode: {}", this_mode, e)
+                    } else {
+                        error!("Failed to render display frame in {} mode: {}", this_mode, e)
+                    });
                 } else {
 
                     // When not playing - mode controller has already set Clock or Weather mode
-                    // Render the frame. The required update and draw method will be called
-                    display_manager.render_frame().await.unwrap_or_else(|e| error!("Failed to render display frame in {} mode: {}", this_mode, e));
+                    // Render the frame per the idle behaviour (clock, blank or dimmed clock)
+                    display_manager.render_idle_frame().await.unwrap_or_else(|e| error!("Failed to render display frame in {} mode: {}", this_mode, e));
+
+                }
 
+                if let Some((second, held_mode)) = second_display.as_mut() {
+                    second.set_display_mode(held_mode.unwrap_or(mode));
+                    let rendered = if is_playing {
+                        second.render_frame().await
+                    } else {
+                        second.render_idle_frame().await
+                    };
+                    rendered.unwrap_or_else(|e| warn!("Failed to render second display frame: {}", e));
                 }
                 
-                // Determine sleep duration based on the current display mode
-                let current_poll_duration = if display_manager.current_mode == display::DisplayMode::Clock {
-                    clock_poll_duration
-                } else if display_manager.current_mode == display::DisplayMode::Visualizer {
-                    viz_poll_duration
-                } else {
-                    scrolling_poll_duration
-                };
+                // Determine sleep duration based on the current display mode, no shorter
+                // than either panel's frame cap allows
+                let mut current_poll_duration = poll.for_mode(display_manager.current_mode)
+                    .max(display_manager.pacing_delay());
+                if let Some((second, _)) = second_display.as_ref() {
+                    current_poll_duration = current_poll_duration.max(second.pacing_delay());
+                }
 
                 // Ensure LMS server data is refreshed
-                lms_guard.ask_refresh();
+                if let Some(guard) = lms_guard.as_mut() {
+                    guard.ask_refresh();
+                }
                 // Release the lock before yielding to the Tokio runtime
                 drop(lms_guard); 
                 tokio::time::sleep(current_poll_duration).await; // Wait for appropriate period
@@ -1353,7 +1680,9 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     info!("Main application exiting. Clearing display and stopping polling thread.");
 
     // Clear the display on shutdown
-    display_manager.clear().unwrap();
+    if let Err(e) = display_manager.clear() {
+        warn!("Failed to clear display on shutdown: {}", e);
+    }
 
     // When `lms_arc` goes out of scope here (at the end of main),
     // its `Drop` implementation will be called, which will attempt to stop the background polling thread.
@@ -27,10 +27,11 @@
 
 use std::fs;
 use std::io::{self};
+use std::time::Instant;
 
 /// A struct to hold metrics information.
 /// Corresponds to meminfo_t in the C code.
-#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct MachineMetrics {
     pub cpu_load: f64,
     pub cpu_temp: f64,
@@ -38,6 +39,162 @@ pub struct MachineMetrics {
     pub mem_total_kib: u64,
     pub mem_avail_mib: u64,
     pub mem_avail_pct: f64,
+    /// Space used on the root filesystem, percent - used like `mem`
+    pub disk_used_pct: f64,
+    /// Receive rate on the monitored interface, bytes/second
+    pub net_rx_bps: f64,
+    /// Transmit rate on the monitored interface, bytes/second
+    pub net_tx_bps: f64,
+    /// Interface to monitor, `None` picks the busiest non-loopback interface
+    pub net_interface: Option<String>,
+    /// Interface, counters and time of the last sample
+    net_last: Option<(String, NetCounters, Instant)>,
+}
+
+/// Cumulative byte counters for one interface
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NetCounters {
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+}
+
+impl NetCounters {
+    /// Rx/tx rates in bytes/second relative to an earlier snapshot taken `secs` ago.
+    /// A counter that went backwards (wrap, interface reset) reads as zero.
+    pub fn rates_since(&self, prev: &NetCounters, secs: f64) -> (f64, f64) {
+        if secs <= 0.0 {
+            return (0.0, 0.0);
+        }
+        (
+            self.rx_bytes.saturating_sub(prev.rx_bytes) as f64 / secs,
+            self.tx_bytes.saturating_sub(prev.tx_bytes) as f64 / secs,
+        )
+    }
+}
+
+/// A single item on the clock page metrics line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricToken {
+    Cpu,
+    CpuTemp,
+    Mem,
+    Disk,
+    Net,
+    Fps,
+    Uptime,
+}
+
+impl MetricToken {
+    /// Parse a config token name, `None` for anything unknown
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "cpu" => Some(Self::Cpu),
+            "cpu_temp" | "temp" => Some(Self::CpuTemp),
+            "mem" | "memory" => Some(Self::Mem),
+            "disk" => Some(Self::Disk),
+            "net" | "network" => Some(Self::Net),
+            "fps" => Some(Self::Fps),
+            "uptime" => Some(Self::Uptime),
+            _ => None,
+        }
+    }
+}
+
+/// Which metrics the clock page shows, in order, and the temperature units
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricsFormat {
+    pub tokens: Vec<MetricToken>,
+    pub fahrenheit: bool,
+}
+
+impl Default for MetricsFormat {
+    fn default() -> Self {
+        Self {
+            tokens: vec![MetricToken::Cpu, MetricToken::CpuTemp, MetricToken::Uptime],
+            fahrenheit: false,
+        }
+    }
+}
+
+impl MetricsFormat {
+    /// Build from config token names, unknown names are logged and dropped
+    pub fn from_names(names: &[String], fahrenheit: bool) -> Self {
+        let tokens = names.iter()
+            .filter_map(|n| {
+                let token = MetricToken::parse(n);
+                if token.is_none() {
+                    log::warn!("Unknown metrics token '{}' ignored", n);
+                }
+                token
+            })
+            .collect();
+        Self { tokens, fahrenheit }
+    }
+
+    /// Assemble the metrics line from the enabled tokens
+    pub fn format(&self, metrics: &MachineMetrics, fps: f32) -> String {
+        self.tokens.iter()
+            .map(|token| match token {
+                MetricToken::Cpu => format!("CPU {:.1}%", metrics.cpu_load),
+                MetricToken::CpuTemp => if self.fahrenheit {
+                    format!("{:.1}F", metrics.cpu_temp * 9.0 / 5.0 + 32.0)
+                } else {
+                    format!("{:.1}C", metrics.cpu_temp)
+                },
+                MetricToken::Mem => format!("MEM {:.0}%", 100.0 - metrics.mem_avail_pct),
+                MetricToken::Disk => format!("DSK {:.0}%", metrics.disk_used_pct),
+                MetricToken::Net => format!("NET {}/{}", human_rate(metrics.net_rx_bps), human_rate(metrics.net_tx_bps)),
+                MetricToken::Fps => format!("FPS {:.0}", fps),
+                MetricToken::Uptime => format!("{:.1}H", metrics.up_time),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// Compact bytes/second, e.g. `512B`, `12K`, `1.4M`
+fn human_rate(bytes_sec: f64) -> String {
+    if bytes_sec >= 1_048_576.0 {
+        format!("{:.1}M", bytes_sec / 1_048_576.0)
+    } else if bytes_sec >= 1024.0 {
+        format!("{:.0}K", bytes_sec / 1024.0)
+    } else {
+        format!("{:.0}B", bytes_sec)
+    }
+}
+
+/// MemTotal and MemAvailable (KiB) from /proc/meminfo content
+fn parse_meminfo(content: &str) -> Option<(u64, u64)> {
+    let field = |key: &str| content.lines()
+        .find(|l| l.starts_with(key))
+        .and_then(|l| l.split_whitespace().nth(1))
+        .and_then(|v| v.parse::<u64>().ok());
+    Some((field("MemTotal:")?, field("MemAvailable:")?))
+}
+
+/// Interface name and counters for `iface` from /proc/net/dev content.
+/// Without a name the non-loopback interface with the most traffic is used.
+fn parse_net_dev<'a>(content: &'a str, iface: Option<&str>) -> Option<(&'a str, NetCounters)> {
+    let mut interfaces = content.lines()
+        .skip(2)
+        .filter_map(|l| l.split_once(':'))
+        .map(|(name, counters)| {
+            let cols: Vec<u64> = counters.split_whitespace()
+                .map(|c| c.parse().unwrap_or(0))
+                .collect();
+            // column 0 is rx bytes, column 8 is tx bytes
+            let counters = NetCounters {
+                rx_bytes: cols.first().copied().unwrap_or(0),
+                tx_bytes: cols.get(8).copied().unwrap_or(0),
+            };
+            (name.trim(), counters)
+        });
+    match iface {
+        Some(want) => interfaces.find(|(name, _)| *name == want),
+        None => interfaces
+            .filter(|(name, _)| *name != "lo")
+            .max_by_key(|(_, c)| c.rx_bytes + c.tx_bytes),
+    }
 }
 
 impl MachineMetrics {
@@ -87,19 +244,84 @@ impl MachineMetrics {
         }
     }
 
+    /// Fills total/available memory and the available percentage.
+    /// Leaves the previous values on error.
+    fn mem_info(&self, metrics: &mut MachineMetrics) {
+        if let Some((total, avail)) = fs::read_to_string("/proc/meminfo").ok()
+            .as_deref()
+            .and_then(parse_meminfo)
+            && total > 0
+        {
+            metrics.mem_total_kib = total;
+            metrics.mem_avail_mib = avail / 1024;
+            metrics.mem_avail_pct = 100.0 * avail as f64 / total as f64;
+        }
+    }
+
+    /// Returns used space on the root filesystem as a percentage.
+    /// Returns 0.0 on error.
+    fn disk_used_pct(&mut self) -> f64 {
+        let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+        // SAFETY: valid NUL-terminated path and a zeroed out-struct owned by this frame
+        let rc = unsafe { libc::statvfs(c"/".as_ptr(), &mut stat) };
+        if rc != 0 || stat.f_blocks == 0 {
+            return 0.0;
+        }
+        100.0 - 100.0 * stat.f_bavail as f64 / stat.f_blocks as f64
+    }
+
+    /// Samples /proc/net/dev and derives rx/tx rates from the previous sample.
+    fn net_throughput(&self, metrics: &mut MachineMetrics) {
+        let content = fs::read_to_string("/proc/net/dev").unwrap_or_default();
+        let Some((iface, counters)) = parse_net_dev(&content, self.net_interface.as_deref()) else {
+            metrics.net_rx_bps = 0.0;
+            metrics.net_tx_bps = 0.0;
+            return;
+        };
+        metrics.apply_net_sample(iface, counters, Instant::now());
+    }
+
+    /// Fold in a counter snapshot. The first one only primes the rates, as
+    /// does one from a different interface than last time (automatic
+    /// selection moved to a busier one) - its counters aren't comparable.
+    fn apply_net_sample(&mut self, iface: &str, counters: NetCounters, now: Instant) {
+        match &self.net_last {
+            Some((last, prev, at)) if last == iface => {
+                let secs = now.duration_since(*at).as_secs_f64();
+                if secs > 0.0 {
+                    (self.net_rx_bps, self.net_tx_bps) = counters.rates_since(prev, secs);
+                }
+            }
+            Some(_) => (self.net_rx_bps, self.net_tx_bps) = (0.0, 0.0),
+            None => {}
+        }
+        self.net_last = Some((iface.to_string(), counters, now));
+    }
+
+    /// Monitor a specific interface, `None` returns to automatic selection
+    pub fn set_net_interface(&mut self, iface: Option<String>) {
+        if self.net_interface != iface {
+            self.net_interface = iface;
+            self.net_last = None;
+            self.net_rx_bps = 0.0;
+            self.net_tx_bps = 0.0;
+        }
+    }
+
     pub fn update(&mut self, metrics: MachineMetrics) {
-        self.cpu_load = metrics.cpu_load;
-        self.cpu_temp = metrics.cpu_temp;
-        self.up_time = metrics.up_time;
+        *self = metrics;
     }
 
     pub fn check(&mut self) -> MachineMetrics {
-        let mut metrics = MachineMetrics::default();
+        let mut metrics = self.clone();
         metrics.cpu_load = self.cpu_load();
         metrics.cpu_temp = self.cpu_temp();
         metrics.up_time = self.up_time();
+        metrics.disk_used_pct = self.disk_used_pct();
+        self.mem_info(&mut metrics);
+        self.net_throughput(&mut metrics);
         // need to look at this, totally backasswards
-        self.update(metrics);
+        self.update(metrics.clone());
         //println!(
         //    "CPU {:.1}%  {:.1}C {}H",
         //    metrics.cpu_load,
@@ -110,3 +332,64 @@ impl MachineMetrics {
     }
 
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_renders_only_enabled_tokens() {
+        let metrics = MachineMetrics {
+            cpu_load: 12.5,
+            cpu_temp: 50.0,
+            mem_avail_pct: 75.0,
+            disk_used_pct: 42.4,
+            net_rx_bps: 2048.0,
+            net_tx_bps: 300.0,
+            ..Default::default()
+        };
+        let names = ["cpu_temp", "disk", "bogus", "net"].map(String::from);
+        let fmt = MetricsFormat::from_names(&names, true);
+        assert_eq!(fmt.tokens, vec![MetricToken::CpuTemp, MetricToken::Disk, MetricToken::Net]);
+        assert_eq!(fmt.format(&metrics, 30.0), "122.0F DSK 42% NET 2K/300B");
+
+        let fmt = MetricsFormat::from_names(&["mem".to_string(), "fps".to_string()], false);
+        assert_eq!(fmt.format(&metrics, 29.6), "MEM 25% FPS 30");
+    }
+
+    #[test]
+    fn net_dev_skips_loopback() {
+        let content = "Inter-|   Receive                                                |  Transmit\n             face |bytes    packets errs drop fifo frame compressed multicast|bytes    packets errs drop fifo colls carrier compressed\n                lo: 1000 10 0 0 0 0 0 0 1000 10 0 0 0 0 0 0\n              eth0: 300 3 0 0 0 0 0 0 200 2 0 0 0 0 0 0\n";
+        let auto = parse_net_dev(content, None).unwrap();
+        assert_eq!(auto, ("eth0", NetCounters { rx_bytes: 300, tx_bytes: 200 }));
+        assert_eq!(parse_net_dev(content, Some("lo")).unwrap().1.rx_bytes, 1000);
+        assert_eq!(parse_net_dev(content, Some("wlan0")), None);
+        assert_eq!(parse_meminfo("MemTotal: 1000 kB\nMemFree: 10 kB\nMemAvailable: 250 kB\n"), Some((1000, 250)));
+    }
+
+    #[test]
+    fn net_rates_from_two_snapshots() {
+        let mut metrics = MachineMetrics::default();
+        let t0 = Instant::now();
+        metrics.apply_net_sample("eth0", NetCounters { rx_bytes: 10_000, tx_bytes: 4_000 }, t0);
+        assert_eq!((metrics.net_rx_bps, metrics.net_tx_bps), (0.0, 0.0));
+
+        let t1 = t0 + std::time::Duration::from_secs(2);
+        metrics.apply_net_sample("eth0", NetCounters { rx_bytes: 30_000, tx_bytes: 5_000 }, t1);
+        assert_eq!(metrics.net_rx_bps, 10_000.0);
+        assert_eq!(metrics.net_tx_bps, 500.0);
+
+        // counter reset reads as idle rather than a huge rate
+        let t2 = t1 + std::time::Duration::from_secs(1);
+        metrics.apply_net_sample("eth0", NetCounters { rx_bytes: 100, tx_bytes: 5_100 }, t2);
+        assert_eq!((metrics.net_rx_bps, metrics.net_tx_bps), (0.0, 100.0));
+
+        // the busiest interface changed - re-prime rather than diff eth0 against wlan0
+        let t3 = t2 + std::time::Duration::from_secs(1);
+        metrics.apply_net_sample("wlan0", NetCounters { rx_bytes: 900_000, tx_bytes: 0 }, t3);
+        assert_eq!((metrics.net_rx_bps, metrics.net_tx_bps), (0.0, 0.0));
+        let t4 = t3 + std::time::Duration::from_secs(1);
+        metrics.apply_net_sample("wlan0", NetCounters { rx_bytes: 901_000, tx_bytes: 50 }, t4);
+        assert_eq!((metrics.net_rx_bps, metrics.net_tx_bps), (1_000.0, 50.0));
+    }
+}
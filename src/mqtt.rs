@@ -0,0 +1,231 @@
+/*
+ *  mqtt.rs
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  MQTT publishing of now-playing and display state (Home Assistant et al.)
+ *
+ *  QoS 0 publishes through rumqttc, its event loop driven on a background task.
+ *  The client queue is bounded, so a slow or missing broker never blocks the
+ *  render loop; updates are dropped instead and resent on the next change.
+ *
+ *  Topics, all retained:
+ *    <prefix>/now_playing    JSON track, play state, volume and display mode
+ *    <prefix>/display_mode   active display mode name
+ *    <prefix>/status         "online", or "offline" via the last will
+ *
+ *  This program is free software: you can redistribute it and/or modify
+ *  it under the terms of the GNU General Public License as published by
+ *  the Free Software Foundation, either version 3 of the License, or
+ *  (at your option) any later version.
+ *
+ *  This program is distributed in the hope that it will be useful,
+ *  but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *  MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *  GNU General Public License for more details.
+ *
+ *  See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *  Public License.
+ *
+ */
+
+use std::time::Duration;
+
+use log::{debug, info, warn};
+use rumqttc::{AsyncClient, Event, EventLoop, LastWill, MqttOptions, Outgoing, Packet, QoS};
+use serde::Serialize;
+
+use crate::config::MqttConfig;
+use crate::sliminfo::SlimInfo;
+
+/// Pending publishes held while the broker is slow; further updates are dropped.
+const QUEUE_DEPTH: usize = 16;
+const KEEP_ALIVE: Duration = Duration::from_secs(30);
+const RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// Payload published to `<prefix>/now_playing`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NowPlaying<'a> {
+    pub state:         &'a str,
+    pub title:         &'a str,
+    pub artist:        &'a str,
+    pub album:         &'a str,
+    pub album_artist:  &'a str,
+    pub year:          &'a str,
+    pub volume:        u8,
+    pub duration_secs: u32,
+    pub elapsed_secs:  u32,
+    pub display_mode:  &'a str,
+}
+
+impl<'a> NowPlaying<'a> {
+    pub fn new(info: &'a SlimInfo, display_mode: &'a str) -> Self {
+        Self {
+            state:         &info.mode,
+            title:         &info.title,
+            artist:        &info.artist,
+            album:         &info.album,
+            album_artist:  &info.albumartist,
+            year:          &info.year,
+            volume:        info.volume,
+            duration_secs: info.duration.raw.max(0.0) as u32,
+            elapsed_secs:  info.tracktime.raw.max(0.0) as u32,
+            display_mode,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+/// Client options from the `mqtt:` config block, with a retained "offline" last will.
+fn options(config: &MqttConfig, broker: &str, status: &str) -> Option<MqttOptions> {
+    let (host, port) = broker.rsplit_once(':')?;
+    let client_id = config.client_id.clone()
+        .unwrap_or_else(|| format!("lymons-{}", std::process::id()));
+    let mut options = MqttOptions::new(client_id, host, port.parse().ok()?);
+    options.set_keep_alive(KEEP_ALIVE);
+    options.set_last_will(LastWill::new(status, "offline", QoS::AtMostOnce, true));
+    if let Some(user) = &config.username {
+        options.set_credentials(user, config.password.clone().unwrap_or_default());
+    }
+    Some(options)
+}
+
+/// Handle to the background publisher; dropping it disconnects from the broker.
+pub struct MqttPublisher {
+    client:           AsyncClient,
+    prefix:           String,
+    last_now_playing: String,
+    last_mode:        String,
+}
+
+impl MqttPublisher {
+    /// Start the background client. `None` when no broker is configured.
+    ///
+    /// Must be called from within the tokio runtime.
+    pub fn spawn(config: &MqttConfig) -> Option<Self> {
+        let broker = config.broker_addr()?;
+        let prefix = config.topic_prefix();
+        let status = format!("{prefix}/status");
+        let options = options(config, &broker, &status)?;
+        info!("mqtt: publishing to {broker} under {prefix}/");
+
+        let (client, eventloop) = AsyncClient::new(options, QUEUE_DEPTH);
+        tokio::spawn(run(eventloop, client.clone(), broker, status));
+        Some(Self {
+            client,
+            prefix,
+            last_now_playing: String::new(),
+            last_mode:        String::new(),
+        })
+    }
+
+    /// Publish track and play state; unchanged payloads are not resent.
+    pub fn publish_now_playing(&mut self, info: &SlimInfo, display_mode: &str) {
+        let json = NowPlaying::new(info, display_mode).to_json();
+        if json != self.last_now_playing && self.send("now_playing", json.as_bytes()) {
+            self.last_now_playing = json;
+        }
+    }
+
+    /// Publish the active display mode; true when it was a transition.
+    pub fn publish_display_mode(&mut self, mode: &str) -> bool {
+        if mode == self.last_mode {
+            return false;
+        }
+        if self.send("display_mode", mode.as_bytes()) {
+            self.last_mode = mode.to_string();
+        }
+        true
+    }
+
+    /// Queue a retained publish; false when the queue is full and it was dropped.
+    fn send(&self, leaf: &str, payload: &[u8]) -> bool {
+        // never wait on the broker - the render loop matters more than one update
+        let queued = self.client
+            .try_publish(format!("{}/{leaf}", self.prefix), QoS::AtMostOnce, true, payload)
+            .is_ok();
+        if !queued {
+            debug!("mqtt: queue full, dropping {leaf} update");
+        }
+        queued
+    }
+}
+
+impl Drop for MqttPublisher {
+    fn drop(&mut self) {
+        // a clean DISCONNECT suppresses the will, so say it ourselves
+        let _ = self.client.try_publish(format!("{}/status", self.prefix), QoS::AtMostOnce, true, "offline");
+        let _ = self.client.try_disconnect();
+    }
+}
+
+/// Background task: drive the event loop, announcing "online" on every connect.
+/// rumqttc reconnects on the next poll after an error; we pause between tries.
+async fn run(mut eventloop: EventLoop, client: AsyncClient, broker: String, status: String) {
+    loop {
+        match eventloop.poll().await {
+            Ok(Event::Incoming(Packet::ConnAck(_))) => {
+                info!("mqtt: connected to {broker}");
+                if client.try_publish(&status, QoS::AtMostOnce, true, "online").is_err() {
+                    debug!("mqtt: queue full, dropping online status");
+                }
+            }
+            Ok(Event::Outgoing(Outgoing::Disconnect)) => return,
+            Ok(_) => {}
+            Err(e) => {
+                warn!("mqtt: connection to {broker} lost: {e}");
+                tokio::time::sleep(RETRY_DELAY).await;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sliminfo::TimeField;
+
+    fn sample_info() -> SlimInfo {
+        let mut info = SlimInfo::default();
+        info.is_playing  = true;
+        info.mode        = "play".to_string();
+        info.title       = "So What".to_string();
+        info.artist      = "Miles Davis".to_string();
+        info.albumartist = "Miles Davis".to_string();
+        info.album       = "Kind of Blue".to_string();
+        info.year        = "1959".to_string();
+        info.volume      = 42;
+        info.duration    = TimeField { raw: 562.4, display: "09:22".to_string() };
+        info.tracktime   = TimeField { raw: 61.9, display: "01:01".to_string() };
+        info
+    }
+
+    #[test]
+    fn now_playing_payload_serializes() {
+        let info = sample_info();
+        let json = NowPlaying::new(&info, "scrolling").to_json();
+        assert_eq!(
+            json,
+            r#"{"state":"play","title":"So What","artist":"Miles Davis","album":"Kind of Blue","album_artist":"Miles Davis","year":"1959","volume":42,"duration_secs":562,"elapsed_secs":61,"display_mode":"scrolling"}"#
+        );
+    }
+
+    #[test]
+    fn options_split_broker_and_set_credentials() {
+        let config = MqttConfig {
+            broker:   Some("ha.local".to_string()),
+            username: Some("ha".to_string()),
+            password: Some("pw".to_string()),
+            ..Default::default()
+        };
+        let broker = config.broker_addr().unwrap();
+        let options = options(&config, &broker, "lymons/status").unwrap();
+        assert_eq!(options.broker_address(), ("ha.local".to_string(), 1883));
+        assert_eq!(options.credentials(), Some(("ha".to_string(), "pw".to_string())));
+        assert_eq!(options.last_will().unwrap().topic, "lymons/status");
+    }
+}
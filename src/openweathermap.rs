@@ -0,0 +1,265 @@
+/*
+ *  openweathermap.rs
+ *
+ *  LyMonS - worth the squeeze
+ *	(c) 2020-26 Stuart Hunter
+ *
+ *	OpenWeatherMap One Call 3.0 weather provider
+ *
+ *	This program is free software: you can redistribute it and/or modify
+ *	it under the terms of the GNU General Public License as published by
+ *	the Free Software Foundation, either version 3 of the License, or
+ *	(at your option) any later version.
+ *
+ *	This program is distributed in the hope that it will be useful,
+ *	but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *	MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *	GNU General Public License for more details.
+ *
+ *	See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *	Public License.
+ *
+ */
+
+use chrono::{DateTime, Local, TimeZone};
+use log::info;
+use serde_json::Value;
+
+use crate::weather::{
+    compass_point, most_severe, parse_weather_code, AlertSeverity, ProviderFuture,
+    ProviderSettings, WeatherAlert, WeatherApiError, WeatherData, WeatherProvider, WeatherReport,
+};
+
+const OWM_ONECALL_URL: &str = "https://api.openweathermap.org/data/3.0/onecall";
+
+/// OpenWeatherMap One Call provider.
+///
+/// OWM condition ids are translated onto the Tomorrow.io code table so the
+/// same `weather_code` svg assets and translations are used.
+#[derive(Debug)]
+pub struct OpenWeatherMap {
+    base_url: String,
+    settings: ProviderSettings,
+}
+
+/// Map an OWM condition id onto the equivalent Tomorrow.io weather code.
+/// `night` selects the night variant where one exists (OWM icon suffix `n`).
+pub fn owm_to_tomorrow_code(id: i64, night: bool) -> i64 {
+    let day = match id {
+        200..=299 => 8000,          // thunderstorm
+        300..=399 => 4000,          // drizzle
+        500 | 520 => 4200,          // light rain
+        501 | 521 => 4001,          // rain
+        502..=504 | 522 | 531 => 4201, // heavy rain
+        511 => 6001,                // freezing rain
+        600 | 620 => 5100,          // light snow
+        601 | 621 => 5000,          // snow
+        602 | 622 => 5101,          // heavy snow
+        611..=613 => 7000,          // sleet -> ice pellets
+        615 | 616 => 5108,          // rain and snow
+        741 => 2000,                // fog
+        700..=799 => 2100,          // mist, haze, smoke etc.
+        800 => 1000,                // clear
+        801 => 1100,                // few clouds
+        802 => 1101,                // scattered clouds
+        803 => 1102,                // broken clouds
+        804 => 1001,                // overcast
+        _ => 0,
+    };
+    if night {
+        match day {
+            1000 | 1001 | 1100 | 1101 | 1102 => day * 10 + 1,
+            _ => day,
+        }
+    } else {
+        day
+    }
+}
+
+/// Most severe entry in the One Call `alerts` array. OWM gives no severity
+/// field, so it is read from the event name ("Severe Thunderstorm Warning").
+pub fn parse_owm_alerts(alerts: &Value) -> Option<WeatherAlert> {
+    most_severe(alerts.as_array()?.iter().filter_map(|alert| {
+        let title = alert["event"].as_str()?.trim();
+        let severity = title.split_whitespace()
+            .map(AlertSeverity::parse)
+            .max()
+            .unwrap_or_default();
+        Some(WeatherAlert { title: title.to_string(), severity })
+    }))
+}
+
+fn unix_time(v: &Value) -> Option<DateTime<Local>> {
+    match v.as_i64() {
+        Some(ts) if ts > 0 => Local.timestamp_opt(ts, 0).single(),
+        _ => None,
+    }
+}
+
+impl OpenWeatherMap {
+    pub fn new(settings: ProviderSettings) -> Self {
+        Self {
+            base_url: OWM_ONECALL_URL.to_string(),
+            settings,
+        }
+    }
+
+    /// Convert an OWM wind speed to the display units - metric is m/s, we show km/h.
+    fn wind_speed(&self, v: &Value) -> f64 {
+        let speed = v.as_f64().unwrap_or(0.0);
+        if self.settings.units == "imperial" { speed } else { speed * 3.6 }
+    }
+
+    async fn weather_code(&self, node: &Value) -> crate::weather::WeatherCode {
+        let cond = &node["weather"][0];
+        let id = cond["id"].as_i64().unwrap_or(0);
+        let night = cond["icon"].as_str().map(|i| i.ends_with('n')).unwrap_or(false);
+        parse_weather_code(owm_to_tomorrow_code(id, night), self.settings.icons, &self.settings.translate).await
+    }
+
+    fn base_data(&self, node: &Value) -> WeatherData {
+        WeatherData {
+            day: unix_time(&node["dt"]).unwrap_or_else(Local::now),
+            humidity_avg: node["humidity"].as_i64().unwrap_or(0),
+            moonrise_time: unix_time(&node["moonrise"]),
+            moonset_time: unix_time(&node["moonset"]),
+            precipitation_probability_avg: node["pop"].as_f64().unwrap_or(0.0) * 100.0,
+            pressure_sea_level_avg: node["pressure"].as_f64().unwrap_or(0.0),
+            sunrise_time: unix_time(&node["sunrise"]),
+            sunset_time: unix_time(&node["sunset"]),
+            temperature_units: self.settings.temperature_units(),
+            wind_direction: compass_point(node["wind_deg"].as_f64().unwrap_or(0.0)),
+            wind_speed_avg: self.wind_speed(&node["wind_speed"]),
+            wind_speed_units: self.settings.wind_speed_units(),
+            pressure_sea_level_units: self.settings.pressure_units(),
+            ..Default::default()
+        }
+    }
+
+    async fn fetch_report(&self) -> Result<WeatherReport, WeatherApiError> {
+        let params = [
+            ("lat", self.settings.lat.to_string()),
+            ("lon", self.settings.lng.to_string()),
+            ("appid", self.settings.api_key.clone()),
+            ("units", self.settings.units.clone()),
+            ("exclude", "minutely,hourly".to_string()),
+        ];
+
+        let response = self.settings.client.get(&self.base_url)
+            .query(&params)
+            .send()
+            .await?;
+        let status = response.status();
+        let text = response.text().await?;
+        if !status.is_success() {
+            return Err(WeatherApiError::ApiError(format!("{}: {}", status, text)));
+        }
+        let json: Value = serde_json::from_str(&text)
+            .map_err(WeatherApiError::DeserializationError)?;
+
+        let current = &json["current"];
+        if !current.is_object() {
+            return Err(WeatherApiError::MissingData("current".to_string()));
+        }
+        let daily = json["daily"].as_array().cloned().unwrap_or_default();
+
+        let mut report = WeatherReport::default();
+
+        // current block has no moon data or min/max, borrow those from today
+        let mut now = self.base_data(current);
+        let temp = current["temp"].as_f64().unwrap_or(0.0);
+        now.temperature_avg = temp;
+        now.temperature_apparent_avg = current["feels_like"].as_f64().unwrap_or(temp);
+        now.temperature_max = temp;
+        now.temperature_min = temp;
+        now.weather_code = self.weather_code(current).await;
+        if let Some(today) = daily.first() {
+            now.moonrise_time = unix_time(&today["moonrise"]);
+            now.moonset_time = unix_time(&today["moonset"]);
+            now.precipitation_probability_avg = today["pop"].as_f64().unwrap_or(0.0) * 100.0;
+            now.temperature_max = today["temp"]["max"].as_f64().unwrap_or(temp);
+            now.temperature_min = today["temp"]["min"].as_f64().unwrap_or(temp);
+        }
+        report.current = now;
+
+        for day in daily.iter().skip(1) {
+            let mut wd = self.base_data(day);
+            wd.temperature_avg = day["temp"]["day"].as_f64().unwrap_or(0.0);
+            wd.temperature_apparent_avg = day["feels_like"]["day"].as_f64().unwrap_or(wd.temperature_avg);
+            wd.temperature_max = day["temp"]["max"].as_f64().unwrap_or(wd.temperature_avg);
+            wd.temperature_min = day["temp"]["min"].as_f64().unwrap_or(wd.temperature_avg);
+            wd.weather_code = self.weather_code(day).await;
+            report.forecast.push(wd);
+        }
+
+        report.alert = parse_owm_alerts(&json["alerts"]);
+
+        info!("OpenWeatherMap: {} forecast days", report.forecast.len());
+        Ok(report)
+    }
+}
+
+impl WeatherProvider for OpenWeatherMap {
+    fn name(&self) -> &'static str {
+        "openweathermap"
+    }
+
+    fn fetch(&self) -> ProviderFuture<'_> {
+        Box::pin(self.fetch_report())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::weather::ProviderKind;
+
+    #[test]
+    fn owm_clear_and_clouds_map_to_day_and_night_codes() {
+        assert_eq!(owm_to_tomorrow_code(800, false), 1000);
+        assert_eq!(owm_to_tomorrow_code(800, true), 10001);
+        assert_eq!(owm_to_tomorrow_code(801, true), 11001);
+        assert_eq!(owm_to_tomorrow_code(804, false), 1001);
+        assert_eq!(owm_to_tomorrow_code(804, true), 10011);
+    }
+
+    #[test]
+    fn owm_precipitation_ignores_night_flag() {
+        assert_eq!(owm_to_tomorrow_code(211, true), 8000);
+        assert_eq!(owm_to_tomorrow_code(301, false), 4000);
+        assert_eq!(owm_to_tomorrow_code(502, true), 4201);
+        assert_eq!(owm_to_tomorrow_code(601, false), 5000);
+        assert_eq!(owm_to_tomorrow_code(741, false), 2000);
+        assert_eq!(owm_to_tomorrow_code(999, false), 0);
+    }
+
+    #[test]
+    fn provider_kind_parses_names() {
+        assert_eq!(ProviderKind::parse(""), Some(ProviderKind::TomorrowIo));
+        assert_eq!(ProviderKind::parse("Tomorrow.io"), Some(ProviderKind::TomorrowIo));
+        assert_eq!(ProviderKind::parse("owm"), Some(ProviderKind::OpenWeatherMap));
+        assert_eq!(ProviderKind::parse("darksky"), None);
+    }
+
+    #[test]
+    fn owm_alerts_keep_the_most_severe() {
+        let alerts = serde_json::json!([
+            {"sender_name": "NWS", "event": "Wind Advisory", "tags": ["Wind"]},
+            {"sender_name": "NWS", "event": "Severe Thunderstorm Warning", "tags": ["Thunderstorm"]},
+        ]);
+        let alert = parse_owm_alerts(&alerts).unwrap();
+        assert_eq!(alert.title, "Severe Thunderstorm Warning");
+        assert_eq!(alert.severity, AlertSeverity::Severe);
+        assert_eq!(alert.banner_text(), "SEVERE: Severe Thunderstorm Warning");
+        // first alert wins when none carries a severity word
+        assert_eq!(parse_owm_alerts(&alerts.as_array().unwrap()[..1].into()).unwrap().banner_text(), "Wind Advisory");
+        assert!(parse_owm_alerts(&Value::Null).is_none());
+        assert!(parse_owm_alerts(&serde_json::json!([])).is_none());
+    }
+
+    #[test]
+    fn zero_unix_time_is_none() {
+        assert!(unix_time(&Value::from(0)).is_none());
+        assert!(unix_time(&Value::from(1_700_000_000i64)).is_some());
+    }
+}
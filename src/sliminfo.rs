@@ -109,6 +109,7 @@ pub struct PlayerStatus {
     playlist_shuffle: i16,
     #[serde(deserialize_with="deserialize_numeric_i16")]
     playlist_cur_index: i16,
+    playlist_tracks: Option<i32>,
     playlist_loop: Option<Vec<Track>>,
 }
 
@@ -131,6 +132,8 @@ struct Track {
     remotetitle: Option<String>,
     samplerate: Option<String>, // "44100" etc
     samplesize: Option<String>, // "16" etc
+    #[serde(rename = "type")]
+    content_type: Option<String>, // "flc", "mp3" etc
     title: Option<String>,
     trackartist: Option<String>,
     year: Option<String>,
@@ -185,14 +188,23 @@ pub struct SlimInfo {
     pub remotetitle: String,
     pub samplerate: i32,
     pub samplesize: i32,
+    /// LMS content type of the track, e.g. "flc" or "mp3"
+    pub format: String,
     pub title: String,
     pub trackartist: String,
     pub performer: String,
     pub playlist_mode: String,
+    /// Current entry in the playlist, from 0
+    pub playlist_index: i32,
+    /// Tracks in the playlist, 0 when empty
+    pub playlist_tracks: i32,
     pub repeat: u8,
     pub shuffle: u8,
     pub year: String,
     pub coverid: String,
+    /// Upcoming playlist entry, empty when LMS has nothing queued
+    pub next_artist: String,
+    pub next_title: String,
 }
 
 impl SlimInfo {
@@ -215,14 +227,35 @@ impl SlimInfo {
             remotetitle: "".to_string(),
             samplerate: 0,
             samplesize: 0,
+            format: String::new(),
             title: "".to_string(),
             trackartist: "".to_string(),
             performer: "".to_string(),
             playlist_mode: "".to_string(),
+            playlist_index: 0,
+            playlist_tracks: 0,
             repeat: 0,
             shuffle: 0,
             year: "".to_string(),
             coverid: String::new(),
+            next_artist: String::new(),
+            next_title: String::new(),
+        }
+    }
+
+    /// Playing track's place in the playlist, counted from 1, and the
+    /// playlist length; None for an empty playlist
+    pub fn playlist_position(&self) -> Option<(u32, u32)> {
+        (self.playlist_tracks > 0).then(|| ((self.playlist_index.max(0) + 1) as u32, self.playlist_tracks as u32))
+    }
+
+    /// "Artist – Title" for the upcoming track, or None when nothing is queued.
+    pub fn next_track_text(&self) -> Option<String> {
+        match (self.next_artist.trim(), self.next_title.trim()) {
+            ("", "") => None,
+            ("", title) => Some(title.to_string()),
+            (artist, "") => Some(artist.to_string()),
+            (artist, title) => Some(format!("{artist} – {title}")),
         }
     }
 
@@ -251,6 +284,7 @@ impl SlimInfo {
                     remotetitle: None,
                     samplerate: None,
                     samplesize: None,
+                    content_type: None,
                     title: None,
                     trackartist: None,
                     year: None,
@@ -259,6 +293,9 @@ impl SlimInfo {
         };
 
         let track = ps.playlist_loop.as_ref().map(pick);
+        // status is asked for two entries from the current one, the second is up next
+        let next = ps.playlist_loop.as_ref()
+            .and_then(|tracks| tracks.iter().find(|t| t.playlist_index as usize == cur_idx + 1));
 
         let to_bool = |s: &Option<String>| s.as_deref().map(|v| v != "0" && v != "N").unwrap_or(false);
         let parse_i32 = |s: &Option<String>| {
@@ -353,14 +390,65 @@ impl SlimInfo {
             remotetitle: s_or(&track.as_ref().and_then(|t| t.remotetitle.clone()), ""),
             samplerate: parse_i32(&track.as_ref().and_then(|t| t.samplerate.clone())),
             samplesize: parse_i32(&track.as_ref().and_then(|t| t.samplesize.clone())),
+            format: s_or(&track.as_ref().and_then(|t| t.content_type.clone()), ""),
             title: s_or(&track.as_ref().and_then(|t| t.title.clone()), ""),
             trackartist: s_or(&track.as_ref().and_then(|t| t.trackartist.clone()), ""),
             performer,
             playlist_mode: s_or(&ps.playlist_mode, "off"),
+            playlist_index: cur_idx as i32,
+            playlist_tracks: ps.playlist_tracks.unwrap_or(0),
             repeat: ps.playlist_repeat as u8,
             shuffle: ps.playlist_shuffle as u8,
             year: s_or(&track.as_ref().and_then(|t| t.year.clone()), ""),
             coverid: s_or(&track.as_ref().and_then(|t| t.coverid.clone()), ""),
+            next_artist: next.and_then(|t| t.artist.clone().or_else(|| t.albumartist.clone())).unwrap_or_default(),
+            next_title: next.and_then(|t| t.title.clone()).unwrap_or_default(),
+        }
+    }
+}
+
+/// Transport control sent back to the monitored player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransportCommand {
+    PlayPause,
+    /// Pause, leaving a paused or stopped player as it is
+    Pause,
+    Next,
+    Prev,
+    /// Absolute mixer volume, 0-100
+    Volume(u8),
+}
+
+impl TransportCommand {
+    /// The LMS command name and its parameters, e.g. `playlist` + `["index", "+1"]`.
+    pub fn slim_params(&self) -> (&'static str, Vec<Value>) {
+        match self {
+            TransportCommand::PlayPause => ("pause", vec![]),
+            TransportCommand::Pause => ("pause", vec![json!("1")]),
+            TransportCommand::Next => ("playlist", vec![json!("index"), json!("+1")]),
+            TransportCommand::Prev => ("playlist", vec![json!("index"), json!("-1")]),
+            TransportCommand::Volume(v) => ("mixer", vec![json!("volume"), json!((*v).min(100).to_string())]),
+        }
+    }
+}
+
+/// One player's state as every panel needs it, copied out from under the server lock
+#[derive(Debug, Clone, PartialEq)]
+pub struct NowPlaying {
+    pub info: SlimInfo,
+    /// Where cover art is fetched from
+    pub lms_host: String,
+    pub lms_port: u16,
+    pub player_mac: String,
+}
+
+impl NowPlaying {
+    pub fn from_server(lms: &LMSServer) -> Self {
+        Self {
+            info: lms.sliminfo.clone(),
+            lms_host: lms.host.to_string(),
+            lms_port: lms.port,
+            player_mac: lms.player_mac().to_string(),
         }
     }
 }
@@ -379,6 +467,8 @@ pub struct LMSServer {
     pub uuid: String,
     pub vers: String,
     pub port: u16,
+    /// status tags - `a` artist, `A` the role artists (albumartist, composer,
+    /// conductor, band, trackartist), `l` album, `y` year, `c` coverid ...
     pub slim_tags: String,
     pub client: SlimInfoClient,
     working: bool,
@@ -413,7 +503,7 @@ impl LMSServer {
             uuid: "".to_string(),
             vers: "".to_string(),
             port: 9000,
-            slim_tags: "tags:lKeaArCckiqdxNTIzy".to_string(),
+            slim_tags: "tags:lKeaArCckiqdxNTIzyo".to_string(),
             client: SlimInfoClient::new(),
             working: false,
             stop_sender: None,
@@ -604,7 +694,55 @@ impl LMSServer {
         ""
     }
 
+    /// Toggle play/pause on the monitored player
+    pub async fn play_pause(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.send_transport(TransportCommand::PlayPause).await
+    }
+
+    /// Skip to the next track in the player's playlist
+    pub async fn next_track(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.send_transport(TransportCommand::Next).await
+    }
+
+    /// Return to the previous track in the player's playlist
+    pub async fn prev_track(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+        self.send_transport(TransportCommand::Prev).await
+    }
+
+    /// Set the player volume, clamped to 0-100
+    pub async fn set_volume(&mut self, volume: u8) -> Result<(), Box<dyn std::error::Error>> {
+        self.send_transport(TransportCommand::Volume(volume)).await
+    }
+
+    /// Send a transport command to the active player and refresh status on the next poll
+    pub async fn send_transport(&mut self, cmd: TransportCommand) -> Result<(), Box<dyn std::error::Error>> {
+        if !self.ready {
+            return Err("LMS Server not discovered. Call discover() first.".into());
+        }
+        if self.active_player == usize::MAX {
+            return Err("No active player to control".into());
+        }
+
+        let player_mac = self.player_mac().to_string();
+        let (command, params) = cmd.slim_params();
+        debug!("Transport {:?} -> {}", cmd, player_mac);
+        self.client.send_slim_request(
+            self.host.to_string().as_str(),
+            self.port,
+            player_mac.as_str(),
+            command,
+            params,
+        ).await?;
+        self.ask_refresh();
+        Ok(())
+    }
+
     /// fetch the current status inclusive of populating tag details
+    ///
+    /// One `status - 2 tags:...` request per poll carries everything the display
+    /// uses - player state, volume, the current track's tags and the next track.
+    /// slim.request takes a single command, so there is nothing left to batch;
+    /// add any new tag to `slim_tags` rather than issuing a second query.
     pub async fn get_sliminfo_status(&mut self) -> Result<(), Box<dyn std::error::Error>> {
 
         if !self.ready {
@@ -615,7 +753,7 @@ impl LMSServer {
 
             self.refresh = false;
             let command="status";
-            let params = vec![json!("-"), json!("1"), json!(&self.slim_tags)]; // status inclusive supported tags
+            let params = vec![json!("-"), json!("2"), json!(&self.slim_tags)]; // current and next track, inclusive supported tags
 
             self.working = true;
             match self.client.send_slim_request(
@@ -670,21 +808,24 @@ impl LMSServer {
             self.players = serde_json::from_value::<Vec<Player>>(result["players_loop"].clone())
                 .map_err(|e| format!("Failed to parse player list: {}", e))?;
 
-            for (i, player) in self.players.iter().enumerate() {
-                if player_name_filter == "-" || player.player_name.to_lowercase() == player_name_filter.to_lowercase() {
-                    self.active_player = i;
-                    debug!("Active player: {} ({})", player.player_name, player.player_id);
-                    if player.player_id.to_lowercase() == mac_address.to_lowercase() {
-                        self.shared_memory = format!("/squeezelite-{}", player.player_id.to_lowercase());
-                    }
-                    break;
+            if let Some(i) = select_player(&self.players, player_name_filter) {
+                let player = &self.players[i];
+                self.active_player = i;
+                debug!("Active player: {} ({})", player.player_name, player.player_id);
+                if player.player_id.to_lowercase() == mac_address.to_lowercase() {
+                    self.shared_memory = format!("/squeezelite-{}", player.player_id.to_lowercase());
                 }
             }
         }
 
         if self.active_player == usize::MAX {
+            let by_mac = player_mac(player_name_filter).is_some();
             let available = self.players.iter()
-                .map(|p| format!("\"{}\"", p.player_name))
+                .map(|p| if by_mac {
+                    format!("\"{}\" ({})", p.player_name, p.player_id)
+                } else {
+                    format!("\"{}\"", p.player_name)
+                })
                 .collect::<Vec<_>>()
                 .join(", ");
             let msg = if player_name_filter == "-" {
@@ -713,6 +854,28 @@ impl LMSServer {
     }
 }
 
+/// The MAC a player filter asks for: `mac:aa:bb:cc:dd:ee:ff`, or a bare MAC
+pub fn player_mac(filter: &str) -> Option<&str> {
+    if let Some(mac) = filter.strip_prefix("mac:") {
+        return Some(mac.trim());
+    }
+    let octets: Vec<&str> = filter.split(':').collect();
+    (octets.len() == 6 && octets.iter().all(|o| o.len() == 2 && o.chars().all(|c| c.is_ascii_hexdigit())))
+        .then_some(filter)
+}
+
+/// Index of the player `filter` picks: `-` takes the first, a MAC form
+/// matches the player id, then the filter is tried as a player name.
+/// MACs outlive renames, names are what most configs hold.
+pub fn select_player(players: &[Player], filter: &str) -> Option<usize> {
+    if filter == "-" {
+        return (!players.is_empty()).then_some(0);
+    }
+    player_mac(filter)
+        .and_then(|mac| players.iter().position(|p| p.player_id.eq_ignore_ascii_case(mac)))
+        .or_else(|| players.iter().position(|p| p.player_name.eq_ignore_ascii_case(filter)))
+}
+
 impl Drop for LMSServer {
     fn drop(&mut self) {
         info!("LMSServer dropped — stopping polling task.");
@@ -722,3 +885,213 @@ impl Drop for LMSServer {
 
 
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    const MAC: &str = "aa:bb:cc:dd:ee:ff";
+
+    /// Minimal LMS jsonrpc endpoint: answers one request and hands back its body.
+    async fn mock_lms() -> (u16, JoinHandle<Value>) {
+        mock_lms_replying(json!({})).await
+    }
+
+    /// As `mock_lms`, answering with `result`.
+    async fn mock_lms_replying(result: Value) -> (u16, JoinHandle<Value>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let handle = tokio::spawn(async move {
+            let (mut sock, _) = listener.accept().await.unwrap();
+            let mut raw = Vec::new();
+            let mut chunk = [0u8; 1024];
+            let body = loop {
+                let n = sock.read(&mut chunk).await.unwrap();
+                raw.extend_from_slice(&chunk[..n]);
+                let text = String::from_utf8_lossy(&raw).to_string();
+                if let Some(split) = text.find("\r\n\r\n") {
+                    let len = text[..split].lines()
+                        .find_map(|l| l.to_ascii_lowercase().strip_prefix("content-length:").map(|v| v.trim().parse::<usize>().unwrap()))
+                        .unwrap_or(0);
+                    if raw.len() >= split + 4 + len {
+                        break serde_json::from_slice::<Value>(&raw[split + 4..split + 4 + len]).unwrap();
+                    }
+                }
+            };
+            let reply = json!({"id": body["id"], "result": result}).to_string();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                reply.len(), reply
+            );
+            sock.write_all(response.as_bytes()).await.unwrap();
+            body
+        });
+        (port, handle)
+    }
+
+    fn server_on(port: u16) -> LMSServer {
+        let mut lms = LMSServer::new();
+        lms.port = port;
+        lms.ready = true;
+        lms.players.push(Player {
+            player_index: 0,
+            power: true,
+            connected: true,
+            playing: true,
+            player_name: "study".to_string(),
+            player_ip: "127.0.0.1".to_string(),
+            player_id: MAC.to_string(),
+            model_type: "squeezelite".to_string(),
+            model_name: "SqueezeLite".to_string(),
+        });
+        lms.active_player = 0;
+        lms
+    }
+
+    async fn sent_params(cmd: TransportCommand) -> Value {
+        let (port, endpoint) = mock_lms().await;
+        let mut lms = server_on(port);
+        lms.send_transport(cmd).await.unwrap();
+        assert!(lms.refresh, "status refresh should be requested after a command");
+        let body = endpoint.await.unwrap();
+        assert_eq!(body["method"], "slim.request");
+        body["params"].clone()
+    }
+
+    #[tokio::test]
+    async fn play_pause_sends_pause_to_player() {
+        assert_eq!(sent_params(TransportCommand::PlayPause).await, json!([MAC, ["pause"]]));
+        assert_eq!(sent_params(TransportCommand::Pause).await, json!([MAC, ["pause", "1"]]));
+    }
+
+    #[tokio::test]
+    async fn next_and_prev_step_playlist_index() {
+        assert_eq!(sent_params(TransportCommand::Next).await, json!([MAC, ["playlist", "index", "+1"]]));
+        assert_eq!(sent_params(TransportCommand::Prev).await, json!([MAC, ["playlist", "index", "-1"]]));
+    }
+
+    #[tokio::test]
+    async fn set_volume_is_clamped() {
+        let (port, endpoint) = mock_lms().await;
+        let mut lms = server_on(port);
+        lms.set_volume(150).await.unwrap();
+        let body = endpoint.await.unwrap();
+        assert_eq!(body["params"], json!([MAC, ["mixer", "volume", "100"]]));
+    }
+
+    #[test]
+    fn composer_and_conductor_are_kept_from_the_role_tags() {
+        let status: PlayerStatus = serde_json::from_value(json!({
+            "mode": "play", "power": 1, "time": 3, "playlist repeat": 0, "playlist shuffle": 0,
+            "playlist_cur_index": 0,
+            "playlist_loop": [{
+                "playlist index": 0, "duration": 471.2, "title": "Symphony No. 5: I. Allegro con brio",
+                "artist": "Berliner Philharmoniker", "album": "Beethoven: Symphonies 5 & 7",
+                "composer": "Ludwig van Beethoven", "conductor": "Carlos Kleiber"
+            }]
+        })).unwrap();
+        let info = SlimInfo::from_status(status);
+        assert_eq!(info.composer, "Ludwig van Beethoven");
+        assert_eq!(info.conductor, "Carlos Kleiber");
+        assert_eq!(info.artist, "Berliner Philharmoniker");
+        assert!(LMSServer::new().slim_tags.contains('A'), "role tags carry composer and conductor");
+    }
+
+    #[test]
+    fn next_track_comes_from_the_following_playlist_entry() {
+        let status: PlayerStatus = serde_json::from_value(json!({
+            "mode": "play", "power": 1, "time": 12.5, "mixer volume": 40,
+            "playlist repeat": 0, "playlist shuffle": 0, "playlist_cur_index": "3",
+            "playlist_loop": [
+                {"playlist index": 3, "duration": 562.4, "artist": "Miles Davis", "title": "So What"},
+                {"playlist index": 4, "duration": "337.1", "artist": "Miles Davis", "title": "Freddie Freeloader"}
+            ]
+        })).unwrap();
+        let info = SlimInfo::from_status(status);
+        assert_eq!(info.title, "So What");
+        assert_eq!(info.format, "");
+        assert_eq!(info.next_track_text().as_deref(), Some("Miles Davis – Freddie Freeloader"));
+
+        // last entry in the playlist - nothing queued
+        let status: PlayerStatus = serde_json::from_value(json!({
+            "mode": "play", "power": 1, "time": 0, "playlist repeat": 0, "playlist shuffle": 0,
+            "playlist_cur_index": 4,
+            "playlist_loop": [{"playlist index": 4, "duration": 337.1, "title": "Freddie Freeloader"}]
+        })).unwrap();
+        assert_eq!(SlimInfo::from_status(status).next_track_text(), None);
+    }
+
+    #[tokio::test]
+    async fn one_status_call_fills_every_display_field() {
+        let (port, endpoint) = mock_lms_replying(json!({
+            "mode": "play", "power": 1, "time": "61.2", "mixer volume": 35,
+            "playlist mode": "off", "playlist repeat": 1, "playlist shuffle": 0, "playlist_cur_index": 0,
+            "playlist_loop": [
+                {"playlist index": 0, "duration": 412.0, "title": "Blue in Green", "artist": "Miles Davis",
+                 "albumartist": "Miles Davis", "album": "Kind of Blue", "year": "1959",
+                 "composer": "Bill Evans", "bitrate": "1411kb/s", "samplerate": "44100", "samplesize": "16",
+                 "type": "flc", "coverid": "a1b2c3", "compilation": "0"},
+                {"playlist index": 1, "duration": 686.0, "title": "All Blues", "artist": "Miles Davis"}
+            ]
+        })).await;
+        let mut lms = server_on(port);
+        lms.refresh = true;
+        lms.get_sliminfo_status().await.unwrap();
+
+        let body = endpoint.await.unwrap();
+        assert_eq!(body["params"], json!([MAC, ["status", "-", "2", lms.slim_tags]]));
+        assert_eq!(lms.consecutive_poll_errors, 0);
+        assert!(lms.has_changed() && lms.is_playing());
+
+        let info = &lms.sliminfo;
+        assert_eq!((info.title.as_str(), info.artist.as_str(), info.album.as_str()), ("Blue in Green", "Miles Davis", "Kind of Blue"));
+        assert_eq!((info.year.as_str(), info.composer.as_str(), info.coverid.as_str()), ("1959", "Bill Evans", "a1b2c3"));
+        assert_eq!((info.samplerate, info.samplesize, info.format.as_str()), (44100, 16, "flc"));
+        assert_eq!((info.bitrate.as_str(), info.volume, info.repeat), ("1411kb/s", 35, 1));
+        assert_eq!((info.duration.display.as_str(), info.tracktime.display.as_str(), info.remaining.display.as_str()), ("06:52", "01:01", "05:50"));
+        assert_eq!(info.next_track_text().as_deref(), Some("Miles Davis – All Blues"));
+    }
+
+    #[tokio::test]
+    async fn player_is_picked_by_mac_then_by_name() {
+        let players = json!({"count": 3, "players_loop": [
+            {"playerindex": 0, "name": "kitchen", "playerid": "00:04:20:aa:00:01", "ip": "10.0.0.5:3483", "model": "squeezelite", "modelname": "SqueezeLite"},
+            {"playerindex": 1, "name": "study", "playerid": MAC, "ip": "10.0.0.6:3483", "model": "squeezelite", "modelname": "SqueezeLite"},
+            {"playerindex": "2", "name": "AA:BB:CC:DD:EE:00", "playerid": "00:04:20:aa:00:03", "ip": "10.0.0.7:3483", "model": "squeezelite", "modelname": "SqueezeLite"}
+        ]});
+        let pick = |filter: &'static str| {
+            let players = players.clone();
+            async move {
+                let (port, endpoint) = mock_lms_replying(players).await;
+                let mut lms = LMSServer::new();
+                (lms.port, lms.ready) = (port, true);
+                let picked = lms.get_players(filter, MAC).await.ok().map(|_| lms.players[lms.active_player].player_name.clone());
+                assert_eq!(endpoint.await.unwrap()["params"], json!(["", ["players", "0", "99"]]));
+                (picked, lms.shared_memory.clone())
+            }
+        };
+
+        // the study player was renamed - its MAC still finds it
+        let (picked, shm) = pick("mac:AA:BB:CC:DD:EE:FF").await;
+        assert_eq!(picked.as_deref(), Some("study"));
+        assert_eq!(shm, format!("/squeezelite-{MAC}"));
+        assert_eq!(pick(MAC).await.0.as_deref(), Some("study"));
+        // no player with that MAC - a player so named is taken instead
+        assert_eq!(pick("aa:bb:cc:dd:ee:00").await.0.as_deref(), Some("AA:BB:CC:DD:EE:00"));
+        assert_eq!(pick("Kitchen").await.0.as_deref(), Some("kitchen"));
+        assert_eq!(pick("mac:00:00:00:00:00:00").await.0, None);
+
+        assert_eq!(player_mac("mac: 00:04:20:aa:00:01"), Some("00:04:20:aa:00:01"));
+        assert_eq!(player_mac("study"), None);
+        assert_eq!(player_mac("aa:bb:cc:dd:ee"), None);
+    }
+
+    #[tokio::test]
+    async fn transport_needs_active_player() {
+        let mut lms = LMSServer::new();
+        lms.ready = true;
+        assert!(lms.play_pause().await.is_err());
+    }
+}
@@ -22,6 +22,8 @@
  */
 #![allow(dead_code)] // spectrum analysis pipeline; written for future FFT visualizations
 
+use std::time::Duration;
+
 // For histogram display (different from peak meter mapping)
 const HIST_FLOOR_DB: f32 = -80.0;
 const HIST_CEIL_DB:  f32 = -12.0;
@@ -31,6 +33,21 @@ const FFT_MIN: usize = 128;
 const FFT_MAX: usize = 4096;
 const PEAK_METER_LEVELS_MAX: u8 = 48;
 pub const SPECTRUM_BANDS_COUNT:u8 = 16;         // Number of spectrum bands
+pub const SPECTRUM_BANDS_MIN: usize = 4;        // below this a histogram is just a VU meter
+pub const SPECTRUM_BANDS_MAX: usize = 64;       // above this the low bands share FFT bins
+const AGC_HEADROOM_DB: f32 = 3.0;               // AGC parks the running maximum this far under the ceiling
+const AGC_REF_FLOOR_DB: f32 = HIST_FLOOR_DB + 30.0; // quieter than this is noise - don't boost it to full scale
+
+/// Clamp a requested band count into the supported range.
+pub fn clamp_band_count(n: usize) -> usize {
+    n.clamp(SPECTRUM_BANDS_MIN, SPECTRUM_BANDS_MAX)
+}
+
+/// Band count suited to a panel width - 16 bands on a 128px panel, scaling
+/// up on wide panels so the bars don't leave the display mostly empty.
+pub fn band_count_for_width(width: u32) -> usize {
+    clamp_band_count(width as usize / 8)
+}
 
 #[inline]
 fn db_to_hist_level(db: f32) -> u8 {
@@ -38,6 +55,64 @@ fn db_to_hist_level(db: f32) -> u8 {
     (x * (PEAK_METER_LEVELS_MAX as f32)).round() as u8
 }
 
+/// Band AGC attack/release - how fast the running maximum chases a louder
+/// or quieter passage.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AgcSettings {
+    pub attack: Duration,
+    pub release: Duration,
+}
+
+impl Default for AgcSettings {
+    fn default() -> Self {
+        Self { attack: Duration::from_millis(50), release: Duration::from_secs(3) }
+    }
+}
+
+/// Automatic gain for the band levels. Tracks a running maximum of the
+/// loudest band - quick to rise, slow to fall - and shifts every band so that
+/// maximum sits just under the histogram ceiling. Quiet passages still move
+/// and loud ones stop pinning the bars.
+#[derive(Debug, Clone)]
+pub struct SpectrumAgc {
+    attack: f32,
+    release: f32,
+    ref_db: f32,
+}
+
+impl SpectrumAgc {
+    /// `frame_interval` is the time between calls to `apply`.
+    pub fn new(settings: AgcSettings, frame_interval: Duration) -> Self {
+        let coeff = |tau: Duration| {
+            if tau.is_zero() { 1.0 } else { 1.0 - (-frame_interval.as_secs_f32() / tau.as_secs_f32()).exp() }
+        };
+        Self {
+            attack: coeff(settings.attack),
+            release: coeff(settings.release),
+            ref_db: HIST_CEIL_DB,
+        }
+    }
+
+    /// Current running maximum, dBFS.
+    pub fn reference_db(&self) -> f32 {
+        self.ref_db
+    }
+
+    /// Follow this frame's loudest band then normalize both channels by the
+    /// same gain, so the stereo balance survives.
+    pub fn apply(&mut self, db_l: &mut [f32], db_r: &mut [f32]) {
+        let peak = db_l.iter().chain(db_r.iter()).copied()
+            .fold(AGC_REF_FLOOR_DB, f32::max);
+        let k = if peak > self.ref_db { self.attack } else { self.release };
+        self.ref_db += k * (peak - self.ref_db);
+
+        let gain = HIST_CEIL_DB - AGC_HEADROOM_DB - self.ref_db;
+        for db in db_l.iter_mut().chain(db_r.iter_mut()) {
+            *db += gain;
+        }
+    }
+}
+
 pub struct SpectrumEngine {
     sr: u32,
     nfft: usize,
@@ -52,10 +127,12 @@ pub struct SpectrumEngine {
     band_edges: Vec<(usize, usize)>,
     last_levels_l: Vec<u8>,
     last_levels_r: Vec<u8>,
+    agc: Option<SpectrumAgc>,       // None is raw mode
 }
 
 impl SpectrumEngine {
     pub fn new(sr: u32, samples_len: usize, bands: usize) -> Self {
+        let bands = clamp_band_count(bands);
         let want = samples_len.max(FFT_MIN).min(FFT_MAX);
         let nfft = want.next_power_of_two().min(FFT_MAX).max(FFT_MIN);
 
@@ -81,6 +158,7 @@ impl SpectrumEngine {
             sr, nfft, fft, window, win_sum, p_scale, buf, scratch, magsq, bands, band_edges,
             last_levels_l: vec![0; bands],
             last_levels_r: vec![0; bands],
+            agc: None,
         }
     }
 
@@ -108,12 +186,35 @@ impl SpectrumEngine {
         out
     }
 
-    pub fn ensure(&mut self, sr: u32, samples_len: usize) {
+    pub fn bands(&self) -> usize {
+        self.bands
+    }
+
+    /// Rebuild when the sample rate, block size or requested band count changes.
+    pub fn ensure(&mut self, sr: u32, samples_len: usize, bands: usize) {
         if self.sr != sr || samples_len < self.nfft / 2 || self.nfft > FFT_MAX {
-            *self = Self::new(sr, samples_len, self.bands);
+            let agc = self.agc.take();
+            *self = Self::new(sr, samples_len, bands);
+            self.agc = agc;
+        } else if clamp_band_count(bands) != self.bands {
+            self.set_band_count(bands);
         }
     }
 
+    /// Change the band count in place - the FFT plan and window are kept.
+    pub fn set_band_count(&mut self, bands: usize) {
+        let bands = clamp_band_count(bands);
+        self.bands = bands;
+        self.band_edges = Self::build_log_bands(self.sr, self.nfft, bands);
+        self.last_levels_l = vec![0; bands];
+        self.last_levels_r = vec![0; bands];
+    }
+
+    /// Normalize levels with `agc`, or pass `None` for raw levels.
+    pub fn set_agc(&mut self, agc: Option<SpectrumAgc>) {
+        self.agc = agc;
+    }
+
     /// Power dBFS per band with proper normalization (single-sided).
     pub fn compute_db_bands(&mut self, pcm: &[i16]) -> Vec<f32> {
         let need = self.nfft.min(pcm.len());
@@ -157,8 +258,11 @@ impl SpectrumEngine {
 
     /// Map to integer display levels with smoothing/decay you already had.
     pub fn compute_levels(&mut self, left: &[i16], right: &[i16]) -> (Vec<u8>, Vec<u8>) {
-        let db_l = self.compute_db_bands(left);
-        let db_r = self.compute_db_bands(right);
+        let mut db_l = self.compute_db_bands(left);
+        let mut db_r = self.compute_db_bands(right);
+        if let Some(agc) = &mut self.agc {
+            agc.apply(&mut db_l, &mut db_r);
+        }
 
         let mut lv_l = vec![0u8; self.bands];
         let mut lv_r = vec![0u8; self.bands];
@@ -176,3 +280,93 @@ impl SpectrumEngine {
     }
 
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tone(sr: u32, hz: f32, n: usize) -> Vec<i16> {
+        (0..n)
+            .map(|i| ((2.0 * std::f32::consts::PI * hz * i as f32 / sr as f32).sin() * 16000.0) as i16)
+            .collect()
+    }
+
+    #[test]
+    fn band_count_is_clamped() {
+        assert_eq!(clamp_band_count(0), SPECTRUM_BANDS_MIN);
+        assert_eq!(clamp_band_count(1000), SPECTRUM_BANDS_MAX);
+        assert_eq!(band_count_for_width(128), SPECTRUM_BANDS_COUNT as usize);
+        assert_eq!(band_count_for_width(256), 32);
+        assert_eq!(SpectrumEngine::new(44100, 1024, 500).bands(), SPECTRUM_BANDS_MAX);
+    }
+
+    #[test]
+    fn set_band_count_rebuilds_edges_and_levels() {
+        let mut eng = SpectrumEngine::new(44100, 2048, 16);
+        eng.ensure(44100, 2048, 48);
+        assert_eq!(eng.bands(), 48);
+        let pcm = tone(44100, 1000.0, 2048);
+        let (l, r) = eng.compute_levels(&pcm, &pcm);
+        assert_eq!((l.len(), r.len()), (48, 48));
+        assert!(l.iter().all(|&v| v <= PEAK_METER_LEVELS_MAX));
+        assert!(l.iter().any(|&v| v > 0));
+    }
+
+    #[test]
+    fn agc_converges_after_a_step_in_level() {
+        let settings = AgcSettings { attack: Duration::from_millis(50), release: Duration::from_millis(300) };
+        let mut agc = SpectrumAgc::new(settings, Duration::from_millis(16));
+        let target = HIST_CEIL_DB - AGC_HEADROOM_DB;
+        let step = |agc: &mut SpectrumAgc, level: f32| {
+            let (mut l, mut r) = (vec![level, level - 20.0], vec![level - 6.0, HIST_FLOOR_DB]);
+            agc.apply(&mut l, &mut r);
+            l[0]
+        };
+
+        // loud passage settles on the target
+        let mut out = 0.0;
+        for _ in 0..60 { out = step(&mut agc, -20.0); }
+        assert!((out - target).abs() < 0.5, "loud settles at {out}");
+
+        // 30dB drop: first frame shows the drop, then the gain climbs back
+        let mut last = step(&mut agc, -50.0);
+        assert!(last < target - 25.0);
+        for _ in 0..120 {
+            let next = step(&mut agc, -50.0);
+            assert!(next >= last - 1e-3, "normalized level must rise monotonically");
+            last = next;
+        }
+        assert!((last - target).abs() < 0.5, "quiet settles at {last}");
+
+        // and a jump back up is caught within a few frames, not clipped for seconds
+        for _ in 0..15 { last = step(&mut agc, -20.0); }
+        assert!((last - target).abs() < 0.5, "attack settles at {last}");
+    }
+
+    #[test]
+    fn agc_lifts_quiet_material_raw_mode_does_not() {
+        let pcm = tone(44100, 1000.0, 2048);
+        let quiet: Vec<i16> = pcm.iter().map(|s| s / 100).collect();
+        let mut raw = SpectrumEngine::new(44100, 2048, 16);
+        let mut agc = SpectrumEngine::new(44100, 2048, 16);
+        agc.set_agc(Some(SpectrumAgc::new(AgcSettings::default(), Duration::from_millis(16))));
+        agc.ensure(48000, 2048, 16); // a rebuild keeps the AGC
+        raw.ensure(48000, 2048, 16);
+        let (mut raw_l, mut agc_l) = (vec![], vec![]);
+        for _ in 0..400 {
+            raw_l = raw.compute_levels(&quiet, &quiet).0;
+            agc_l = agc.compute_levels(&quiet, &quiet).0;
+        }
+        let peak = |v: &[u8]| *v.iter().max().unwrap();
+        assert!(peak(&agc_l) > peak(&raw_l) + 10, "agc {} raw {}", peak(&agc_l), peak(&raw_l));
+    }
+
+    #[test]
+    fn log_bands_stay_in_range_and_non_empty() {
+        for bands in [SPECTRUM_BANDS_MIN, 16, SPECTRUM_BANDS_MAX] {
+            let edges = SpectrumEngine::build_log_bands(44100, 1024, bands);
+            assert_eq!(edges.len(), bands);
+            assert!(edges.iter().all(|&(a, b)| a < b && b <= 512));
+        }
+    }
+}
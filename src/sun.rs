@@ -282,3 +282,39 @@ pub fn to_fixed_offset(
         t.with_timezone(&offset)
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ymd(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn known_new_moons_are_phase_zero() {
+        assert_eq!(moon_phase_index(ymd(2000, 1, 6)), 0);
+        assert_eq!(moon_phase_index(ymd(2024, 1, 11)), 0);
+        assert_eq!(moon_phase_index(ymd(2025, 9, 21)), 0);
+    }
+
+    #[test]
+    fn known_full_moons_are_phase_four() {
+        assert_eq!(moon_phase_index(ymd(2000, 1, 21)), 4);
+        assert_eq!(moon_phase_index(ymd(2024, 1, 25)), 4);
+        assert_eq!(moon_phase_index(ymd(2025, 10, 7)), 4);
+    }
+
+    #[test]
+    fn quarters_fall_between() {
+        // first quarter 2024-01-18, third quarter 2024-02-02
+        assert_eq!(moon_phase_index(ymd(2024, 1, 18)), 2);
+        assert_eq!(moon_phase_index(ymd(2024, 2, 2)), 6);
+    }
+
+    #[test]
+    fn fraction_tracks_phase() {
+        assert!(moon_phase_fraction(ymd(2024, 1, 11)) < 0.05);
+        assert!(moon_phase_fraction(ymd(2024, 1, 25)) > 0.95);
+    }
+}
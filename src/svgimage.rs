@@ -43,6 +43,7 @@ use tiny_skia::Pixmap;
 use log::{debug, error};
 use std::{error::Error};
 use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
 //use std::fs;
 
 /// Custom error type for SVG rendering operations.
@@ -77,6 +78,156 @@ impl fmt::Display for SvgImageError {
 
 impl Error for SvgImageError {}
 
+/// How luminance is reduced to the 16 Gray4 levels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Gray4Dither {
+    /// Nearest level, bands on gradients
+    #[default]
+    None,
+    /// 4x4 ordered Bayer matrix, stable frame to frame
+    Bayer,
+    /// Floyd-Steinberg error diffusion, smoothest on stills
+    FloydSteinberg,
+}
+
+impl Gray4Dither {
+    /// Parse a config value, unknown names fall back to `None`
+    pub fn parse(name: &str) -> Self {
+        match name.trim().to_lowercase().as_str() {
+            "bayer" | "ordered" => Gray4Dither::Bayer,
+            "floyd" | "floyd-steinberg" | "floyd_steinberg" | "fs" => Gray4Dither::FloydSteinberg,
+            _ => Gray4Dither::None,
+        }
+    }
+}
+
+static SVG_DITHER: AtomicU8 = AtomicU8::new(0);
+
+/// Select the Gray4 dithering used for all subsequent SVG rasterization.
+pub fn set_svg_dither(mode: Gray4Dither) {
+    SVG_DITHER.store(mode as u8, Ordering::Relaxed);
+}
+
+/// Current Gray4 dithering mode.
+pub fn svg_dither() -> Gray4Dither {
+    match SVG_DITHER.load(Ordering::Relaxed) {
+        1 => Gray4Dither::Bayer,
+        2 => Gray4Dither::FloydSteinberg,
+        _ => Gray4Dither::None,
+    }
+}
+
+const BAYER_4X4: [[u8; 4]; 4] = [
+    [ 0,  8,  2, 10],
+    [12,  4, 14,  6],
+    [ 3, 11,  1,  9],
+    [15,  7, 13,  5],
+];
+
+/// Quantize row-major luminance (0-255) to Gray4 levels (0-15).
+pub fn quantize_gray4(luminance: &[f32], width: usize, mode: Gray4Dither) -> Vec<u8> {
+    let scale = |l: f32| (l.clamp(0.0, 255.0) / 255.0) * 15.0;
+    match mode {
+        Gray4Dither::None => luminance.iter().map(|&l| scale(l).round() as u8).collect(),
+        Gray4Dither::Bayer => luminance.iter().enumerate().map(|(i, &l)| {
+            let v = scale(l);
+            let base = v.floor();
+            let threshold = (BAYER_4X4[(i / width) % 4][(i % width) % 4] as f32 + 0.5) / 16.0;
+            let level = if v - base > threshold { base + 1.0 } else { base };
+            level.min(15.0) as u8
+        }).collect(),
+        Gray4Dither::FloydSteinberg => floyd_steinberg(luminance, width, 15.0),
+    }
+}
+
+/// Dither row-major luminance (0-255) to 1bpp, true for a lit pixel.
+pub fn quantize_mono(luminance: &[f32], width: usize) -> Vec<bool> {
+    floyd_steinberg(luminance, width, 1.0).into_iter().map(|l| l > 0).collect()
+}
+
+/// Floyd-Steinberg error diffusion of luminance down to `0..=max_level`.
+fn floyd_steinberg(luminance: &[f32], width: usize, max_level: f32) -> Vec<u8> {
+    let height = luminance.len() / width.max(1);
+    let mut work: Vec<f32> = luminance.iter().map(|&l| (l.clamp(0.0, 255.0) / 255.0) * max_level).collect();
+    let mut out = vec![0u8; work.len()];
+    for y in 0..height {
+        for x in 0..width {
+            let i = y * width + x;
+            let level = work[i].round().clamp(0.0, max_level);
+            out[i] = level as u8;
+            let err = work[i] - level;
+            if x + 1 < width { work[i + 1] += err * 7.0 / 16.0; }
+            if y + 1 < height {
+                if x > 0 { work[i + width - 1] += err * 3.0 / 16.0; }
+                work[i + width] += err * 5.0 / 16.0;
+                if x + 1 < width { work[i + width + 1] += err * 1.0 / 16.0; }
+            }
+        }
+    }
+    out
+}
+
+/// Row-major luminance (0-255) of a pixmap, alpha blended against black.
+pub fn pixmap_luminance(pixmap: &Pixmap) -> Vec<f32> {
+    pixmap
+        .pixels()
+        .iter()
+        .map(|p| {
+            let luminance = 0.299 * p.red() as f32 + 0.597 * p.green() as f32 + 0.114 * p.blue() as f32;
+            let alpha_factor = p.alpha() as f32 / 255.0;
+            (luminance * alpha_factor).clamp(0.0, 255.0)
+        })
+        .collect()
+}
+
+/// Dither a photo-like pixmap to 1bpp, rows padded to whole bytes, MSB first.
+pub fn pixmap_to_mono(pixmap: &Pixmap) -> Vec<u8> {
+    let width = pixmap.width() as usize;
+    let padded_width = width.div_ceil(8);
+    let mut buffer = vec![0u8; padded_width * pixmap.height() as usize];
+    for (i, on) in quantize_mono(&pixmap_luminance(pixmap), width).into_iter().enumerate() {
+        if on {
+            let (y, x) = (i / width, i % width);
+            buffer[y * padded_width + x / 8] |= 1 << (7 - x % 8);
+        }
+    }
+    buffer
+}
+
+/// Floyd-Steinberg dither a photo-like pixmap to packed Gray4, high nibble first.
+pub fn pixmap_to_gray4(pixmap: &Pixmap) -> Vec<u8> {
+    let width = pixmap.width() as usize;
+    let levels = quantize_gray4(&pixmap_luminance(pixmap), width, Gray4Dither::FloydSteinberg);
+    let mut buffer = vec![0u8; levels.len().div_ceil(2)];
+    for (i, level) in levels.into_iter().enumerate() {
+        buffer[i / 2] |= if i % 2 == 0 { level << 4 } else { level };
+    }
+    buffer
+}
+
+/// Pixmap to big-endian Rgb565, alpha composited against black.
+pub fn pixmap_to_rgb565(pixmap: &Pixmap) -> Vec<u8> {
+    pixmap
+        .pixels()
+        .iter()
+        .flat_map(|p| {
+            // premultiplied, so the colour channels are already blended against black
+            let pixel: u16 = ((p.red() as u16 >> 3) << 11)
+                | ((p.green() as u16 >> 2) << 5)
+                | (p.blue() as u16 >> 3);
+            pixel.to_be_bytes()
+        })
+        .collect()
+}
+
+/// `len` zeroed bytes from a buffer kept between renders; it only
+/// reallocates when a larger icon comes along.
+pub fn scratch(buffer: &mut Vec<u8>, len: usize) -> &mut Vec<u8> {
+    buffer.clear();
+    buffer.resize(len, 0);
+    buffer
+}
+
 /// Renders simple SVG data to a monochrome pixel buffer.
 #[derive(Debug)]
 pub struct SvgImageRenderer {
@@ -101,10 +252,22 @@ impl SvgImageRenderer {
         })
     }
 
+    /// Intrinsic size of the SVG document in user units.
+    pub fn svg_size(&self) -> (f32, f32) {
+        let size = self.tree.size();
+        (size.width(), size.height())
+    }
+
+    /// Change the rasterization size without reparsing.
+    pub fn set_target_size(&mut self, target_width: u32, target_height: u32) {
+        self.target_width = target_width;
+        self.target_height = target_height;
+    }
+
     /// Render the SVG to a fresh Pixmap at the target dimensions.
     ///
     /// Shared by all `render_to_buffer_*` methods to avoid boilerplate.
-    fn render_to_pixmap(&self) -> Result<tiny_skia::Pixmap, SvgImageError> {
+    pub fn render_to_pixmap(&self) -> Result<tiny_skia::Pixmap, SvgImageError> {
         let mut pixmap = Pixmap::new(self.target_width, self.target_height)
             .ok_or_else(|| SvgImageError::PixmapCreationError("Failed to create pixmap".to_string()))?;
         let svg_size = self.tree.size();
@@ -300,36 +463,21 @@ impl SvgImageRenderer {
         buffer.fill(0);
         let pixmap = self.render_to_pixmap()?;
 
-        // Convert RGBA to 4-bit grayscale (0-15)
-        pixmap
-            .pixels()
-            .chunks(self.target_width as usize)
-            .take(self.target_height as usize)
-            .enumerate()
-            .for_each(|(y, row)| {
-                row.iter().enumerate().for_each(|(x, p)| {
-                    // Calculate luminance (0-255 range)
-                    let luminance = 0.299 * p.red() as f32 + 0.597 * p.green() as f32 + 0.114 * p.blue() as f32;
-
-                    // Apply alpha blending (assume black background)
-                    let alpha_factor = p.alpha() as f32 / 255.0;
-                    let final_luminance = (luminance * alpha_factor).clamp(0.0, 255.0);
+        // Luminance with alpha blended against a black background
+        let luminance = pixmap_luminance(&pixmap);
 
-                    // Convert to 4-bit grayscale (0-15)
-                    let gray4_value = (final_luminance / 255.0 * 15.0).round() as u8;
+        // Convert to 4-bit grayscale (0-15), dithered per the global setting
+        let levels = quantize_gray4(&luminance, self.target_width as usize, svg_dither());
 
-                    // Pack into buffer (2 pixels per byte, high nibble first)
-                    let pixel_idx = y * self.target_width as usize + x;
-                    let byte_idx = pixel_idx / 2;
-                    if pixel_idx % 2 == 0 {
-                        // High nibble (first pixel in byte)
-                        buffer[byte_idx] = (gray4_value << 4) | (buffer[byte_idx] & 0x0F);
-                    } else {
-                        // Low nibble (second pixel in byte)
-                        buffer[byte_idx] = (buffer[byte_idx] & 0xF0) | gray4_value;
-                    }
-                });
-            });
+        // Pack into buffer (2 pixels per byte, high nibble first)
+        for (pixel_idx, gray4_value) in levels.into_iter().enumerate() {
+            let byte_idx = pixel_idx / 2;
+            if pixel_idx % 2 == 0 {
+                buffer[byte_idx] = (gray4_value << 4) | (buffer[byte_idx] & 0x0F);
+            } else {
+                buffer[byte_idx] = (buffer[byte_idx] & 0xF0) | gray4_value;
+            }
+        }
 
         debug!("SVG rendered to Gray4 buffer successfully.");
         Ok(())
@@ -433,3 +581,49 @@ impl SvgImageRenderer {
     }
 
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn histogram(levels: &[u8]) -> [usize; 16] {
+        let mut bins = [0usize; 16];
+        levels.iter().for_each(|&l| bins[l as usize] += 1);
+        bins
+    }
+
+    #[test]
+    fn dithering_spreads_flat_field_across_levels() {
+        // 110/255 sits at level 6.47, between two Gray4 steps
+        let (width, height) = (32, 16);
+        let flat = vec![110.0f32; width * height];
+        let target = 110.0 / 255.0 * 15.0;
+
+        let nearest = histogram(&quantize_gray4(&flat, width, Gray4Dither::None));
+        assert_eq!(nearest.iter().filter(|&&n| n > 0).count(), 1);
+
+        for mode in [Gray4Dither::Bayer, Gray4Dither::FloydSteinberg] {
+            let levels = quantize_gray4(&flat, width, mode);
+            let bins = histogram(&levels);
+            assert!(bins[6] > 0 && bins[7] > 0, "{mode:?} should mix levels 6 and 7: {bins:?}");
+            let mean = levels.iter().map(|&l| l as f32).sum::<f32>() / levels.len() as f32;
+            assert!((mean - target).abs() < 0.1, "{mode:?} mean {mean} drifts from {target}");
+        }
+    }
+
+    #[test]
+    fn dither_parse_defaults_to_nearest() {
+        assert_eq!(Gray4Dither::parse("bayer"), Gray4Dither::Bayer);
+        assert_eq!(Gray4Dither::parse("Floyd-Steinberg"), Gray4Dither::FloydSteinberg);
+        assert_eq!(Gray4Dither::parse("whatever"), Gray4Dither::None);
+    }
+
+    #[test]
+    fn mono_dither_keeps_mid_grey_density() {
+        let (width, height) = (32, 16);
+        let flat = vec![128.0f32; width * height];
+        let lit = quantize_mono(&flat, width).into_iter().filter(|&on| on).count();
+        let ratio = lit as f32 / (width * height) as f32;
+        assert!((ratio - 0.5).abs() < 0.05, "mid grey should light about half the pixels, got {ratio}");
+    }
+}
@@ -31,12 +31,14 @@ use embedded_graphics::{
     prelude::*,
 };
 
+use std::borrow::Cow;
 use std::sync::Arc;
 use tokio::sync::Mutex as TokMutex; // Aliased tokio::sync::Mutex
 use std::time::Duration;
 use tokio::task::JoinHandle;
 use tokio::time::sleep;
 use log::{info, debug}; // Add logging
+use serde::{Deserialize, Serialize};
 
 const SCROLL_LEFT: i8 = -1;
 const SCROLL_RIGHT: i8 = 1;
@@ -47,6 +49,155 @@ pub enum ScrollMode {
     Static,
     ScrollLeft,
     ScrollCylon,
+    /// Cylon bounce that slows into each edge (ease-in-out)
+    ScrollCylonEased,
+}
+
+/// Space between the end of looping text and its repeat.
+///
+/// A whole number is pixels, a decimal a fraction of the line width,
+/// e.g. `24` or `0.25`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum LoopGap {
+    Pixels(u32),
+    Fraction(f32),
+}
+
+impl LoopGap {
+    /// Gap in pixels for a line `region_width` pixels wide
+    pub fn resolve(self, region_width: u32) -> u32 {
+        match self {
+            LoopGap::Pixels(px) => px,
+            LoopGap::Fraction(f) => (region_width as f32 * f.max(0.0)).round() as u32,
+        }
+    }
+}
+
+/// Offset of an eased cylon bounce at `phase` through one full cycle.
+///
+/// Phase 0 is the start position (0), 0.5 the far edge (`-travel`); the
+/// raised cosine has zero speed at both, so the text glides into each turn.
+pub fn eased_cylon_offset(phase: f32, travel: f32) -> f32 {
+    -travel * (1.0 - (std::f32::consts::TAU * phase).cos()) / 2.0
+}
+
+/// Phase advance per tick for a bounce over `travel` pixels, chosen so a full
+/// cycle takes as many ticks as the linear cylon at `step` pixels per tick.
+pub fn eased_cylon_phase_step(travel: f32, step: f32) -> f32 {
+    if travel <= 0.0 { 0.0 } else { step / (2.0 * travel) }
+}
+
+/// Predominant script direction of a string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TextDirection {
+    LeftToRight,
+    RightToLeft,
+}
+
+/// Hebrew, Arabic, Syriac, Thaana, N'Ko etc. plus their presentation forms.
+fn is_rtl_char(c: char) -> bool {
+    matches!(c as u32,
+        0x0590..=0x08FF | 0xFB1D..=0xFDFF | 0xFE70..=0xFEFF | 0x10800..=0x10FFF | 0x1E800..=0x1EFFF)
+}
+
+/// Direction of the majority of strong (alphabetic) characters.
+pub fn text_direction(text: &str) -> TextDirection {
+    let (mut rtl, mut ltr) = (0usize, 0usize);
+    for c in text.chars() {
+        if is_rtl_char(c) {
+            rtl += 1;
+        } else if c.is_alphabetic() {
+            ltr += 1;
+        }
+    }
+    if rtl > ltr { TextDirection::RightToLeft } else { TextDirection::LeftToRight }
+}
+
+/// Reorder logical-order RTL text into visual order for our left-to-right glyph renderers.
+/// Embedded LTR runs (Latin words, numbers) keep their own order - a simplified bidi pass.
+pub fn visual_order(text: &str) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let strong_ltr = |c: char| c.is_alphanumeric() && !is_rtl_char(c);
+    let is_strong = |c: char| c.is_alphanumeric() || is_rtl_char(c);
+
+    // neutrals take LTR only when sandwiched between LTR characters
+    let ltr: Vec<bool> = (0..chars.len()).map(|i| {
+        if is_strong(chars[i]) {
+            return strong_ltr(chars[i]);
+        }
+        let prev = chars[..i].iter().rev().find(|c| is_strong(**c)).map(|c| strong_ltr(*c));
+        let next = chars[i + 1..].iter().find(|c| is_strong(**c)).map(|c| strong_ltr(*c));
+        prev == Some(true) && next == Some(true)
+    }).collect();
+
+    let mut runs: Vec<(bool, Vec<char>)> = Vec::new();
+    for (c, l) in chars.into_iter().zip(ltr) {
+        match runs.last_mut() {
+            Some((run_ltr, run)) if *run_ltr == l => run.push(c),
+            _ => runs.push((l, vec![c])),
+        }
+    }
+    runs.into_iter().rev().flat_map(|(l, mut run)| {
+        if !l {
+            run.reverse();
+        }
+        run
+    }).collect()
+}
+
+/// Drawn for a character the font has no glyph for and no stand-in
+pub const GLYPH_PLACEHOLDER: char = '?';
+
+/// Letters folded to their base letter when the font lacks the accented form
+const LATIN_FOLDS: &[(&str, char)] = &[
+    ("ÀÁÂÃÄÅĀĂĄ", 'A'), ("àáâãäåāăą", 'a'), ("ÇĆĈĊČ", 'C'), ("çćĉċč", 'c'),
+    ("ÈÉÊËĒĔĖĘĚ", 'E'), ("èéêëēĕėęě", 'e'), ("ÌÍÎÏĨĪĬĮİ", 'I'), ("ìíîïĩīĭįı", 'i'),
+    ("ÑŃŅŇ", 'N'), ("ñńņň", 'n'), ("ÒÓÔÕÖØŌŎŐ", 'O'), ("òóôõöøōŏő", 'o'),
+    ("ÙÚÛÜŨŪŬŮŰŲ", 'U'), ("ùúûüũūŭůűų", 'u'), ("ÝŸŶ", 'Y'), ("ýÿŷ", 'y'),
+    ("ŚŜŞŠ", 'S'), ("śŝşš", 's'), ("ŹŻŽ", 'Z'), ("źżž", 'z'),
+];
+
+/// Punctuation and symbols spelt out when the font lacks them
+const SYMBOL_SUBS: &[(char, &str)] = &[
+    ('‘', "'"), ('’', "'"), ('‚', "'"), ('′', "'"), ('“', "\""), ('”', "\""), ('„', "\""), ('″', "\""),
+    ('‐', "-"), ('‑', "-"), ('–', "-"), ('—', "-"), ('―', "-"), ('…', "..."), ('•', "*"),
+    ('™', "TM"), ('€', "EUR"), ('\u{00A0}', " "),
+];
+
+/// Zero width joiners, variation selectors and skin tone modifiers - parts of
+/// an emoji sequence that would otherwise each draw a placeholder
+fn is_emoji_joiner(c: char) -> bool {
+    matches!(c as u32, 0x200D | 0xFE00..=0xFE0F | 0x1F3FB..=0x1F3FF | 0xE0020..=0xE007F)
+}
+
+/// Whether `font` has a glyph for `c` rather than drawing its replacement
+pub fn font_covers(font: &MonoFont, c: char) -> bool {
+    c == ' ' || c.is_ascii_graphic() || font.glyph_mapping.index(c) != font.glyph_mapping.index('\u{FFFF}')
+}
+
+/// Fit `text` to the glyphs `font` has: accented letters it lacks fold to
+/// the base letter, typographic punctuation to ASCII, and anything else
+/// (emoji, CJK) becomes one `GLYPH_PLACEHOLDER` per character.
+pub fn sanitize_for_font<'a>(text: &'a str, font: &MonoFont) -> Cow<'a, str> {
+    if text.chars().all(|c| font_covers(font, c)) {
+        return Cow::Borrowed(text);
+    }
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        if font_covers(font, c) {
+            out.push(c);
+        } else if is_emoji_joiner(c) {
+            continue;
+        } else if let Some(&(_, base)) = LATIN_FOLDS.iter().find(|(accented, _)| accented.contains(c)) {
+            out.push(base);
+        } else if let Some(&(_, sub)) = SYMBOL_SUBS.iter().find(|(symbol, _)| *symbol == c) {
+            out.push_str(sub);
+        } else {
+            out.push(GLYPH_PLACEHOLDER);
+        }
+    }
+    Cow::Owned(out)
 }
 
 /// Internal shared state for the `TextScroller` task.
@@ -64,11 +215,15 @@ pub struct State { // Made public so OledDisplay can read it
     pub character_width: usize,
     pub character_height: usize,
     pub gap_between_looped_text: f32,
+    pub loop_gap: Option<LoopGap>, // configured gap, replaces the three character default
+    pub rtl: bool, // right-to-left text: right aligned, scrolls rightwards
+    pub phase: f32, // eased cylon position through one bounce, 0..1
 }
 
 pub fn transform_scroll_mode(scroll_mode: &str) -> ScrollMode {
     match scroll_mode {
         "cylon" =>  ScrollMode::ScrollCylon,
+        "cylon_eased" => ScrollMode::ScrollCylonEased,
         "loop" => ScrollMode::ScrollLeft,
         "loopleft" => ScrollMode::ScrollLeft,
         _ => ScrollMode::Static,
@@ -95,6 +250,9 @@ impl Default for State {
             character_width,
             character_height,
             gap_between_looped_text,
+            loop_gap: None,
+            rtl: false,
+            phase: 0.0,
         }
     }
 }
@@ -139,6 +297,9 @@ impl TextScroller {
             character_width,
             character_height,
             gap_between_looped_text,
+            loop_gap: None,
+            rtl: false,
+            phase: 0.0,
         }));
 
         Self {
@@ -200,6 +361,13 @@ impl TextScroller {
                         drop(s);
                         break; // Exit the loop and terminate the task
                     }
+                    ScrollMode::ScrollLeft if s.rtl => {
+                        // mirror image of the LTR loop, wraps back to right aligned
+                        s.current_offset_float += SCROLL_AMOUNT_PER_TICK;
+                        if s.current_offset_float >= display_width as f32 + looped_text_gap {
+                            s.current_offset_float = max_offset_right;
+                        }
+                    }
                     ScrollMode::ScrollLeft => {
                         s.current_offset_float -= SCROLL_AMOUNT_PER_TICK;
                         if s.current_offset_float <= -(text_width as f32 + looped_text_gap) {
@@ -227,7 +395,11 @@ impl TextScroller {
                             }
                         } // Reverse direction to left
                     }
-                        
+                    ScrollMode::ScrollCylonEased => {
+                        let travel = text_width as f32 - display_width as f32;
+                        s.phase = (s.phase + eased_cylon_phase_step(travel, SCROLL_AMOUNT_PER_TICK)).fract();
+                        s.current_offset_float = eased_cylon_offset(s.phase, travel);
+                    }
                 }
 
                 // Update the last_drawn_x_rounded in state so OledDisplay knows to redraw.
@@ -259,6 +431,11 @@ impl TextScroller {
 
     }
 
+    /// Replace the default three character loop gap; takes effect on the next content update
+    pub async fn set_loop_gap(&mut self, gap: LoopGap) {
+        self.state.lock().await.loop_gap = Some(gap);
+    }
+
     /// Updates the text, scroll mode, and text width in the scroller's state.
     /// Does NOT start or stop the internal task. OledDisplay handles that.
     pub async fn update_content(&mut self, new_text: String, new_mode: ScrollMode, new_text_width: u32) {
@@ -269,16 +446,33 @@ impl TextScroller {
         let display_width = self.width;
         debug!("{} updating state. {:?} width {}", self.name, new_mode, new_text_width);
 
+        s.rtl = text_direction(&new_text) == TextDirection::RightToLeft;
         s.text = new_text;
         s.scroll_mode = new_mode;
         s.text_width = new_text_width; // Update text width
+        if let Some(gap) = s.loop_gap {
+            s.gap_between_looped_text = gap.resolve(display_width) as f32;
+        }
         if new_text_width <= display_width {
-            s.current_offset_float = ((display_width - new_text_width) / 2) as f32;
+            s.current_offset_float = if s.rtl {
+                (display_width - new_text_width) as f32 // right align
+            } else {
+                ((display_width - new_text_width) / 2) as f32
+            };
             s.scroll_mode = ScrollMode::Static;
+        } else if new_mode == ScrollMode::ScrollCylonEased {
+            // the raised cosine starts at rest against the left edge
+            s.current_offset_float = 0.0;
+            s.phase = 0.0;
         } else {
             if new_mode == ScrollMode::ScrollCylon || new_mode == ScrollMode::ScrollLeft {
-                s.current_offset_float = display_width as f32;
-                s.direction = SCROLL_LEFT;
+                if s.rtl {
+                    s.current_offset_float = -(new_text_width as f32);
+                    s.direction = SCROLL_RIGHT;
+                } else {
+                    s.current_offset_float = display_width as f32;
+                    s.direction = SCROLL_LEFT;
+                }
             }
         }
         s.last_drawn_x_rounded = i32::MIN; // Force redraw by OledDisplay
@@ -335,3 +529,55 @@ impl Drop for TextScroller {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsupported_glyphs_become_placeholders_or_stand_ins() {
+        // one placeholder per emoji, the joiners and variation selectors dropped
+        assert_eq!(sanitize_for_font("Happy 😀🎉 Song ❤️", &FONT_5X8), "Happy ?? Song ?");
+        assert_eq!(sanitize_for_font("👨‍👩‍👧 Family", &FONT_5X8), "??? Family");
+        assert_eq!(sanitize_for_font("東京", &FONT_5X8), "??");
+
+        // ISO 8859-13 has ö and the curly quotes, not ñ or the ellipsis
+        assert_eq!(sanitize_for_font("Björk – “Jóga”", &FONT_5X8), "Björk - “Jóga”");
+        assert_eq!(sanitize_for_font("Señor…", &FONT_5X8), "Senor...");
+        assert!(matches!(sanitize_for_font("So What", &FONT_5X8), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn detects_predominant_direction() {
+        assert_eq!(text_direction("Bohemian Rhapsody"), TextDirection::LeftToRight);
+        assert_eq!(text_direction("שיר השירים"), TextDirection::RightToLeft);
+        assert_eq!(text_direction("أم كلثوم - Live 1967"), TextDirection::RightToLeft);
+        assert_eq!(text_direction("1999"), TextDirection::LeftToRight);
+    }
+
+    #[test]
+    fn eased_cylon_slows_into_the_turnaround() {
+        let travel = 60.0;
+        let step = eased_cylon_phase_step(travel, 1.0);
+        let at = |tick: u32| eased_cylon_offset(tick as f32 * step, travel);
+
+        // one cycle takes as long as the linear bounce: out 60px and back
+        assert_eq!((1.0 / step).round() as u32, 120);
+        assert_eq!(at(0), 0.0);
+        assert!((at(60) + travel).abs() < 1e-3, "far edge at half a cycle");
+
+        // each step into the far edge is shorter than the one before
+        let steps: Vec<f32> = (50..60).map(|t| (at(t + 1) - at(t)).abs()).collect();
+        assert!(steps.windows(2).all(|w| w[1] < w[0]), "{steps:?}");
+        assert!(steps[9] < 0.05 && (at(31) - at(30)).abs() > 1.5, "slow at the edge, fast mid-travel");
+        // and out of it the text picks up speed again
+        assert!((at(62) - at(61)).abs() > (at(61) - at(60)).abs());
+    }
+
+    #[test]
+    fn visual_order_reverses_rtl_but_keeps_ltr_runs() {
+        assert_eq!(visual_order("שלום"), "םולש");
+        assert_eq!(visual_order("שיר 2024"), "2024 ריש");
+        assert_eq!(visual_order("abc def"), "abc def");
+    }
+}
@@ -56,7 +56,7 @@ impl Translation {
 
         let value = self.google_trans
             .translate_async(phrase, "", self.to_language.as_str())
-            .await.unwrap();
+            .await?;
         self.cache.insert(key.clone(), value.clone());
         Ok(value)
     }
@@ -69,8 +69,7 @@ impl Translation {
         }
 
         let value = self.google_trans
-            .translate_sync(phrase, "", self.to_language.as_str())
-            .unwrap();
+            .translate_sync(phrase, "", self.to_language.as_str())?;
         self.cache.insert(key.clone(), value.clone());
         Ok(value)
     }
@@ -78,17 +77,247 @@ impl Translation {
     pub async fn translate_phrase_no_cache(&mut self, phrase: &str) -> anyhow::Result<String> {
         let value = self.google_trans
             .translate_async(phrase, "", self.to_language.as_str())
-            .await.unwrap();
+            .await?;
         Ok(value)
     }
 
     pub fn translate_phrase_no_cache_sync(&mut self, phrase: &str) -> anyhow::Result<String> {
         let value = self.google_trans
-            .translate_sync(phrase, "", self.to_language.as_str())
-            .unwrap();
+            .translate_sync(phrase, "", self.to_language.as_str())?;
         Ok(value)
     }
 
 
 }
 
+/// Languages with built in display strings; others fall back to English for
+/// the UI and to online translation for weather conditions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    En,
+    De,
+}
+
+/// Fixed display strings
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UiText {
+    NoWeatherData,
+    ConfigureWeather,
+    LoadingForecast,
+    Stale,
+    RecentlyPlayed,
+}
+
+/// Weather condition terms a description is built from, joined with "and"
+const CONDITIONS_DE: &[(&str, &str)] = &[
+    ("Clear, Sunny", "klar, sonnig"),
+    ("Clear", "klar"),
+    ("Cloudy", "bewölkt"),
+    ("Mostly Clear", "überwiegend klar"),
+    ("Mostly Cloudy", "überwiegend bewölkt"),
+    ("Partly Cloudy", "teilweise bewölkt"),
+    ("Fog", "Nebel"),
+    ("Light Fog", "leichter Nebel"),
+    ("Drizzle", "Nieselregen"),
+    ("Freezing Drizzle", "gefrierender Nieselregen"),
+    ("Rain", "Regen"),
+    ("Light Rain", "leichter Regen"),
+    ("Heavy Rain", "starker Regen"),
+    ("Freezing Rain", "gefrierender Regen"),
+    ("Light Freezing Rain", "leichter gefrierender Regen"),
+    ("Heavy Freezing Rain", "starker gefrierender Regen"),
+    ("Snow", "Schnee"),
+    ("Light Snow", "leichter Schnee"),
+    ("Heavy Snow", "starker Schnee"),
+    ("Flurries", "Schneegestöber"),
+    ("Ice Pellets", "Eiskörner"),
+    ("Light Ice Pellets", "leichte Eiskörner"),
+    ("Heavy Ice Pellets", "starke Eiskörner"),
+    ("Thunderstorm", "Gewitter"),
+    ("Unknown", "unbekannt"),
+];
+
+impl Locale {
+    /// Built in locale for a language code - "de", "de_AT", "DE-ch"; None
+    /// for languages without one, English included only as "en"
+    pub fn parse(code: &str) -> Option<Self> {
+        let lang = code.trim().split(['_', '-']).next().unwrap_or("").to_ascii_lowercase();
+        match lang.as_str() {
+            "en" => Some(Locale::En),
+            "de" => Some(Locale::De),
+            _ => None,
+        }
+    }
+
+    pub fn text(self, key: UiText) -> &'static str {
+        match (self, key) {
+            (Locale::En, UiText::NoWeatherData) => "No Weather Data",
+            (Locale::En, UiText::ConfigureWeather) => "Configure -W option\nwith API key",
+            (Locale::En, UiText::LoadingForecast) => "Loading Forecast...",
+            (Locale::En, UiText::Stale) => "stale",
+            (Locale::En, UiText::RecentlyPlayed) => "Recently played",
+            (Locale::De, UiText::NoWeatherData) => "Keine Wetterdaten",
+            (Locale::De, UiText::ConfigureWeather) => "Option -W mit\nAPI-Schlüssel setzen",
+            (Locale::De, UiText::LoadingForecast) => "Lade Vorhersage...",
+            (Locale::De, UiText::Stale) => "veraltet",
+            (Locale::De, UiText::RecentlyPlayed) => "Zuletzt gespielt",
+        }
+    }
+
+    pub fn play_mode(self, mode: PlayMode) -> &'static str {
+        match (self, mode) {
+            (Locale::En, _) => mode.label(),
+            (Locale::De, PlayMode::Playing) => "Wiedergabe",
+            (Locale::De, PlayMode::Paused) => "Pause",
+            (Locale::De, PlayMode::Stopped) => "Gestoppt",
+        }
+    }
+
+    /// A weather code's English description in this locale, term by term;
+    /// None when a term has no entry
+    pub fn condition(self, english: &str) -> Option<String> {
+        let table = match self {
+            Locale::En => return Some(english.to_string()),
+            Locale::De => CONDITIONS_DE,
+        };
+        let terms = english
+            .split(" and ")
+            .map(|term| table.iter().find(|(en, _)| *en == term).map(|(_, local)| *local))
+            .collect::<Option<Vec<_>>>()?;
+        let joined = terms.join(" und ");
+        let mut chars = joined.chars();
+        Some(chars.next().map(|c| c.to_uppercase().chain(chars).collect()).unwrap_or_default())
+    }
+}
+
+/// Player state as LMS reports it in `mode`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayMode {
+    Playing,
+    Paused,
+    Stopped,
+}
+
+impl PlayMode {
+    pub const ALL: [PlayMode; 3] = [PlayMode::Playing, PlayMode::Paused, PlayMode::Stopped];
+
+    /// Read an LMS `mode` - "play", "pause" or "stop", in any case, or the
+    /// already spelt out form; None for anything else
+    pub fn from_lms(mode: &str) -> Option<Self> {
+        match mode.trim().to_ascii_lowercase().as_str() {
+            "play" | "playing" => Some(PlayMode::Playing),
+            "pause" | "paused" => Some(PlayMode::Paused),
+            "stop" | "stopped" => Some(PlayMode::Stopped),
+            _ => None,
+        }
+    }
+
+    /// The English label
+    pub fn label(self) -> &'static str {
+        match self {
+            PlayMode::Playing => "Playing",
+            PlayMode::Paused => "Paused",
+            PlayMode::Stopped => "Stopped",
+        }
+    }
+}
+
+/// What the panels show for each player state - the one place raw LMS
+/// modes become display text, English unless translated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModeLabels {
+    playing: String,
+    paused: String,
+    stopped: String,
+}
+
+impl Default for ModeLabels {
+    fn default() -> Self {
+        Self::for_locale(Locale::En)
+    }
+}
+
+impl ModeLabels {
+    /// Built in labels for `locale`
+    pub fn for_locale(locale: Locale) -> Self {
+        Self {
+            playing: locale.play_mode(PlayMode::Playing).to_string(),
+            paused: locale.play_mode(PlayMode::Paused).to_string(),
+            stopped: locale.play_mode(PlayMode::Stopped).to_string(),
+        }
+    }
+
+    /// Labels in `translation`'s language; any that fail to translate stay English
+    pub async fn translated(translation: &mut Translation) -> Self {
+        let mut labels = Self::default();
+        for mode in PlayMode::ALL {
+            match translation.translate_phrase(mode.label()).await {
+                Ok(text) if !text.trim().is_empty() => *labels.slot(mode) = text,
+                Ok(_) => {}
+                Err(e) => log::warn!("Keeping '{}' untranslated: {}", mode.label(), e),
+            }
+        }
+        labels
+    }
+
+    fn slot(&mut self, mode: PlayMode) -> &mut String {
+        match mode {
+            PlayMode::Playing => &mut self.playing,
+            PlayMode::Paused => &mut self.paused,
+            PlayMode::Stopped => &mut self.stopped,
+        }
+    }
+
+    pub fn label(&self, mode: PlayMode) -> &str {
+        match mode {
+            PlayMode::Playing => &self.playing,
+            PlayMode::Paused => &self.paused,
+            PlayMode::Stopped => &self.stopped,
+        }
+    }
+
+    /// Display text for a raw LMS `mode`; an empty mode stays empty and one
+    /// LMS doesn't use is shown as given
+    pub fn text_for(&self, mode: &str) -> String {
+        PlayMode::from_lms(mode).map_or_else(|| mode.trim().to_string(), |m| self.label(m).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lms_modes_map_to_labels() {
+        let labels = ModeLabels::default();
+        for (raw, text) in [
+            ("play", "Playing"),
+            ("pause", "Paused"),
+            ("stop", "Stopped"),
+            ("PLAY", "Playing"),
+            (" pause\n", "Paused"),
+            ("stopped", "Stopped"),
+            ("", ""),
+            ("buffering", "buffering"),
+        ] {
+            assert_eq!(labels.text_for(raw), text, "{raw:?}");
+        }
+        assert_eq!(PlayMode::from_lms("Paused"), Some(PlayMode::Paused));
+        assert_eq!(PlayMode::from_lms("idle"), None);
+    }
+
+    #[test]
+    fn built_in_locale_translates_conditions_term_by_term() {
+        assert_eq!(Locale::parse("de_AT"), Some(Locale::De));
+        assert_eq!(Locale::parse("EN"), Some(Locale::En));
+        assert_eq!(Locale::parse("fr"), None);
+
+        assert_eq!(Locale::De.condition("Mostly Cloudy and Light Rain").as_deref(), Some("Überwiegend bewölkt und leichter Regen"));
+        assert_eq!(Locale::De.condition("Clear, Sunny").as_deref(), Some("Klar, sonnig"));
+        assert_eq!(Locale::De.condition("Sandstorm"), None);
+        assert_eq!(Locale::En.condition("Fog").as_deref(), Some("Fog"));
+        assert_eq!(ModeLabels::for_locale(Locale::De).text_for("pause"), "Pause");
+        assert_eq!(Locale::De.text(UiText::RecentlyPlayed), "Zuletzt gespielt");
+    }
+}
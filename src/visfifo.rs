@@ -0,0 +1,202 @@
+/*
+ *  visfifo.rs
+ *
+ *  LyMonS - worth the squeeze
+ *	(c) 2020-26 Stuart Hunter
+ *
+ *	Visualizer audio from a named pipe - for setups without squeezelite's
+ *	shared memory. The writer streams raw interleaved stereo s16le PCM, e.g.
+ *	  mkfifo /tmp/lymons.pcm
+ *	  squeezelite ... -o - | tee >(cat > /tmp/lymons.pcm) | aplay ...
+ *	The pipe is opened non-blocking so LyMonS starts before (and survives)
+ *	the writer; each poll drains what arrived and hands the newest window
+ *	to the same processing the shared-memory path uses.
+ *
+ *	This program is free software: you can redistribute it and/or modify
+ *	it under the terms of the GNU General Public License as published by
+ *	the Free Software Foundation, either version 3 of the License, or
+ *	(at your option) any later version.
+ *
+ *	This program is distributed in the hope that it will be useful,
+ *	but WITHOUT ANY WARRANTY; without even the implied warranty of
+ *	MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+ *	GNU General Public License for more details.
+ *
+ *	See <http://www.gnu.org/licenses/> to get a copy of the GNU General
+ *	Public License.
+ *
+ */
+
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read};
+use std::os::unix::fs::OpenOptionsExt;
+use std::path::Path;
+use std::time::Instant;
+
+use crate::vision::{AudioSource, VisFrame, STALE_THRESHOLD};
+
+/// Sample rate assumed for FIFO PCM, which carries no header
+pub const FIFO_SAMPLE_RATE: u32 = 44_100;
+
+/// Stereo frames handed to the visualizer per poll
+pub const FIFO_WINDOW: usize = 1024;
+
+/// Upper bound on bytes drained per poll so a fast writer cannot starve the worker
+const MAX_DRAIN: usize = 64 * 1024;
+
+/// Reads raw interleaved stereo s16le PCM from a pipe
+pub struct FifoReader<R: Read = File> {
+    reader: R,
+    sample_rate: u32,
+    /// A read can end mid-sample; the odd byte waits here
+    carry: Option<u8>,
+    /// Newest interleaved samples, at most `FIFO_WINDOW` stereo frames
+    samples: Vec<i16>,
+    samples_l: Vec<i16>,
+    samples_r: Vec<i16>,
+    last_data: Instant,
+}
+
+impl FifoReader<File> {
+    /// Open `path` without waiting for a writer to appear
+    pub fn open(path: &Path) -> io::Result<Self> {
+        let file = OpenOptions::new()
+            .read(true)
+            .custom_flags(libc::O_NONBLOCK)
+            .open(path)?;
+        Ok(Self::new(file, FIFO_SAMPLE_RATE))
+    }
+}
+
+impl<R: Read> FifoReader<R> {
+    pub fn new(reader: R, sample_rate: u32) -> Self {
+        Self {
+            reader,
+            sample_rate,
+            carry: None,
+            samples: Vec::with_capacity(FIFO_WINDOW * 2),
+            samples_l: Vec::with_capacity(FIFO_WINDOW),
+            samples_r: Vec::with_capacity(FIFO_WINDOW),
+            last_data: Instant::now(),
+        }
+    }
+
+    /// Drain what the writer has sent; true when any new samples arrived
+    fn drain(&mut self) -> io::Result<bool> {
+        let mut buf = [0u8; 4096];
+        let mut total = 0;
+        while total < MAX_DRAIN {
+            let n = match self.reader.read(&mut buf) {
+                Ok(0) => break, // no writer attached
+                Ok(n) => n,
+                Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            total += n;
+            self.push_bytes(&buf[..n]);
+        }
+        let keep = FIFO_WINDOW * 2;
+        if self.samples.len() > keep {
+            self.samples.drain(..self.samples.len() - keep);
+        }
+        Ok(total > 0)
+    }
+
+    fn push_bytes(&mut self, mut bytes: &[u8]) {
+        if let Some(lo) = self.carry.take() {
+            let Some((&hi, rest)) = bytes.split_first() else {
+                self.carry = Some(lo);
+                return;
+            };
+            self.samples.push(i16::from_le_bytes([lo, hi]));
+            bytes = rest;
+        }
+        let pairs = bytes.chunks_exact(2);
+        self.carry = pairs.remainder().first().copied();
+        self.samples.extend(pairs.map(|p| i16::from_le_bytes([p[0], p[1]])));
+    }
+}
+
+impl<R: Read + Send> AudioSource for FifoReader<R> {
+    fn with_frame<F>(&mut self, f: F) -> io::Result<bool>
+    where
+        F: FnOnce(&VisFrame, &[i16], &[i16]),
+    {
+        if !self.drain()? || self.samples.len() < 2 {
+            return Ok(false);
+        }
+        self.last_data = Instant::now();
+
+        let stereo = &self.samples[..self.samples.len() & !1];
+        self.samples_l.clear();
+        self.samples_r.clear();
+        for pair in stereo.chunks_exact(2) {
+            self.samples_l.push(pair[0]);
+            self.samples_r.push(pair[1]);
+        }
+        let frame = VisFrame {
+            sample_rate: self.sample_rate,
+            timestamp: chrono::Utc::now().timestamp_millis(),
+            running: true,
+            samples: stereo.to_vec(),
+        };
+        f(&frame, &self.samples_l, &self.samples_r);
+        Ok(true)
+    }
+
+    fn poll_stale(&mut self) -> bool {
+        self.last_data.elapsed() > STALE_THRESHOLD
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// A pipe that hands out its queued chunks, then would block
+    struct Chunks(VecDeque<Vec<u8>>);
+
+    impl Read for Chunks {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let Some(mut chunk) = self.0.pop_front() else {
+                return Err(io::ErrorKind::WouldBlock.into());
+            };
+            let n = chunk.len().min(buf.len());
+            buf[..n].copy_from_slice(&chunk[..n]);
+            if n < chunk.len() {
+                self.0.push_front(chunk.split_off(n));
+            }
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn split_samples_are_joined_across_reads() {
+        // L=1000 R=-2 L=300 R=4, the second sample split over two reads
+        let pcm: Vec<u8> = [1000i16, -2, 300, 4].iter().flat_map(|s| s.to_le_bytes()).collect();
+        let chunks = VecDeque::from([pcm[..3].to_vec(), pcm[3..].to_vec()]);
+        let mut fifo = FifoReader::new(Chunks(chunks), 48_000);
+
+        let mut seen = None;
+        assert!(fifo.with_frame(|frame, l, r| seen = Some((frame.sample_rate, l.to_vec(), r.to_vec()))).unwrap());
+        assert_eq!(seen, Some((48_000, vec![1000, 300], vec![-2, 4])));
+
+        // nothing new until the writer sends more
+        assert!(!fifo.with_frame(|_, _, _| panic!("no data")).unwrap());
+    }
+
+    #[test]
+    fn only_the_newest_window_is_kept() {
+        let frames = FIFO_WINDOW + 10;
+        let pcm: Vec<u8> = (0..frames as i16).flat_map(|i| [i, -i]).flat_map(|s| s.to_le_bytes()).collect();
+        let mut fifo = FifoReader::new(Chunks(VecDeque::from([pcm])), FIFO_SAMPLE_RATE);
+
+        fifo.with_frame(|_, l, r| {
+            assert_eq!(l.len(), FIFO_WINDOW);
+            assert_eq!((l[0], *l.last().unwrap()), (10, frames as i16 - 1));
+            assert_eq!(r[0], -10);
+        }).unwrap();
+    }
+}
@@ -100,6 +100,25 @@ impl Default for VizState {
         }
     }
 }
+/// Peak hold timing: how long a cap or hold marker stays put, then how fast it falls
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PeakHold {
+    pub hold: Duration,
+    /// levels (LEDs or histogram steps) per second
+    pub decay_rate: f32,
+}
+
+impl PeakHold {
+    /// Histogram caps, also the peak meter default
+    pub const HISTOGRAM: Self = Self { hold: Duration::from_millis(500), decay_rate: 8.0 };
+}
+
+impl Default for PeakHold {
+    fn default() -> Self {
+        Self::HISTOGRAM
+    }
+}
+
 /// simple state carried across calls (last metrics + peak-hold)
 #[derive(Debug, PartialEq, Clone)]
 pub struct LastVizState {
@@ -142,6 +161,9 @@ pub struct LastVizState {
     pub cap_last_update_l: Vec<Instant>,
     pub cap_last_update_r: Vec<Instant>,
 
+    pub peak_hold: PeakHold,             // peak meter hold marker timing
+    pub frame_at: Instant,               // when this frame is drawn, from the component time source
+
     pub vu_m: VuMeter,
     pub vu_l: VuMeter,
     pub vu_r: VuMeter,
@@ -153,6 +175,7 @@ pub struct LastVizState {
     pub spectrum_max_cols: usize, // Maximum history columns (display width)
     pub last_waveform_l: Vec<i16>, // Last waveform data (left channel)
     pub last_waveform_r: Vec<i16>, // Last waveform data (right channel)
+    pub last_waveform_m: Vec<i16>, // Last waveform data (downmix, oscilloscope)
 
 }
 
@@ -192,6 +215,8 @@ impl Default for LastVizState {
             cap_last_update_m: Vec::new(),
             cap_last_update_l: Vec::new(),
             cap_last_update_r: Vec::new(),
+            peak_hold: PeakHold::default(),
+            frame_at: Instant::now(),
 
             vu_m: VuMeter::new()
                 .with_sweep(-23.0, 4.8, -44.01, 44.01)
@@ -228,6 +253,7 @@ impl Default for LastVizState {
             spectrum_max_cols: 128, // Default to narrow display width
             last_waveform_l: Vec::new(),
             last_waveform_r: Vec::new(),
+            last_waveform_m: Vec::new(),
 
         }
 
@@ -366,6 +392,32 @@ pub struct VisFrame {
     pub samples: Vec<i16>,
 }
 
+/// A feed of stereo PCM for the visualizer worker: squeezelite's shared
+/// memory or a FIFO, both driving the same downstream processing.
+pub trait AudioSource: Send {
+    /// Run `f` on the newest frame split into left and right; Ok(false) when nothing new arrived
+    fn with_frame<F>(&mut self, f: F) -> io::Result<bool>
+    where
+        F: FnOnce(&VisFrame, &[i16], &[i16]);
+
+    /// Called after an empty poll; true once the writer has stopped producing
+    fn poll_stale(&mut self) -> bool;
+}
+
+impl AudioSource for VisReader {
+    fn with_frame<F>(&mut self, f: F) -> io::Result<bool>
+    where
+        F: FnOnce(&VisFrame, &[i16], &[i16]),
+    {
+        self.with_data_extended(f)
+    }
+
+    fn poll_stale(&mut self) -> bool {
+        let _ = self.reopen_if_stale();
+        self.is_stale()
+    }
+}
+
 pub struct VisReader {
     _mmap: MmapMut,    // keep mapping alive
     shm_path: PathBuf,
@@ -160,7 +160,7 @@ pub fn parse_event(json: &str, kind: Visualization) -> Option<VizPayload> {
             Some(VizPayload::HistStereo { bands_l, bands_r })
         }
 
-        Visualization::HistMono if is_sa => {
+        Visualization::HistMono | Visualization::HistMirror if is_sa => {
             let bl = fft_bins_to_levels(valid_fft(ch_l));
             let br = fft_bins_to_levels(valid_fft(ch_r));
             let bands = bl.iter().zip(br.iter()).map(|(a, b)| (*a).max(*b)).collect();
@@ -176,7 +176,7 @@ pub fn parse_event(json: &str, kind: Visualization) -> Option<VizPayload> {
             Some(VizPayload::HistAio { bands, bands_l, bands_r })
         }
 
-        // WaveformSpectrum requires raw PCM — not available via SSE.
+        // WaveformSpectrum and Oscilloscope require raw PCM — not available via SSE.
         // All other type/event mismatches are silently skipped.
         _ => None,
     }
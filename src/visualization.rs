@@ -34,6 +34,7 @@ use embedded_graphics::{
 use std::error::Error;
 use std::fmt;
 use std::fs;
+use std::sync::LazyLock;
 
 use crate::svgimage::SvgImageRenderer;
 
@@ -57,8 +58,12 @@ pub trait SvgColorDepth: PixelColor + From<<Self as PixelColor>::Raw> {
     where
         D: DrawTarget<Color = Self>;
 
+    /// Pack a photo-like pixmap into this depth's buffer format, dithered
+    /// where the depth needs it (slideshow images).
+    fn pack_pixmap(pixmap: &tiny_skia::Pixmap) -> Vec<u8>;
+
     /// Asset sub-folder for weather SVG icons (path differs by color depth).
-    fn weather_asset_folder() -> &'static str;
+    fn weather_asset_folder() -> String;
 
     /// Maximum brightness / "on" color for this depth.
     fn on() -> Self;
@@ -88,7 +93,10 @@ impl SvgColorDepth for BinaryColor {
         let raw = ImageRaw::<BinaryColor>::new(buffer, width);
         Image::new(&raw, position).draw(display)
     }
-    fn weather_asset_folder() -> &'static str { "./assets/mono" }
+    fn pack_pixmap(pixmap: &tiny_skia::Pixmap) -> Vec<u8> {
+        crate::svgimage::pixmap_to_mono(pixmap)
+    }
+    fn weather_asset_folder() -> String { crate::assets::path("mono") }
     fn on() -> Self { BinaryColor::On }
     fn use_svg_glyphs() -> bool { false }
 }
@@ -108,7 +116,10 @@ impl SvgColorDepth for Gray4 {
         let raw = ImageRaw::<Gray4>::new(buffer, width);
         Image::new(&raw, position).draw(display)
     }
-    fn weather_asset_folder() -> &'static str { "./assets/color" }
+    fn pack_pixmap(pixmap: &tiny_skia::Pixmap) -> Vec<u8> {
+        crate::svgimage::pixmap_to_gray4(pixmap)
+    }
+    fn weather_asset_folder() -> String { crate::assets::path("color") }
     fn on() -> Self { Gray4::WHITE }
 }
 
@@ -127,7 +138,10 @@ impl SvgColorDepth for Rgb565 {
         let raw = ImageRaw::<Rgb565>::new(buffer, width);
         Image::new(&raw, position).draw(display)
     }
-    fn weather_asset_folder() -> &'static str { "./assets/color" }
+    fn pack_pixmap(pixmap: &tiny_skia::Pixmap) -> Vec<u8> {
+        crate::svgimage::pixmap_to_rgb565(pixmap)
+    }
+    fn weather_asset_folder() -> String { crate::assets::path("color") }
     fn on() -> Self { Rgb565::WHITE }
 }
 
@@ -140,13 +154,137 @@ pub enum Visualization {
     PeakMono,                 // mono peak meter with hold/decay
     HistStereo,               // two histogram bars (L/R)
     HistMono,                 // mono histogram (downmix)
+    HistMirror,               // mono histogram mirrored out from the centre
     VuStereoWithCenterPeak,   // L/R VU with a central mono peak meter
     VuAio,                    // All In One with downmix VU
     HistAio,                  // All In One with downmix histogram
     WaveformSpectrum,         // Waveform + Spectrogram (oscilloscope + waterfall)
+    Oscilloscope,             // downmix waveform trace across the panel
     NoVisualization,          // no visualization
 }
 
+/// Where the combination mode's mono peak column sits
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CombiPeak {
+    /// Between the VU faces, as drawn in the asset
+    #[default]
+    Centre,
+    /// At the left edge, the faces side by side to its right
+    Left,
+    /// At the right edge
+    Right,
+}
+
+/// Arrangement of the combination mode's L/R VU faces and mono peak column
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct CombiLayout {
+    pub peak: CombiPeak,
+    /// Peak column width as a fraction of the panel - None keeps the asset's,
+    /// smaller widens the VU faces
+    pub peak_width: Option<f32>,
+}
+
+/// Panel rectangles of a combination layout, within the visualizer area
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CombiPanels {
+    pub vu_left: Rectangle,
+    pub peak: Rectangle,
+    pub vu_right: Rectangle,
+}
+
+impl CombiLayout {
+    /// Accepted `peak_width` fractions
+    pub const PEAK_WIDTH_RANGE: std::ops::RangeInclusive<f32> = 0.04..=0.5;
+    /// Narrowest peak column, whatever the fraction
+    pub const MIN_PEAK_PX: u32 = 4;
+
+    /// The asset drawn as is
+    pub fn is_native(&self) -> bool {
+        self.peak == CombiPeak::Centre && self.peak_width.is_none()
+    }
+
+    /// Split `area` into the three panels. `native_width` is the asset's own
+    /// peak column fraction. The panels tile the area exactly, so none
+    /// overlaps or runs off the panel whatever the fraction asked for.
+    pub fn panels(&self, area: Rectangle, native_width: f32) -> CombiPanels {
+        let (min, max) = (*Self::PEAK_WIDTH_RANGE.start(), *Self::PEAK_WIDTH_RANGE.end());
+        let share = self.peak_width.unwrap_or(native_width).clamp(min, max);
+        let width = area.size.width;
+        let peak = ((width as f32 * share).round() as u32).clamp(Self::MIN_PEAK_PX.min(width), width);
+        let left = (width - peak) / 2;
+        let right = width - peak - left;
+
+        let (left_x, peak_x, right_x) = match self.peak {
+            CombiPeak::Centre => (0, left, left + peak),
+            CombiPeak::Left => (peak, 0, peak + left),
+            CombiPeak::Right => (0, left + right, left),
+        };
+        let panel = |x: u32, w: u32| Rectangle::new(
+            area.top_left + Point::new(x as i32, 0),
+            Size::new(w, area.size.height),
+        );
+        CombiPanels {
+            vu_left: panel(left_x, left),
+            peak: panel(peak_x, peak),
+            vu_right: panel(right_x, right),
+        }
+    }
+}
+
+static PEAK_X: LazyLock<regex::Regex> = LazyLock::new(|| regex::Regex::new(r#"\sx="([^"]*)""#).unwrap());
+static PEAK_WIDTH: LazyLock<regex::Regex> = LazyLock::new(|| regex::Regex::new(r#"\swidth="([^"]*)""#).unwrap());
+static VIEWPORT_ATTRS: LazyLock<regex::Regex> = LazyLock::new(|| {
+    regex::Regex::new(r#"\s(width|height|viewBox|preserveAspectRatio)="[^"]*""#).unwrap()
+});
+
+/// Horizontal extent (x, width) of the `PEAK_COLUMN` rect in a combination asset
+fn peak_column(svg: &str) -> Option<(f32, f32)> {
+    let id = svg.find(r#"id="PEAK_COLUMN""#)?;
+    let start = svg[..id].rfind('<')?;
+    let tag = &svg[start..start + svg[start..].find('>')?];
+    let attr = |re: &regex::Regex| -> Option<f32> { re.captures(tag)?.get(1)?.as_str().parse().ok() };
+    Some((attr(&PEAK_X)?, attr(&PEAK_WIDTH)?))
+}
+
+/// Where a combination asset is cut into its three panels, in SVG user units
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct CombiCuts {
+    left: f32,
+    right: f32,
+    svg_width: f32,
+    svg_height: f32,
+}
+
+impl CombiCuts {
+    /// Cut points for `svg`, at the `PEAK_COLUMN` edges or a centred column
+    /// without one; `None` when the SVG doesn't parse
+    fn of(svg: &str) -> Option<Self> {
+        let (svg_width, svg_height) = SvgImageRenderer::new(svg, 1, 1).ok()?.svg_size();
+        // a unit either side keeps the column outline and its neighbours apart
+        let (col_x, col_w) = peak_column(svg).unwrap_or((svg_width * 0.46, svg_width * 0.08));
+        Some(Self {
+            left: (col_x - 1.0).max(0.0),
+            right: (col_x + col_w + 1.0).min(svg_width),
+            svg_width,
+            svg_height,
+        })
+    }
+}
+
+/// `svg` with its root viewport narrowed to user units `x..x + width`, stretched
+/// to whatever size it is rendered at
+fn crop_svg(svg: &str, x: f32, width: f32, height: f32) -> String {
+    let Some(start) = svg.find("<svg") else { return svg.to_string() };
+    let Some(len) = svg[start..].find('>') else { return svg.to_string() };
+    let root = &svg[start..start + len];
+    let mut cropped = VIEWPORT_ATTRS.replace_all(root, "").into_owned();
+    cropped.push_str(&format!(
+        r#" width="{width}" height="{height}" viewBox="{x} 0 {width} {height}" preserveAspectRatio="none""#
+    ));
+    format!("{}{}{}", &svg[..start], cropped, &svg[start + len..])
+}
+
 /// Custom error type for SVG visualization rendering operations.
 #[derive(Debug)]
 pub enum VizError {
@@ -190,6 +328,9 @@ pub struct Visual {
     pub peak_r: Vec<bool>,
     pub hold_r: Vec<bool>,
     re: String,
+    combi: CombiLayout,
+    /// Panel cut points, worked out on the first combination frame
+    combi_cuts: Option<CombiCuts>,
 }
 
 #[allow(dead_code)]
@@ -240,14 +381,26 @@ impl Visual {
             peak_r: init_vec.clone(),
             hold_r: init_vec.clone(),
             re,
+            combi: CombiLayout::default(),
+            combi_cuts: None,
         }
     }
 
+    /// Arrange the combination panel's meters, ignored by other kinds
+    pub fn set_combi_layout(&mut self, combi: CombiLayout) {
+        self.combi = combi;
+    }
+
     /// Override the render rectangle (used by AIO modes to apply layout-derived bounds).
     pub fn set_rect(&mut self, rect: Rectangle) {
         self.rect = rect;
     }
 
+    /// Where the panel SVG is drawn.
+    pub fn rect(&self) -> Rectangle {
+        self.rect
+    }
+
     pub fn apply_template(
         &mut self,
         template: &str, 
@@ -382,6 +535,9 @@ impl Visual {
                 // log and treat as a no-op draw rather than crashing.
                 eprintln!("SVG update error: {}", e);
             }).ok();
+            if self.kind == Visualization::VuStereoWithCenterPeak && !self.combi.is_native() {
+                return self.draw_combi_panels(display);
+            }
             let data = self.modified_svg_data.clone();
             if let Ok(svg_renderer) = crate::svgimage::SvgImageRenderer::new(&data, width, height) {
                 let buffer_size = D::Color::required_buffer_size(width, height);
@@ -394,6 +550,42 @@ impl Visual {
         Ok(())
     }
 
+    /// Draw the combination asset's VU faces and peak column each into its
+    /// `CombiLayout` panel - the asset is cut at the `PEAK_COLUMN` edges.
+    fn draw_combi_panels<D>(&mut self, display: &mut D) -> Result<(), D::Error>
+    where
+        D: DrawTarget,
+        D::Color: SvgColorDepth,
+    {
+        let data = self.modified_svg_data.clone();
+        // the meter values change each frame, the asset's geometry never does
+        if self.combi_cuts.is_none() {
+            self.combi_cuts = CombiCuts::of(&data);
+        }
+        let Some(CombiCuts { left: cut_l, right: cut_r, svg_width: svg_w, svg_height: svg_h }) = self.combi_cuts else {
+            return Ok(());
+        };
+
+        let panels = self.combi.panels(Rectangle::new(self.rect.top_left, self.rect.size), (cut_r - cut_l) / svg_w);
+        for (x, w, panel) in [
+            (0.0, cut_l, panels.vu_left),
+            (cut_l, cut_r - cut_l, panels.peak),
+            (cut_r, svg_w - cut_r, panels.vu_right),
+        ] {
+            if panel.size.width == 0 || w <= 0.0 {
+                continue;
+            }
+            let Ok(part) = SvgImageRenderer::new(&crop_svg(&data, x, w, svg_h), panel.size.width, panel.size.height) else {
+                continue;
+            };
+            self.buffer.resize(D::Color::required_buffer_size(panel.size.width, panel.size.height), 0);
+            if D::Color::render_to_buffer(&part, &mut self.buffer).is_ok() {
+                D::Color::draw_buffer_to_display(&self.buffer, panel.size.width, panel.top_left, display)?;
+            }
+        }
+        Ok(())
+    }
+
     pub fn get_svg_filename(&self) -> &str {
         &self.svg_name.as_str()
     }
@@ -412,11 +604,13 @@ pub fn transpose_kind(kind: &str) -> Visualization {
         "peak_mono" => Visualization::PeakMono,
         "hist_stereo" => Visualization::HistStereo,
         "hist_mono" => Visualization::HistMono,
+        "hist_mirror" => Visualization::HistMirror,
         "vu_stereo_with_center_peak" | "combination" | "vu_combi" 
             => Visualization::VuStereoWithCenterPeak,
         "vu_aio" => Visualization::VuAio,
         "hist_aio" => Visualization::HistAio,
         "waveform_spectrum" => Visualization::WaveformSpectrum,
+        "oscilloscope" => Visualization::Oscilloscope,
         "no_viz" => Visualization::NoVisualization,
         &_ => Visualization::NoVisualization,
     }
@@ -438,7 +632,9 @@ pub fn get_visualizer_panel_with_layout(kind: Visualization, layout: &LayoutConf
         Visualization::HistAio => format!("{}histaio.svg", folder),
         Visualization::HistStereo |
         Visualization::HistMono |
+        Visualization::HistMirror |
         Visualization::WaveformSpectrum |
+        Visualization::Oscilloscope |
         Visualization::NoVisualization => "".to_string(),
     };
     println!(">>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>>  visualization : {panel}  <<<<<<<<<<<<<<<");
@@ -456,7 +652,9 @@ pub fn visualizer_svg_supported(kind: Visualization) -> bool {
         Visualization::HistAio => true,
         Visualization::HistStereo |
         Visualization::HistMono |
+        Visualization::HistMirror |
         Visualization::WaveformSpectrum |
+        Visualization::Oscilloscope |
         Visualization::NoVisualization => false,
     };
     supported
@@ -468,7 +666,7 @@ pub fn visualizer_svg_supported(kind: Visualization) -> bool {
 /// New code should use `get_visualizer_panel_with_layout` instead.
 /// THIS NEEDS TO COME FROM Visual configurator
 pub fn get_visualizer_panel(kind: Visualization, wide: bool) -> String {
-    let folder = if wide {"./assets/ssd1322/"}else{"./assets/ssd1309/"};
+    let folder = crate::assets::path(if wide {"ssd1322/"} else {"ssd1309/"});
     let panel = match kind {
         Visualization::VuStereo => format!("{folder}vu2up.svg"),
         Visualization::VuMono  => format!("{folder}vudownmix.svg"),
@@ -479,7 +677,9 @@ pub fn get_visualizer_panel(kind: Visualization, wide: bool) -> String {
         Visualization::HistAio => format!("{folder}histaio.svg"),
         Visualization::HistStereo |
         Visualization::HistMono |
+        Visualization::HistMirror |
         Visualization::WaveformSpectrum |
+        Visualization::Oscilloscope |
         Visualization::NoVisualization => "".to_string(),
     };
     panel.clone()
@@ -618,7 +818,7 @@ pub fn get_visual(kind: Visualization, wide: bool, layout: LayoutConfig) -> Visu
                 0,
             )
         },
-        Visualization::HistMono  => {
+        Visualization::HistMono | Visualization::HistMirror => {
             Visual::new(
                 kind,
                 String::from(format!("{folder}none.svg")),
@@ -632,7 +832,7 @@ pub fn get_visual(kind: Visualization, wide: bool, layout: LayoutConfig) -> Visu
                 0,
             )
         },
-        Visualization::WaveformSpectrum  => {
+        Visualization::WaveformSpectrum | Visualization::Oscilloscope => {
             Visual::new(
                 kind,
                 String::from(format!("{folder}none.svg")),
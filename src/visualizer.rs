@@ -35,11 +35,15 @@ use log::{info, error, warn};
 use chrono;
 
 use crate::spectrum::{
+    AgcSettings,
+    SpectrumAgc,
     SpectrumEngine,
     SPECTRUM_BANDS_COUNT,
+    clamp_band_count,
 };
 use crate::dbfs;
 use crate::vision::{
+    AudioSource,
     VisReader,
     peak_and_rms, dbfs,
     PEAK_METER_LEVELS_MAX,
@@ -54,6 +58,67 @@ use crate::visualization::{
     Visualization,
     transpose_kind
 };
+use crate::visfifo::FifoReader;
+
+/// Where the visualizer reads audio: `shm` or `fifo:/path`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum VizSource {
+    /// squeezelite's shared memory, falling back to visionon SSE when configured
+    #[default]
+    Shm,
+    /// Raw stereo s16le PCM from a named pipe
+    Fifo(std::path::PathBuf),
+}
+
+impl std::str::FromStr for VizSource {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim() {
+            "" | "shm" => Ok(VizSource::Shm),
+            other => match other.strip_prefix("fifo:") {
+                Some(path) if !path.is_empty() => Ok(VizSource::Fifo(path.into())),
+                _ => Err(format!("unknown visualizer source '{other}', expected shm or fifo:/path")),
+            },
+        }
+    }
+}
+
+/// How stereo collapses to mono for the single-channel meters and histograms
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DownmixMode {
+    /// Mean of the two channel levels - reads low when one side is quiet
+    Average,
+    /// Power mean, sqrt((L² + R²) / 2) - the -3 dB compensated sum, a mono
+    /// signal on both channels reads the same as either one
+    RmsSum,
+    /// The louder channel
+    Max,
+}
+
+impl DownmixMode {
+    /// Meters when none is configured
+    pub const METER_DEFAULT: Self = DownmixMode::RmsSum;
+    /// Histogram bands when none is configured - punchier than a mean
+    pub const BANDS_DEFAULT: Self = DownmixMode::Max;
+
+    /// Mono level of two channel levels (RMS amplitude or band level)
+    pub fn mix(self, l: f32, r: f32) -> f32 {
+        match self {
+            DownmixMode::Average => (l + r) * 0.5,
+            DownmixMode::RmsSum => ((l * l + r * r) * 0.5).sqrt(),
+            DownmixMode::Max => l.max(r),
+        }
+    }
+
+    /// Per-band mono levels
+    pub fn mix_bands(self, l: &[u8], r: &[u8]) -> Vec<u8> {
+        l.iter().zip(r)
+            .map(|(&a, &b)| self.mix(a as f32, b as f32).round().min(u8::MAX as f32) as u8)
+            .collect()
+    }
+}
 
 /// Configuration for the visionon SSE fallback acquisition path.
 #[derive(Debug, Clone)]
@@ -120,6 +185,9 @@ pub enum VizPayload {
         waveform_r: Vec<i16>,     // Downsampled waveform data (R channel)
         spectrum_column: Vec<u8>, // Current FFT frequency bands for this frame
     },
+    Oscilloscope {
+        waveform: Vec<i16>,       // Downsampled (L+R)/2 waveform
+    },
     NoVisualization {},
     /// Squeezelite has stopped writing to shmem — warn the user.
     ShmemStale,
@@ -130,6 +198,9 @@ pub enum VizPayload {
 pub enum VizCommand {
     Enable(bool),                 // enable/disable publishing
     SetKind(Visualization),       // switch viz mode
+    SetBandCount(usize),          // histogram/spectrum band count
+    SetAgc(Option<AgcSettings>),  // spectrum band AGC, None for raw levels
+    SetDownmix(Option<DownmixMode>),  // stereo to mono, None for each mode's default
     Shutdown,                     // stop worker
 }
 
@@ -152,14 +223,17 @@ fn is_local_ip(ip: &str) -> bool {
 impl Visualizer {
     /// Probe whether any audio data source is reachable for `player_ip`.
     ///
-    /// Three-step check (short-circuit):
+    /// A FIFO source only needs its pipe to exist; otherwise a three-step check (short-circuit):
     ///   1. SSE config provided → always valid (handles both local and remote players)
     ///   2. `player_ip` resolves to a local interface → check shared memory
     ///   3. Remote player, no SSE → no data source
     ///
     /// Use this before `spawn()` to avoid setting up a visualizer that will
     /// never receive data.
-    pub fn data_source_available(player_ip: &str, sse_config: Option<&SseConfig>) -> bool {
+    pub fn data_source_available(player_ip: &str, sse_config: Option<&SseConfig>, source: &VizSource) -> bool {
+        if let VizSource::Fifo(path) = source {
+            return path.exists();
+        }
         if sse_config.is_some() {
             return true;
         }
@@ -174,11 +248,13 @@ impl Visualizer {
     ///
     /// If `sse_config` is provided the worker will fall back to the visionon
     /// SSE data source when the local shared-memory reader is unavailable
-    /// (i.e. the player is running on a different device).
+    /// (i.e. the player is running on a different device). A FIFO `source`
+    /// replaces both.
     pub fn spawn(
         kind: &str,
         playing_rx: watch::Receiver<bool>,
         sse_config: Option<SseConfig>,
+        source: VizSource,
     ) -> std::io::Result<Self> {
         // small bounded queues (drop newest when full via try_send)
         let (cmd_tx, cmd_rx) = mpsc::channel::<VizCommand>(16);
@@ -191,7 +267,7 @@ impl Visualizer {
 
         // spawn async worker task
         let join = tokio::spawn(async move {
-            visualizer_worker(cmd_rx, out_tx, playing_rx, sse_config).await
+            visualizer_worker(cmd_rx, out_tx, playing_rx, sse_config, source).await
         });
 
         Ok(Self { cmd_tx, join: Some(join), rx: out_rx })
@@ -206,6 +282,24 @@ impl Visualizer {
         let _ = self.cmd_tx.try_send(VizCommand::SetKind(k));
     }
 
+    /// Request `n` spectrum bands, clamped to the supported range. Only the
+    /// shared-memory path honors this - visionon fixes its own band count.
+    pub fn set_band_count(&self, n: usize) {
+        let _ = self.cmd_tx.try_send(VizCommand::SetBandCount(clamp_band_count(n)));
+    }
+
+    /// Normalize spectrum bands against a running maximum, or `None` for raw
+    /// levels. Shared-memory and FIFO sources only, like the band count.
+    pub fn set_agc(&self, agc: Option<AgcSettings>) {
+        let _ = self.cmd_tx.try_send(VizCommand::SetAgc(agc));
+    }
+
+    /// Collapse stereo to mono this way in the mono meters and histograms,
+    /// `None` keeps the power mean for meters and the louder band for histograms.
+    pub fn set_downmix(&self, downmix: Option<DownmixMode>) {
+        let _ = self.cmd_tx.try_send(VizCommand::SetDownmix(downmix));
+    }
+
     /// Ask the worker to stop; the task will exit on its own.
     pub fn shutdown(mut self) {
         let _ = self.cmd_tx.try_send(VizCommand::Shutdown);
@@ -229,7 +323,19 @@ async fn visualizer_worker(
     out_tx: Sender<VizFrameOut>,
     playing_rx: watch::Receiver<bool>,
     sse_config: Option<SseConfig>,
+    source: VizSource,
 ) {
+    if let VizSource::Fifo(path) = source {
+        match FifoReader::open(&path) {
+            Ok(reader) => {
+                info!("visualizer: reading PCM from {}", path.display());
+                visualizer_source_loop(cmd_rx, out_tx, playing_rx, reader).await;
+            }
+            Err(e) => error!("visualizer: cannot open FIFO {}: {e}", path.display()),
+        }
+        return;
+    }
+
     // Try the shared-memory reader first.
     let reader_result = VisReader::new();
 
@@ -237,7 +343,7 @@ async fn visualizer_worker(
         Ok(reader) => {
             // Local shared memory available — use the existing SHM path.
             info!("visualizer: using shared-memory acquisition");
-            visualizer_shm_loop(cmd_rx, out_tx, playing_rx, reader).await;
+            visualizer_source_loop(cmd_rx, out_tx, playing_rx, reader).await;
         }
         Err(e) => {
             if let Some(cfg) = sse_config {
@@ -250,18 +356,22 @@ async fn visualizer_worker(
     }
 }
 
-/// Original shared-memory acquisition loop (unchanged logic).
-async fn visualizer_shm_loop(
+/// PCM acquisition loop, shared by the shared-memory and FIFO sources.
+async fn visualizer_source_loop<S: AudioSource>(
     mut cmd_rx: Receiver<VizCommand>,
     mut out_tx: Sender<VizFrameOut>,
     playing_rx: watch::Receiver<bool>,
-    mut reader: VisReader,
+    mut reader: S,
 ) {
     let mut eng: Option<SpectrumEngine> = None;
 
     // State
     let mut enabled = false;
     let mut kind = Visualization::VuStereo;
+    let mut band_count = SPECTRUM_BANDS_COUNT as usize;
+    let mut agc: Option<AgcSettings> = None;
+    let mut meter_downmix = DownmixMode::METER_DEFAULT;
+    let mut bands_downmix = DownmixMode::BANDS_DEFAULT;
     let mut shmem_stale_sent = false; // avoid re-spamming ShmemStale every poll cycle
 
     // Peak-hold (for peak meters & center peak). Units: 0..=PEAK_METER_LEVELS_MAX
@@ -277,6 +387,17 @@ async fn visualizer_shm_loop(
             match cmd {
                 VizCommand::Enable(on) => { enabled = on; }
                 VizCommand::SetKind(k) => { kind = k; }
+                VizCommand::SetBandCount(n) => { band_count = clamp_band_count(n); }
+                VizCommand::SetAgc(a) => {
+                    agc = a;
+                    if let Some(e) = &mut eng {
+                        e.set_agc(agc.map(|s| SpectrumAgc::new(s, POLL_ENABLED)));
+                    }
+                }
+                VizCommand::SetDownmix(d) => {
+                    meter_downmix = d.unwrap_or(DownmixMode::METER_DEFAULT);
+                    bands_downmix = d.unwrap_or(DownmixMode::BANDS_DEFAULT);
+                }
                 VizCommand::Shutdown   => { break 'outer; }
             }
         }
@@ -291,16 +412,19 @@ async fn visualizer_shm_loop(
         }
 
         // Get fresh audio, when available.
-        match reader.with_data_extended(|frame, left, right| {
+        match reader.with_frame(|frame, left, right| {
             if !frame.running {
                 return;
             }
 
             // Build / refresh spectrum engine lazily (for histogram modes)
-            let need_bands = SPECTRUM_BANDS_COUNT as usize;
             match &mut eng {
-                Some(e) => e.ensure(frame.sample_rate, left.len()),
-                None => eng = Some(SpectrumEngine::new(frame.sample_rate, left.len(), need_bands)),
+                Some(e) => e.ensure(frame.sample_rate, left.len(), band_count),
+                None => {
+                    let mut e = SpectrumEngine::new(frame.sample_rate, left.len(), band_count);
+                    e.set_agc(agc.map(|s| SpectrumAgc::new(s, POLL_ENABLED)));
+                    eng = Some(e);
+                }
             }
 
             // Compute metrics per chosen viz
@@ -320,8 +444,7 @@ async fn visualizer_shm_loop(
                 Visualization::VuMono => {
                     let (_pk_l, rms_l) = peak_and_rms(left);
                     let (_pk_r, rms_r) = peak_and_rms(right);
-                    // downmix RMS ≈ sqrt((L^2 + R^2)/2)
-                    let m_rms = (((rms_l*rms_l) + (rms_r*rms_r)) * 0.5).sqrt();
+                    let m_rms = meter_downmix.mix(rms_l, rms_r);
                     let m_db = dbfs::dbfs_to_vudb(dbfs(m_rms)); // includes VU meter adj.
                     publish(&mut out_tx, frame.timestamp, is_playing, frame.sample_rate, kind,
                         VizPayload::VuMono { 
@@ -334,8 +457,7 @@ async fn visualizer_shm_loop(
                     let (_pk_r, rms_r) = peak_and_rms(right);
                     let l_db = dbfs::dbfs_to_vudb(dbfs(rms_l));
                     let r_db = dbfs::dbfs_to_vudb(dbfs(rms_r));
-                    // downmix RMS ≈ sqrt((L^2 + R^2)/2)
-                    let m_rms = (((rms_l*rms_l) + (rms_r*rms_r)) * 0.5).sqrt();
+                    let m_rms = meter_downmix.mix(rms_l, rms_r);
                     let m_db = dbfs::dbfs_to_vudb(dbfs(m_rms)); // includes VU meter adj.
                     publish(&mut out_tx, frame.timestamp, is_playing, frame.sample_rate, kind,
                         VizPayload::VuAio { m_db, l_db, r_db }
@@ -358,8 +480,7 @@ async fn visualizer_shm_loop(
                 Visualization::PeakMono => {
                     let (_pk_l, rms_l) = peak_and_rms(left);
                     let (_pk_r, rms_r) = peak_and_rms(right);
-                    // downmix RMS ≈ sqrt((L^2 + R^2)/2)
-                    let m_rms = (((rms_l*rms_l) + (rms_r*rms_r)) * 0.5).sqrt();
+                    let m_rms = meter_downmix.mix(rms_l, rms_r);
                     let m_db = dbfs::dbfs_to_vudb(dbfs(m_rms)); // includes VU meter adj.
                     publish(&mut out_tx, frame.timestamp, is_playing, frame.sample_rate, kind,
                         VizPayload::PeakMono { 
@@ -379,13 +500,10 @@ async fn visualizer_shm_loop(
                         );
                     }
                 }
-                Visualization::HistMono => {
+                Visualization::HistMono | Visualization::HistMirror => {
                     if let Some(e) = &mut eng {
                         let (l, r) = e.compute_levels(left, right);
-                        // downmix = max(L,R) per band (punchier than mean)
-                        let bands = l.iter().zip(r.iter())
-                                     .map(|(a,b)| (*a).max(*b))
-                                     .collect::<Vec<u8>>();
+                        let bands = bands_downmix.mix_bands(&l, &r);
                         publish(&mut out_tx, frame.timestamp, is_playing, frame.sample_rate, kind,
                             VizPayload::HistMono { 
                                 bands 
@@ -396,10 +514,7 @@ async fn visualizer_shm_loop(
                 Visualization::HistAio => {
                     if let Some(e) = &mut eng {
                         let (bands_l, bands_r) = e.compute_levels(left, right);
-                        // downmix = max(L,R) per band (punchier than mean)
-                        let bands = bands_l.iter().zip(bands_r.iter())
-                                     .map(|(a,b)| (*a).max(*b))
-                                     .collect::<Vec<u8>>();
+                        let bands = bands_downmix.mix_bands(&bands_l, &bands_r);
                         publish(&mut out_tx, frame.timestamp, is_playing, frame.sample_rate, kind,
                             VizPayload::HistAio { bands, bands_l, bands_r }
                         );
@@ -410,8 +525,7 @@ async fn visualizer_shm_loop(
                     let (_pk_r_i16, rms_r) = peak_and_rms(right);
                     let l_db = dbfs::dbfs_to_vudb(dbfs(rms_l));
                     let r_db = dbfs::dbfs_to_vudb(dbfs(rms_r));
-                    // downmix RMS ≈ sqrt((L^2 + R^2)/2)
-                    let m_rms = (((rms_l*rms_l) + (rms_r*rms_r)) * 0.5).sqrt();
+                    let m_rms = meter_downmix.mix(rms_l, rms_r);
                     let m_db = dbfs::dbfs_to_vudb(dbfs(m_rms)); // includes VU meter adj.
                     publish(&mut out_tx, frame.timestamp, is_playing, frame.sample_rate, kind,
                         VizPayload::VuStereoWithCenterPeak {
@@ -433,14 +547,25 @@ async fn visualizer_shm_loop(
                     let spectrum_column = if let Some(e) = &mut eng {
                         let (l, r) = e.compute_levels(left, right);
                         // downmix spectrum for single column
-                        l.iter().zip(r.iter()).map(|(a,b)| (*a).max(*b)).collect::<Vec<u8>>()
+                        bands_downmix.mix_bands(&l, &r)
                     } else {
-                        vec![0; SPECTRUM_BANDS_COUNT as usize]
+                        vec![0; band_count]
                     };
 
                     publish(&mut out_tx, frame.timestamp, is_playing, frame.sample_rate, kind,
                         VizPayload::WaveformSpectrum { waveform_l, waveform_r, spectrum_column });
                 }
+                Visualization::Oscilloscope => {
+                    const TARGET_WIDTH: usize = 320;
+                    let step = (left.len() / TARGET_WIDTH).max(1);
+                    let waveform: Vec<i16> = left.iter().zip(right.iter())
+                        .step_by(step)
+                        .take(TARGET_WIDTH)
+                        .map(|(&l, &r)| ((l as i32 + r as i32) / 2) as i16)
+                        .collect();
+                    publish(&mut out_tx, frame.timestamp, is_playing, frame.sample_rate, kind,
+                        VizPayload::Oscilloscope { waveform });
+                }
                 Visualization::NoVisualization => {}
             }
         }) {
@@ -455,9 +580,8 @@ async fn visualizer_shm_loop(
             Ok(false) => {
                 // Nothing new; keep CPU low and try remap if stale
                 sleep(POLL_ENABLED).await;
-                let _ = reader.reopen_if_stale();
-                // Warn once when squeezelite stops writing audio data to shmem
-                if reader.is_stale() && !shmem_stale_sent {
+                // Warn once when squeezelite (or the FIFO writer) stops sending audio
+                if reader.poll_stale() && !shmem_stale_sent {
                     shmem_stale_sent = true;
                     publish(&mut out_tx, 0, false, 0, kind, VizPayload::ShmemStale);
                 }
@@ -469,7 +593,7 @@ async fn visualizer_shm_loop(
         }
     }
 
-    info!("visualizer worker stopped (shm)");
+    info!("visualizer worker stopped");
 }
 
 /// SSE-based acquisition loop: connects to the visionon daemon on the remote
@@ -499,6 +623,9 @@ async fn visualizer_sse_loop(
             match cmd {
                 VizCommand::Enable(on)  => { enabled = on; }
                 VizCommand::SetKind(k)  => { kind = k; }
+                VizCommand::SetBandCount(_) => {} // visionon publishes fixed bands
+                VizCommand::SetAgc(_) => {}       // and its own levels
+                VizCommand::SetDownmix(_) => {}   // and mono mix
                 VizCommand::Shutdown    => {
                     info!("visualizer SSE worker stopped");
                     return;
@@ -591,3 +718,82 @@ impl PeakHold {
         self.peak_index
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn source_parses_shm_and_fifo_paths() {
+        assert_eq!("shm".parse::<VizSource>(), Ok(VizSource::Shm));
+        assert_eq!("fifo:/tmp/lymons.pcm".parse::<VizSource>(), Ok(VizSource::Fifo("/tmp/lymons.pcm".into())));
+        assert!("fifo:".parse::<VizSource>().is_err());
+        assert!("alsa".parse::<VizSource>().is_err());
+    }
+
+    #[test]
+    fn downmix_modes_read_a_lopsided_tone_differently() {
+        // 1 kHz at half scale on the left, quarter scale on the right
+        let tone = |amp: f32| -> Vec<i16> {
+            (0..4410).map(|i| (amp * 32767.0 * (i as f32 * 2.0 * std::f32::consts::PI * 1000.0 / 44_100.0).sin()) as i16).collect()
+        };
+        let (_, rms_l) = peak_and_rms(&tone(0.5));
+        let (_, rms_r) = peak_and_rms(&tone(0.25));
+        let db = |mode: DownmixMode| dbfs(mode.mix(rms_l, rms_r));
+
+        // sine RMS sits 3 dB under peak: -9.0 dBFS left, -15.1 right
+        assert!((db(DownmixMode::Max) - -9.03).abs() < 0.05, "{}", db(DownmixMode::Max));
+        assert!((db(DownmixMode::RmsSum) - -11.07).abs() < 0.05, "{}", db(DownmixMode::RmsSum));
+        assert!((db(DownmixMode::Average) - -11.53).abs() < 0.05, "{}", db(DownmixMode::Average));
+
+        // the same signal on both sides reads as either channel in every mode
+        for mode in [DownmixMode::Average, DownmixMode::RmsSum, DownmixMode::Max] {
+            assert!((dbfs(mode.mix(rms_l, rms_l)) - dbfs(rms_l)).abs() < 1e-3, "{mode:?}");
+        }
+        assert_eq!(DownmixMode::Average.mix_bands(&[10, 255], &[20, 255]), vec![15, 255]);
+    }
+
+    #[tokio::test]
+    async fn fifo_pcm_comes_out_as_payloads() {
+        let path = std::env::temp_dir().join(format!("lymons-viz-{}.pcm", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let c_path = std::ffi::CString::new(path.to_str().unwrap()).unwrap();
+        assert_eq!(unsafe { libc::mkfifo(c_path.as_ptr(), 0o600) }, 0);
+
+        let (_playing_tx, playing_rx) = watch::channel(true);
+        let mut viz = Visualizer::spawn("vu_stereo", playing_rx, None, VizSource::Fifo(path.clone())).unwrap();
+        viz.enable(true);
+
+        // a loud left, quiet right 1 kHz tone, written until the test is done
+        let writer_path = path.clone();
+        let writer = std::thread::spawn(move || {
+            let mut pipe = std::fs::OpenOptions::new().write(true).open(&writer_path).unwrap();
+            let block: Vec<u8> = (0..1024)
+                .map(|i| (i as f32 * 2.0 * std::f32::consts::PI * 1000.0 / 44_100.0).sin())
+                .flat_map(|s| [(s * 20_000.0) as i16, (s * 200.0) as i16])
+                .flat_map(|s| s.to_le_bytes())
+                .collect();
+            while pipe.write_all(&block).is_ok() {
+                std::thread::sleep(std::time::Duration::from_millis(10));
+            }
+        });
+
+        let frame = tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            loop {
+                let frame = viz.rx.recv().await.unwrap();
+                if matches!(frame.payload, VizPayload::VuStereo { .. }) {
+                    return frame;
+                }
+            }
+        }).await.expect("no payload from the FIFO");
+
+        viz.shutdown();
+        let _ = std::fs::remove_file(&path);
+        drop(writer); // exits on the broken pipe once the reader is gone
+
+        assert_eq!(frame.sample_rate, crate::visfifo::FIFO_SAMPLE_RATE);
+        let VizPayload::VuStereo { l_db, r_db } = frame.payload else { unreachable!() };
+        assert!(l_db > r_db, "left {l_db} right {r_db}");
+    }
+}
@@ -71,6 +71,37 @@
 
 use std::time::Instant;
 
+/// dB at the negative stop of the standard VU scale.
+pub const VU_FLOOR_DB: f64 = -23.0;
+/// dB at the positive stop of the standard VU scale.
+pub const VU_CEIL_DB: f64 = 4.8;
+
+// =============================================================================
+//  Calibration
+// =============================================================================
+
+/// User calibration for sources that run hot or quiet.
+///
+/// `reference_offset_db` is added to every input level before it is mapped
+/// to the arc, so +6 dB makes a quiet source read 6 dB higher. `floor_db`
+/// and `ceil_db` replace the dB values at the two arc stops.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VuCalibration {
+    pub reference_offset_db: f64,
+    pub floor_db: f64,
+    pub ceil_db: f64,
+}
+
+impl Default for VuCalibration {
+    fn default() -> Self {
+        Self {
+            reference_offset_db: 0.0,
+            floor_db: VU_FLOOR_DB,
+            ceil_db: VU_CEIL_DB,
+        }
+    }
+}
+
 // =============================================================================
 //  Public result type returned from every update() call
 // =============================================================================
@@ -115,6 +146,9 @@ pub struct VuMeter {
     /// Angle in degrees at the positive stop (typically positive).
     sweep_max: f64,
 
+    /// Added to every input level before mapping [dB].
+    reference_offset_db: f64,
+
     /// Signal level above which the overload accumulator runs [dB].
     overload_threshold_db: f64,
     /// Duration the signal must be above threshold before `overload` fires [s].
@@ -156,10 +190,11 @@ impl VuMeter {
         let sweep_min = -44.01_f64;
         Self {
             // Scale
-            scale_min: -23.0,
-            scale_max: 4.8,
+            scale_min: VU_FLOOR_DB,
+            scale_max: VU_CEIL_DB,
             sweep_min,
             sweep_max: 44.01,
+            reference_offset_db: 0.0,
 
             // Overload: > 0 dB for > 5 ms
             overload_threshold_db: 0.0,
@@ -359,6 +394,17 @@ impl VuMeter {
         self.reset();
     }
 
+    /// Change the input reference offset [dB] at runtime.
+    pub fn set_reference_offset(&mut self, offset_db: f64) {
+        self.reference_offset_db = offset_db;
+    }
+
+    /// Apply a user calibration: reference offset plus floor/ceil scale overrides.
+    pub fn set_calibration(&mut self, cal: &VuCalibration) {
+        self.set_db_range(cal.floor_db, cal.ceil_db);
+        self.set_reference_offset(cal.reference_offset_db);
+    }
+
     /// Change the overload threshold and hold time at runtime.
     pub fn set_overload_threshold(&mut self, threshold_db: f64, hold_s: f64) {
         self.overload_threshold_db = threshold_db;
@@ -424,6 +470,11 @@ impl VuMeter {
         (self.scale_min, self.scale_max)
     }
 
+    /// The configured input reference offset [dB].
+    pub fn reference_offset(&self) -> f64 {
+        self.reference_offset_db
+    }
+
     /// The configured arc range as `(sweep_min, sweep_max)`.
     pub fn arc_degrees(&self) -> (f64, f64) {
         (self.sweep_min, self.sweep_max)
@@ -549,7 +600,7 @@ impl VuMeter {
     #[inline(always)]
     fn db_to_target_rad(&self, db: f64) -> f64 {
         let slope = (self.sweep_max - self.sweep_min) / (self.scale_max - self.scale_min);
-        let angle_deg = self.sweep_min + (db - self.scale_min) * slope;
+        let angle_deg = self.sweep_min + (db + self.reference_offset_db - self.scale_min) * slope;
         angle_deg
             .clamp(self.sweep_min, self.sweep_max)
             .to_radians()
@@ -576,7 +627,7 @@ impl VuMeter {
     /// Advance the overload accumulator; latch or clear the overload flag.
     #[inline(always)]
     fn update_overload(&mut self, db: f64, dt: f64) {
-        if db > self.overload_threshold_db {
+        if db + self.reference_offset_db > self.overload_threshold_db {
             self.overload_accumulated_s += dt;
             if self.overload_accumulated_s >= self.overload_hold_s {
                 self.overload = true;
@@ -611,6 +662,29 @@ mod tests {
         }
     }
 
+    #[test]
+    fn default_calibration_matches_standard_scale() {
+        let mut m = VuMeter::new();
+        m.set_calibration(&VuCalibration::default());
+        assert_eq!(m, VuMeter { last_tick: m.last_tick, ..VuMeter::new() });
+    }
+
+    #[test]
+    fn reference_offset_shifts_needle_by_scale_slope() {
+        let mut hot = VuMeter::new();
+        hot.set_reference_offset(6.0);
+        // degrees per dB on the default scale
+        let slope = (44.01 * 2.0) / (VU_CEIL_DB - VU_FLOOR_DB);
+        let shift = hot.steady_state_degrees(-12.0) - VuMeter::new().steady_state_degrees(-12.0);
+        assert!((shift - 6.0 * slope).abs() < 1e-9, "shift={shift}");
+        // and the physics settles there too - the movement rings, give it time
+        let mut plain = VuMeter::new();
+        simulate(&mut hot, -12.0, 30.0, 0.001);
+        simulate(&mut plain, -12.0, 30.0, 0.001);
+        let settled = hot.angle_degrees() - plain.angle_degrees();
+        assert!((settled - 6.0 * slope).abs() < 0.5, "settled shift={settled}");
+    }
+
     #[test]
     fn default_ranges() {
         let m = VuMeter::new();
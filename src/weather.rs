@@ -27,8 +27,11 @@ use serde::{Deserialize, Serialize};
 use serde_json::{Value, Error as JsonError};
 use reqwest::{Client, header};
 use std::fmt::{self, Display};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
 use std::time::{Duration, Instant};
-use log::{info, error};
+use log::{info, warn, error};
 use tokio::sync::{mpsc, watch, Mutex as TokMutex};
 use tokio::task::JoinHandle;
 use std::sync::Arc;
@@ -39,7 +42,7 @@ use std::io::Read;
 use std::thread;
 
 use crate::geoloc::{fetch_location};
-use crate::translate::Translation;
+use crate::translate::{Locale, Translation};
 
 //use embedded_graphics::prelude::*;
 use crate::sun;
@@ -89,6 +92,7 @@ pub enum WeatherApiError {
     ApiError(String), // For specific API error messages
     MissingData(String),
     TranslationError(String),
+    CacheError(String),
 }
 
 #[allow(dead_code)]
@@ -98,13 +102,14 @@ impl Display for WeatherApiError {
             WeatherApiError::HttpRequestError(e) => write!(f, "HTTP request error: {}", e),
             WeatherApiError::SerializationError(e) => write!(f, "JSON serialization error: {}", e),
             WeatherApiError::DeserializationError(e) => write!(f, "JSON deserialization error: {}", e),
-            WeatherApiError::ApiError(msg) => write!(f, "Tomorrow.io API error: {}", msg),
-            WeatherApiError::ApiKeyError(msg) => write!(f, "Tomorrow.io API key required: {}", msg),
+            WeatherApiError::ApiError(msg) => write!(f, "Weather API error: {}", msg),
+            WeatherApiError::ApiKeyError(msg) => write!(f, "Weather API key required: {}", msg),
             WeatherApiError::GeolocationError(msg) => write!(f, "Geolocation error: {}", msg),
             WeatherApiError::MissingData(msg) => write!(f, "Missing weather data: {}", msg),
             WeatherApiError::TranslationError(msg) => write!(f, "Google Translate error: {}", msg),
             WeatherApiError::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
             WeatherApiError::PollingError(msg) => write!(f, "Polling error: {}", msg),
+            WeatherApiError::CacheError(msg) => write!(f, "Weather cache error: {}", msg),
         }
     }
 }
@@ -124,7 +129,7 @@ impl From<JsonError> for WeatherApiError {
 }
 
 #[allow(dead_code)]
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WeatherCode {
 	pub description: String,
 	pub icon: u8, // old imgdata
@@ -138,7 +143,7 @@ pub struct Location {
     pub lon: f64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq)]
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct WeatherData {
     pub day: DateTime<Local>,
     pub humidity_avg: i64,
@@ -161,7 +166,7 @@ pub struct WeatherData {
 }
 
 // Main weather data struct
-#[derive(Debug, Clone, PartialEq)] // Added PartialEq
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)] // Added PartialEq
 pub struct WeatherConditions {
     location_name: String,
     pub base_folder: String,
@@ -173,20 +178,276 @@ pub struct WeatherConditions {
     pub current: WeatherData,
     pub forecast: Vec<WeatherData>,
     pub last_updated: DateTime<Local>,
+    /// Most severe active alert, if any
+    #[serde(default)]
+    pub alert: Option<WeatherAlert>,
+}
+
+/// Provider poll interval when none is configured.
+pub const DEFAULT_REFRESH: Duration = Duration::from_secs(35 * 60);
+
+/// Cached weather older than this is shown with a stale marker.
+pub const WEATHER_CACHE_TTL: Duration = Duration::from_secs(3 * 60 * 60);
+
+/// Last good weather snapshot - kept in the user cache dir, not /dev/shm, so it survives a reboot.
+pub fn weather_cache_path() -> PathBuf {
+    dirs_next::cache_dir()
+        .unwrap_or_else(|| PathBuf::from("/tmp"))
+        .join("lymons/weather.json")
+}
+
+/// Alert severity, least to most severe so alerts can be ranked with `max`.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlertSeverity {
+    #[default]
+    Unknown,
+    Minor,
+    Moderate,
+    Severe,
+    Extreme,
+}
+
+impl AlertSeverity {
+    /// Parse a CAP style severity ("minor" ... "extreme"); anything else is Unknown.
+    pub fn parse(name: &str) -> Self {
+        match name.trim().to_lowercase().as_str() {
+            "minor" => AlertSeverity::Minor,
+            "moderate" => AlertSeverity::Moderate,
+            "severe" => AlertSeverity::Severe,
+            "extreme" => AlertSeverity::Extreme,
+            _ => AlertSeverity::Unknown,
+        }
+    }
+
+    /// Banner prefix, empty when the provider gave no severity
+    pub fn label(self) -> &'static str {
+        match self {
+            AlertSeverity::Unknown => "",
+            AlertSeverity::Minor => "MINOR",
+            AlertSeverity::Moderate => "MODERATE",
+            AlertSeverity::Severe => "SEVERE",
+            AlertSeverity::Extreme => "EXTREME",
+        }
+    }
+}
+
+/// An active severe weather alert/warning.
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WeatherAlert {
+    pub title: String,
+    pub severity: AlertSeverity,
+}
+
+impl WeatherAlert {
+    /// Banner text - "SEVERE: Flood Warning", or just the title without a severity
+    pub fn banner_text(&self) -> String {
+        match self.severity.label() {
+            "" => self.title.clone(),
+            label => format!("{}: {}", label, self.title),
+        }
+    }
+}
+
+/// Most severe of `alerts`; the first listed wins a tie.
+pub fn most_severe(alerts: impl IntoIterator<Item = WeatherAlert>) -> Option<WeatherAlert> {
+    alerts.into_iter().fold(None, |best: Option<WeatherAlert>, alert| match best {
+        Some(b) if b.severity >= alert.severity => Some(b),
+        _ => Some(alert),
+    })
+}
+
+/// Current conditions plus daily forecast, as returned by a provider fetch.
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct WeatherReport {
+    pub current: WeatherData,
+    pub forecast: Vec<WeatherData>,
+    pub alert: Option<WeatherAlert>,
+}
+
+/// Boxed fetch future - keeps `WeatherProvider` usable as a trait object.
+pub type ProviderFuture<'a> = Pin<Box<dyn Future<Output = Result<WeatherReport, WeatherApiError>> + Send + 'a>>;
+
+/// A weather data source.
+///
+/// Implementors map their API payload onto `WeatherData`, using the shared
+/// Tomorrow.io code table (`parse_weather_code`) so every provider resolves
+/// to the same `weather_code` svg assets.
+pub trait WeatherProvider: fmt::Debug + Send + Sync {
+    /// Short provider name, used in logging.
+    fn name(&self) -> &'static str;
+
+    /// Fetch current conditions and the daily forecast (tomorrow onwards).
+    fn fetch(&self) -> ProviderFuture<'_>;
+}
+
+/// Weather providers selectable via config (`provider:` / `-W provider=`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProviderKind {
+    #[default]
+    TomorrowIo,
+    OpenWeatherMap,
+}
+
+impl ProviderKind {
+    /// Parse a provider name; `None` for unknown names.
+    pub fn parse(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "" | "tomorrow" | "tomorrowio" | "tomorrow.io" | "climacell" => Some(ProviderKind::TomorrowIo),
+            "owm" | "openweathermap" | "openweather" => Some(ProviderKind::OpenWeatherMap),
+            _ => None,
+        }
+    }
+
+    /// Shortest poll interval that stays inside the free-tier request quota,
+    /// allowing for the retries a flaky connection costs. A tomorrow.io
+    /// refresh is two calls, the forecast then the events endpoint.
+    pub fn min_refresh(self) -> Duration {
+        match self {
+            ProviderKind::TomorrowIo => Duration::from_secs(20 * 60),   // 2 calls each, 25/hour, 500/day
+            ProviderKind::OpenWeatherMap => Duration::from_secs(5 * 60), // 1000/day
+        }
+    }
+}
+
+/// Display unit labels for a units system: (temperature, wind speed, pressure).
+pub fn unit_labels(units: &str) -> (&'static str, &'static str, &'static str) {
+    if units == "imperial" {
+        ("F", "mph", "mb")
+    } else {
+        ("C", "km/h", "hPa")
+    }
+}
+
+/// Temperature scale shown on the weather pages; wind speed follows it
+/// (km/h with Celsius, mph with Fahrenheit).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TempUnits {
+    Celsius,
+    Fahrenheit,
+}
+
+impl TempUnits {
+    /// Parse a temperature label as produced by `unit_labels`.
+    pub fn parse(label: &str) -> Option<Self> {
+        match label.trim().to_uppercase().as_str() {
+            "C" | "CELSIUS" => Some(TempUnits::Celsius),
+            "F" | "FAHRENHEIT" => Some(TempUnits::Fahrenheit),
+            _ => None,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TempUnits::Celsius => "C",
+            TempUnits::Fahrenheit => "F",
+        }
+    }
+
+    pub fn wind_label(self) -> &'static str {
+        match self {
+            TempUnits::Celsius => "km/h",
+            TempUnits::Fahrenheit => "mph",
+        }
+    }
+
+    pub fn toggled(self) -> Self {
+        match self {
+            TempUnits::Celsius => TempUnits::Fahrenheit,
+            TempUnits::Fahrenheit => TempUnits::Celsius,
+        }
+    }
+}
+
+const KMH_PER_MPH: f64 = 1.609344;
+
+impl WeatherData {
+    /// Copy with temperatures and wind speed converted to `units`.
+    /// Data already in `units`, or with unrecognised labels, is returned as is.
+    pub fn in_units(&self, units: TempUnits) -> WeatherData {
+        let mut out = self.clone();
+        match TempUnits::parse(&self.temperature_units) {
+            Some(from) if from != units => {
+                let convert = |t: f64| match units {
+                    TempUnits::Fahrenheit => t * 9.0 / 5.0 + 32.0,
+                    TempUnits::Celsius => (t - 32.0) * 5.0 / 9.0,
+                };
+                out.temperature_avg = convert(self.temperature_avg);
+                out.temperature_apparent_avg = convert(self.temperature_apparent_avg);
+                out.temperature_max = convert(self.temperature_max);
+                out.temperature_min = convert(self.temperature_min);
+                out.temperature_units = units.label().to_string();
+            }
+            _ => {}
+        }
+        match (self.wind_speed_units.as_str(), units) {
+            ("km/h", TempUnits::Fahrenheit) => {
+                out.wind_speed_avg = self.wind_speed_avg / KMH_PER_MPH;
+                out.wind_speed_units = units.wind_label().to_string();
+            }
+            ("mph", TempUnits::Celsius) => {
+                out.wind_speed_avg = self.wind_speed_avg * KMH_PER_MPH;
+                out.wind_speed_units = units.wind_label().to_string();
+            }
+            _ => {}
+        }
+        out
+    }
+}
+
+/// 16-point compass label for a wind bearing in degrees.
+pub fn compass_point(deg: f64) -> String {
+    let mut d16 = ((deg / 22.5) + 0.5) as u8;
+    d16 %= 16;
+    let compass_points = [
+        "N",  "NNE", "NE", "ENE", "E",  "ESE",
+        "SE", "SSE", "S",  "SSW", "SW", "WSW",
+        "W",  "WNW", "NW", "NNW"];
+    compass_points[d16 as usize].to_string()
+}
+
+/// Settings shared by every provider implementation.
+#[derive(Debug, Clone)]
+pub struct ProviderSettings {
+    pub api_key: String,
+    pub lat: f64,
+    pub lng: f64,
+    pub units: String, // "metric" or "imperial"
+    pub translate: String,
+    pub icons: i32,
+    pub client: Client,
+}
+
+impl ProviderSettings {
+    pub fn temperature_units(&self) -> String {
+        unit_labels(&self.units).0.to_string()
+    }
+
+    pub fn wind_speed_units(&self) -> String {
+        unit_labels(&self.units).1.to_string()
+    }
+
+    pub fn pressure_units(&self) -> String {
+        unit_labels(&self.units).2.to_string()
+    }
+}
+
+/// Tomorrow.io (formerly climacell) v4 forecast API.
+#[derive(Debug)]
+pub struct TomorrowIo {
+    base_url: String,
+    settings: ProviderSettings,
 }
 
 // Main Weather client
 #[derive(Debug)]
 pub struct Weather {
     pub active: bool,
-    base_url: String,
-    api_key: String,
     lat: f64,
     lng: f64,
-    units: String, // "metric" or "imperial"
-    translate: String,
-    client: Client,
-    icons: i32,
+    provider: Box<dyn WeatherProvider>,
+    cache_path: PathBuf,
+    refresh: Duration,
     pub weather_data: WeatherConditions,
     weather_tx: Option<watch::Sender<WeatherConditions>>,
     stop_sender: Option<mpsc::Sender<()>>,
@@ -196,17 +457,19 @@ pub struct Weather {
 
 impl WeatherConditions {
     pub fn new(location_name: String, units: String, base_folder: String, lat: f64, lng: f64) -> Self {
+        let (temperature_units, wind_speed_units, pressure_units) = unit_labels(&units);
         Self {
             location_name,
             base_folder,
-            temperature_units: if units == "imperial" { "F" } else { "C" }.to_string(),
-            wind_speed_units: if units == "imperial" { "mph" } else { "km/h" }.to_string(),
-            pressure_units: if units == "imperial" { "mb" } else { "hPa" }.to_string(),
+            temperature_units: temperature_units.to_string(),
+            wind_speed_units: wind_speed_units.to_string(),
+            pressure_units: pressure_units.to_string(),
             lat,
             lng,
             current: WeatherData::default(),
             forecast: vec![WeatherData::default(); 8],
             last_updated: Local::now(),
+            alert: None,
         }
     }
     fn get_svg_path(&self, wc: WeatherCondition) -> String {
@@ -229,6 +492,30 @@ impl WeatherConditions {
         ret
     }
 
+    /// True when the data is older than `ttl`.
+    pub fn is_stale(&self, ttl: Duration) -> bool {
+        let age = Local::now().signed_duration_since(self.last_updated);
+        age.to_std().map(|a| a > ttl).unwrap_or(false)
+    }
+
+    /// Persist the conditions as JSON.
+    pub fn save_to(&self, path: &Path) -> Result<(), WeatherApiError> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)
+                .map_err(|e| WeatherApiError::CacheError(format!("{}: {}", dir.display(), e)))?;
+        }
+        let json = serde_json::to_string(self).map_err(WeatherApiError::SerializationError)?;
+        std::fs::write(path, json)
+            .map_err(|e| WeatherApiError::CacheError(format!("{}: {}", path.display(), e)))
+    }
+
+    /// Load conditions previously written by `save_to`.
+    pub fn load_from(path: &Path) -> Result<Self, WeatherApiError> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|e| WeatherApiError::CacheError(format!("{}: {}", path.display(), e)))?;
+        serde_json::from_str(&json).map_err(WeatherApiError::DeserializationError)
+    }
+
     pub fn get_weather_display(&self) -> WeatherDisplay {
         let temp_units = self.temperature_units.clone();
         let wind_speed_units = self.wind_speed_units.clone();
@@ -283,6 +570,13 @@ impl Weather {
         let units = cfg.normalised_units();
         let transl = cfg.translate.clone().unwrap_or_default();
         let conditions_units = units.clone();
+        let kind = cfg.provider_kind();
+        let refresh = cfg.refresh_interval();
+        if let Some(mins) = cfg.refresh_mins
+            && Duration::from_secs(mins * 60) < refresh
+        {
+            warn!("Weather refresh of {} min is below the provider minimum, using {} min", mins, refresh.as_secs() / 60);
+        }
 
         // icons: default 1 (mono), could be extended via config later
         let icons: i32 = 1;
@@ -311,22 +605,34 @@ impl Weather {
         };
 
         let base_folder = match icons {
-            1 => "./assets/mono/".to_string(),
-            2 => "./assets/basic/".to_string(),
-            3 => "./assets/color/".to_string(),
-            _ => "./assets/basic/".to_string(),
+            1 => crate::assets::path("mono/"),
+            2 => crate::assets::path("basic/"),
+            3 => crate::assets::path("color/"),
+            _ => crate::assets::path("basic/"),
         };
 
-        Ok(Weather {
-            active: false,
-            base_url: "https://api.tomorrow.io/v4/weather/forecast".to_string(),
+        let settings = ProviderSettings {
             api_key: api_key.to_string(),
             lat: final_lat,
             lng: final_lng,
             units,
             translate: transl,
-            client,
             icons,
+            client,
+        };
+        let provider: Box<dyn WeatherProvider> = match kind {
+            ProviderKind::TomorrowIo => Box::new(TomorrowIo::new(settings)),
+            ProviderKind::OpenWeatherMap => Box::new(crate::openweathermap::OpenWeatherMap::new(settings)),
+        };
+        info!("Weather provider: {}", provider.name());
+
+        Ok(Weather {
+            active: false,
+            lat: final_lat,
+            lng: final_lng,
+            provider,
+            cache_path: weather_cache_path(),
+            refresh,
             weather_data: WeatherConditions::new(
                 location_name, conditions_units, base_folder, final_lat, final_lng,
             ),
@@ -337,10 +643,224 @@ impl Weather {
         })
     }
 
+    /// Loads the last good snapshot from the disk cache so something can be shown
+    /// before the first fetch succeeds. Snapshots for other units or another
+    /// location are ignored. Returns true when cached data was loaded.
+    pub fn load_cached(&mut self) -> bool {
+        let cached = match WeatherConditions::load_from(&self.cache_path) {
+            Ok(c) => c,
+            Err(e) => {
+                info!("No cached weather: {}", e);
+                return false;
+            }
+        };
+        if cached.temperature_units != self.weather_data.temperature_units
+            || (cached.lat - self.lat).abs() > 0.01
+            || (cached.lng - self.lng).abs() > 0.01
+        {
+            info!("Cached weather is for other units or location, ignoring");
+            return false;
+        }
+        info!("Loaded cached weather from {}", cached.last_updated.format("%Y-%m-%d %H:%M"));
+        self.weather_data.current = cached.current;
+        self.weather_data.forecast = cached.forecast;
+        self.weather_data.last_updated = cached.last_updated;
+        // a cached alert may long have expired - wait for a fresh fetch to raise one
+        true
+    }
+
+    /// Fetches current weather and daily forecast from the configured provider.
+    pub async fn fetch_weather_data(&mut self) -> Result<(), WeatherApiError> {
+        info!("Fetching {} weather data for {}...", self.provider.name(), self.weather_data.location_name);
+
+        let report = match self.provider.fetch().await {
+            Ok(report) => report,
+            Err(e) => {
+                if matches!(e, WeatherApiError::HttpRequestError(_)) {
+                    self.active = false;
+                }
+                return Err(e);
+            }
+        };
+
+        self.weather_data.current = report.current;
+        for (slot, day) in self.weather_data.forecast.iter_mut().zip(report.forecast) {
+            *slot = day;
+        }
+        if let Some(alert) = &report.alert {
+            info!("Weather alert: {}", alert.banner_text());
+        }
+        self.weather_data.alert = report.alert;
+
+        self.weather_data.last_updated = Local::now();
+        info!("Weather data fetched successfully.");
+        self.last_fetch_time = Some(Instant::now()); // Record fetch time
+        self.active = true;
+
+        if let Err(e) = self.weather_data.save_to(&self.cache_path) {
+            warn!("Failed to cache weather data: {}", e);
+        }
+
+        // Send update via watch channel if available (no lock!)
+        if let Some(tx) = &self.weather_tx {
+            let _ = tx.send(self.weather_data.clone());
+        }
+
+        Ok(())
+
+    }
+
+    /// Starts a background polling task to fetch weather data periodically (legacy API).
+    ///
+    /// This is the legacy Arc<Mutex<Weather>> version for backwards compatibility.
+    /// New code should use start_polling_with_watch() instead for lock-free updates.
+    pub async fn start_polling(instance: Arc<TokMutex<Self>>) -> Result<(), WeatherApiError> {
+        let (tx, rx) = mpsc::channel(1);
+        {
+            let mut locked_instance = instance.lock().await;
+            if locked_instance.poll_handle.is_some() {
+                return Err(WeatherApiError::PollingError("Polling already running".to_string()));
+            }
+            locked_instance.stop_sender = Some(tx);
+        }
+
+        let instance_for_poll_task = Arc::clone(&instance);
+        let refresh = instance.lock().await.refresh;
+
+        let poll_handle = tokio::spawn(async move {
+            let mut rx = rx;
+            loop {
+                tokio::select! {
+                    _ = tokio::time::sleep(refresh) => {
+                        let mut locked_self = instance_for_poll_task.lock().await;
+                        match locked_self.fetch_weather_data().await {
+                            Ok(_) => info!("Weather polling successful."),
+                            Err(e) => error!("Weather polling failed: {}", e),
+                        }
+                    }
+                    _ = rx.recv() => {
+                        info!("Weather polling thread received stop signal. Exiting.");
+                        break;
+                    }
+                }
+            }
+        });
+
+        instance.lock().await.poll_handle = Some(poll_handle);
+        Ok(())
+    }
+
+    /// Starts a background polling task with lock-free updates via watch channel (new API).
+    ///
+    /// This takes ownership of the Weather instance and returns a watch::Receiver
+    /// for lock-free access to weather updates. Preferred for new code.
+    pub async fn start_polling_with_watch(mut self) -> Result<(JoinHandle<()>, watch::Receiver<WeatherConditions>), WeatherApiError> {
+        if self.poll_handle.is_some() {
+            return Err(WeatherApiError::PollingError("Polling already running".to_string()));
+        }
+
+        // Create watch channel for weather updates (lock-free!)
+        let (weather_tx, weather_rx) = watch::channel(self.weather_data.clone());
+        self.weather_tx = Some(weather_tx);
+
+        // Create stop channel
+        let (stop_tx, mut stop_rx) = mpsc::channel(1);
+        self.stop_sender = Some(stop_tx);
+
+        info!("Weather refresh every {} min", self.refresh.as_secs() / 60);
+        let poll_handle = tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = tokio::time::sleep(self.refresh) => {
+                        match self.fetch_weather_data().await {
+                            Ok(_) => info!("Weather polling successful."),
+                            Err(e) => error!("Weather polling failed: {}", e),
+                        }
+                    }
+                    _ = stop_rx.recv() => {
+                        info!("Weather polling thread received stop signal. Exiting.");
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok((poll_handle, weather_rx))
+    }
+
+
+    /// Stops the background polling task.
+    pub async fn stop_polling(&mut self) {
+        if let Some(sender) = self.stop_sender.take() {
+            if let Err(e) = sender.send(()).await {
+                error!("Failed to send stop signal to weather polling thread: {}", e);
+            }
+        }
+        if let Some(handle) = self.poll_handle.take() {
+            // It's generally not good practice to block in Drop, but for async shutdown
+            // from a sync context (like main's cleanup or explicit shutdown), awaiting is fine.
+            handle.await.unwrap_or_else(|e| error!("Weather polling thread failed to join: {}", e));
+        }
+        info!("Weather polling stopped.");
+    }
+}
+
+const TOMORROW_EVENTS_URL: &str = "https://api.tomorrow.io/v4/events";
+
+/// Tomorrow.io event insight categories treated as weather alerts.
+const TOMORROW_ALERT_INSIGHTS: [&str; 7] = [
+    "thunderstorms", "floods", "wind", "winter", "tornado", "tropical", "temperature",
+];
+
+/// Most severe event in a Tomorrow.io events payload.
+pub fn parse_tomorrow_events(payload: &Value) -> Option<WeatherAlert> {
+    let events = payload["data"]["events"].as_array()?;
+    most_severe(events.iter().filter_map(|event| {
+        let title = event["eventValues"]["title"].as_str()
+            .or_else(|| event["insight"].as_str())?;
+        Some(WeatherAlert {
+            title: title.trim().to_string(),
+            severity: AlertSeverity::parse(event["severity"].as_str().unwrap_or("")),
+        })
+    }))
+}
+
+impl TomorrowIo {
+    pub fn new(settings: ProviderSettings) -> Self {
+        Self {
+            base_url: "https://api.tomorrow.io/v4/weather/forecast".to_string(),
+            settings,
+        }
+    }
+
+    /// Active alerts from the events endpoint. Alerts are extra - a plan without
+    /// events access or a failed request just means no banner.
+    async fn fetch_alert(&self) -> Option<WeatherAlert> {
+        let mut params = vec![
+            ("location", format!("{},{}", self.settings.lat, self.settings.lng)),
+            ("buffer", "1".to_string()),
+            ("apikey", self.settings.api_key.clone()),
+        ];
+        params.extend(TOMORROW_ALERT_INSIGHTS.iter().map(|i| ("insights", i.to_string())));
+        let response = match self.settings.client.get(TOMORROW_EVENTS_URL).query(&params).send().await {
+            Ok(r) if r.status().is_success() => r,
+            Ok(r) => {
+                info!("Tomorrow.io events unavailable: {}", r.status());
+                return None;
+            }
+            Err(e) => {
+                warn!("Tomorrow.io events request failed: {}", e);
+                return None;
+            }
+        };
+        let payload: Value = response.json().await.ok()?;
+        parse_tomorrow_events(&payload)
+    }
+
     pub async fn get_forecast_data(
-        &mut self, 
-        day: &Value, 
-        temp_units: String, 
+        &self,
+        day: &Value,
+        temp_units: String,
         wind_speed_units: String,
     ) -> Result<WeatherData, JsonError> {
 
@@ -350,18 +870,12 @@ impl Weather {
             deg = values["windDirection"].as_f64().unwrap_or(0.0);
         }
         
-        let mut d16 = ((deg / 22.5) + 0.5) as u8;
-        d16 %= 16;
-        let compass_points = [
-            "N",  "NNE", "NE", "ENE", "E",  "ESE",
-            "SE", "SSE", "S",  "SSW", "SW", "WSW",
-            "W",  "WNW", "NW", "NNW"];
-        let wind_dir = compass_points[d16 as usize].to_string();
+        let wind_dir = compass_point(deg);
         let mut weather_code = values["weatherCodeMax"].as_i64().unwrap_or(99999);
         if weather_code == 99999 {
             weather_code = values["weatherCode"].as_i64().unwrap_or(0);
         }
-        let wc: WeatherCode = self.parse_weather_code(weather_code).await;
+        let wc: WeatherCode = parse_weather_code(weather_code, self.settings.icons, &self.settings.translate).await;
 
         let moonrise_time = if "" != values["moonriseTime"].as_str().unwrap_or("") {
             let date_str = values["moonriseTime"].as_str().unwrap();
@@ -440,16 +954,16 @@ impl Weather {
             wind_direction: wind_dir,
             wind_speed_avg: wind_speed,
             wind_speed_units: wind_speed_units.clone(),
-            pressure_sea_level_units: self.weather_data.pressure_units.clone(),
+            pressure_sea_level_units: self.settings.pressure_units(),
         };
         Ok(wd)
 
     }
 
-    async fn send_with_retries<T: Serialize + ?Sized>(&mut self, params: &T, max_retries: u8) -> Result<String, reqwest::Error> {
+    async fn send_with_retries<T: Serialize + ?Sized>(&self, params: &T, max_retries: u8) -> Result<String, reqwest::Error> {
         let mut retries = 0;
         loop {
-            match self.client.get(&self.base_url.clone())
+            match self.settings.client.get(&self.base_url)
                 .query(params).send().await {
                 Ok(response) => {
                     let raw = response.bytes().await?;
@@ -471,7 +985,6 @@ impl Weather {
                 Err(e) => {
                     retries += 1;
                     if retries >= max_retries {
-                        self.active = false;
                         return Err(e); // max retries reached
                     }
                     thread::sleep(Duration::from_secs(1)); // Wait before retrying
@@ -480,9 +993,8 @@ impl Weather {
         }
     }
 
-    /// Fetches current weather and 3-day forecast from Tomorrow.io.
-    pub async fn fetch_weather_data(&mut self) -> Result<(), WeatherApiError> {
-        info!("Fetching weather data for {}...", self.weather_data.location_name);
+    /// Fetches current weather and daily forecast from Tomorrow.io.
+    async fn fetch_report(&self) -> Result<WeatherReport, WeatherApiError> {
 
         // fields do not appear to work!
         let fields= [
@@ -510,14 +1022,14 @@ impl Weather {
             .join(",");
 
         let params = [
-            ("location", format!("{},{}", self.lat, self.lng)),
+            ("location", format!("{},{}", self.settings.lat, self.settings.lng)),
             ("fields", fields), // this is NOT working???
-            ("units", self.units.clone()),
+            ("units", self.settings.units.clone()),
             ("timesteps", "1h,1d".to_string()),
             ("startTime", "now".to_string()),
             ("endTime", "nowPlus9d".to_string()),
             ("dailyStartTime", "1".to_string()),
-            ("apikey", self.api_key.clone()),
+            ("apikey", self.settings.api_key.clone()),
         ];
 
         let plain =
@@ -528,6 +1040,10 @@ impl Weather {
         let payload: Value = serde_json::from_str(&plain.as_str())
             .map_err(|e| WeatherApiError::DeserializationError(e))?;
 
+        let temp_units = self.settings.temperature_units();
+        let wind_speed_units = self.settings.wind_speed_units();
+        let mut report = WeatherReport::default();
+
         let now = Local::now();
         if let Some(timelines) = payload.get("timelines") {
             // Current weather (next hour)
@@ -535,10 +1051,10 @@ impl Weather {
                 for hour in hours.iter() {
                     let date_str = hour["time"].as_str().unwrap();
                     if DateTime::parse_from_rfc3339(date_str).unwrap().with_timezone(&Local) > now {
-                        self.weather_data.current = self.get_forecast_data(
+                        report.current = self.get_forecast_data(
                             hour,
-                            self.weather_data.temperature_units.clone(),
-                            self.weather_data.wind_speed_units.clone(),
+                            temp_units.clone(),
+                            wind_speed_units.clone(),
                         )
                             .await
                             .map_err(|e| WeatherApiError::DeserializationError(e))?;
@@ -559,631 +1075,544 @@ impl Weather {
                         continue;
                     }
                     //println!("Forecast {} for {} > {}", idx, date_str, now_day);
-                    self.weather_data.forecast[idx] = self.get_forecast_data(
-                        day, 
-                        self.weather_data.temperature_units.clone(),
-                        self.weather_data.wind_speed_units.clone(),
+                    report.forecast.push(self.get_forecast_data(
+                        day,
+                        temp_units.clone(),
+                        wind_speed_units.clone(),
                     )
                         .await
-                        .map_err(|e| WeatherApiError::DeserializationError(e))?;
+                        .map_err(WeatherApiError::DeserializationError)?);
                     idx += 1;
                 }
             }
         } else {
             println!("No forecast data found in the response.");
         }
-        self.weather_data.last_updated = Local::now();
-        info!("Weather data fetched successfully.");
-        self.last_fetch_time = Some(Instant::now()); // Record fetch time
-        self.active = true;
-        
-        // Send update via watch channel if available (no lock!)
-        if let Some(tx) = &self.weather_tx {
-            let _ = tx.send(self.weather_data.clone());
-        }
-
-        Ok(())
+        report.alert = self.fetch_alert().await;
+        Ok(report)
 
     }
 
-    /// Starts a background polling task to fetch weather data periodically (legacy API).
-    ///
-    /// This is the legacy Arc<Mutex<Weather>> version for backwards compatibility.
-    /// New code should use start_polling_with_watch() instead for lock-free updates.
-    pub async fn start_polling(instance: Arc<TokMutex<Self>>) -> Result<(), WeatherApiError> {
-        let (tx, rx) = mpsc::channel(1);
-        {
-            let mut locked_instance = instance.lock().await;
-            if locked_instance.poll_handle.is_some() {
-                return Err(WeatherApiError::PollingError("Polling already running".to_string()));
-            }
-            locked_instance.stop_sender = Some(tx);
-        }
-
-        let instance_for_poll_task = Arc::clone(&instance);
-
-        let poll_handle = tokio::spawn(async move {
-            let mut rx = rx;
-            loop {
-                tokio::select! {
-                    _ = tokio::time::sleep(Duration::from_secs(35 * 60)) => {
-                        let mut locked_self = instance_for_poll_task.lock().await;
-                        match locked_self.fetch_weather_data().await {
-                            Ok(_) => info!("Weather polling successful."),
-                            Err(e) => error!("Weather polling failed: {}", e),
-                        }
-                    }
-                    _ = rx.recv() => {
-                        info!("Weather polling thread received stop signal. Exiting.");
-                        break;
-                    }
-                }
-            }
-        });
+}
 
-        instance.lock().await.poll_handle = Some(poll_handle);
-        Ok(())
+impl WeatherProvider for TomorrowIo {
+    fn name(&self) -> &'static str {
+        "tomorrow.io"
     }
 
-    /// Starts a background polling task with lock-free updates via watch channel (new API).
-    ///
-    /// This takes ownership of the Weather instance and returns a watch::Receiver
-    /// for lock-free access to weather updates. Preferred for new code.
-    pub async fn start_polling_with_watch(mut self) -> Result<(JoinHandle<()>, watch::Receiver<WeatherConditions>), WeatherApiError> {
-        if self.poll_handle.is_some() {
-            return Err(WeatherApiError::PollingError("Polling already running".to_string()));
-        }
-
-        // Create watch channel for weather updates (lock-free!)
-        let (weather_tx, weather_rx) = watch::channel(self.weather_data.clone());
-        self.weather_tx = Some(weather_tx);
-
-        // Create stop channel
-        let (stop_tx, mut stop_rx) = mpsc::channel(1);
-        self.stop_sender = Some(stop_tx);
-
-        let poll_handle = tokio::spawn(async move {
-            loop {
-                tokio::select! {
-                    _ = tokio::time::sleep(Duration::from_secs(35 * 60)) => { // Poll every 35 minutes
-                        match self.fetch_weather_data().await {
-                            Ok(_) => info!("Weather polling successful."),
-                            Err(e) => error!("Weather polling failed: {}", e),
-                        }
-                    }
-                    _ = stop_rx.recv() => {
-                        info!("Weather polling thread received stop signal. Exiting.");
-                        break;
-                    }
-                }
-            }
-        });
-
-        Ok((poll_handle, weather_rx))
+    fn fetch(&self) -> ProviderFuture<'_> {
+        Box::pin(self.fetch_report())
     }
+}
 
-
-    /// Stops the background polling task.
-    pub async fn stop_polling(&mut self) {
-        if let Some(sender) = self.stop_sender.take() {
-            if let Err(e) = sender.send(()).await {
-                error!("Failed to send stop signal to weather polling thread: {}", e);
-            }
-        }
-        if let Some(handle) = self.poll_handle.take() {
-            // It's generally not good practice to block in Drop, but for async shutdown
-            // from a sync context (like main's cleanup or explicit shutdown), awaiting is fine.
-            handle.await.unwrap_or_else(|e| error!("Weather polling thread failed to join: {}", e));
-        }
-        info!("Weather polling stopped.");
-    }
-    /// Returns the weather condition description for a given weather code.
-    ///
-    /// # Arguments
-    /// * `weather_code` - The numeric weather code as i64.
-    ///
-    /// # Returns
-    /// A &'static str describing the condition, an icon index, and an svg filename.
-
-    async fn parse_weather_code(&self, weather_code: i64) -> WeatherCode {
-        let mut wcd = match weather_code {
-            1000 | 10000 => WeatherCode {
-                description: "Clear, Sunny".to_string(),
-                icon: 0,
-                svg: "clear_day.svg".to_string()
-            },
-            10001 => WeatherCode {
-                description: "Clear".to_string(),
-                icon: 1,
-                svg: "clear_night.svg".to_string()
-            }, // night
-            1001 | 10010 => WeatherCode {
-                description: "Cloudy".to_string(),
-                icon: 2,
-                svg: "mostly_cloudy_day.svg".to_string()
-            },
-            10011 => WeatherCode {
-                description: "Cloudy".to_string(),
-                icon: 2,
-                svg: if self.icons == 2 {"mostly_cloudy.svg".to_string()} else {"mostly_cloudy_night.svg".to_string()}
-            }, // night
-            1100 | 11000 => WeatherCode {
-                description: "Mostly Clear".to_string(),
-                icon: 14,
-                svg: "mostly_clear_day.svg".to_string()
-            },
-            11001 => WeatherCode {
-                description: "Mostly Clear".to_string(),
-                icon: 15,
-                svg: "mostly_clear_night.svg".to_string()
-            }, // night
-            1101 | 11010 => WeatherCode {
-                description: "Partly Cloudy".to_string(),
-                icon: 17,
-                svg: "partly_cloudy_day.svg".to_string()
-            },
-            11011 => WeatherCode {
-                description: "Partly Cloudy".to_string(),
-                icon: 18,
-                svg: "partly_cloudy_night.svg".to_string()
-            }, // night
-            1102 | 11020 => WeatherCode {
-                description: "Mostly Cloudy".to_string(),
-                icon: 17,
-                svg: "mostly_cloudy_day.svg".to_string()
-            },
-            11021 => WeatherCode {
-                description: "Mostly Cloudy".to_string(),
-                icon: 18,
-                svg: "mostly_cloudy_night.svg".to_string()
-            }, // night
-            1103 | 11030 => WeatherCode {
-                description: "Partly Cloudy and Mostly Clear".to_string(),
-                icon: 17,
-                svg: "mostly_clear_day.svg".to_string()
-            },
-            11031 => WeatherCode {
-                description: "Partly Cloudy and Mostly Clear".to_string(),
-                icon: 18,
-                svg: "mostly_clear_night.svg".to_string()
-            }, // night
-            // Fog
-            2000 | 20001 => WeatherCode {
-                description: "Fog".to_string(),
-                icon: 5,
-                svg: if self.icons == 2 {"fog.svg".to_string()} else {"haze_fog_dust_smoke.svg".to_string()}
-            },
-            2100 | 21000 | 21001 => WeatherCode {
-                description: "Light Fog".to_string(),
-                icon: 6,
-                svg: if self.icons == 2 {"fog.svg".to_string()} else {"haze_fog_dust_smoke.svg".to_string()}
-            },
-            2101 | 21010 | 21011 => WeatherCode {
-                description: "Mostly Clear and Light Fog".to_string(),
-                icon: 5,
-                svg: if self.icons == 2 {"fog.svg".to_string()} else {"haze_fog_dust_smoke.svg".to_string()}
-            },
-            2102 | 21020 | 21021 => WeatherCode {
-                description: "Partly Cloudy and Light Fog".to_string(),
-                icon: 6,
-                svg: if self.icons == 2 {"fog.svg".to_string()} else {"haze_fog_dust_smoke.svg".to_string()}
-            },
-            2103 | 21030 | 21031 => WeatherCode {
-                description: "Mostly Cloudy and Light Fog".to_string(),
-                icon: 5,
-                svg: if self.icons == 2 {"fog.svg".to_string()} else {"haze_fog_dust_smoke.svg".to_string()}
-            },
-            2106 | 21060 | 21061 => WeatherCode {
-                description: "Mostly Clear and Fog".to_string(),
-                icon: 6,
-                svg: if self.icons == 2 {"fog.svg".to_string()} else {"haze_fog_dust_smoke.svg".to_string()}
-            },
-            2107 | 21070 | 21071 => WeatherCode {
-                description: "Partly Cloudy and Fog".to_string(),
-                icon: 5,
-                svg: if self.icons == 2 {"fog.svg".to_string()} else {"haze_fog_dust_smoke.svg".to_string()}
-            },
-            2108 | 21080 | 21081 => WeatherCode {
-                description: "Mostly Cloudy and Fog".to_string(),
-                icon: 6,
-                svg: if self.icons == 2 {"fog.svg".to_string()} else {"haze_fog_dust_smoke.svg".to_string()}
-            },
-            // Drizzle
-            4000 | 40000 | 40001 => WeatherCode {
-                description: "Drizzle".to_string(),
-                icon: 3,
-                svg: "drizzle.svg".to_string()
-            },
-            4203 | 42030 | 42031 => WeatherCode {
-                description: "Mostly Clear and Drizzle".to_string(),
-                icon: 3,
-                svg: "drizzle.svg".to_string()
-            },
-            4204 | 42040 | 42041 => WeatherCode {
-                description: "Partly Cloudy and Drizzle".to_string(),
-                icon: 3,
-                svg: "drizzle.svg".to_string(),
-            },
-            4205 | 42050 | 42051 => WeatherCode {
-                description: "Mostly Cloudy and Drizzle".to_string(),
-                icon: 3,
-                svg: "drizzle.svg".to_string()
-            },
-            // Rain
-            4001 | 40010 | 40011 => WeatherCode {
-                description: "Rain".to_string(),
-                icon: 21,
-                svg: if self.icons == 2 {"rain.svg".to_string()}else{"drizzle.svg".to_string()}
-            },
-            4200 | 42000 => WeatherCode {
-                description: "Light Rain".to_string(),
-                icon: 21,
-                svg: if self.icons == 2 {"rain_light.svg".to_string()}else{"cloudy_with_rain_light.svg".to_string()}
-            },
-            4201 | 42010 => WeatherCode {
-                description: "Heavy Rain".to_string(),
-                icon: 20,
-                svg: if self.icons == 2 {"rain_heavy.svg".to_string()}else{"heavy_rain.svg".to_string()}
-            },
-            4213 | 42130 | 42131 => WeatherCode {
-                description: "Mostly Clear and Light Rain".to_string(),
-                icon: 21,
-                svg: if self.icons == 2 {"rain_light.svg".to_string()}else{"cloudy_with_rain_light.svg".to_string()}
-            },
-            4214 | 42140 | 42141 => WeatherCode {
-                description: "Partly Cloudy and Light Rain".to_string(),
-                icon: 21,
-                svg: if self.icons == 2 {"rain_light.svg".to_string()}else{"cloudy_with_rain_light.svg".to_string()}
-            },
-            4215 | 42150 | 42151 => WeatherCode {
-                description: "Mostly Cloudy and Light Rain".to_string(),
-                icon: 21,
-                svg: if self.icons == 2 {"rain_light.svg".to_string()}else{"cloudy_with_rain_light.svg".to_string()}
-            },
-            4209 | 42090 | 42091 => WeatherCode {
-                description: "Mostly Clear and Rain".to_string(),
-                icon: 21,
-                svg: if self.icons == 2 {"rain_light.svg".to_string()}else{"cloudy_with_rain_light.svg".to_string()}
-            },
-            4208 | 42080 | 42081 => WeatherCode {
-                description: "Partly Cloudy and Rain".to_string(),
-                icon: 21,
-                svg: if self.icons == 2 {"rain_light.svg".to_string()}else{"showers_rain.svg".to_string()}
-            },
-            4210 | 42100 | 42101 => WeatherCode {
-                description: "Mostly Cloudy and Rain".to_string(),
-                icon: 21,
-                svg: if self.icons == 2 {"rain_light.svg".to_string()}else{"showers_rain.svg".to_string()}
-            },
-            4211 | 42110 | 42111 => WeatherCode {
-                description: "Mostly Clear and Heavy Rain".to_string(),
-                icon: 20,
-                svg: if self.icons == 2 {"rain_heavy.svg".to_string()}else{"heavy_rain.svg".to_string()}
-            },
-            4202 | 42020 | 42021 => WeatherCode {
-                description: "Partly Cloudy and Heavy Rain".to_string(),
-                icon: 20,
-                svg: if self.icons == 2 {"rain_heavy.svg".to_string()}else{"heavy_rain.svg".to_string()}
-            },
-            4212 | 42120 | 42121 => WeatherCode {
-                description: "Mostly Cloudy and Heavy Rain".to_string(),
-                icon: 20,
-                svg: if self.icons == 2 {"rain_heavy.svg".to_string()}else{"heavy_rain.svg".to_string()}
-            },
-            6220 | 62200 => WeatherCode {
-                description: "Light Rain and Freezing Rain".to_string(),
-                icon: 7,
-                svg: if self.icons == 2 {"freezing_rain.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            6222 | 62220 => WeatherCode {
-                description: "Rain and Freezing Rain".to_string(),
-                icon: 8,
-                svg: if self.icons == 2 {"freezing_rain.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            // Snow
-            5000 | 50000 | 50001 => WeatherCode {
-                description: "Snow".to_string(),
-                icon: 22,
-                svg: if self.icons == 2 {"snow.svg".to_string()}else{"scattered_snow_showers_day.svg".to_string()}
-            },
-            5001 | 50010 | 50011 => WeatherCode {
-                description: "Flurries".to_string(),
-                icon: 4,
-                svg: "flurries.svg".to_string(),
-            },
-            5100 | 51000 | 51001 => WeatherCode {
-                description: "Light Snow".to_string(),
-                icon: 24,
-                svg: if self.icons == 2 {"snow_light.svg".to_string()}else{"light_snow.svg".to_string()}
-            },
-            5101 | 51010 | 51011 => WeatherCode {
-                description: "Heavy Snow".to_string(),
-                icon: 22,
-                svg: if self.icons == 2 {"snow_heavy.svg".to_string()}else{"heavy_snow.svg".to_string()}
-            },
-            5102 | 51020 | 51021 => WeatherCode {
-                description: "Mostly Clear and Light Snow".to_string(),
-                icon: 24,
-                svg: if self.icons == 2 {"snow_light.svg".to_string()}else{"cloudy_with_snow_light.svg".to_string()}
-            },
-            5103 | 51030 | 51031 => WeatherCode {
-                description: "Partly Cloudy and Light Snow".to_string(),
-                icon: 24,
-                svg: if self.icons == 2 {"snow_light.svg".to_string()}else{"cloudy_with_snow_light.svg".to_string()}
-            },
-            5104 | 51040 | 51041 => WeatherCode {
-                description: "Mostly Cloudy and Light Snow".to_string(),
-                icon: 24,
-                svg: if self.icons == 2 {"snow_light.svg".to_string()}else{"cloudy_with_snow_light.svg".to_string()}
-            },
-            5105 | 51050 | 51051 => WeatherCode {
-                description: "Mostly Clear and Snow".to_string(),
-                icon: 24,
-                svg: if self.icons == 2 {"snow_light.svg".to_string()}else{"cloudy_with_snow_light.svg".to_string()}
-            },
-            5106 | 51060 | 51061 => WeatherCode {
-                description: "Partly Cloudy and Snow".to_string(),
-                icon: 24,
-                svg: if self.icons == 2 {"snow_light.svg".to_string()}else{"cloudy_with_snow_light.svg".to_string()}
-            },
-            5107 | 51070 | 51071 => WeatherCode {
-                description: "Mostly Cloudy and Snow".to_string(),
-                icon: 24,
-                svg: if self.icons == 2 {"snow_light.svg".to_string()}else{"cloudy_with_snow_light.svg".to_string()}
-            },
-            5119 | 51190 | 51191 => WeatherCode {
-                description: "Mostly Clear and Heavy Snow".to_string(),
-                icon: 22,
-                svg: if self.icons == 2 {"snow_heavy.svg".to_string()}else{"heavy_snow.svg".to_string()}
-            },
-            5120 | 51200 | 51201 => WeatherCode {
-                description: "Partly Cloudy and Heavy Snow".to_string(),
-                icon: 22,
-                svg: if self.icons == 2 {"snow_heavy.svg".to_string()}else{"heavy_snow.svg".to_string()}
-            },
-            5121 | 51210 | 51211 => WeatherCode {
-                description: "Mostly Cloudy and Heavy Snow".to_string(),
-                icon: 22,
-                svg: if self.icons == 2 {"snow_heavy.svg".to_string()}else{"heavy_snow.svg".to_string()}
-            },
-            5115 | 51150 | 51151 => WeatherCode {
-                description: "Mostly Clear and Flurries".to_string(),
-                icon: 7,
-                svg: "flurries.svg".to_string(),
-            },
-            5116 | 51160 | 51161 => WeatherCode {
-                description: "Partly Cloudy and Flurries".to_string(),
-                icon: 4,
-                svg: "flurries.svg".to_string(),
-            },
-            5117 | 51170 | 51171 => WeatherCode {
-                description: "Mostly Cloudy and Flurries".to_string(),
-                icon: 4,
-                svg: "flurries.svg".to_string(),
-            },
-            5110 | 51100 | 51101 => WeatherCode {
-                description: "Drizzle and Snow".to_string(),
-                icon: 7,
-                svg: if self.icons == 2 {"drizzle.svg".to_string()}else{"sleet_rain.svg".to_string()}
-            },
-            5108 | 51080 => WeatherCode {
-                description: "Rain and Snow".to_string(),
-                icon: 4,
-                svg: if self.icons == 2 {"snow.svg".to_string()}else{"showers_snow.svg".to_string()}
-            },
-            5122 | 51220 | 51221 => WeatherCode {
-                description: "Drizzle and Light Snow".to_string(),
-                icon: 4,
-                svg: if self.icons == 2 {"snow.svg".to_string()}else{"showers_snow.svg".to_string()}
-            },
-            // Freezing Drizzle / Rain
-            6000 | 60000 | 60001 => WeatherCode {
-                description: "Freezing Drizzle".to_string(),
-                icon: 8,
-                svg: if self.icons == 2 {"freezing_drizzle.svg".to_string()}else{"showers_snow.svg".to_string()}
-            },
-            6001 | 60010 | 60011 => WeatherCode {
-                description: "Freezing Rain".to_string(),
-                icon: 8, 
-                svg: if self.icons == 2 {"freezing_rain.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
-            },
-            6200 | 62000 | 62001 => WeatherCode {
-                description: "Light Freezing Rain".to_string(),
-                icon: 10,
-                svg: if self.icons == 2 {"freezing_rain_light.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
-            },
-            6201 | 62010 | 62011 => WeatherCode {
-                description: "Heavy Freezing Rain".to_string(),
-                icon: 9,
-                svg: if self.icons == 2 {"freezing_rain_heavy.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
-            },
-            6003 | 60030 | 60031 => WeatherCode {
-                description: "Mostly Clear and Freezing Drizzle".to_string(),
-                icon: 8,
-                svg: if self.icons == 2 {"freezing_drizzle.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            6002 | 60020 | 60021 => WeatherCode {
-                description: "Partly Cloudy and Freezing Drizzle".to_string(),
-                icon: 8,
-                svg: if self.icons == 2 {"freezing_rain_light.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            6004 | 60040 | 60041 => WeatherCode {
-                description: "Mostly Cloudy and Freezing Drizzle".to_string(),
-                icon: 8,
-                svg: if self.icons == 2 {"freezing_rain_light.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            6204 | 62040 | 62041 => WeatherCode {
-                description: "Drizzle and Freezing Drizzle".to_string(),
-                icon: 8,
-                svg: if self.icons == 2 {"freezing_drizzle.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            6206 | 62060 | 62061 => WeatherCode {
-                description: "Light Rain and Freezing Drizzle".to_string(),
-                icon: 8,
-                svg: if self.icons == 2 {"freezing_rain_light.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            6205 | 62050 | 62051 => WeatherCode {
-                description: "Mostly Clear and Light Freezing Rain".to_string(),
-                icon: 10,
-                svg: if self.icons == 2 {"freezing_rain_light.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            6203 | 62030 | 62031 => WeatherCode {
-                description: "Partly Cloudy and Light Freezing Rain".to_string(),
-                icon: 10,
-                svg: if self.icons == 2 {"freezing_rain_light.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            6209 | 62090 | 62091 => WeatherCode {
-                description: "Mostly Cloudy and Light Freezing Rain".to_string(),
-                icon: 10,
-                svg: if self.icons == 2 {"freezing_rain_light.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            6213 | 62130 | 62131 => WeatherCode {
-                description: "Mostly Clear and Freezing Rain".to_string(),
-                icon: 10,
-                svg: if self.icons == 2 {"freezing_rain_light.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            6214 | 62140 | 62141 => WeatherCode {
-                description: "Partly Cloudy and Freezing Rain".to_string(),
-                icon: 10,
-                svg: if self.icons == 2 {"freezing_rain_light.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            6215 | 62150 | 62151 => WeatherCode {
-                description: "Mostly Cloudy and Freezing Rain".to_string(),
-                icon: 10,
-                svg: if self.icons == 2 {"freezing_rain_light.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
-            },
-            6212 | 62120 | 62121 => WeatherCode {
-                description: "Drizzle and Freezing Rain".to_string(),
-                icon: 10,
-                svg: if self.icons == 2 {"freezing_drizzle.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            // Ice Pellets
-            7000 | 70001 => WeatherCode {
-                description: "Ice Pellets".to_string(),
-                icon: 11,
-                svg: if self.icons == 2 {"ice_pellets.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            7101 | 71010 => WeatherCode {
-                description: "Heavy Ice Pellets".to_string(),
-                icon: 12,
-                svg: if self.icons == 2 {"ice_pellets_heavy.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            7102 | 71020 | 71021 => WeatherCode {
-                description: "Light Ice Pellets".to_string(),
-                icon: 13,
-                svg: if self.icons == 2 {"ice_pellets_light.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            7105 | 71050 | 71051 => WeatherCode {
-                description: "Drizzle and Ice Pellets".to_string(),
-                icon: 13,
-                svg: if self.icons == 2 {"ice_pellets_light.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
-            },
-            7106 | 71060 | 71061 => WeatherCode {
-                description: "Freezing Rain and Ice Pellets".to_string(),
-                icon: 13,
-                svg: if self.icons == 2 {"ice_pellets_light.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
-            },
-            7115 | 71150 | 71151 => WeatherCode {
-                description: "Light Rain and Ice Pellets".to_string(),
-                icon: 13,
-                svg: if self.icons == 2 {"ice_pellets_light.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
-            },
-            7117 | 71170 | 71171 => WeatherCode {
-                description: "Rain and Ice Pellets".to_string(),
-                icon: 13,
-                svg: if self.icons == 2 {"ice_pellets_light.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
-            },
-            7103 | 71030 | 71031 => WeatherCode {
-                description: "Freezing Rain and Heavy Ice Pellets".to_string(),
-                icon: 12,
-                svg: if self.icons == 2 {"ice_pellets_heavy.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
-            },
-            7113 | 71130 | 71131 => WeatherCode {
-                description: "Mostly Clear and Heavy Ice Pellets".to_string(),
-                icon: 12,
-                svg: if self.icons == 2 {"ice_pellets_heavy.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
-            },
-            7114 | 71140 | 71141 => WeatherCode {
-                description: "Partly Cloudy and Heavy Ice Pellets".to_string(),
-                icon: 12,
-                svg: if self.icons == 2 {"ice_pellets_heavy.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            7116 | 71160 | 71161 => WeatherCode {
-                description: "Mostly Cloudy and Heavy Ice Pellets".to_string(),
-                icon: 12,
-                svg: if self.icons == 2 {"ice_pellets_heavy.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            7108 | 71080 | 71081 => WeatherCode {
-                description: "Mostly Clear and Ice Pellets".to_string(),
-                icon: 11,
-                svg: if self.icons == 2 {"ice_pellets.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            7107 | 71070 | 71071 => WeatherCode {
-                description: "Partly Cloudy and Ice Pellets".to_string(),
-                icon: 11,
-                svg: if self.icons == 2 {"ice_pellets.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            7109 | 71090 | 71091 => WeatherCode {
-                description: "Mostly Cloudy and Ice Pellets".to_string(),
-                icon: 11,
-                svg: if self.icons == 2 {"ice_pellets.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            7110 | 71100 | 71101 => WeatherCode {
-                description: "Mostly Clear and Light Ice Pellets".to_string(),
-                icon: 11,
-                svg: if self.icons == 2 {"ice_pellets.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            7111 | 71110 | 71111 => WeatherCode {
-                description: "Partly Cloudy and Light Ice Pellets".to_string(),
-                icon: 11,
-                svg: if self.icons == 2 {"ice_pellets_light.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            7112 | 71120 | 71121 => WeatherCode {
-                description: "Mostly Cloudy and Light Ice Pellets".to_string(),
-                icon: 11,
-                svg: if self.icons == 2 {"ice_pellets_light.svg".to_string()}else{"icy.svg".to_string()}
-            },
-            // Thunderstorm
-            8000 | 80000 => WeatherCode {
-                description: "Thunderstorm".to_string(),
-                icon: 25,
-                svg: if self.icons == 2 {"tstorm.svg".to_string()}else{"strong_thunderstorms.svg".to_string()}
-            },
-            8001 | 80010 | 80011 => WeatherCode {
-                description: "Mostly Clear and Thunderstorm".to_string(),
-                icon: 25,
-                svg: if self.icons == 2 {"tstorm.svg".to_string()}else{"isolated_thunderstorms.svg".to_string()}
-            },
-            8002 | 80020 | 80021 => WeatherCode {
-                description: "Mostly Cloudy and Thunderstorm".to_string(),
-                icon: 25,
-                svg: if self.icons == 2 {"tstorm.svg".to_string()}else{"isolated_thunderstorms.svg".to_string()}
-            },
-            8003 | 80030 | 80031 => WeatherCode {
-                description: "Partly Cloudy and Thunderstorm".to_string(),
-                icon: 25,
-                svg: "isolated_thunderstorms.svg".to_string(),
-            },
-            _ => WeatherCode {
-                description: "Unknown".to_string(),
-                icon: 26,
-                svg: "no_data.svg".to_string(),
-            },
+/// Returns the weather condition description for a given weather code.
+///
+/// # Arguments
+/// * `weather_code` - The numeric weather code as i64.
+///
+/// # Returns
+/// A `WeatherCode` with the condition description, an icon index, and an svg filename.
+/// Shared by every provider - non Tomorrow.io codes are mapped onto this table.
+pub async fn parse_weather_code(weather_code: i64, icons: i32, translate: &str) -> WeatherCode {
+    let mut wcd = match weather_code {
+        1000 | 10000 => WeatherCode {
+            description: "Clear, Sunny".to_string(),
+            icon: 0,
+            svg: "clear_day.svg".to_string()
+        },
+        10001 => WeatherCode {
+            description: "Clear".to_string(),
+            icon: 1,
+            svg: "clear_night.svg".to_string()
+        }, // night
+        1001 | 10010 => WeatherCode {
+            description: "Cloudy".to_string(),
+            icon: 2,
+            svg: "mostly_cloudy_day.svg".to_string()
+        },
+        10011 => WeatherCode {
+            description: "Cloudy".to_string(),
+            icon: 2,
+            svg: if icons == 2 {"mostly_cloudy.svg".to_string()} else {"mostly_cloudy_night.svg".to_string()}
+        }, // night
+        1100 | 11000 => WeatherCode {
+            description: "Mostly Clear".to_string(),
+            icon: 14,
+            svg: "mostly_clear_day.svg".to_string()
+        },
+        11001 => WeatherCode {
+            description: "Mostly Clear".to_string(),
+            icon: 15,
+            svg: "mostly_clear_night.svg".to_string()
+        }, // night
+        1101 | 11010 => WeatherCode {
+            description: "Partly Cloudy".to_string(),
+            icon: 17,
+            svg: "partly_cloudy_day.svg".to_string()
+        },
+        11011 => WeatherCode {
+            description: "Partly Cloudy".to_string(),
+            icon: 18,
+            svg: "partly_cloudy_night.svg".to_string()
+        }, // night
+        1102 | 11020 => WeatherCode {
+            description: "Mostly Cloudy".to_string(),
+            icon: 17,
+            svg: "mostly_cloudy_day.svg".to_string()
+        },
+        11021 => WeatherCode {
+            description: "Mostly Cloudy".to_string(),
+            icon: 18,
+            svg: "mostly_cloudy_night.svg".to_string()
+        }, // night
+        1103 | 11030 => WeatherCode {
+            description: "Partly Cloudy and Mostly Clear".to_string(),
+            icon: 17,
+            svg: "mostly_clear_day.svg".to_string()
+        },
+        11031 => WeatherCode {
+            description: "Partly Cloudy and Mostly Clear".to_string(),
+            icon: 18,
+            svg: "mostly_clear_night.svg".to_string()
+        }, // night
+        // Fog
+        2000 | 20001 => WeatherCode {
+            description: "Fog".to_string(),
+            icon: 5,
+            svg: if icons == 2 {"fog.svg".to_string()} else {"haze_fog_dust_smoke.svg".to_string()}
+        },
+        2100 | 21000 | 21001 => WeatherCode {
+            description: "Light Fog".to_string(),
+            icon: 6,
+            svg: if icons == 2 {"fog.svg".to_string()} else {"haze_fog_dust_smoke.svg".to_string()}
+        },
+        2101 | 21010 | 21011 => WeatherCode {
+            description: "Mostly Clear and Light Fog".to_string(),
+            icon: 5,
+            svg: if icons == 2 {"fog.svg".to_string()} else {"haze_fog_dust_smoke.svg".to_string()}
+        },
+        2102 | 21020 | 21021 => WeatherCode {
+            description: "Partly Cloudy and Light Fog".to_string(),
+            icon: 6,
+            svg: if icons == 2 {"fog.svg".to_string()} else {"haze_fog_dust_smoke.svg".to_string()}
+        },
+        2103 | 21030 | 21031 => WeatherCode {
+            description: "Mostly Cloudy and Light Fog".to_string(),
+            icon: 5,
+            svg: if icons == 2 {"fog.svg".to_string()} else {"haze_fog_dust_smoke.svg".to_string()}
+        },
+        2106 | 21060 | 21061 => WeatherCode {
+            description: "Mostly Clear and Fog".to_string(),
+            icon: 6,
+            svg: if icons == 2 {"fog.svg".to_string()} else {"haze_fog_dust_smoke.svg".to_string()}
+        },
+        2107 | 21070 | 21071 => WeatherCode {
+            description: "Partly Cloudy and Fog".to_string(),
+            icon: 5,
+            svg: if icons == 2 {"fog.svg".to_string()} else {"haze_fog_dust_smoke.svg".to_string()}
+        },
+        2108 | 21080 | 21081 => WeatherCode {
+            description: "Mostly Cloudy and Fog".to_string(),
+            icon: 6,
+            svg: if icons == 2 {"fog.svg".to_string()} else {"haze_fog_dust_smoke.svg".to_string()}
+        },
+        // Drizzle
+        4000 | 40000 | 40001 => WeatherCode {
+            description: "Drizzle".to_string(),
+            icon: 3,
+            svg: "drizzle.svg".to_string()
+        },
+        4203 | 42030 | 42031 => WeatherCode {
+            description: "Mostly Clear and Drizzle".to_string(),
+            icon: 3,
+            svg: "drizzle.svg".to_string()
+        },
+        4204 | 42040 | 42041 => WeatherCode {
+            description: "Partly Cloudy and Drizzle".to_string(),
+            icon: 3,
+            svg: "drizzle.svg".to_string(),
+        },
+        4205 | 42050 | 42051 => WeatherCode {
+            description: "Mostly Cloudy and Drizzle".to_string(),
+            icon: 3,
+            svg: "drizzle.svg".to_string()
+        },
+        // Rain
+        4001 | 40010 | 40011 => WeatherCode {
+            description: "Rain".to_string(),
+            icon: 21,
+            svg: if icons == 2 {"rain.svg".to_string()}else{"drizzle.svg".to_string()}
+        },
+        4200 | 42000 => WeatherCode {
+            description: "Light Rain".to_string(),
+            icon: 21,
+            svg: if icons == 2 {"rain_light.svg".to_string()}else{"cloudy_with_rain_light.svg".to_string()}
+        },
+        4201 | 42010 => WeatherCode {
+            description: "Heavy Rain".to_string(),
+            icon: 20,
+            svg: if icons == 2 {"rain_heavy.svg".to_string()}else{"heavy_rain.svg".to_string()}
+        },
+        4213 | 42130 | 42131 => WeatherCode {
+            description: "Mostly Clear and Light Rain".to_string(),
+            icon: 21,
+            svg: if icons == 2 {"rain_light.svg".to_string()}else{"cloudy_with_rain_light.svg".to_string()}
+        },
+        4214 | 42140 | 42141 => WeatherCode {
+            description: "Partly Cloudy and Light Rain".to_string(),
+            icon: 21,
+            svg: if icons == 2 {"rain_light.svg".to_string()}else{"cloudy_with_rain_light.svg".to_string()}
+        },
+        4215 | 42150 | 42151 => WeatherCode {
+            description: "Mostly Cloudy and Light Rain".to_string(),
+            icon: 21,
+            svg: if icons == 2 {"rain_light.svg".to_string()}else{"cloudy_with_rain_light.svg".to_string()}
+        },
+        4209 | 42090 | 42091 => WeatherCode {
+            description: "Mostly Clear and Rain".to_string(),
+            icon: 21,
+            svg: if icons == 2 {"rain_light.svg".to_string()}else{"cloudy_with_rain_light.svg".to_string()}
+        },
+        4208 | 42080 | 42081 => WeatherCode {
+            description: "Partly Cloudy and Rain".to_string(),
+            icon: 21,
+            svg: if icons == 2 {"rain_light.svg".to_string()}else{"showers_rain.svg".to_string()}
+        },
+        4210 | 42100 | 42101 => WeatherCode {
+            description: "Mostly Cloudy and Rain".to_string(),
+            icon: 21,
+            svg: if icons == 2 {"rain_light.svg".to_string()}else{"showers_rain.svg".to_string()}
+        },
+        4211 | 42110 | 42111 => WeatherCode {
+            description: "Mostly Clear and Heavy Rain".to_string(),
+            icon: 20,
+            svg: if icons == 2 {"rain_heavy.svg".to_string()}else{"heavy_rain.svg".to_string()}
+        },
+        4202 | 42020 | 42021 => WeatherCode {
+            description: "Partly Cloudy and Heavy Rain".to_string(),
+            icon: 20,
+            svg: if icons == 2 {"rain_heavy.svg".to_string()}else{"heavy_rain.svg".to_string()}
+        },
+        4212 | 42120 | 42121 => WeatherCode {
+            description: "Mostly Cloudy and Heavy Rain".to_string(),
+            icon: 20,
+            svg: if icons == 2 {"rain_heavy.svg".to_string()}else{"heavy_rain.svg".to_string()}
+        },
+        6220 | 62200 => WeatherCode {
+            description: "Light Rain and Freezing Rain".to_string(),
+            icon: 7,
+            svg: if icons == 2 {"freezing_rain.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        6222 | 62220 => WeatherCode {
+            description: "Rain and Freezing Rain".to_string(),
+            icon: 8,
+            svg: if icons == 2 {"freezing_rain.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        // Snow
+        5000 | 50000 | 50001 => WeatherCode {
+            description: "Snow".to_string(),
+            icon: 22,
+            svg: if icons == 2 {"snow.svg".to_string()}else{"scattered_snow_showers_day.svg".to_string()}
+        },
+        5001 | 50010 | 50011 => WeatherCode {
+            description: "Flurries".to_string(),
+            icon: 4,
+            svg: "flurries.svg".to_string(),
+        },
+        5100 | 51000 | 51001 => WeatherCode {
+            description: "Light Snow".to_string(),
+            icon: 24,
+            svg: if icons == 2 {"snow_light.svg".to_string()}else{"light_snow.svg".to_string()}
+        },
+        5101 | 51010 | 51011 => WeatherCode {
+            description: "Heavy Snow".to_string(),
+            icon: 22,
+            svg: if icons == 2 {"snow_heavy.svg".to_string()}else{"heavy_snow.svg".to_string()}
+        },
+        5102 | 51020 | 51021 => WeatherCode {
+            description: "Mostly Clear and Light Snow".to_string(),
+            icon: 24,
+            svg: if icons == 2 {"snow_light.svg".to_string()}else{"cloudy_with_snow_light.svg".to_string()}
+        },
+        5103 | 51030 | 51031 => WeatherCode {
+            description: "Partly Cloudy and Light Snow".to_string(),
+            icon: 24,
+            svg: if icons == 2 {"snow_light.svg".to_string()}else{"cloudy_with_snow_light.svg".to_string()}
+        },
+        5104 | 51040 | 51041 => WeatherCode {
+            description: "Mostly Cloudy and Light Snow".to_string(),
+            icon: 24,
+            svg: if icons == 2 {"snow_light.svg".to_string()}else{"cloudy_with_snow_light.svg".to_string()}
+        },
+        5105 | 51050 | 51051 => WeatherCode {
+            description: "Mostly Clear and Snow".to_string(),
+            icon: 24,
+            svg: if icons == 2 {"snow_light.svg".to_string()}else{"cloudy_with_snow_light.svg".to_string()}
+        },
+        5106 | 51060 | 51061 => WeatherCode {
+            description: "Partly Cloudy and Snow".to_string(),
+            icon: 24,
+            svg: if icons == 2 {"snow_light.svg".to_string()}else{"cloudy_with_snow_light.svg".to_string()}
+        },
+        5107 | 51070 | 51071 => WeatherCode {
+            description: "Mostly Cloudy and Snow".to_string(),
+            icon: 24,
+            svg: if icons == 2 {"snow_light.svg".to_string()}else{"cloudy_with_snow_light.svg".to_string()}
+        },
+        5119 | 51190 | 51191 => WeatherCode {
+            description: "Mostly Clear and Heavy Snow".to_string(),
+            icon: 22,
+            svg: if icons == 2 {"snow_heavy.svg".to_string()}else{"heavy_snow.svg".to_string()}
+        },
+        5120 | 51200 | 51201 => WeatherCode {
+            description: "Partly Cloudy and Heavy Snow".to_string(),
+            icon: 22,
+            svg: if icons == 2 {"snow_heavy.svg".to_string()}else{"heavy_snow.svg".to_string()}
+        },
+        5121 | 51210 | 51211 => WeatherCode {
+            description: "Mostly Cloudy and Heavy Snow".to_string(),
+            icon: 22,
+            svg: if icons == 2 {"snow_heavy.svg".to_string()}else{"heavy_snow.svg".to_string()}
+        },
+        5115 | 51150 | 51151 => WeatherCode {
+            description: "Mostly Clear and Flurries".to_string(),
+            icon: 7,
+            svg: "flurries.svg".to_string(),
+        },
+        5116 | 51160 | 51161 => WeatherCode {
+            description: "Partly Cloudy and Flurries".to_string(),
+            icon: 4,
+            svg: "flurries.svg".to_string(),
+        },
+        5117 | 51170 | 51171 => WeatherCode {
+            description: "Mostly Cloudy and Flurries".to_string(),
+            icon: 4,
+            svg: "flurries.svg".to_string(),
+        },
+        5110 | 51100 | 51101 => WeatherCode {
+            description: "Drizzle and Snow".to_string(),
+            icon: 7,
+            svg: if icons == 2 {"drizzle.svg".to_string()}else{"sleet_rain.svg".to_string()}
+        },
+        5108 | 51080 => WeatherCode {
+            description: "Rain and Snow".to_string(),
+            icon: 4,
+            svg: if icons == 2 {"snow.svg".to_string()}else{"showers_snow.svg".to_string()}
+        },
+        5122 | 51220 | 51221 => WeatherCode {
+            description: "Drizzle and Light Snow".to_string(),
+            icon: 4,
+            svg: if icons == 2 {"snow.svg".to_string()}else{"showers_snow.svg".to_string()}
+        },
+        // Freezing Drizzle / Rain
+        6000 | 60000 | 60001 => WeatherCode {
+            description: "Freezing Drizzle".to_string(),
+            icon: 8,
+            svg: if icons == 2 {"freezing_drizzle.svg".to_string()}else{"showers_snow.svg".to_string()}
+        },
+        6001 | 60010 | 60011 => WeatherCode {
+            description: "Freezing Rain".to_string(),
+            icon: 8, 
+            svg: if icons == 2 {"freezing_rain.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
+        },
+        6200 | 62000 | 62001 => WeatherCode {
+            description: "Light Freezing Rain".to_string(),
+            icon: 10,
+            svg: if icons == 2 {"freezing_rain_light.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
+        },
+        6201 | 62010 | 62011 => WeatherCode {
+            description: "Heavy Freezing Rain".to_string(),
+            icon: 9,
+            svg: if icons == 2 {"freezing_rain_heavy.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
+        },
+        6003 | 60030 | 60031 => WeatherCode {
+            description: "Mostly Clear and Freezing Drizzle".to_string(),
+            icon: 8,
+            svg: if icons == 2 {"freezing_drizzle.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        6002 | 60020 | 60021 => WeatherCode {
+            description: "Partly Cloudy and Freezing Drizzle".to_string(),
+            icon: 8,
+            svg: if icons == 2 {"freezing_rain_light.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        6004 | 60040 | 60041 => WeatherCode {
+            description: "Mostly Cloudy and Freezing Drizzle".to_string(),
+            icon: 8,
+            svg: if icons == 2 {"freezing_rain_light.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        6204 | 62040 | 62041 => WeatherCode {
+            description: "Drizzle and Freezing Drizzle".to_string(),
+            icon: 8,
+            svg: if icons == 2 {"freezing_drizzle.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        6206 | 62060 | 62061 => WeatherCode {
+            description: "Light Rain and Freezing Drizzle".to_string(),
+            icon: 8,
+            svg: if icons == 2 {"freezing_rain_light.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        6205 | 62050 | 62051 => WeatherCode {
+            description: "Mostly Clear and Light Freezing Rain".to_string(),
+            icon: 10,
+            svg: if icons == 2 {"freezing_rain_light.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        6203 | 62030 | 62031 => WeatherCode {
+            description: "Partly Cloudy and Light Freezing Rain".to_string(),
+            icon: 10,
+            svg: if icons == 2 {"freezing_rain_light.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        6209 | 62090 | 62091 => WeatherCode {
+            description: "Mostly Cloudy and Light Freezing Rain".to_string(),
+            icon: 10,
+            svg: if icons == 2 {"freezing_rain_light.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        6213 | 62130 | 62131 => WeatherCode {
+            description: "Mostly Clear and Freezing Rain".to_string(),
+            icon: 10,
+            svg: if icons == 2 {"freezing_rain_light.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        6214 | 62140 | 62141 => WeatherCode {
+            description: "Partly Cloudy and Freezing Rain".to_string(),
+            icon: 10,
+            svg: if icons == 2 {"freezing_rain_light.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        6215 | 62150 | 62151 => WeatherCode {
+            description: "Mostly Cloudy and Freezing Rain".to_string(),
+            icon: 10,
+            svg: if icons == 2 {"freezing_rain_light.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
+        },
+        6212 | 62120 | 62121 => WeatherCode {
+            description: "Drizzle and Freezing Rain".to_string(),
+            icon: 10,
+            svg: if icons == 2 {"freezing_drizzle.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        // Ice Pellets
+        7000 | 70001 => WeatherCode {
+            description: "Ice Pellets".to_string(),
+            icon: 11,
+            svg: if icons == 2 {"ice_pellets.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        7101 | 71010 => WeatherCode {
+            description: "Heavy Ice Pellets".to_string(),
+            icon: 12,
+            svg: if icons == 2 {"ice_pellets_heavy.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        7102 | 71020 | 71021 => WeatherCode {
+            description: "Light Ice Pellets".to_string(),
+            icon: 13,
+            svg: if icons == 2 {"ice_pellets_light.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        7105 | 71050 | 71051 => WeatherCode {
+            description: "Drizzle and Ice Pellets".to_string(),
+            icon: 13,
+            svg: if icons == 2 {"ice_pellets_light.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
+        },
+        7106 | 71060 | 71061 => WeatherCode {
+            description: "Freezing Rain and Ice Pellets".to_string(),
+            icon: 13,
+            svg: if icons == 2 {"ice_pellets_light.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
+        },
+        7115 | 71150 | 71151 => WeatherCode {
+            description: "Light Rain and Ice Pellets".to_string(),
+            icon: 13,
+            svg: if icons == 2 {"ice_pellets_light.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
+        },
+        7117 | 71170 | 71171 => WeatherCode {
+            description: "Rain and Ice Pellets".to_string(),
+            icon: 13,
+            svg: if icons == 2 {"ice_pellets_light.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
+        },
+        7103 | 71030 | 71031 => WeatherCode {
+            description: "Freezing Rain and Heavy Ice Pellets".to_string(),
+            icon: 12,
+            svg: if icons == 2 {"ice_pellets_heavy.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
+        },
+        7113 | 71130 | 71131 => WeatherCode {
+            description: "Mostly Clear and Heavy Ice Pellets".to_string(),
+            icon: 12,
+            svg: if icons == 2 {"ice_pellets_heavy.svg".to_string()}else{"mixed_rain_hail_sleet.svg".to_string()}
+        },
+        7114 | 71140 | 71141 => WeatherCode {
+            description: "Partly Cloudy and Heavy Ice Pellets".to_string(),
+            icon: 12,
+            svg: if icons == 2 {"ice_pellets_heavy.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        7116 | 71160 | 71161 => WeatherCode {
+            description: "Mostly Cloudy and Heavy Ice Pellets".to_string(),
+            icon: 12,
+            svg: if icons == 2 {"ice_pellets_heavy.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        7108 | 71080 | 71081 => WeatherCode {
+            description: "Mostly Clear and Ice Pellets".to_string(),
+            icon: 11,
+            svg: if icons == 2 {"ice_pellets.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        7107 | 71070 | 71071 => WeatherCode {
+            description: "Partly Cloudy and Ice Pellets".to_string(),
+            icon: 11,
+            svg: if icons == 2 {"ice_pellets.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        7109 | 71090 | 71091 => WeatherCode {
+            description: "Mostly Cloudy and Ice Pellets".to_string(),
+            icon: 11,
+            svg: if icons == 2 {"ice_pellets.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        7110 | 71100 | 71101 => WeatherCode {
+            description: "Mostly Clear and Light Ice Pellets".to_string(),
+            icon: 11,
+            svg: if icons == 2 {"ice_pellets.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        7111 | 71110 | 71111 => WeatherCode {
+            description: "Partly Cloudy and Light Ice Pellets".to_string(),
+            icon: 11,
+            svg: if icons == 2 {"ice_pellets_light.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        7112 | 71120 | 71121 => WeatherCode {
+            description: "Mostly Cloudy and Light Ice Pellets".to_string(),
+            icon: 11,
+            svg: if icons == 2 {"ice_pellets_light.svg".to_string()}else{"icy.svg".to_string()}
+        },
+        // Thunderstorm
+        8000 | 80000 => WeatherCode {
+            description: "Thunderstorm".to_string(),
+            icon: 25,
+            svg: if icons == 2 {"tstorm.svg".to_string()}else{"strong_thunderstorms.svg".to_string()}
+        },
+        8001 | 80010 | 80011 => WeatherCode {
+            description: "Mostly Clear and Thunderstorm".to_string(),
+            icon: 25,
+            svg: if icons == 2 {"tstorm.svg".to_string()}else{"isolated_thunderstorms.svg".to_string()}
+        },
+        8002 | 80020 | 80021 => WeatherCode {
+            description: "Mostly Cloudy and Thunderstorm".to_string(),
+            icon: 25,
+            svg: if icons == 2 {"tstorm.svg".to_string()}else{"isolated_thunderstorms.svg".to_string()}
+        },
+        8003 | 80030 | 80031 => WeatherCode {
+            description: "Partly Cloudy and Thunderstorm".to_string(),
+            icon: 25,
+            svg: "isolated_thunderstorms.svg".to_string(),
+        },
+        _ => WeatherCode {
+            description: "Unknown".to_string(),
+            icon: 26,
+            svg: "no_data.svg".to_string(),
+        },
+    };
+    // built in locales translate offline, other languages go to the online translator
+    let built_in = Locale::parse(translate).and_then(|locale| locale.condition(&wcd.description));
+    if let Some(description) = built_in {
+        wcd.description = description;
+    } else if !translate.is_empty() && translate != "en" {
+        let translated = match Translation::new(translate) {
+            Ok(mut tl8) => tl8.translate_phrase(&wcd.description).await,
+            Err(e) => Err(e),
         };
-        if self.translate.len() > 0 {
-            if self.translate != "en" {
-                let text = wcd.description.clone();
-                let mut tl8 = Translation::new(self.translate.as_str()).unwrap();
-                wcd.description = tl8.translate_phrase(text.as_str())
-                    .await
-                    .unwrap()
-                    .to_string();
-            }
+        match translated {
+            Ok(text) => wcd.description = text,
+            Err(e) => warn!("Weather conditions left untranslated: {}", e),
         }
-        wcd
     }
-
-
+    wcd
 }
 
 // Implement Drop trait to stop the background thread when Weather goes out of scope
@@ -1200,3 +1629,94 @@ impl Drop for Weather {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_conditions() -> WeatherConditions {
+        let mut wc = WeatherConditions::new(
+            "Testville".to_string(), "metric".to_string(), "./assets/mono/".to_string(), 51.5, -0.12,
+        );
+        wc.current.temperature_avg = 12.5;
+        wc.current.moonset_time = Some(Local::now());
+        wc.current.weather_code = WeatherCode {
+            description: "Partly Cloudy".to_string(),
+            icon: 3,
+            svg: "partly_cloudy_day.svg".to_string(),
+        };
+        wc.forecast[0].temperature_max = 18.0;
+        wc
+    }
+
+    #[test]
+    fn cache_file_round_trip() {
+        let path = std::env::temp_dir()
+            .join(format!("lymons-weather-test-{}", std::process::id()))
+            .join("weather.json");
+        let wc = sample_conditions();
+        wc.save_to(&path).unwrap();
+        let loaded = WeatherConditions::load_from(&path).unwrap();
+        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+        assert_eq!(loaded, wc);
+    }
+
+    #[test]
+    fn stale_after_ttl() {
+        let mut wc = sample_conditions();
+        assert!(!wc.is_stale(WEATHER_CACHE_TTL));
+        wc.last_updated = Local::now() - chrono::Duration::hours(4);
+        assert!(wc.is_stale(WEATHER_CACHE_TTL));
+    }
+
+    #[test]
+    fn tomorrow_events_rank_by_severity() {
+        let payload = serde_json::json!({"data": {"events": [
+            {"insight": "wind", "severity": "moderate", "eventValues": {"title": "Wind Advisory"}},
+            {"insight": "floods", "severity": "extreme", "eventValues": {"title": "Flash Flood Warning"}},
+            {"insight": "fog", "severity": "minor"},
+        ]}});
+        let alert = parse_tomorrow_events(&payload).unwrap();
+        assert_eq!(alert, WeatherAlert { title: "Flash Flood Warning".into(), severity: AlertSeverity::Extreme });
+        assert!(parse_tomorrow_events(&serde_json::json!({"data": {"events": []}})).is_none());
+        assert!(AlertSeverity::Unknown < AlertSeverity::Minor);
+    }
+
+    /// Counts fetches instead of calling out to an API.
+    #[derive(Debug)]
+    struct CountingProvider(Arc<std::sync::atomic::AtomicUsize>);
+
+    impl WeatherProvider for CountingProvider {
+        fn name(&self) -> &'static str {
+            "counting"
+        }
+
+        fn fetch(&self) -> ProviderFuture<'_> {
+            self.0.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            Box::pin(async { Ok(WeatherReport::default()) })
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn poller_honours_configured_refresh() {
+        let cfg = crate::config::WeatherConfig {
+            api: Some("test-key".to_string()),
+            latitude: Some(51.5),
+            longitude: Some(-0.12),
+            refresh_mins: Some(25),
+            ..Default::default()
+        };
+        let mut weather = Weather::new(&cfg).await.unwrap();
+        let fetches = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        weather.provider = Box::new(CountingProvider(fetches.clone()));
+        let dir = std::env::temp_dir().join(format!("lymons-weather-poll-{}", std::process::id()));
+        weather.cache_path = dir.join("weather.json");
+
+        let (handle, _rx) = weather.start_polling_with_watch().await.unwrap();
+        // paused clock - sleeps auto-advance, so this covers three 25 minute periods and a bit
+        tokio::time::sleep(Duration::from_secs(3 * 25 * 60 + 30)).await;
+        handle.abort();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(fetches.load(std::sync::atomic::Ordering::SeqCst), 3);
+    }
+}
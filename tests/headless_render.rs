@@ -0,0 +1,542 @@
+/*
+ *  tests/headless_render.rs
+ *
+ *  Golden-image style render tests against the headless mock driver
+ *
+ *  LyMonS - worth the squeeze
+ *  (c) 2020-26 Stuart Hunter
+ *
+ *  cargo test --features headless --test headless_render
+ *
+ *  Set LYMONS_DUMP_PBM=<dir> to write each captured frame as a PBM for
+ *  visual diffing.
+ */
+
+use lymons::display::drivers::mock::MockDriver;
+use lymons::display::{DisplayManager, DisplayMode};
+
+fn manager(width: u32, height: u32) -> DisplayManager {
+    let driver = MockDriver::new_with_size(width, height).unwrap();
+    DisplayManager::new_with_driver(Box::new(driver), 0, "cylon", "7seg", false, "none", "classic").unwrap()
+}
+
+fn capture(mgr: &DisplayManager, name: &str) -> MockDriver {
+    let mock = mgr.driver().as_any().downcast_ref::<MockDriver>().unwrap().clone();
+    if let Ok(dir) = std::env::var("LYMONS_DUMP_PBM") {
+        mock.save_to_pbm(&format!("{}/{}.pbm", dir, name)).unwrap();
+    }
+    mock
+}
+
+#[test]
+fn clock_frame_is_captured() {
+    let mut mgr = manager(128, 64);
+    mgr.set_display_mode(DisplayMode::Clock);
+    mgr.render().unwrap();
+
+    let mock = capture(&mgr, "clock_128x64");
+    assert_eq!(mock.last_frame().len(), 128 * 64 / 8);
+    assert!(mock.count_on_pixels() > 0, "clock digits should light pixels");
+    assert!(mock.to_pbm().starts_with("P1\n128 64\n"));
+}
+
+#[test]
+fn clear_blanks_the_panel() {
+    let mut mgr = manager(128, 64);
+    mgr.set_display_mode(DisplayMode::Clock);
+    mgr.render().unwrap();
+    mgr.clear().unwrap();
+
+    let mock = capture(&mgr, "cleared_128x64");
+    assert_eq!(mock.count_on_pixels(), 0);
+    assert!(mock.last_frame().iter().all(|&b| b == 0));
+}
+
+#[test]
+fn scrolling_frame_renders_status_without_track() {
+    let mut mgr = manager(128, 64);
+    mgr.set_display_mode(DisplayMode::Scrolling);
+    mgr.render().unwrap();
+
+    let mock = capture(&mgr, "scrolling_128x64");
+    assert_eq!(mock.last_frame().len(), 128 * 64 / 8);
+}
+
+#[test]
+fn export_frame_writes_png() {
+    let mut mgr = manager(128, 64);
+    mgr.set_display_mode(DisplayMode::Clock);
+    mgr.render().unwrap();
+
+    let path = std::env::temp_dir().join(format!("lymons-export-{}.png", std::process::id()));
+    mgr.export_frame(&path).unwrap();
+    let png = std::fs::read(&path).unwrap();
+    let _ = std::fs::remove_file(&path);
+
+    assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    // IHDR width/height, big-endian
+    assert_eq!(&png[16..24], &[0, 0, 0, 128, 0, 0, 0, 64]);
+}
+
+
+#[tokio::test]
+async fn splash_spinner_advances_with_each_status() {
+    let mut mgr = manager(128, 64);
+    mgr.splash(true, "v:test", "today").await.unwrap();
+
+    mgr.update_splash_status("Discovering LMS...").unwrap();
+    let first = capture(&mgr, "splash_spinner_0").last_frame().to_vec();
+    mgr.update_splash_status("Discovering LMS...").unwrap();
+    let second = capture(&mgr, "splash_spinner_1").last_frame().to_vec();
+
+    // same status text, only the spinner frame differs
+    assert_ne!(first, second);
+}
+
+#[test]
+fn eggs_rotate_on_the_configured_interval() {
+    use std::time::{Duration, Instant};
+
+    let mut mgr = manager(128, 64);
+    mgr.easter_egg = lymons::eggs::set_easter_egg("tvtime");
+    mgr.set_egg_rotation_interval(Some(Duration::from_secs(300)));
+    let t0 = Instant::now();
+
+    // only rotates in easter egg mode
+    assert!(!mgr.rotate_easter_egg_at(t0 + Duration::from_secs(600)));
+
+    mgr.set_display_mode(DisplayMode::EasterEggs);
+    assert!(!mgr.rotate_easter_egg_at(t0));
+    assert!(!mgr.rotate_easter_egg_at(t0 + Duration::from_secs(299)));
+    assert_eq!(mgr.easter_egg.egg_type_name(), "tvtime");
+
+    assert!(mgr.rotate_easter_egg_at(t0 + Duration::from_secs(300)));
+    assert_eq!(mgr.easter_egg.egg_type_name(), "vcr");
+
+    // the next interval counts from the switch, and "none" is passed over
+    assert!(!mgr.rotate_easter_egg_at(t0 + Duration::from_secs(599)));
+    assert!(mgr.rotate_easter_egg_at(t0 + Duration::from_secs(600)));
+    assert_eq!(mgr.easter_egg.egg_type_name(), "bass");
+}
+
+#[test]
+fn history_lists_recent_tracks_on_wide_panels() {
+    let mut mgr = manager(256, 64);
+    mgr.set_history(4, None);
+    assert!(mgr.shows_history());
+
+    // nothing played yet - the clock stands in
+    mgr.set_display_mode(DisplayMode::History);
+    mgr.render().unwrap();
+    let empty = capture(&mgr, "history_empty_256x64").last_frame().to_vec();
+
+    mgr.record_played("Miles Davis", "So What");
+    mgr.record_played("John Coltrane", "Naima");
+    mgr.render().unwrap();
+    let mock = capture(&mgr, "history_256x64");
+    assert!(mock.count_on_pixels() > 0);
+    assert_ne!(mock.last_frame(), &empty[..]);
+
+    let mut small = manager(128, 64);
+    small.set_history(4, None);
+    assert!(!small.shows_history(), "no room for the list on small panels");
+}
+
+#[tokio::test]
+async fn big_title_fills_the_panel_with_the_title() {
+    use lymons::display::components::scrollers::TrackInfo;
+
+    let mut mgr = manager(256, 64);
+    mgr.set_display_mode(DisplayMode::BigTitle);
+    mgr.render().unwrap();
+    assert_eq!(capture(&mgr, "big_title_empty_256x64").count_on_pixels(), 0);
+
+    let track = TrackInfo { title: "So What".into(), artist: "Miles Davis".into(), ..Default::default() };
+    mgr.set_track_details(track, "", "", "", 9000, "").await;
+    mgr.render().unwrap();
+    let mock = capture(&mgr, "big_title_256x64");
+    // 10x20 glyphs centred vertically - nothing lit in the top rows
+    assert!(mock.count_on_pixels() > 100, "{}", mock.count_on_pixels());
+    assert!(mock.last_frame()[..256].iter().all(|&b| b == 0));
+}
+
+#[test]
+fn volume_change_raises_a_timed_overlay() {
+    use embedded_graphics::pixelcolor::BinaryColor;
+    use lymons::display::manager::VOLUME_OVERLAY_HOLD;
+    use lymons::glyphs::{RepeatMode, ShuffleMode};
+    use std::time::{Duration, Instant};
+
+    let mut mgr = manager(128, 64);
+    let status = |mgr: &mut DisplayManager, volume: u8| mgr.set_status_line_data(
+        volume, volume == 0, "16".into(), "44100".into(), RepeatMode::Off, ShuffleMode::Off);
+
+    // the first status after connecting only records the level
+    status(&mut mgr, 40);
+    assert_eq!(mgr.volume_overlay_until(), None);
+    status(&mut mgr, 40);
+    assert_eq!(mgr.volume_overlay_until(), None);
+
+    let before = Instant::now();
+    status(&mut mgr, 55);
+    let until = mgr.volume_overlay_until().expect("volume change raises the overlay");
+    assert!(until >= before + VOLUME_OVERLAY_HOLD && until <= Instant::now() + VOLUME_OVERLAY_HOLD);
+    assert!(mgr.volume_overlay_active_at(until - Duration::from_millis(1)));
+    assert!(!mgr.volume_overlay_active_at(until), "overlay expires at the deadline");
+
+    // drawn over the clock: the box outline is centred on the panel
+    mgr.set_display_mode(DisplayMode::Clock);
+    mgr.render().unwrap();
+    let mock = capture(&mgr, "volume_overlay_128x64");
+    assert_eq!(mock.get_pixel(16, 19), Some(BinaryColor::On));
+    assert_eq!(mock.get_pixel(111, 44), Some(BinaryColor::On));
+}
+
+#[test]
+fn locale_switches_the_no_weather_message() {
+    use lymons::translate::Locale;
+
+    let mut mgr = manager(128, 64);
+    mgr.set_display_mode(DisplayMode::WeatherCurrent);
+    mgr.render().unwrap();
+    let english = capture(&mgr, "no_weather_en_128x64").last_frame().to_vec();
+
+    mgr.set_locale(Locale::De);
+    mgr.render().unwrap();
+    let mock = capture(&mgr, "no_weather_de_128x64");
+    assert!(mock.count_on_pixels() > 0);
+    assert_ne!(mock.last_frame(), &english[..], "German message draws differently");
+
+    mgr.set_locale(Locale::En);
+    mgr.render().unwrap();
+    assert_eq!(capture(&mgr, "no_weather_en_again_128x64").last_frame(), &english[..]);
+}
+
+#[tokio::test]
+async fn qr_code_overlays_the_playing_modes() {
+    use embedded_graphics::pixelcolor::BinaryColor;
+    use lymons::display::components::scrollers::TrackInfo;
+    use lymons::display::manager::QR_OVERLAY_HOLD;
+    use std::time::{Duration, Instant};
+
+    let mut mgr = manager(128, 64);
+    mgr.set_qr_code(Some("https://example.org/?q={artist}+{title}".into()), None);
+    // nothing playing - nothing to share
+    mgr.show_qr_at(Instant::now());
+    assert!(!mgr.qr_overlay_active_at(Instant::now()));
+
+    let track = TrackInfo { title: "So What".into(), artist: "Miles Davis".into(), ..Default::default() };
+    mgr.set_track_details(track, "", "", "", 9000, "").await;
+    assert_eq!(mgr.qr_text(), "https://example.org/?q=Miles%20Davis+So%20What");
+
+    let now = Instant::now();
+    mgr.show_qr_at(now);
+    assert!(mgr.qr_overlay_active_at(now + QR_OVERLAY_HOLD - Duration::from_millis(1)));
+    assert!(!mgr.qr_overlay_active_at(now + QR_OVERLAY_HOLD));
+
+    mgr.set_display_mode(DisplayMode::BigTitle);
+    mgr.render().unwrap();
+    let mock = capture(&mgr, "qr_overlay_128x64");
+    // 46 bytes is a version 3 code, 29 modules and the quiet zone centred at 1px:
+    // lit quiet zone, dark finder corner
+    assert_eq!(mock.get_pixel(45, 13), Some(BinaryColor::On));
+    assert_eq!(mock.get_pixel(49, 17), Some(BinaryColor::Off));
+    assert_eq!(mock.get_pixel(44, 13), Some(BinaryColor::Off));
+}
+
+#[tokio::test]
+async fn timed_qr_code_follows_the_time_source() {
+    use chrono::TimeZone;
+    use lymons::display::components::scrollers::TrackInfo;
+    use lymons::display::manager::QR_OVERLAY_HOLD;
+    use lymons::display::time_source::{MockTimeSource, TimeSource};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let time = Arc::new(MockTimeSource::new(chrono::Local.with_ymd_and_hms(2026, 1, 15, 20, 0, 0).unwrap()));
+    let mut mgr = manager(128, 64);
+    mgr.set_time_source(time.clone());
+    mgr.set_qr_code(None, Some(Duration::from_secs(5 * 60)));
+    let track = TrackInfo { title: "So What".into(), artist: "Miles Davis".into(), ..Default::default() };
+    mgr.set_track_details(track, "", "", "", 9000, "").await;
+    mgr.set_display_mode(DisplayMode::BigTitle);
+
+    mgr.render().unwrap();
+    assert!(mgr.qr_overlay_active_at(time.instant()), "first playing frame shows the code");
+
+    time.advance(QR_OVERLAY_HOLD);
+    mgr.render().unwrap();
+    assert!(!mgr.qr_overlay_active_at(time.instant()));
+
+    time.advance(Duration::from_secs(5 * 60) - QR_OVERLAY_HOLD);
+    mgr.render().unwrap();
+    assert!(mgr.qr_overlay_active_at(time.instant()), "back after the interval");
+}
+
+#[test]
+fn time_toggle_flips_total_and_remaining() {
+    let mut mgr = manager(128, 64);
+    mgr.set_track_progress_data(false, 245.0, 60.0, 185.0, "play".into());
+    assert_eq!(mgr.end_time_text(), "4:05");
+
+    assert!(mgr.toggle_show_remaining());
+    assert_eq!(mgr.end_time_text(), "-3:05");
+
+    assert!(!mgr.toggle_show_remaining());
+    assert_eq!(mgr.end_time_text(), "4:05");
+}
+
+#[test]
+fn watchdog_reinits_a_wedged_bus() {
+    use lymons::display::BusWatchdog;
+    use std::time::Duration;
+
+    let driver = MockDriver::new_with_size(128, 64).unwrap();
+    let state = driver.state();
+    let mut mgr = DisplayManager::new_with_driver(Box::new(driver), 0, "cylon", "7seg", false, "none", "classic").unwrap();
+    mgr.set_bus_watchdog(BusWatchdog::new(3, Duration::from_secs(60)));
+    mgr.set_display_mode(DisplayMode::Clock);
+    mgr.render().unwrap();
+    let inits = state.lock().unwrap().init_count;
+
+    // the bus wedges: frames are lost until the third in a row trips the watchdog
+    state.lock().unwrap().simulate_wedged_bus = true;
+    assert!(mgr.render().is_err());
+    assert!(mgr.render().is_err());
+    assert_eq!(state.lock().unwrap().init_count, inits);
+
+    mgr.render().expect("re-init brings the bus back and the frame goes through");
+    let state = state.lock().unwrap();
+    assert_eq!(state.init_count, inits + 1);
+    assert!(!state.simulate_wedged_bus);
+}
+
+#[tokio::test(start_paused = true)]
+async fn transient_bus_errors_are_retried_after_a_backoff() {
+    use std::time::Duration;
+
+    let driver = MockDriver::new_with_size(128, 64).unwrap();
+    let state = driver.state();
+    let mut mgr = DisplayManager::new_with_driver(Box::new(driver), 0, "cylon", "7seg", false, "none", "classic").unwrap();
+    mgr.set_display_mode(DisplayMode::Clock);
+
+    // two lost transfers, then the frame goes through after 2 ms + 4 ms of sleep
+    state.lock().unwrap().simulate_transient_flushes = 2;
+    let start = tokio::time::Instant::now();
+    mgr.render_frame().await.unwrap();
+    assert!(start.elapsed() >= Duration::from_millis(6));
+    assert_eq!(state.lock().unwrap().simulate_transient_flushes, 0);
+
+    // the sync path gets a single attempt
+    state.lock().unwrap().simulate_transient_flushes = 1;
+    assert!(mgr.render().unwrap_err().is_transient());
+    mgr.render().unwrap();
+}
+
+#[test]
+fn seconds_bar_fill_follows_the_time_source() {
+    use chrono::TimeZone;
+    use embedded_graphics::pixelcolor::BinaryColor;
+    use lymons::display::time_source::MockTimeSource;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let time = Arc::new(MockTimeSource::new(chrono::Local.with_ymd_and_hms(2026, 1, 15, 21, 45, 30).unwrap()));
+    let mut mgr = manager(128, 64);
+    mgr.set_time_source(time.clone());
+    mgr.set_display_mode(DisplayMode::Clock);
+
+    // 128x64 bar: outline at x 2..126, y 51..54 - inner row 52 is 122 px from x 3
+    let lit = |mgr: &DisplayManager, x| capture(mgr, "seconds_bar_128x64").get_pixel(x, 52) == Some(BinaryColor::On);
+    mgr.render().unwrap();
+    assert!(lit(&mgr, 63) && !lit(&mgr, 64), "half a minute fills 61 px");
+
+    time.advance(Duration::from_secs(15));
+    mgr.render().unwrap();
+    assert!(lit(&mgr, 93) && !lit(&mgr, 94), "45 s fills 91 px");
+    assert!(lit(&mgr, 125), "outline stays");
+}
+
+#[tokio::test]
+async fn two_panels_share_one_player_update() {
+    use lymons::sliminfo::{NowPlaying, SlimInfo};
+
+    let mut info = SlimInfo::default();
+    info.is_playing = true;
+    info.mode = "play".into();
+    info.artist = "Nina Simone".into();
+    info.title = "Sinnerman".into();
+    info.album = "Pastel Blues".into();
+    info.volume = 60;
+    info.duration.raw = 245.0;
+    let now = NowPlaying { info, lms_host: "127.0.0.1".into(), lms_port: 9000, player_mac: String::new() };
+
+    // the scroller and a panel held on the clock, plus one that never hears from LMS
+    let (mut scroller, mut clock, mut unfed) = (manager(128, 64), manager(128, 64), manager(128, 64));
+    scroller.apply_now_playing(&now, false).await;
+    clock.apply_now_playing(&now, false).await;
+    scroller.set_display_mode(DisplayMode::Scrolling);
+    unfed.set_display_mode(DisplayMode::Scrolling);
+    clock.set_display_mode(DisplayMode::Clock);
+    for mgr in [&mut scroller, &mut clock, &mut unfed] {
+        mgr.render().unwrap();
+    }
+
+    let frame = |mgr: &DisplayManager, name| capture(mgr, name).last_frame().to_vec();
+    let scrolled = frame(&scroller, "shared_scroller_128x64");
+    assert_ne!(scrolled, frame(&unfed, "shared_unfed_128x64"), "track lines come from the shared update");
+    assert_ne!(scrolled, frame(&clock, "shared_clock_128x64"));
+    assert_eq!((clock.end_time_text(), scroller.end_time_text()), ("4:05".into(), "4:05".into()));
+}
+
+#[tokio::test]
+async fn inactivity_timeout_powers_the_panel_off_and_playback_wakes_it() {
+    use chrono::TimeZone;
+    use lymons::display::time_source::MockTimeSource;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let time = Arc::new(MockTimeSource::new(chrono::Local.with_ymd_and_hms(2026, 1, 15, 23, 10, 0).unwrap()));
+    let mut mgr = manager(128, 64);
+    mgr.set_time_source(time.clone());
+    mgr.set_inactivity_timeout(Some(Duration::from_secs(10 * 60)));
+    mgr.set_display_mode(DisplayMode::Clock);
+    let state = capture(&mgr, "inactivity").state();
+
+    mgr.render_frame().await.unwrap();
+    time.advance(Duration::from_secs(9 * 60));
+    mgr.render_idle_frame().await.unwrap();
+    assert!(state.lock().unwrap().display_on_calls.is_empty(), "nine idle minutes keep the panel on");
+
+    time.advance(Duration::from_secs(60));
+    mgr.render_idle_frame().await.unwrap();
+    mgr.render_idle_frame().await.unwrap();
+    assert_eq!(state.lock().unwrap().display_on_calls, [false], "off once, after ten minutes");
+    assert!(mgr.is_panel_off());
+
+    mgr.render_frame().await.unwrap();
+    assert_eq!(state.lock().unwrap().display_on_calls, [false, true]);
+    assert!(!mgr.is_panel_off());
+}
+
+#[tokio::test]
+async fn sleep_timer_runs_out_while_blank_and_playback_resumes_unpaused() {
+    use chrono::TimeZone;
+    use lymons::display::IdleBehavior;
+    use lymons::display::time_source::MockTimeSource;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let time = Arc::new(MockTimeSource::new(chrono::Local.with_ymd_and_hms(2026, 1, 15, 23, 30, 0).unwrap()));
+    let mut mgr = manager(128, 64);
+    mgr.set_time_source(time.clone());
+    mgr.set_idle_behavior(IdleBehavior::Blank);
+    mgr.set_display_mode(DisplayMode::Clock);
+    mgr.start_sleep_timer(Duration::from_secs(60));
+
+    // each main loop pass: tick the timer, then draw per the idle behaviour
+    mgr.update_sleep_timer();
+    mgr.render_idle_frame().await.unwrap();
+    assert!(mgr.is_idle_blanked());
+    assert!(!mgr.take_sleep_timer_expired());
+
+    time.advance(Duration::from_secs(61));
+    mgr.update_sleep_timer();
+    mgr.render_idle_frame().await.unwrap();
+    assert!(mgr.take_sleep_timer_expired(), "runs out on time with the panel blank");
+    assert!(!mgr.sleep_timer_running());
+
+    // play pressed later - no stale expiry left to pause it
+    time.advance(Duration::from_secs(600));
+    mgr.update_sleep_timer();
+    mgr.render_frame().await.unwrap();
+    assert!(!mgr.take_sleep_timer_expired());
+    assert!(!mgr.is_idle_blanked());
+}
+
+#[tokio::test]
+async fn info_line_draws_the_chosen_tokens() {
+    use embedded_graphics::mono_font::{iso_8859_13::FONT_5X8, MonoTextStyle};
+    use embedded_graphics::pixelcolor::BinaryColor;
+    use embedded_graphics::prelude::*;
+    use embedded_text::{alignment::HorizontalAlignment, style::TextBoxStyleBuilder, TextBox};
+    use lymons::config::{DisplayConfig, DriverKind};
+    use lymons::display::components::info_line::{InfoLineSlots, InfoToken};
+    use lymons::display::layout_dump;
+    use lymons::sliminfo::{NowPlaying, SlimInfo};
+
+    /// Plain pixel grid to draw the expected line into
+    struct Grid(Vec<bool>);
+    impl OriginDimensions for Grid {
+        fn size(&self) -> Size { Size::new(128, 64) }
+    }
+    impl DrawTarget for Grid {
+        type Color = BinaryColor;
+        type Error = core::convert::Infallible;
+        fn draw_iter<I: IntoIterator<Item = Pixel<BinaryColor>>>(&mut self, pixels: I) -> Result<(), Self::Error> {
+            for Pixel(p, c) in pixels {
+                if (0..128).contains(&p.x) && (0..64).contains(&p.y) {
+                    self.0[(p.y * 128 + p.x) as usize] = c.is_on();
+                }
+            }
+            Ok(())
+        }
+    }
+
+    let mut info = SlimInfo::default();
+    info.is_playing = true;
+    info.mode = "play".into();
+    info.title = "Blue in Green".into();
+    info.samplesize = 24;
+    info.samplerate = 96000;
+    info.playlist_index = 2;
+    info.playlist_tracks = 12;
+    info.duration.raw = 337.0;
+    info.tracktime.raw = 60.0;
+    info.remaining.raw = 277.0;
+    let now = NowPlaying { info, lms_host: "127.0.0.1".into(), lms_port: 9000, player_mac: String::new() };
+
+    let mut mgr = manager(128, 64);
+    mgr.set_info_line(InfoLineSlots::from_tokens(&[InfoToken::PlaylistPos, InfoToken::Bitrate, InfoToken::Remaining]).unwrap());
+    mgr.apply_now_playing(&now, false).await;
+    mgr.set_display_mode(DisplayMode::Scrolling);
+    mgr.render().unwrap();
+    let mock = capture(&mgr, "info_line_tokens_128x64");
+
+    // the same three boxes the scroller page draws, filled with the expected text
+    let config = DisplayConfig { driver: Some(DriverKind::Ssd1306), width: Some(128), height: Some(64), ..Default::default() };
+    let page = layout_dump::build_page(&layout_dump::layout_manager(&config, None), "scrolling").unwrap();
+    let field = page.get_field("info_line").unwrap();
+    let font = field.font.unwrap_or(&FONT_5X8);
+    let style = MonoTextStyle::new(font, BinaryColor::On);
+    let fixed = 12 * font.character_size.width;
+    let mut line = field.bounds;
+    line.size.height += 1;
+    let mut left = line;
+    left.size.width = fixed;
+    let mut right = line;
+    right.top_left.x = (line.size.width - fixed) as i32;
+    right.size.width = fixed;
+
+    let mut expected = Grid(vec![false; 128 * 64]);
+    for (text, rect, align) in [
+        ("-4:37", right, HorizontalAlignment::Right),
+        ("24/96", line, HorizontalAlignment::Center),
+        ("3/12", left, HorizontalAlignment::Left),
+    ] {
+        let tb = TextBoxStyleBuilder::new().alignment(align).build();
+        TextBox::with_textbox_style(text, rect, style, tb).draw(&mut expected).unwrap();
+    }
+
+    let rows = line.top_left.y..(line.top_left.y + line.size.height as i32).min(64);
+    assert!(rows.clone().any(|y| (0..128).any(|x| expected.0[(y * 128 + x) as usize])));
+    for y in rows {
+        for x in 0..128 {
+            assert_eq!(
+                mock.get_pixel(x as u32, y as u32) == Some(BinaryColor::On),
+                expected.0[(y * 128 + x) as usize],
+                "info line pixel {x},{y}"
+            );
+        }
+    }
+}